@@ -28,6 +28,14 @@ macro_rules! enum_file {
             writeln!(enums_priv, "using slint::PointerEventButton;")?;
             &mut enums_pub
         }};
+        (PhysicalKey) => {{
+            writeln!(enums_priv, "using slint::PhysicalKey;")?;
+            &mut enums_pub
+        }};
+        (KeyLocation) => {{
+            writeln!(enums_priv, "using slint::KeyLocation;")?;
+            &mut enums_pub
+        }};
         (AccessibleRole) => {{
             writeln!(enums_priv, "using slint::testing::AccessibleRole;")?;
             &mut enums_pub
@@ -119,6 +127,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     writeln!(structs_priv, "// This file is auto-generated from {}", file!())?;
     writeln!(structs_priv, "#include \"slint_builtin_structs.h\"")?;
     writeln!(structs_priv, "#include \"slint_enums_internal.h\"")?;
+    writeln!(structs_priv, "#include \"slint_image.h\"")?;
     writeln!(structs_priv, "namespace slint::cbindgen_private {{")?;
     writeln!(structs_priv, "enum class KeyEventType : uint8_t;")?;
     macro_rules! struct_file {
@@ -126,6 +135,10 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (TreeViewItem) => {{
+            writeln!(structs_priv, "using slint::TreeViewItem;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -220,6 +233,7 @@ fn default_config() -> cbindgen::Config {
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
+            ("StringArg".into(), "SharedString".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("MenuEntryArg".into(), "MenuEntry".into()),
@@ -304,6 +318,7 @@ fn gen_corelib(
         "Layer",
         "ContextMenu",
         "MenuItem",
+        "Shortcut",
     ];
 
     config.export.include = [
@@ -314,6 +329,7 @@ fn gen_corelib(
         "PropertyAnimation",
         "AnimationDirection",
         "EasingCurve",
+        "Keyframe",
         "TextHorizontalAlignment",
         "TextVerticalAlignment",
         "TextOverflow",
@@ -349,6 +365,7 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "TreeViewItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -382,6 +399,7 @@ fn gen_corelib(
         "PointerEventArg",
         "PointerScrollEventArg",
         "PointArg",
+        "StringArg",
         "Point",
         "MenuEntryModel",
         "MenuEntryArg",
@@ -397,6 +415,7 @@ fn gen_corelib(
         "slint_image_load_from_path",
         "slint_image_load_from_embedded_data",
         "slint_image_from_embedded_textures",
+        "slint_image_from_static_animation_frames",
         "slint_image_compare_equal",
         "slint_image_set_nine_slice_edges",
         "slint_image_to_rgb8",
@@ -455,6 +474,8 @@ fn gen_corelib(
 
     let mut properties_config = config.clone();
     properties_config.export.exclude.clear();
+    // Keyframe needs EasingCurve, so it is generated in the main header
+    properties_config.export.exclude.push("Keyframe".into());
     properties_config.structure.derive_eq = true;
     properties_config.structure.derive_neq = true;
     private_exported_types.extend(properties_config.export.include.iter().cloned());
@@ -511,6 +532,7 @@ fn gen_corelib(
                 "slint_image_load_from_path",
                 "slint_image_load_from_embedded_data",
                 "slint_image_from_embedded_textures",
+                "slint_image_from_static_animation_frames",
                 "slint_image_compare_equal",
                 "slint_image_set_nine_slice_edges",
                 "slint_image_to_rgb8",
@@ -519,11 +541,12 @@ fn gen_corelib(
                 "SharedPixelBuffer",
                 "SharedImageBuffer",
                 "StaticTextures",
+                "StaticAnimationFrame",
                 "BorrowedOpenGLTextureOrigin"
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; } }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; namespace types{ struct NineSliceImage{}; struct AnimatedImage{}; } }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker",
@@ -568,6 +591,8 @@ fn gen_corelib(
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
             "slint_windowrc_close_popup",
+            "slint_windowrc_show_secondary_window",
+            "slint_windowrc_close_secondary_window",
             "slint_windowrc_set_rendering_notifier",
             "slint_windowrc_request_redraw",
             "slint_windowrc_on_close_requested",
@@ -609,6 +634,7 @@ fn gen_corelib(
             "slint_image_to_rgba8",
             "slint_image_to_rgba8_premultiplied",
             "slint_image_from_embedded_textures",
+            "slint_image_from_static_animation_frames",
             "slint_image_compare_equal",
         ]
         .iter()
@@ -721,7 +747,8 @@ fn gen_corelib(
     );
     config.export.body.insert(
         "EasingCurve".to_owned(),
-        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr EasingCurve(Spring_Body s) : tag(Tag::Spring), spring(s) {}".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -740,6 +767,28 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("ImageItem".to_owned(), "    inline ImageItem(); inline ~ImageItem();".into());
+    config.export.body.insert(
+        "ClippedImage".to_owned(),
+        "    inline ClippedImage(); inline ~ClippedImage();".into(),
+    );
+    config.export.pre_body.insert("ImageDataBox".to_owned(), "struct ImageData;".into());
+    config
+        .export
+        .body
+        .insert("Shortcut".to_owned(), "    inline Shortcut(); inline ~Shortcut();".into());
+    config.export.pre_body.insert("ShortcutDataBox".to_owned(), "struct ShortcutData;".into());
+    config.export.body.insert(
+        "ComplexText".to_owned(),
+        "    inline ComplexText(); inline ~ComplexText();".into(),
+    );
+    config
+        .export
+        .pre_body
+        .insert("ComplexTextDataBox".to_owned(), "struct ComplexTextData;".into());
 
     cbindgen::Builder::new()
         .with_config(config)
@@ -444,6 +444,10 @@ inline Value::Value(const std::shared_ptr<slint::Model<Value>> &model)
         {
             cbindgen_private::slint_interpreter_model_notify_row_removed(&notify, index, count);
         }
+        void row_moved(size_t from, size_t count, size_t to) override
+        {
+            cbindgen_private::slint_interpreter_model_notify_row_moved(&notify, from, count, to);
+        }
         void reset() override { cbindgen_private::slint_interpreter_model_notify_reset(&notify); }
     };
 
@@ -3,10 +3,12 @@
 
 #include "slint.h"
 #include "slint_testing_internal.h"
+#include <chrono>
 #include <cstdint>
 #include <optional>
 #include <string_view>
 #include <type_traits>
+#include <utility>
 
 #ifdef SLINT_FEATURE_TESTING
 #    ifdef SLINT_FEATURE_EXPERIMENTAL
@@ -28,6 +30,8 @@ inline void init()
     cbindgen_private::slint_testing_init_backend();
 }
 
+class ElementQuery;
+
 /// A handle to an element for querying accessible properties, intended for testing purposes.
 class ElementHandle
 {
@@ -35,7 +39,30 @@ class ElementHandle
 
     explicit ElementHandle(const cbindgen_private::ElementHandle *inner) : inner(*inner) { }
 
+    friend class ElementQuery;
+
+    static cbindgen_private::Slice<uint8_t> string_to_slice(std::string_view str)
+    {
+        return cbindgen_private::Slice<uint8_t> {
+            const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(str.data())),
+            str.size()
+        };
+    }
+
 public:
+    /// Returns the root element of the given component.
+    template<typename T>
+    static ElementHandle root_element(const ComponentHandle<T> &component)
+    {
+        auto vrc = component.into_dyn();
+        cbindgen_private::ElementHandle root {};
+        cbindgen_private::slint_testing_element_root(&vrc, &root);
+        return ElementHandle(&root);
+    }
+
+    /// Creates a new ElementQuery to match any descendants of this element.
+    ElementQuery query_descendants() const;
+
     /// Visits visible elements within a component and calls the visitor for each of them.
     ///
     /// The visitor must be a callable object that accepts an `ElementHandle` and returns either
@@ -144,6 +171,26 @@ public:
     /// Returns true if the underlying element still exists; false otherwise.
     bool is_valid() const { return private_api::upgrade_item_weak(inner.item).has_value(); }
 
+    /// Returns true if the element is valid and visible from the root of the window, i.e. it is
+    /// not hidden and not clipped away by any of its parents; false otherwise.
+    bool is_visible() const { return cbindgen_private::slint_testing_element_is_visible(&inner); }
+
+    /// Advances the mock time until the element is visible or the timeout has elapsed.
+    /// Returns true if the element became visible; false on timeout.
+    bool wait_until_visible(std::chrono::milliseconds timeout) const
+    {
+        return cbindgen_private::slint_testing_element_wait_until_visible(&inner, timeout.count());
+    }
+
+    /// Advances the mock time until the accessible-value of the element is equal to `value` or the
+    /// timeout has elapsed. Returns true if the value was reached; false on timeout.
+    bool wait_for_accessible_value(std::string_view value, std::chrono::milliseconds timeout) const
+    {
+        auto value_view = ElementHandle::string_to_slice(value);
+        return cbindgen_private::slint_testing_element_wait_for_accessible_value(
+                &inner, &value_view, timeout.count());
+    }
+
     /// Returns the element's qualified id. Returns None if the element is not valid anymore or the
     /// element does not have an id.
     /// A qualified id consists of the name of the surrounding component as well as the provided
@@ -500,6 +547,34 @@ public:
         return LogicalPosition({ 0, 0 });
     }
 
+    /// Returns the opacity that is applied when rendering this element. This is the product of
+    /// the opacity property multiplied with any opacity specified by parent elements. Returns zero
+    /// if the element is not valid.
+    float computed_opacity() const
+    {
+        return cbindgen_private::slint_testing_element_computed_opacity(&inner);
+    }
+
+    /// Simulates a single click (or touch tap) on the element at its center point with the
+    /// specified button. The mock time advances between the press and the release.
+    ///
+    /// Unlike the asynchronous Rust version, this function returns only after the release was
+    /// dispatched. It doesn't wait in real time and doesn't run the event loop, since the testing
+    /// backend initialized with init() always uses mock time.
+    void single_click(PointerEventButton button = PointerEventButton::Left) const
+    {
+        cbindgen_private::slint_testing_element_click(&inner, button, false);
+    }
+
+    /// Simulates a double click (or touch tap) on the element at its center point with the
+    /// specified button. The mock time advances between the presses and the releases.
+    ///
+    /// Like single_click(), this function is synchronous and only advances the mock time.
+    void double_click(PointerEventButton button = PointerEventButton::Left) const
+    {
+        cbindgen_private::slint_testing_element_click(&inner, button, true);
+    }
+
 private:
     std::optional<SharedString>
     get_accessible_string_property(cbindgen_private::AccessibleStringProperty what) const
@@ -528,6 +603,190 @@ private:
         return std::nullopt;
     }
 };
+
+/// Use ElementQuery to form a query into the tree of UI elements and then locate one or multiple
+/// matching elements.
+///
+/// ElementQuery uses the builder pattern to concatenate criteria, such as searching for
+/// descendants, or matching elements only with a certain id.
+///
+/// Construct an instance of this by calling ElementQuery::from_root() or
+/// ElementHandle::query_descendants(). Apply additional criteria on the returned ElementQuery and
+/// fetch results by either calling find_first() to collect just the first match or find_all() to
+/// collect all matches for the query.
+///
+/// ```cpp
+/// auto label = slint::testing::ElementQuery::from_root(component)
+///                 .match_id("App::form")
+///                 .match_descendants()
+///                 .match_accessible_role(slint::testing::AccessibleRole::Text)
+///                 .find_first();
+/// ```
+class ElementQuery
+{
+    cbindgen_private::ElementQuery *inner;
+
+    explicit ElementQuery(cbindgen_private::ElementQuery *inner) : inner(inner) { }
+
+    friend class ElementHandle;
+
+public:
+    /// Creates a new element query starting at the root of the component and matching all
+    /// descendants.
+    template<typename T>
+    static ElementQuery from_root(const ComponentHandle<T> &component)
+    {
+        return ElementHandle::root_element(component).query_descendants();
+    }
+
+    ElementQuery(const ElementQuery &) = delete;
+    ElementQuery &operator=(const ElementQuery &) = delete;
+    /// Move-constructs a query from `other`, which is left empty.
+    ElementQuery(ElementQuery &&other) : inner(std::exchange(other.inner, nullptr)) { }
+    /// Move-assigns `other` to this query.
+    ElementQuery &operator=(ElementQuery &&other)
+    {
+        std::swap(inner, other.inner);
+        return *this;
+    }
+    ~ElementQuery()
+    {
+        if (inner) {
+            cbindgen_private::slint_testing_element_query_drop(inner);
+        }
+    }
+
+    /// Applies any subsequent matches to all descendants of the results of the query up to this
+    /// point.
+    ElementQuery &match_descendants() &
+    {
+        inner = cbindgen_private::slint_testing_element_query_match_descendants(inner);
+        return *this;
+    }
+    /// \copydoc match_descendants()
+    ElementQuery &&match_descendants() && { return std::move(match_descendants()); }
+
+    /// Include only elements in the results where ElementHandle::id() is equal to the provided
+    /// `id`.
+    ElementQuery &match_id(std::string_view id) &
+    {
+        auto id_view = ElementHandle::string_to_slice(id);
+        inner = cbindgen_private::slint_testing_element_query_match_id(inner, &id_view);
+        return *this;
+    }
+    /// \copydoc match_id()
+    ElementQuery &&match_id(std::string_view id) && { return std::move(match_id(id)); }
+
+    /// Include only elements in the results where ElementHandle::type_name() is equal to the
+    /// provided `type_name`.
+    ElementQuery &match_type_name(std::string_view type_name) &
+    {
+        auto type_name_view = ElementHandle::string_to_slice(type_name);
+        inner = cbindgen_private::slint_testing_element_query_match_type_name(inner,
+                                                                               &type_name_view);
+        return *this;
+    }
+    /// \copydoc match_type_name()
+    ElementQuery &&match_type_name(std::string_view type_name) &&
+    {
+        return std::move(match_type_name(type_name));
+    }
+
+    /// Include only elements in the results where ElementHandle::type_name() or
+    /// ElementHandle::bases() contains the provided `type_name`.
+    ElementQuery &match_inherits(std::string_view type_name) &
+    {
+        auto type_name_view = ElementHandle::string_to_slice(type_name);
+        inner = cbindgen_private::slint_testing_element_query_match_inherits(inner,
+                                                                              &type_name_view);
+        return *this;
+    }
+    /// \copydoc match_inherits()
+    ElementQuery &&match_inherits(std::string_view type_name) &&
+    {
+        return std::move(match_inherits(type_name));
+    }
+
+    /// Include only elements in the results where ElementHandle::accessible_role() is equal to the
+    /// provided `role`.
+    ElementQuery &match_accessible_role(AccessibleRole role) &
+    {
+        inner = cbindgen_private::slint_testing_element_query_match_accessible_role(inner, role);
+        return *this;
+    }
+    /// \copydoc match_accessible_role()
+    ElementQuery &&match_accessible_role(AccessibleRole role) &&
+    {
+        return std::move(match_accessible_role(role));
+    }
+
+    /// Include only elements in the results for which the `predicate` returns true. The predicate
+    /// must be a callable object that accepts an `ElementHandle` and returns `bool`.
+    template<std::invocable<ElementHandle> Predicate>
+    ElementQuery &match_predicate(Predicate predicate) &
+    {
+        inner = cbindgen_private::slint_testing_element_query_match_predicate(
+                inner, new Predicate(std::move(predicate)),
+                [](void *predicate, const cbindgen_private::ElementHandle *element) -> bool {
+                    return (*reinterpret_cast<Predicate *>(predicate))(ElementHandle(element));
+                },
+                [](void *predicate) { delete reinterpret_cast<Predicate *>(predicate); });
+        return *this;
+    }
+    /// \copydoc match_predicate()
+    template<std::invocable<ElementHandle> Predicate>
+    ElementQuery &&match_predicate(Predicate predicate) &&
+    {
+        return std::move(match_predicate(std::move(predicate)));
+    }
+
+    /// Runs the query and returns the first result; returns std::nullopt if no element matches
+    /// the selected criteria.
+    std::optional<ElementHandle> find_first() const
+    {
+        cbindgen_private::ElementHandle result {};
+        if (cbindgen_private::slint_testing_element_query_find_first(inner, &result)) {
+            return ElementHandle(&result);
+        }
+        return std::nullopt;
+    }
+
+    /// Runs the query and returns a vector of all matching elements.
+    SharedVector<ElementHandle> find_all() const
+    {
+        SharedVector<ElementHandle> result;
+        cbindgen_private::slint_testing_element_query_find_all(
+                inner, reinterpret_cast<SharedVector<cbindgen_private::ElementHandle> *>(&result));
+        return result;
+    }
+
+    /// Runs the query repeatedly while advancing the mock time, until an element matches or the
+    /// `timeout` has elapsed. Returns the first matching element, or std::nullopt on timeout.
+    std::optional<ElementHandle> wait_for_first(std::chrono::milliseconds timeout) const
+    {
+        cbindgen_private::ElementHandle result {};
+        if (cbindgen_private::slint_testing_element_query_wait_for_first(inner, timeout.count(),
+                                                                         &result)) {
+            return ElementHandle(&result);
+        }
+        return std::nullopt;
+    }
+};
+
+inline ElementQuery ElementHandle::query_descendants() const
+{
+    return ElementQuery(cbindgen_private::slint_testing_element_query_descendants(&inner));
+}
+
+/// Advances the mock time until no animations are running and no timers are pending in the
+/// component's window, or the `timeout` has elapsed. Returns true if the UI became idle; false on
+/// timeout.
+template<typename T>
+bool wait_until_idle(const ComponentHandle<T> &component, std::chrono::milliseconds timeout)
+{
+    auto vrc = component.into_dyn();
+    return cbindgen_private::slint_testing_wait_until_idle(&vrc, timeout.count());
+}
 }
 
 #    endif // SLINT_FEATURE_EXPERIMENTAL
@@ -110,6 +110,8 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
         return false;
     } else if (a.tag == EasingCurve::Tag::CubicBezier) {
         return std::equal(a.cubic_bezier._0, a.cubic_bezier._0 + 4, b.cubic_bezier._0);
+    } else if (a.tag == EasingCurve::Tag::Spring) {
+        return std::equal(a.spring._0, a.spring._0 + 3, b.spring._0);
     }
     return true;
 }
@@ -164,6 +166,47 @@ box_layout_info_ortho(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellDat
     return cbindgen_private::slint_box_layout_info_ortho(cells, &padding);
 }
 
+inline SharedVector<float> solve_flex_layout(const cbindgen_private::FlexLayoutData &data,
+                                             cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flex_layout(&data, ri, &result);
+    return result;
+}
+
+inline SharedVector<float>
+solve_flex_layout_ortho(const cbindgen_private::FlexLayoutData &data,
+                        cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cross_cells,
+                        float cross_size, float cross_spacing,
+                        const cbindgen_private::Padding &cross_padding,
+                        cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flex_layout_ortho(&data, cross_cells, cross_size, cross_spacing,
+                                                    &cross_padding, ri, &result);
+    return result;
+}
+
+inline cbindgen_private::LayoutInfo
+flex_layout_info(cbindgen_private::Slice<cbindgen_private::FlexLayoutCellData> cells,
+                 float spacing, const cbindgen_private::Padding &padding, bool wrap)
+{
+    return cbindgen_private::slint_flex_layout_info(cells, spacing, &padding, wrap);
+}
+
+inline cbindgen_private::LayoutInfo
+flex_layout_info_ortho(const cbindgen_private::FlexLayoutData &data,
+                       cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cross_cells,
+                       float cross_spacing, const cbindgen_private::Padding &cross_padding)
+{
+    return cbindgen_private::slint_flex_layout_info_ortho(&data, cross_cells, cross_spacing,
+                                                          &cross_padding);
+}
+
 /// Access the layout cache of an item within a repeater
 inline float layout_cache_access(const SharedVector<float> &cache, int offset, int repeater_index)
 {
@@ -303,6 +346,42 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::ImageItem::ImageItem()
+{
+    slint_image_data_init(&data);
+}
+cbindgen_private::ImageItem::~ImageItem()
+{
+    slint_image_data_free(&data);
+}
+
+cbindgen_private::ClippedImage::ClippedImage()
+{
+    slint_image_data_init(&data);
+}
+cbindgen_private::ClippedImage::~ClippedImage()
+{
+    slint_image_data_free(&data);
+}
+
+cbindgen_private::Shortcut::Shortcut()
+{
+    slint_shortcut_data_init(&data);
+}
+cbindgen_private::Shortcut::~Shortcut()
+{
+    slint_shortcut_data_free(&data);
+}
+
+cbindgen_private::ComplexText::ComplexText()
+{
+    slint_complex_text_data_init(&data);
+}
+cbindgen_private::ComplexText::~ComplexText()
+{
+    slint_complex_text_data_free(&data);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
@@ -283,13 +283,13 @@ inline Brush Brush::with_alpha(float alpha) const
 
 namespace private_api {
 
-template<>
-inline void Property<slint::Brush>::set_animated_value(
-        const slint::Brush &new_value,
-        const cbindgen_private::PropertyAnimation &animation_data) const
+inline void slint_property_set_animated_value_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, const Brush &from, const Brush &to,
+        const cbindgen_private::PropertyAnimation *animation_data,
+        const cbindgen_private::Keyframe<Brush> *keyframes, uintptr_t keyframe_count)
 {
-    cbindgen_private::slint_property_set_animated_value_brush(&inner, &value, &new_value,
-                                                              &animation_data);
+    cbindgen_private::slint_property_set_animated_value_brush(handle, &from, &to, animation_data,
+                                                              keyframes, keyframe_count);
 }
 
 } // namespace private_api
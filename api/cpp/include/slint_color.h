@@ -287,13 +287,13 @@ RgbaColor<float> Color::to_argb_float() const
 
 namespace private_api {
 
-template<>
-inline void
-Property<Color>::set_animated_value(const Color &new_value,
-                                    const cbindgen_private::PropertyAnimation &animation_data) const
+inline void slint_property_set_animated_value_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, const Color &from, const Color &to,
+        const cbindgen_private::PropertyAnimation *animation_data,
+        const cbindgen_private::Keyframe<Color> *keyframes, uintptr_t keyframe_count)
 {
-    cbindgen_private::slint_property_set_animated_value_color(&inner, value, new_value,
-                                                              &animation_data);
+    cbindgen_private::slint_property_set_animated_value_color(handle, from, to, animation_data,
+                                                              keyframes, keyframe_count);
 }
 
 } // namespace private_api
@@ -299,6 +299,18 @@ inline Image image_from_embedded_textures(const cbindgen_private::types::StaticT
     cbindgen_private::types::slint_image_from_embedded_textures(textures, &img);
     return Image(img);
 }
+
+inline Image image_from_static_animation_frames(
+        std::span<const cbindgen_private::types::StaticAnimationFrame> frames)
+{
+    cbindgen_private::types::Image img(cbindgen_private::types::Image::ImageInner_None());
+    cbindgen_private::types::slint_image_from_static_animation_frames(
+            slint::cbindgen_private::Slice<cbindgen_private::types::StaticAnimationFrame> {
+                    const_cast<cbindgen_private::types::StaticAnimationFrame *>(frames.data()),
+                    frames.size() },
+            &img);
+    return Image(img);
+}
 }
 
 }
@@ -6,9 +6,11 @@
 #include "slint_item_tree.h"
 
 #include <algorithm>
+#include <cmath>
 #include <functional>
 #include <memory>
 #include <optional>
+#include <utility>
 
 namespace slint {
 
@@ -20,6 +22,12 @@ struct ModelChangeListener
     virtual void row_added(size_t index, size_t count) = 0;
     virtual void row_removed(size_t index, size_t count) = 0;
     virtual void row_changed(size_t index) = 0;
+    /// The default implementation reports the move as a removal followed by an insertion.
+    virtual void row_moved(size_t from, size_t count, size_t to)
+    {
+        row_removed(from, count);
+        row_added(to, count);
+    }
     virtual void reset() = 0;
 };
 using ModelPeer = std::weak_ptr<ModelChangeListener>;
@@ -60,8 +68,9 @@ long int model_length(const std::shared_ptr<M> &model)
 ///
 /// An implementation of the Model can provide data to slint by re-implementing the `row_count` and
 /// `row_data` functions. It is the responsibility of the Model implementation to call the
-/// `Model::notify_row_changed()`, `Model::notify_row_added()`, `Model::notify_row_removed()`, or
-/// `Model::notify_reset()` functions when the underlying data changes.
+/// `Model::notify_row_changed()`, `Model::notify_row_added()`, `Model::notify_row_removed()`,
+/// `Model::notify_row_moved()`, or `Model::notify_reset()` functions when the underlying data
+/// changes.
 ///
 /// Note that the Model is not thread-safe. All Model operations need to be done in the main thread.
 /// If you need to update the model data from another thread, use the
@@ -159,6 +168,20 @@ protected:
         model_row_data_dirty_property.mark_dirty();
         for_each_peers([=](auto peer) { peer->row_removed(index, count); });
     }
+    /// Notify the views that `count` rows starting at `from` were moved, so that the first of
+    /// them is now at index `to`.
+    ///
+    /// Your model implementation should call this function after the rows were moved.
+    void notify_row_moved(size_t from, size_t count, size_t to)
+    {
+        private_api::assert_main_thread();
+        if (count == 0 || from == to) {
+            return;
+        }
+        tracked_rows.clear();
+        model_row_data_dirty_property.mark_dirty();
+        for_each_peers([=](auto peer) { peer->row_moved(from, count, to); });
+    }
 
     /// Notify the views that the model has been changed and that everything needs to be reloaded
     ///
@@ -314,6 +337,17 @@ public:
         this->notify_row_added(index, 1);
     }
 
+    /// Moves the row at index `from` so that it ends up at index `to`
+    void move_row(size_t from, size_t to)
+    {
+        if (from < to) {
+            std::rotate(data.begin() + from, data.begin() + from + 1, data.begin() + to + 1);
+        } else if (to < from) {
+            std::rotate(data.begin() + to, data.begin() + from, data.begin() + from + 1);
+        }
+        this->notify_row_moved(from, 1, to);
+    }
+
     /// Erases all rows from the VectorModel.
     void clear()
     {
@@ -885,6 +919,58 @@ private:
 
 namespace private_api {
 
+/// The instances of a Repeater or a Conditional that were removed from the model, but are kept
+/// alive while they play their exit transition.
+template<typename C>
+class LeavingInstances
+{
+    std::vector<ComponentHandle<C>> instances;
+
+public:
+    /// Starts the exit transition of an instance that was removed. Instances without exit
+    /// transition are dropped right away.
+    void retire(ComponentHandle<C> instance)
+    {
+        if constexpr (requires(const C &c) { c.start_exit(); }) {
+            if (instance->start_exit()) {
+                instances.push_back(std::move(instance));
+            }
+        }
+    }
+
+    /// Drops the instances whose exit transition is over
+    void drop_finished()
+    {
+        if constexpr (requires(const C &c) { c.is_exit_done(); }) {
+            auto leaving = std::move(instances);
+            instances.clear();
+            std::erase_if(leaving, [](const auto &c) { return c->is_exit_done(); });
+            // Instances might have been retired while we were checking
+            instances.insert(instances.begin(), std::make_move_iterator(leaving.begin()),
+                             std::make_move_iterator(leaving.end()));
+        }
+    }
+
+    /// The leaving instances are rendered below the other instances, but they don't get any
+    /// input event, so they are only visited in the back to front order.
+    bool visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
+    {
+        if (order != TraversalOrder::BackToFront) {
+            return true;
+        }
+        auto leaving = instances;
+        for (const auto &c : leaving) {
+            vtable::VRef<private_api::ItemTreeVTable> ref { &C::static_vtable,
+                                                            const_cast<C *>(&(*c)) };
+            if (ref.vtable->visit_children_item(ref, -1, order, visitor)
+                != std::numeric_limits<uint64_t>::max()) {
+                return false;
+            }
+        }
+        return true;
+    }
+};
+
 template<typename C, typename ModelData>
 class Repeater
 {
@@ -899,6 +985,16 @@ class Repeater
         std::vector<RepeatedInstanceWithState> data;
         private_api::Property<bool> is_dirty { true };
         std::shared_ptr<Model<ModelData>> model;
+        std::shared_ptr<LeavingInstances<C>> leaving = std::make_shared<LeavingInstances<C>>();
+
+        void retire(std::vector<RepeatedInstanceWithState> removed)
+        {
+            for (auto &c : removed) {
+                if (c.ptr) {
+                    leaving->retire(std::move(*c.ptr));
+                }
+            }
+        }
 
         void row_added(size_t index, size_t count) override
         {
@@ -936,16 +1032,41 @@ class Repeater
                 return;
             }
             is_dirty.set(true);
+            std::vector<RepeatedInstanceWithState> removed(
+                std::make_move_iterator(data.begin() + index),
+                std::make_move_iterator(data.begin() + index + count));
             data.erase(data.begin() + index, data.begin() + index + count);
             for (std::size_t i = index; i < data.size(); ++i) {
                 // all the indexes are dirty
                 data[i].state = State::Dirty;
             }
+            retire(std::move(removed));
+        }
+        void row_moved(size_t from, size_t count, size_t to) override
+        {
+            auto begin = std::min(from, to);
+            auto end = std::max(from, to) + count;
+            if (end > data.size()) {
+                // Can happen before ensure_updated was called
+                ModelChangeListener::row_moved(from, count, to);
+                return;
+            }
+            is_dirty.set(true);
+            if (from < to) {
+                std::rotate(data.begin() + from, data.begin() + from + count,
+                            data.begin() + to + count);
+            } else {
+                std::rotate(data.begin() + to, data.begin() + from, data.begin() + from + count);
+            }
+            for (std::size_t i = begin; i < end; ++i) {
+                // the indexes of the moved range are dirty
+                data[i].state = State::Dirty;
+            }
         }
         void reset() override
         {
             is_dirty.set(true);
-            data.clear();
+            retire(std::exchange(data, {}));
         }
     };
 
@@ -972,7 +1093,11 @@ public:
             auto old_model = model.get_internal();
             auto m = model.get();
             if (!inner || old_model != m) {
-                inner = std::make_shared<RepeaterInner>();
+                auto old_inner = std::exchange(inner, std::make_shared<RepeaterInner>());
+                if (old_inner) {
+                    inner->leaving = old_inner->leaving;
+                    inner->retire(std::exchange(old_inner->data, {}));
+                }
                 if (m) {
                     inner->model = m;
                     m->attach_peer(inner);
@@ -1009,6 +1134,9 @@ public:
             // layout property tracker becomes dirty.
             model.get();
         }
+        if (inner) {
+            inner->leaving->drop_finished();
+        }
     }
 
     template<typename Parent>
@@ -1016,17 +1144,103 @@ public:
                                  const private_api::Property<float> *viewport_width,
                                  const private_api::Property<float> *viewport_height,
                                  const private_api::Property<float> *viewport_y,
-                                 float listview_width, [[maybe_unused]] float listview_height) const
+                                 float listview_width, float listview_height,
+                                 const private_api::Property<int> *requested_row) const
     {
         // TODO: the rust code in model.rs try to only allocate as many items as visible items
         ensure_updated(parent);
 
-        float h = compute_layout_listview(viewport_width, listview_width, viewport_y->get());
+        float vp_y = viewport_y->get();
+        float h = compute_layout_listview(viewport_width, listview_width, vp_y);
         viewport_height->set(h);
+
+        if (auto row = take_requested_row(requested_row)) {
+            auto [pos, size] = item_position(private_api::Orientation::Vertical, vp_y, *row);
+            float new_vp_y = viewport_position_showing(vp_y, pos, size, listview_height);
+            if (new_vp_y != vp_y) {
+                viewport_y->set(new_vp_y);
+                compute_layout_listview(viewport_width, listview_width, new_vp_y);
+            }
+        }
+    }
+
+    template<typename Parent>
+    void ensure_updated_listview_horizontal(const Parent *parent,
+                                            const private_api::Property<float> *viewport_width,
+                                            const private_api::Property<float> *viewport_height,
+                                            const private_api::Property<float> *viewport_x,
+                                            float listview_width, float listview_height,
+                                            const private_api::Property<int> *requested_row) const
+    {
+        // Unlike the Rust runtime, the C++ repeater isn't virtualized: it instantiates all the rows
+        // of the model and lays them all out.
+        ensure_updated(parent);
+
+        auto layout = [&](float vp_x) {
+            float offset = vp_x;
+            auto vp_height = listview_height;
+            for (auto &x : inner->data) {
+                vp_height = std::max(vp_height,
+                                     (*x.ptr)->listview_layout(
+                                             private_api::Orientation::Horizontal, &offset));
+            }
+            viewport_height->set(vp_height);
+            viewport_width->set(offset - vp_x);
+        };
+        if (!inner) {
+            return;
+        }
+        float vp_x = viewport_x->get();
+        layout(vp_x);
+
+        if (auto row = take_requested_row(requested_row)) {
+            auto [pos, size] = item_position(private_api::Orientation::Horizontal, vp_x, *row);
+            float new_vp_x = viewport_position_showing(vp_x, pos, size, listview_width);
+            if (new_vp_x != vp_x) {
+                viewport_x->set(new_vp_x);
+                layout(new_vp_x);
+            }
+        }
+    }
+
+    template<typename Parent>
+    void ensure_updated_grid(const Parent *parent,
+                             const private_api::Property<float> *viewport_width,
+                             const private_api::Property<float> *viewport_height,
+                             const private_api::Property<float> *viewport_y, float listview_width,
+                             float listview_height, float cell_width, float cell_height,
+                             const private_api::Property<int> *requested_row) const
+    {
+        // Unlike the Rust runtime, the C++ repeater isn't virtualized: it instantiates all the rows
+        // of the model and lays them all out.
+        ensure_updated(parent);
+
+        std::size_t columns = cell_width > 0
+                ? std::max<std::size_t>(1, std::size_t(std::floor(listview_width / cell_width)))
+                : 1;
+        std::size_t count = len();
+        float vp_y = viewport_y->get();
+        if (auto row = take_requested_row(requested_row)) {
+            float row_y = cell_height * float(*row / columns);
+            vp_y = viewport_position_showing(vp_y, row_y, cell_height, listview_height);
+            viewport_y->set(vp_y);
+        }
+        for (std::size_t i = 0; i < count; ++i) {
+            float x = cell_width * float(i % columns);
+            float y = cell_height * float(i / columns) + vp_y;
+            auto &c = inner->data[i];
+            (*c.ptr)->listview_layout(private_api::Orientation::Horizontal, &x);
+            (*c.ptr)->listview_layout(private_api::Orientation::Vertical, &y);
+        }
+        viewport_width->set(std::max(listview_width, cell_width * float(columns)));
+        viewport_height->set(cell_height * float((count + columns - 1) / columns));
     }
 
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
     {
+        if (!inner->leaving->visit(order, visitor)) {
+            return 0;
+        }
         for (std::size_t i = 0; i < inner->data.size(); ++i) {
             auto index = order == TraversalOrder::BackToFront ? i : inner->data.size() - 1 - i;
             auto ref = item_at(index);
@@ -1062,12 +1276,62 @@ public:
         if (!inner)
             return offset;
         for (auto &x : inner->data) {
-            vp_width = std::max(vp_width, (*x.ptr)->listview_layout(&offset));
+            vp_width = std::max(
+                    vp_width,
+                    (*x.ptr)->listview_layout(private_api::Orientation::Vertical, &offset));
         }
         viewport_width->set(vp_width);
         return offset - viewport_y;
     }
 
+    /// Returns the row requested by the ListView's `scroll-to-index()`, if it is valid, and
+    /// resets the request. `requested_row` is null if `scroll-to-index()` is never called.
+    std::optional<std::size_t>
+    take_requested_row(const private_api::Property<int> *requested_row) const
+    {
+        if (!requested_row) {
+            return {};
+        }
+        int row = requested_row->get();
+        if (row < 0) {
+            return {};
+        }
+        requested_row->set(-1);
+        if (std::size_t(row) >= len()) {
+            return {};
+        }
+        return std::size_t(row);
+    }
+
+    /// Returns the position, relative to the viewport, and the size of the item at `row`
+    /// along the orientation of the list.
+    std::pair<float, float> item_position(private_api::Orientation o, float viewport_pos,
+                                          std::size_t row) const
+    {
+        float offset = viewport_pos;
+        for (std::size_t i = 0; i < row; ++i) {
+            (*inner->data[i].ptr)->listview_layout(o, &offset);
+        }
+        float pos = offset;
+        (*inner->data[row].ptr)->listview_layout(o, &offset);
+        return { pos - viewport_pos, offset - pos };
+    }
+
+    /// Returns the viewport position that scrolls by the smallest amount such that the item
+    /// at `item_pos` with `item_size` is visible (same as in flickable.rs)
+    static float viewport_position_showing(float viewport_pos, float item_pos, float item_size,
+                                           float visible_size)
+    {
+        float top = item_pos + viewport_pos;
+        float bottom = top + item_size;
+        if (top < 0 && bottom < visible_size) {
+            return -item_pos;
+        } else if (top > 0 && bottom > visible_size) {
+            return std::max(visible_size - item_pos - item_size, -item_pos);
+        }
+        return viewport_pos;
+    }
+
     void model_set_row_data(size_t row, const ModelData &data) const
     {
         if (model.is_dirty()) {
@@ -1095,6 +1359,7 @@ class Conditional
 {
     private_api::Property<bool> model;
     mutable std::optional<ComponentHandle<C>> instance;
+    mutable LeavingInstances<C> leaving;
 
 public:
     template<typename F>
@@ -1107,15 +1372,21 @@ public:
     void ensure_updated(const Parent *parent) const
     {
         if (!model.get()) {
-            instance = std::nullopt;
+            if (auto removed = std::exchange(instance, std::nullopt)) {
+                leaving.retire(std::move(*removed));
+            }
         } else if (!instance) {
             instance = C::create(parent);
             (*instance)->init();
         }
+        leaving.drop_finished();
     }
 
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
     {
+        if (!leaving.visit(order, visitor)) {
+            return 0;
+        }
         if (instance) {
             vtable::VRef<private_api::ItemTreeVTable> ref { &C::static_vtable,
                                                             const_cast<C *>(&(**instance)) };
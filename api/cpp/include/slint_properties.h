@@ -4,9 +4,12 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <vector>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
+template<typename T>
+struct Keyframe;
 struct ChangeTracker
 {
     void *inner;
@@ -24,42 +27,88 @@ inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, int *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const cbindgen_private::Keyframe<int> *keyframes, uintptr_t keyframe_count,
+        cbindgen_private::PropertyAnimation (*transition_data)(
+                void *, uint64_t *, const cbindgen_private::Keyframe<int> **, uintptr_t *))
 {
-    cbindgen_private::slint_property_set_animated_binding_int(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_int(handle, binding, user_data,
+                                                              drop_user_data, animation_data,
+                                                              keyframes, keyframe_count,
+                                                              transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, float *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const cbindgen_private::Keyframe<float> *keyframes, uintptr_t keyframe_count,
+        cbindgen_private::PropertyAnimation (*transition_data)(
+                void *, uint64_t *, const cbindgen_private::Keyframe<float> **, uintptr_t *))
 {
-    cbindgen_private::slint_property_set_animated_binding_float(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_float(handle, binding, user_data,
+                                                              drop_user_data, animation_data,
+                                                              keyframes, keyframe_count,
+                                                              transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Color *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const cbindgen_private::Keyframe<Color> *keyframes, uintptr_t keyframe_count,
+        cbindgen_private::PropertyAnimation (*transition_data)(
+                void *, uint64_t *, const cbindgen_private::Keyframe<Color> **, uintptr_t *))
 {
-    cbindgen_private::slint_property_set_animated_binding_color(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_binding_color(handle, binding, user_data,
+                                                              drop_user_data, animation_data,
+                                                              keyframes, keyframe_count,
+                                                              transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Brush *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const cbindgen_private::Keyframe<Brush> *keyframes, uintptr_t keyframe_count,
+        cbindgen_private::PropertyAnimation (*transition_data)(
+                void *, uint64_t *, const cbindgen_private::Keyframe<Brush> **, uintptr_t *))
+{
+    cbindgen_private::slint_property_set_animated_binding_brush(handle, binding, user_data,
+                                                              drop_user_data, animation_data,
+                                                              keyframes, keyframe_count,
+                                                              transition_data);
+}
+
+inline void slint_property_set_animated_value_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, int from, int to,
+        const cbindgen_private::PropertyAnimation *animation_data,
+        const cbindgen_private::Keyframe<int> *keyframes, uintptr_t keyframe_count)
+{
+    cbindgen_private::slint_property_set_animated_value_int(handle, from, to, animation_data,
+                                                            keyframes, keyframe_count);
+}
+
+inline void slint_property_set_animated_value_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, float from, float to,
+        const cbindgen_private::PropertyAnimation *animation_data,
+        const cbindgen_private::Keyframe<float> *keyframes, uintptr_t keyframe_count)
 {
-    cbindgen_private::slint_property_set_animated_binding_brush(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+    cbindgen_private::slint_property_set_animated_value_float(handle, from, to, animation_data,
+                                                              keyframes, keyframe_count);
 }
 
+// Defined in slint_color.h
+inline void slint_property_set_animated_value_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, const Color &from, const Color &to,
+        const cbindgen_private::PropertyAnimation *animation_data,
+        const cbindgen_private::Keyframe<Color> *keyframes, uintptr_t keyframe_count);
+
+// Defined in slint_brush.h
+inline void slint_property_set_animated_value_helper(
+        const cbindgen_private::PropertyHandleOpaque *handle, const Brush &from, const Brush &to,
+        const cbindgen_private::PropertyAnimation *animation_data,
+        const cbindgen_private::Keyframe<Brush> *keyframes, uintptr_t keyframe_count);
+
 template<typename T>
 struct Property
 {
@@ -104,8 +153,25 @@ struct Property
                 nullptr, nullptr);
     }
 
-    inline void set_animated_value(const T &value,
-                                   const cbindgen_private::PropertyAnimation &animation_data) const;
+    inline void set_animated_value(const T &new_value,
+                                   const cbindgen_private::PropertyAnimation &animation_data) const
+    {
+        private_api::slint_property_set_animated_value_helper(&inner, value, new_value,
+                                                              &animation_data, nullptr, 0);
+    }
+
+    /// Like set_animated_value, but the animation goes through the given keyframes.
+    /// `keyframes` is a container of `cbindgen_private::Keyframe<T>` sorted by offset.
+    template<typename Keyframes>
+    inline void
+    set_animated_value_with_keyframes(const T &new_value,
+                                      const cbindgen_private::PropertyAnimation &animation_data,
+                                      const Keyframes &keyframes) const
+    {
+        private_api::slint_property_set_animated_value_helper(
+                &inner, value, new_value, &animation_data, keyframes.data(), keyframes.size());
+    }
+
     template<typename F>
     inline void
     set_animated_binding(F binding, const cbindgen_private::PropertyAnimation &animation_data) const
@@ -116,9 +182,26 @@ struct Property
                     *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
                 },
                 new F(binding), [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
-                &animation_data, nullptr);
+                &animation_data, nullptr, 0, nullptr);
+    }
+
+    template<typename F, typename Keyframes>
+    inline void
+    set_animated_binding_with_keyframes(F binding,
+                                        const cbindgen_private::PropertyAnimation &animation_data,
+                                        const Keyframes &keyframes) const
+    {
+        private_api::slint_property_set_animated_binding_helper(
+                &inner,
+                [](void *user_data, T *value) {
+                    *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
+                },
+                new F(binding), [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
+                &animation_data, keyframes.data(), keyframes.size(), nullptr);
     }
 
+    /// `animation` is called with the pointers to the start time and to the keyframes
+    /// of the transition to fill, and returns the animation.
     template<typename F, typename Trans>
     inline void set_animated_binding_for_transition(F binding, Trans animation) const
     {
@@ -126,6 +209,7 @@ struct Property
         {
             F binding;
             Trans animation;
+            std::vector<cbindgen_private::Keyframe<T>> keyframes;
         };
         private_api::slint_property_set_animated_binding_helper(
                 &inner,
@@ -133,10 +217,16 @@ struct Property
                     *reinterpret_cast<T *>(value) =
                             reinterpret_cast<UserData *>(user_data)->binding();
                 },
-                new UserData { binding, animation },
+                new UserData { binding, animation, {} },
                 [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); }, nullptr,
-                [](void *user_data, uint64_t *instant) {
-                    return reinterpret_cast<UserData *>(user_data)->animation(instant);
+                nullptr, 0,
+                [](void *user_data, uint64_t *instant,
+                   const cbindgen_private::Keyframe<T> **keyframes, uintptr_t *keyframe_count) {
+                    auto data = reinterpret_cast<UserData *>(user_data);
+                    auto anim = data->animation(instant, &data->keyframes);
+                    *keyframes = data->keyframes.data();
+                    *keyframe_count = data->keyframes.size();
+                    return anim;
                 });
     }
 
@@ -195,23 +285,6 @@ private:
     friend void set_state_binding(const Property<StateInfo> &property, F binding);
 };
 
-template<>
-inline void Property<int32_t>::set_animated_value(
-        const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data) const
-{
-    cbindgen_private::slint_property_set_animated_value_int(&inner, value, new_value,
-                                                            &animation_data);
-}
-
-template<>
-inline void
-Property<float>::set_animated_value(const float &new_value,
-                                    const cbindgen_private::PropertyAnimation &animation_data) const
-{
-    cbindgen_private::slint_property_set_animated_value_float(&inner, value, new_value,
-                                                              &animation_data);
-}
-
 template<typename F>
 void set_state_binding(const Property<StateInfo> &property, F binding)
 {
@@ -57,11 +57,9 @@ public:
     }
 
     template<typename Component, typename SubMenu, typename Activated>
-    void setup_native_menu_bar(Component component, SubMenu submenu, Activated activated) const
+    void setup_native_menu_bar(Component component, SubMenu submenu, Activated activated,
+                               bool no_native = false) const
     {
-        if (!supports_native_menu_bar()) {
-            return;
-        }
         struct MenuWrapper
         {
             Component component;
@@ -90,7 +88,23 @@ public:
                     },
         };
         auto instance = new MenuWrapper { component, std::move(submenu), std::move(activated) };
-        cbindgen_private::slint_windowrc_setup_native_menu_bar(&inner, &menu_vtable, instance);
+        if (!no_native && supports_native_menu_bar()) {
+            cbindgen_private::slint_windowrc_setup_native_menu_bar(&inner, &menu_vtable, instance);
+        } else {
+            cbindgen_private::slint_windowrc_setup_menubar_shortcuts(&inner, &menu_vtable,
+                                                                     instance);
+        }
+    }
+
+    void setup_menubar_shortcuts(const ItemTreeRc &menu_item_tree) const
+    {
+        vtable::VBox<cbindgen_private::MenuVTable> box {};
+        cbindgen_private::slint_menus_create_wrapper(&menu_item_tree, &box);
+        cbindgen_private::slint_windowrc_setup_menubar_shortcuts(
+                &inner, const_cast<cbindgen_private::MenuVTable *>(box.vtable), box.instance);
+        // The ownership of the VBox is transferred to slint_windowrc_setup_menubar_shortcuts
+        box.instance = nullptr;
+        box.vtable = nullptr;
     }
 
     bool text_input_focused() const { return slint_windowrc_get_text_input_focused(&inner); }
@@ -141,6 +155,31 @@ public:
         }
     }
 
+    template<typename Component, typename Parent>
+    uint32_t show_secondary_window(
+            std::optional<vtable::VRc<cbindgen_private::ItemTreeVTable, Component>> &instance,
+            const Parent *parent_component, bool transient, bool modal) const
+    {
+        bool created = !instance.has_value();
+        if (created) {
+            instance = Component::create(parent_component);
+        }
+        auto window_dyn = instance->into_dyn();
+        auto id = cbindgen_private::slint_windowrc_show_secondary_window(&inner, &window_dyn,
+                                                                         transient, modal);
+        if (created) {
+            (*instance)->user_init();
+        }
+        return id;
+    }
+
+    void close_secondary_window(uint32_t id) const
+    {
+        if (id > 0) {
+            cbindgen_private::slint_windowrc_close_secondary_window(&inner, id);
+        }
+    }
+
     template<typename Component, typename SharedGlobals, typename InitFn>
     uint32_t show_popup_menu(SharedGlobals *globals, LogicalPosition pos,
                              cbindgen_private::ItemRc context_menu_rc, InitFn init) const
@@ -459,10 +498,22 @@ public:
     ///
     /// The \a text is the unicode representation of the key.
     void dispatch_key_press_event(const SharedString &text)
+    {
+        dispatch_key_press_event(text, PhysicalKey::Unidentified, KeyLocation::Standard);
+    }
+
+    /// Dispatch a key press event to the scene, with the physical key and its location.
+    ///
+    /// Use this when you're implementing your own backend and want to forward user input events.
+    ///
+    /// The \a text is the unicode representation of the key, \a physical_key is the key
+    /// independent of the keyboard layout, and \a location its location on the keyboard.
+    void dispatch_key_press_event(const SharedString &text, PhysicalKey physical_key, KeyLocation location)
     {
         private_api::assert_main_thread();
         cbindgen_private::slint_windowrc_dispatch_key_event(
-                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, false);
+                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, false, physical_key,
+                location);
     }
 
     /// Dispatch an auto-repeated key press event to the scene.
@@ -471,10 +522,22 @@ public:
     ///
     /// The \a text is the unicode representation of the key.
     void dispatch_key_press_repeat_event(const SharedString &text)
+    {
+        dispatch_key_press_repeat_event(text, PhysicalKey::Unidentified, KeyLocation::Standard);
+    }
+
+    /// Dispatch an auto-repeated key press event to the scene, with the physical key and its location.
+    ///
+    /// Use this when you're implementing your own backend and want to forward user input events.
+    ///
+    /// The \a text is the unicode representation of the key, \a physical_key is the key
+    /// independent of the keyboard layout, and \a location its location on the keyboard.
+    void dispatch_key_press_repeat_event(const SharedString &text, PhysicalKey physical_key, KeyLocation location)
     {
         private_api::assert_main_thread();
         cbindgen_private::slint_windowrc_dispatch_key_event(
-                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, true);
+                &inner.handle(), cbindgen_private::KeyEventType::KeyPressed, &text, true, physical_key,
+                location);
     }
 
     /// Dispatch a key release event to the scene.
@@ -483,10 +546,22 @@ public:
     ///
     /// The \a text is the unicode representation of the key.
     void dispatch_key_release_event(const SharedString &text)
+    {
+        dispatch_key_release_event(text, PhysicalKey::Unidentified, KeyLocation::Standard);
+    }
+
+    /// Dispatch a key release event to the scene, with the physical key and its location.
+    ///
+    /// Use this when you're implementing your own backend and want to forward user input events.
+    ///
+    /// The \a text is the unicode representation of the key, \a physical_key is the key
+    /// independent of the keyboard layout, and \a location its location on the keyboard.
+    void dispatch_key_release_event(const SharedString &text, PhysicalKey physical_key, KeyLocation location)
     {
         private_api::assert_main_thread();
         cbindgen_private::slint_windowrc_dispatch_key_event(
-                &inner.handle(), cbindgen_private::KeyEventType::KeyReleased, &text, false);
+                &inner.handle(), cbindgen_private::KeyEventType::KeyReleased, &text, false, physical_key,
+                location);
     }
 
     /// Dispatches a pointer or mouse press event to the scene.
@@ -19,6 +19,10 @@ struct ModelObserver : public slint::private_api::ModelChangeListener
     {
         removed_rows.push_back(Range { index, count });
     }
+    void row_moved(size_t from, size_t count, size_t to) override
+    {
+        moved_rows.push_back(Move { from, count, to });
+    }
     void reset() override { model_reset = true; }
 
     void clear()
@@ -26,6 +30,7 @@ struct ModelObserver : public slint::private_api::ModelChangeListener
         added_rows.clear();
         changed_rows.clear();
         removed_rows.clear();
+        moved_rows.clear();
         model_reset = false;
     }
 
@@ -39,6 +44,16 @@ struct ModelObserver : public slint::private_api::ModelChangeListener
     std::vector<Range> added_rows;
     std::vector<size_t> changed_rows;
     std::vector<Range> removed_rows;
+
+    struct Move
+    {
+        size_t from;
+        size_t count;
+        size_t to;
+
+        bool operator==(const Move &) const = default;
+    };
+    std::vector<Move> moved_rows;
     bool model_reset = false;
 };
 
@@ -48,6 +63,12 @@ std::ostream &operator<<(std::ostream &os, const ModelObserver::Range &value)
     return os;
 }
 
+std::ostream &operator<<(std::ostream &os, const ModelObserver::Move &value)
+{
+    os << "{ from: " << value.from << "; count: " << value.count << "; to: " << value.to << " }";
+    return os;
+}
+
 SCENARIO("Filtering Model")
 {
     auto vec_model =
@@ -691,3 +712,32 @@ TEST_CASE("VectorModel clear and replace")
     std::vector<int> new_data { 5, 6, 7, 8 };
     model->set_vector(new_data);
 }
+
+TEST_CASE("VectorModel move_row")
+{
+    using namespace slint::private_api;
+
+    auto model = std::make_shared<slint::VectorModel<int>>(std::vector<int> { 0, 1, 2, 3, 4 });
+
+    auto observer = std::make_shared<ModelObserver>();
+    model->attach_peer(observer);
+
+    model->move_row(0, 3);
+    REQUIRE(model->row_data(0) == 1);
+    REQUIRE(model->row_data(3) == 0);
+    REQUIRE(model->row_data(4) == 4);
+    REQUIRE(observer->moved_rows == std::vector<ModelObserver::Move> { { 0, 1, 3 } });
+    REQUIRE(observer->added_rows.empty());
+    REQUIRE(observer->removed_rows.empty());
+    observer->clear();
+
+    model->move_row(4, 1);
+    REQUIRE(model->row_data(0) == 1);
+    REQUIRE(model->row_data(1) == 4);
+    REQUIRE(model->row_data(2) == 2);
+    REQUIRE(observer->moved_rows == std::vector<ModelObserver::Move> { { 4, 1, 1 } });
+    observer->clear();
+
+    model->move_row(2, 2);
+    REQUIRE(observer->moved_rows.empty());
+}
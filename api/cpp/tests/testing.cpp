@@ -8,11 +8,18 @@
 #include <slint-interpreter.h>
 #include <slint-testing.h>
 
+static void init_testing_backend()
+{
+    [[maybe_unused]] static bool initialized = (slint::testing::init(), true);
+}
+
 SCENARIO("ElementHandle")
 {
     using namespace slint::interpreter;
     using namespace slint;
 
+    init_testing_backend();
+
     ComponentCompiler compiler;
 
     auto result = compiler.build_from_source(
@@ -70,3 +77,111 @@ SCENARIO("ElementHandle")
         REQUIRE(*elements[1].id() == "App::second");
     }
 }
+
+SCENARIO("ElementQuery")
+{
+    using namespace slint::interpreter;
+    using namespace slint;
+    using slint::testing::AccessibleRole;
+    using slint::testing::ElementHandle;
+    using slint::testing::ElementQuery;
+
+    init_testing_backend();
+
+    ComponentCompiler compiler;
+
+    auto result = compiler.build_from_source(
+            R"(
+        component ButtonBase {
+            @children
+        }
+        component PushButton inherits ButtonBase {
+            callback clicked;
+            accessible-role: button;
+            in property <string> text <=> label.text;
+            label := Text {}
+            TouchArea { clicked => { root.clicked(); } }
+        }
+        export component App {
+            in-out property <int> click-count;
+            in-out property <bool> loaded;
+            Timer {
+                interval: 200ms;
+                running: !root.loaded;
+                triggered => { root.loaded = true; }
+            }
+            VerticalLayout {
+                first := PushButton {
+                    text: "first";
+                    clicked => { root.click-count += 1; }
+                }
+                second := PushButton { text: "second"; opacity: 0.5; }
+                if root.loaded: status := Text {
+                    accessible-role: text;
+                    accessible-value: self.text;
+                    text: "loaded";
+                }
+            }
+        }
+    )",
+            "");
+    for (auto &&x : compiler.diagnostics())
+        std::cerr << x.message << std::endl;
+    REQUIRE(result.has_value());
+    auto component_definition = *result;
+
+    auto instance = component_definition.create();
+
+    SECTION("Match by id and descendants")
+    {
+        auto label = ElementQuery::from_root(instance)
+                             .match_id("App::second")
+                             .match_descendants()
+                             .match_accessible_role(AccessibleRole::Text)
+                             .find_first();
+        REQUIRE(label.has_value());
+        REQUIRE(*label->accessible_label() == "second");
+        REQUIRE(label->computed_opacity() == 0.5);
+    }
+
+    SECTION("Match by type name and inheritance")
+    {
+        REQUIRE(ElementQuery::from_root(instance).match_type_name("PushButton").find_all().size()
+                == 2);
+        REQUIRE(ElementQuery::from_root(instance).match_inherits("ButtonBase").find_all().size()
+                == 2);
+        REQUIRE(!ElementQuery::from_root(instance).match_type_name("ButtonBase").find_first());
+    }
+
+    SECTION("Match by predicate")
+    {
+        auto query = ElementHandle::root_element(instance).query_descendants();
+        query.match_predicate([](const ElementHandle &element) {
+            return element.accessible_label() == "first";
+        });
+        auto elements = query.find_all();
+        REQUIRE(elements.size() == 1);
+        REQUIRE(*elements[0].id() == "PushButton::label");
+    }
+
+    SECTION("Click")
+    {
+        auto button = ElementQuery::from_root(instance).match_id("App::first").find_first();
+        REQUIRE(button.has_value());
+        button->single_click();
+        REQUIRE(instance.get_property("click-count") == Value(1.));
+        button->double_click();
+        REQUIRE(instance.get_property("click-count") == Value(3.));
+    }
+
+    SECTION("Wait")
+    {
+        auto query = ElementQuery::from_root(instance).match_id("App::status");
+        REQUIRE(!query.find_first());
+        auto status = query.wait_for_first(std::chrono::milliseconds(1000));
+        REQUIRE(status.has_value());
+        REQUIRE(status->is_visible());
+        REQUIRE(status->wait_for_accessible_value("loaded", std::chrono::milliseconds(100)));
+        REQUIRE(slint::testing::wait_until_idle(instance, std::chrono::milliseconds(1000)));
+    }
+}
@@ -225,8 +225,9 @@ struct MyComponent { /*...*/ }
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    FilterModel, FlattenedTreeModel, FlattenedTreeRow, MapModel, Model, ModelExt, ModelNotify,
+    ModelPeer, ModelRc, ModelTracker, ReverseModel, SortModel, StandardListViewItem, TableColumn,
+    TreeModel, TreeModelListener, TreeModelNotify, TreeModelTracker, TreeViewItem, VecModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
@@ -80,6 +80,24 @@ pub fn set_animated_property_binding<
     )
 }
 
+pub fn set_animated_property_binding_with_keyframes<
+    T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    binding: fn(StrongRef) -> T,
+    animation_data: PropertyAnimation,
+    keyframes: alloc::vec::Vec<i_slint_core::properties::Keyframe<T>>,
+) {
+    let weak = component_strong.to_weak();
+    property.set_animated_binding_with_keyframes(
+        move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak).unwrap()),
+        animation_data,
+        keyframes,
+    )
+}
+
 pub fn set_animated_property_binding_for_transition<
     T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
     StrongRef: StrongItemTreeRef + 'static,
@@ -89,7 +107,11 @@ pub fn set_animated_property_binding_for_transition<
     binding: fn(StrongRef) -> T,
     compute_animation_details: fn(
         StrongRef,
-    ) -> (PropertyAnimation, i_slint_core::animations::Instant),
+    ) -> (
+        PropertyAnimation,
+        i_slint_core::animations::Instant,
+        alloc::vec::Vec<i_slint_core::properties::Keyframe<T>>,
+    ),
 ) {
     let weak_1 = component_strong.to_weak();
     let weak_2 = weak_1.clone();
@@ -207,12 +229,12 @@ pub mod re_exports {
     pub use i_slint_core::items::*;
     pub use i_slint_core::layout::*;
     pub use i_slint_core::lengths::{
-        logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect,
+        logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect, LogicalSize,
     };
     pub use i_slint_core::menus::{Menu, MenuFromItemTree, MenuVTable};
     pub use i_slint_core::model::*;
     pub use i_slint_core::properties::{
-        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+        set_state_binding, ChangeTracker, Keyframe, Property, PropertyTracker, StateInfo,
     };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::string::shared_string_from_number;
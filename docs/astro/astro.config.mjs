@@ -237,6 +237,10 @@ export default defineConfig({
                                                 label: "FocusScope",
                                                 slug: "reference/keyboard-input/focusscope",
                                             },
+                                            {
+                                                label: "Shortcut",
+                                                slug: "reference/keyboard-input/shortcut",
+                                            },
                                             {
                                                 label: "TextInput",
                                                 slug: "reference/keyboard-input/textinput",
@@ -254,6 +258,10 @@ export default defineConfig({
                                                 label: "Common Properties",
                                                 slug: "reference/layouts/overview",
                                             },
+                                            {
+                                                label: "FlexLayout",
+                                                slug: "reference/layouts/flexlayout",
+                                            },
                                             {
                                                 label: "GridLayout",
                                                 slug: "reference/layouts/gridlayout",
@@ -694,6 +694,7 @@ module.exports = grammar({
         "ease-out-bounce",
         "ease-in-out-bounce",
         seq("cubic-bezier", $.arguments),
+        seq("spring", $.arguments),
       ),
 
     user_type_identifier: ($) => prec(1, $._identifier),
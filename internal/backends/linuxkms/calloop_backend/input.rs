@@ -308,9 +308,17 @@ fn process_events<F>(
                     }
 
                     if let Some(text) = map_key_sym(sym) {
+                        let physical_key =
+                            i_slint_core::input::physical_key_from_evdev_code(key_event.key());
+                        let location =
+                            i_slint_core::input::key_location_from_physical_key(physical_key);
                         let event = match state {
-                            KeyState::Pressed => WindowEvent::KeyPressed { text },
-                            KeyState::Released => WindowEvent::KeyReleased { text },
+                            KeyState::Pressed => {
+                                WindowEvent::PhysicalKeyPressed { text, physical_key, location }
+                            }
+                            KeyState::Released => {
+                                WindowEvent::PhysicalKeyReleased { text, physical_key, location }
+                            }
                         };
                         window.try_dispatch_event(event).map_err(Self::Error::other)?;
                     }
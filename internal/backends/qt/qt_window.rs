@@ -14,13 +14,14 @@
 use i_slint_core::input::{KeyEvent, KeyEventType, MouseEvent};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage,
-    RenderRectangle, RenderText,
+    RenderRectangle, RenderText, TextDecoration,
 };
 use i_slint_core::item_tree::ParentItemTraversalMode;
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef};
 use i_slint_core::items::{
     self, ColorScheme, FillRule, ImageRendering, ItemRc, ItemRef, Layer, LineCap, MouseCursor,
-    Opacity, PointerEventButton, RenderingResult, TextOverflow, TextStrokeStyle, TextWrap,
+    Opacity, PointerEventButton, RenderingResult, TextDecorationStyle, TextOverflow,
+    TextStrokeStyle, TextWrap,
 };
 use i_slint_core::layout::Orientation;
 use i_slint_core::lengths::{
@@ -28,6 +29,7 @@
     PhysicalPx, ScaleFactor,
 };
 use i_slint_core::platform::{PlatformError, WindowEvent};
+use i_slint_core::styled_text::StyledText;
 use i_slint_core::window::{WindowAdapter, WindowAdapterInternal, WindowInner};
 use i_slint_core::{ImageInner, Property, SharedString};
 use items::{TextHorizontalAlignment, TextVerticalAlignment};
@@ -68,6 +70,129 @@
 
     using QPainterPtr = std::unique_ptr<QPainter>;
 
+    // Sets the line height and the spacing after each paragraph, except the last one, on all the blocks of the document.
+    void apply_line_spacing(QTextDocument &document, qreal line_height, qreal paragraph_spacing) {
+        if (line_height <= 0 && paragraph_spacing == 0) {
+            return;
+        }
+        QTextBlockFormat format;
+        if (line_height > 0) {
+            format.setLineHeight(line_height, QTextBlockFormat::FixedHeight);
+        }
+        format.setBottomMargin(paragraph_spacing);
+        QTextCursor cursor(&document);
+        cursor.select(QTextCursor::Document);
+        cursor.mergeBlockFormat(format);
+        QTextCursor last_block(document.lastBlock());
+        QTextBlockFormat last_format = last_block.blockFormat();
+        last_format.setBottomMargin(0);
+        last_block.setBlockFormat(last_format);
+    }
+
+    // Lays out the HTML of a Text element with markup in the document. A negative width disables the wrapping.
+    void layout_styled_text(QTextDocument &document, const QString &html, const QFont &font,
+                            Qt::Alignment alignment, bool wrap, bool word_wrap, qreal width,
+                            qreal line_height, qreal paragraph_spacing) {
+        document.setDocumentMargin(0);
+        document.setDefaultFont(font);
+        QTextOption options = document.defaultTextOption();
+        options.setAlignment(alignment);
+        if (!wrap) {
+            options.setWrapMode(QTextOption::NoWrap);
+        } else if (word_wrap) {
+            options.setWrapMode(QTextOption::WordWrap);
+        } else {
+            options.setWrapMode(QTextOption::WrapAnywhere);
+        }
+        document.setDefaultTextOption(options);
+        document.setHtml(html);
+        if (paragraph_spacing != 0) {
+            // <br> is a line separator, but the paragraph spacing is the margin of the blocks
+            QTextCursor cursor(&document);
+            while (!(cursor = document.find(QString(QChar::LineSeparator), cursor)).isNull()) {
+                cursor.removeSelectedText();
+                cursor.insertBlock();
+            }
+        }
+        apply_line_spacing(document, line_height, paragraph_spacing);
+        document.setTextWidth(width);
+    }
+
+    // Applies the style and color of the underline of a Text or TextInput, which QFont doesn't support.
+    void apply_underline_style(QTextCharFormat &format, bool wavy_underline, QRgb underline_color) {
+        if (wavy_underline) {
+            format.setUnderlineStyle(QTextCharFormat::WaveUnderline);
+        }
+        if (qAlpha(underline_color) != 0) {
+            format.setUnderlineColor(QColor::fromRgba(underline_color));
+        }
+    }
+
+    // Returns the offset to apply to the content of the document so that it is vertically aligned within the height.
+    qreal styled_text_vertical_offset(const QTextDocument &document, Qt::Alignment alignment, qreal height) {
+        if (alignment & Qt::AlignVCenter) {
+            return (height - document.size().height()) / 2.;
+        } else if (alignment & Qt::AlignBottom) {
+            return height - document.size().height();
+        }
+        return 0;
+    }
+
+    // Replaces the text of the lines that don't fit in the size with an ellipsis.
+    void elide_styled_text(QTextDocument &document, qreal width, qreal height) {
+        const QString ellipsis = QString::fromUtf8("…");
+        struct Line {
+            QTextBlock block;
+            QTextLine line;
+        };
+        QVector<Line> lines;
+        int last_visible_line = 0;
+        for (QTextBlock block = document.begin(); block.isValid(); block = block.next()) {
+            qreal block_top = document.documentLayout()->blockBoundingRect(block).top();
+            QTextLayout *layout = block.layout();
+            for (int i = 0; i < layout->lineCount(); ++i) {
+                QTextLine line = layout->lineAt(i);
+                if (block_top + line.y() + line.height() <= height) {
+                    last_visible_line = lines.size();
+                }
+                lines.push_back({ block, line });
+            }
+        }
+
+        // The ranges of the document to replace with an ellipsis, in document order
+        QVector<std::pair<int, int>> ranges;
+        for (int i = 0; i <= last_visible_line && i < lines.size(); ++i) {
+            const auto &[block, line] = lines[i];
+            bool more_lines = i == last_visible_line && i + 1 < lines.size();
+            if (!more_lines && line.naturalTextWidth() <= width) {
+                continue;
+            }
+            QString block_text = block.text();
+            int line_end = line.textStart() + line.textLength();
+            if (line_end > line.textStart() && block_text.at(line_end - 1) == QChar::LineSeparator) {
+                line_end--;
+            }
+            int text_end = line_end;
+            while (text_end > line.textStart() && block_text.at(text_end - 1).isSpace()) {
+                text_end--;
+            }
+            QTextCursor cursor(block);
+            cursor.setPosition(block.position() + line.textStart());
+            qreal ellipsis_width = QFontMetricsF(cursor.charFormat().font()).horizontalAdvance(ellipsis);
+            int cut = line.xToCursor(width - ellipsis_width, QTextLine::CursorOnCharacter);
+            cut = std::clamp(cut, line.textStart(), text_end);
+            int end = more_lines ? document.characterCount() - 1 : block.position() + line_end;
+            ranges.push_back({ block.position() + cut, end });
+        }
+
+        for (auto it = ranges.rbegin(); it != ranges.rend(); ++it) {
+            QTextCursor cursor(&document);
+            cursor.setPosition(it->first);
+            cursor.setPosition(it->second, QTextCursor::KeepAnchor);
+            cursor.insertText(ellipsis);
+        }
+    }
+
     struct TimerHandler : QObject {
         QBasicTimer timer;
         static TimerHandler& instance() {
@@ -228,8 +353,11 @@ struct SlintWidget : QWidget {
             QString text =  event->text();
             int key = event->key();
             bool repeat = event->isAutoRepeat();
-            rust!(Slint_keyPress [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", repeat: bool as "bool"] {
-                rust_window.key_event(key, text.clone(), false, repeat);
+            uint32_t scan_code = event->nativeScanCode();
+            bool keypad = event->modifiers() & Qt::KeypadModifier;
+            rust!(Slint_keyPress [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString", repeat: bool as "bool",
+                    scan_code: u32 as "uint32_t", keypad: bool as "bool"] {
+                rust_window.key_event(key, text.clone(), false, repeat, scan_code, keypad);
             });
         }
         void keyReleaseEvent(QKeyEvent *event) override {
@@ -242,8 +370,11 @@ struct SlintWidget : QWidget {
 
             QString text =  event->text();
             int key = event->key();
-            rust!(Slint_keyRelease [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString"] {
-                rust_window.key_event(key, text.clone(), true, false);
+            uint32_t scan_code = event->nativeScanCode();
+            bool keypad = event->modifiers() & Qt::KeypadModifier;
+            rust!(Slint_keyRelease [rust_window: &QtWindow as "void*", key: i32 as "int", text: qttypes::QString as "QString",
+                    scan_code: u32 as "uint32_t", keypad: bool as "bool"] {
+                rust_window.key_event(key, text.clone(), true, false, scan_code, keypad);
             });
         }
 
@@ -369,7 +500,12 @@ struct SlintWidget : QWidget {
     // Helper function used for the TextInput layouting
     //
     // if line_for_y_pos > 0, then the function will return the line at this y position
-    static int do_text_layout(QTextLayout &layout, int flags, const QRectF &rect, int line_for_y_pos = -1) {
+    // Lays out the lines of the text. A positive line_height replaces the height of the lines, and
+    // the paragraph_spacing is added after each line that ends with a line separator.
+    // Returns the index of the line at line_for_y_pos, if positive, and stores the height of the
+    // text in text_height if not null.
+    static int do_text_layout(QTextLayout &layout, int flags, const QRectF &rect, int line_for_y_pos = -1,
+                              qreal line_height = 0, qreal paragraph_spacing = 0, qreal *text_height = nullptr) {
         QTextOption options;
         options.setWrapMode((flags & Qt::TextWordWrap) ? QTextOption::WordWrap : ((flags & Qt::TextWrapAnywhere) ? QTextOption::WrapAnywhere : QTextOption::NoWrap));
         if (flags & Qt::AlignHCenter)
@@ -383,7 +519,9 @@ struct SlintWidget : QWidget {
         layout.setCacheEnabled(true);
         QFontMetrics fm(layout.font());
         int leading = fm.leading();
+        const QString text = layout.text();
         qreal height = 0;
+        qreal spacing_before = 0;
         layout.beginLayout();
         int count = 0;
         while(1) {
@@ -391,15 +529,26 @@ struct SlintWidget : QWidget {
             if (!line.isValid())
                 break;
             line.setLineWidth(rect.width());
-            height += leading;
-            line.setPosition(QPointF(0, height));
-            height += line.height();
+            height += spacing_before;
+            if (line_height > 0) {
+                line.setPosition(QPointF(0, height + (line_height - line.height()) / 2.));
+                height += line_height;
+            } else {
+                height += leading;
+                line.setPosition(QPointF(0, height));
+                height += line.height();
+            }
             if (line_for_y_pos >= 0 && height > line_for_y_pos) {
                 return count;
             }
             count++;
+            int line_end = line.textStart() + line.textLength();
+            spacing_before = line_end > 0 && text.at(line_end - 1) == QChar::LineSeparator ? paragraph_spacing : 0;
         }
         layout.endLayout();
+        if (text_height) {
+            *text_height = height;
+        }
         if (flags & Qt::AlignVCenter) {
             layout.setPosition(QPointF(0, (rect.height() - height) / 2.));
         } else if (flags & Qt::AlignBottom) {
@@ -702,21 +851,50 @@ fn draw_text(
     ) {
         let rect: qttypes::QRectF = check_geometry!(size);
         let fill_brush: qttypes::QBrush = into_qbrush(text.color(), rect.width, rect.height);
-        let mut string: qttypes::QString = text.text().as_str().into();
-        let font: QFont = get_font(text.font_request(self_rc));
-        let (horizontal_alignment, vertical_alignment) = text.alignment();
-        let alignment = match horizontal_alignment {
-            TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
-            TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
-            TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
-        } | match vertical_alignment {
-            TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
-            TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
-            TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
-        };
+        let font_request = text.font_request(self_rc);
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
+        let mut font: QFont = get_font(font_request);
+        let decoration = text.decoration();
+        set_font_decoration(&mut font, &decoration);
+        let (wavy_underline, underline_color) = underline_style(&decoration);
+        let alignment = qt_alignment(text.alignment());
         let wrap = text.wrap() != TextWrap::NoWrap;
         let word_wrap = text.wrap() == TextWrap::WordWrap;
         let elide = text.overflow() == TextOverflow::Elide;
+        let painter: &mut QPainterPtr = &mut self.painter;
+
+        if let Some(styled_text) = text.styled_text() {
+            let html = styled_text_to_html(&styled_text);
+            cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", html as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", wavy_underline as "bool", underline_color as "QRgb", line_height as "float", paragraph_spacing as "float"] {
+                QTextDocument document;
+                layout_styled_text(document, html, font, alignment, wrap, word_wrap, rect.width(), line_height, paragraph_spacing);
+                if (font.underline() || font.overline() || font.strikeOut()) {
+                    // The spans only inherit the family and size of the default font
+                    QTextCharFormat format;
+                    format.setFontUnderline(font.underline());
+                    format.setFontOverline(font.overline());
+                    format.setFontStrikeOut(font.strikeOut());
+                    apply_underline_style(format, wavy_underline, underline_color);
+                    QTextCursor cursor(&document);
+                    cursor.select(QTextCursor::Document);
+                    cursor.mergeCharFormat(format);
+                }
+                if (elide) {
+                    elide_styled_text(document, rect.width(), rect.height());
+                }
+                qreal dy = styled_text_vertical_offset(document, alignment, rect.height());
+
+                QAbstractTextDocumentLayout::PaintContext context;
+                context.palette.setBrush(QPalette::Text, fill_brush);
+                (*painter)->save();
+                (*painter)->translate(rect.x(), rect.y() + dy);
+                document.documentLayout()->draw((*painter).get(), context);
+                (*painter)->restore();
+            }}
+            return;
+        }
+
+        let mut string: qttypes::QString = text.text().as_str().into();
         let (stroke_brush, stroke_width, stroke_style) = text.stroke();
         let stroke_visible = !stroke_brush.is_transparent();
         let stroke_brush: qttypes::QBrush = into_qbrush(stroke_brush, rect.width, rect.height);
@@ -725,8 +903,7 @@ fn draw_text(
             TextStrokeStyle::Outside => stroke_width.get() * 2.0,
             TextStrokeStyle::Center => stroke_width.get(),
         };
-        let painter: &mut QPainterPtr = &mut self.painter;
-        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float"] {
+        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", stroke_brush as "QBrush", mut string as "QString", font as "QFont", elide as "bool", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", stroke_visible as "bool", stroke_outside as "bool", stroke_width as "float", wavy_underline as "bool", underline_color as "QRgb", line_height as "float", paragraph_spacing as "float"] {
             QString elided;
             if (!elide) {
                 elided = string;
@@ -760,6 +937,7 @@ fn draw_text(
                 layout.beginLayout();
                 int leading = fm.leading();
                 qreal height = 0;
+                qreal spacing_before = 0;
                 int last_line_begin = 0, last_line_size = 0;
                 while (true) {
                     auto line = layout.createLine();
@@ -768,12 +946,14 @@ fn draw_text(
                         break;
                     }
                     line.setLineWidth(rect.width());
-                    height += leading + line.height();
+                    height += spacing_before + (line_height > 0 ? line_height : leading + line.height());
                     if (height > rect.height()) {
                         break;
                     }
                     last_line_begin = line.textStart();
                     last_line_size = line.textLength();
+                    int line_end = last_line_begin + last_line_size;
+                    spacing_before = line_end > 0 && string.at(line_end - 1) == QChar::LineSeparator ? paragraph_spacing : 0;
                 }
                 if (last_line_begin < string.size()) {
                     elided = string.left(last_line_begin);
@@ -782,7 +962,7 @@ fn draw_text(
                 }
             }
 
-            if (!stroke_visible) {
+            if (!stroke_visible && !wavy_underline && qAlpha(underline_color) == 0) {
                 int flags = alignment;
                 if (wrap) {
                     if (word_wrap) {
@@ -792,11 +972,22 @@ fn draw_text(
                     }
                 }
 
-                (*painter)->setFont(font);
                 (*painter)->setBrush(Qt::NoBrush);
                 (*painter)->setPen(QPen(fill_brush, 0));
-                (*painter)->drawText(rect, flags, elided);
+                if (line_height > 0 || paragraph_spacing != 0) {
+                    elided.replace(QChar('\n'), QChar::LineSeparator);
+                    QTextLayout layout(elided, font);
+                    do_text_layout(layout, flags, rect, -1, line_height, paragraph_spacing);
+                    layout.draw(painter->get(), rect.topLeft());
+                } else {
+                    (*painter)->setFont(font);
+                    (*painter)->drawText(rect, flags, elided);
+                }
             } else {
+                if (paragraph_spacing != 0) {
+                    // The paragraph spacing is the margin of the blocks, which are separated by \n
+                    elided.replace(QChar::LineSeparator, QChar('\n'));
+                }
                 QTextDocument document(elided);
                 document.setDocumentMargin(0);
                 document.setPageSize(rect.size());
@@ -812,6 +1003,7 @@ fn draw_text(
                     }
                 }
                 document.setDefaultTextOption(options);
+                apply_line_spacing(document, line_height, paragraph_spacing);
 
                 // Workaround for https://bugreports.qt.io/browse/QTBUG-13467
                 float dy = 0;
@@ -829,6 +1021,7 @@ fn draw_text(
 
                 QTextCharFormat format;
                 format.setFont(font);
+                apply_underline_style(format, wavy_underline, underline_color);
 
                 QPen stroke_pen(stroke_brush, stroke_width, Qt::SolidLine, Qt::FlatCap, Qt::MiterJoin);
                 stroke_pen.setMiterLimit(10.0);
@@ -847,7 +1040,7 @@ fn draw_text(
                 (*painter)->save();
                 (*painter)->translate(0, dy);
 
-                if (stroke_outside) {
+                if (stroke_visible && stroke_outside) {
                     format.setForeground(Qt::NoBrush);
                     format.setTextOutline(stroke_pen);
                     cursor.mergeCharFormat(format);
@@ -855,7 +1048,9 @@ fn draw_text(
                 }
 
                 format.setForeground(fill_brush);
-                if (!stroke_outside) {
+                if (!stroke_visible) {
+                    format.setTextOutline(Qt::NoPen);
+                } else if (!stroke_outside) {
                     format.setTextOutline(stroke_pen);
                 } else {
                     // Use a transparent pen instead of Qt::NoPen so the
@@ -879,7 +1074,12 @@ fn draw_text_input(
         let rect: qttypes::QRectF = check_geometry!(size);
         let fill_brush: qttypes::QBrush = into_qbrush(text_input.color(), rect.width, rect.height);
 
-        let font: QFont = get_font(text_input.font_request(self_rc));
+        let font_request = text_input.font_request(self_rc);
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
+        let mut font: QFont = get_font(font_request);
+        let decoration = text_input.decoration();
+        set_font_decoration(&mut font, &decoration);
+        let (wavy_underline, underline_color) = underline_style(&decoration);
         let flags = match text_input.horizontal_alignment() {
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
@@ -964,14 +1164,24 @@ fn draw_text_input(
                 selection_start_position as "int",
                 selection_end_position as "int",
                 cursor_position as "int",
-                text_cursor_width as "float"] {
+                text_cursor_width as "float",
+                wavy_underline as "bool",
+                underline_color as "QRgb",
+                line_height as "float",
+                paragraph_spacing as "float"] {
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(string, font);
-            do_text_layout(layout, flags, rect);
+            do_text_layout(layout, flags, rect, -1, line_height, paragraph_spacing);
             (*painter)->setPen(QPen(fill_brush, 0));
             QVector<QTextLayout::FormatRange> selections;
+            if (wavy_underline || qAlpha(underline_color) != 0) {
+                QTextCharFormat fmt;
+                fmt.setFontUnderline(true);
+                apply_underline_style(fmt, wavy_underline, underline_color);
+                selections << QTextLayout::FormatRange{ 0, int(string.size()), fmt };
+            }
             if (selection_end_position != selection_start_position) {
                 QTextCharFormat fmt;
                 if (qAlpha(selection_background_color) != 0) {
@@ -1770,18 +1980,39 @@ fn mouse_event(&self, event: MouseEvent) {
         timer_event();
     }
 
-    fn key_event(&self, key: i32, text: qttypes::QString, released: bool, repeat: bool) {
+    fn key_event(
+        &self,
+        key: i32,
+        text: qttypes::QString,
+        released: bool,
+        repeat: bool,
+        scan_code: u32,
+        keypad: bool,
+    ) {
         i_slint_core::animations::update_animations();
         let text: String = text.into();
 
         let text = qt_key_to_string(key as key_generated::Qt_Key, text);
 
+        // With xcb and wayland, the native scan code is the evdev code plus 8. Qt doesn't
+        // expose the physical key on the other platforms.
+        let physical_key = if cfg!(target_os = "linux") && scan_code >= 8 {
+            i_slint_core::input::physical_key_from_evdev_code(scan_code - 8)
+        } else {
+            i_slint_core::input::PhysicalKey::Unidentified
+        };
+        let location = if keypad {
+            i_slint_core::input::KeyLocation::Numpad
+        } else {
+            i_slint_core::input::key_location_from_physical_key(physical_key)
+        };
+
         let event = if released {
-            WindowEvent::KeyReleased { text }
+            WindowEvent::PhysicalKeyReleased { text, physical_key, location }
         } else if repeat {
-            WindowEvent::KeyPressRepeated { text }
+            WindowEvent::PhysicalKeyPressRepeated { text, physical_key, location }
         } else {
-            WindowEvent::KeyPressed { text }
+            WindowEvent::PhysicalKeyPressed { text, physical_key, location }
         };
         self.window.dispatch_event(event);
 
@@ -2083,6 +2314,21 @@ fn create_popup(&self, geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>> {
         Some(popup_window as _)
     }
 
+    fn set_transient_parent(&self, parent: &Rc<dyn WindowAdapter>, modal: bool) {
+        let Some(parent) = parent
+            .internal(i_slint_core::InternalToken)
+            .and_then(|x| x.as_any().downcast_ref::<QtWindow>())
+        else {
+            return;
+        };
+        let widget_ptr = self.widget_ptr();
+        let parent_ptr = parent.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*", parent_ptr as "QWidget*", modal as "bool"] {
+            widget_ptr->setParent(parent_ptr, Qt::Window);
+            widget_ptr->setWindowModality(modal ? Qt::WindowModal : Qt::NonModal);
+        }};
+    }
+
     fn set_mouse_cursor(&self, cursor: MouseCursor) {
         let widget_ptr = self.widget_ptr();
         //unidirectional resize cursors are replaced with bidirectional ones
@@ -2215,11 +2461,87 @@ fn text_size(
         _scale_factor: ScaleFactor,
         text_wrap: TextWrap,
     ) -> LogicalSize {
-        get_font(font_request).font_metrics().text_size(
-            text,
-            max_width.map(|logical_width| logical_width.get()),
-            text_wrap,
-        )
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
+        let font = get_font(font_request);
+        let max_width = max_width.map(|logical_width| logical_width.get());
+        if line_height <= 0. && paragraph_spacing == 0. {
+            return font.font_metrics().text_size(text, max_width, text_wrap);
+        }
+        let string = qttypes::QString::from(text);
+        let width: f32 = max_width.unwrap_or_default();
+        let flags = match (max_width, text_wrap) {
+            (None, _) | (_, TextWrap::NoWrap) => 0,
+            (_, TextWrap::WordWrap) => key_generated::Qt_TextFlag_TextWordWrap,
+            (_, TextWrap::CharWrap) => key_generated::Qt_TextFlag_TextWrapAnywhere,
+        };
+        let size = cpp! { unsafe [font as "QFont", mut string as "QString", width as "float", flags as "int", line_height as "float", paragraph_spacing as "float"]
+                -> qttypes::QRectF as "QRectF" {
+            string.replace(QChar('\n'), QChar::LineSeparator);
+            QTextLayout layout(string, font);
+            qreal height = 0;
+            do_text_layout(layout, flags, QRectF(0, 0, width, 0), -1, line_height, paragraph_spacing, &height);
+            qreal text_width = 0;
+            for (int i = 0; i < layout.lineCount(); ++i) {
+                text_width = std::max(text_width, layout.lineAt(i).naturalTextWidth());
+            }
+            return QRectF(0, 0, text_width, height);
+        }};
+        LogicalSize::new(size.width as _, size.height as _)
+    }
+
+    fn styled_text_size(
+        &self,
+        font_request: FontRequest,
+        text: &StyledText,
+        max_width: Option<LogicalLength>,
+        _scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
+        let font: QFont = get_font(font_request);
+        let html = styled_text_to_html(text);
+        let width: f64 = max_width.map_or(-1., |max_width| max_width.get() as f64);
+        let wrap = text_wrap != TextWrap::NoWrap;
+        let word_wrap = text_wrap == TextWrap::WordWrap;
+        let size = cpp! { unsafe [font as "QFont", html as "QString", width as "qreal", wrap as "bool", word_wrap as "bool", line_height as "float", paragraph_spacing as "float"] -> qttypes::QRectF as "QRectF" {
+            QTextDocument document;
+            layout_styled_text(document, html, font, Qt::AlignLeft, wrap, word_wrap, width, line_height, paragraph_spacing);
+            return QRectF(0, 0, width < 0 ? document.size().width() : document.idealWidth(), document.size().height());
+        }};
+        LogicalSize::new(size.width as _, size.height as _)
+    }
+
+    fn styled_text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn RenderText>,
+        self_rc: &ItemRc,
+        styled_text: &StyledText,
+        pos: LogicalPoint,
+        _scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let size = text.target_size();
+        let rect: qttypes::QRectF = check_geometry!(size);
+        let pos = qttypes::QPointF { x: pos.x as _, y: pos.y as _ };
+        let font_request = text.font_request(self_rc);
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
+        let font: QFont = get_font(font_request);
+        let html = styled_text_to_html(styled_text);
+        let alignment = qt_alignment(text.alignment());
+        let wrap = text.wrap() != TextWrap::NoWrap;
+        let word_wrap = text.wrap() == TextWrap::WordWrap;
+        let position = cpp! { unsafe [font as "QFont", html as "QString", pos as "QPointF", rect as "QRectF", alignment as "Qt::Alignment", wrap as "bool", word_wrap as "bool", line_height as "float", paragraph_spacing as "float"] -> i32 as "int" {
+            QTextDocument document;
+            layout_styled_text(document, html, font, alignment, wrap, word_wrap, rect.width(), line_height, paragraph_spacing);
+            qreal dy = styled_text_vertical_offset(document, alignment, rect.height());
+            return document.documentLayout()->hitTest(QPointF(pos.x(), pos.y() - dy), Qt::ExactHit);
+        }};
+        // The document has one UTF-16 code unit per code unit of the text
+        let position = usize::try_from(position).ok()?;
+        let mut utf16_offset = 0;
+        styled_text.text.char_indices().find_map(|(byte_offset, c)| {
+            utf16_offset += c.len_utf16();
+            (utf16_offset > position).then_some(byte_offset)
+        })
     }
 
     fn font_metrics(
@@ -2249,6 +2571,7 @@ fn text_input_byte_offset_for_position(
         let size = LogicalSize::new(text_input.width().get(), text_input.height().get());
         let rect: qttypes::QRectF = check_geometry!(size);
         let pos = qttypes::QPointF { x: pos.x as _, y: pos.y as _ };
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
         let font: QFont = get_font(font_request);
 
         let visual_representation = text_input.visual_representation(Some(qt_password_character));
@@ -2270,14 +2593,14 @@ fn text_input_byte_offset_for_position(
         };
         let single_line: bool = text_input.single_line();
         let byte_offset = cpp! { unsafe [font as "QFont", string as "QString", pos as "QPointF", flags as "int",
-                rect as "QRectF", single_line as "bool"] -> usize as "size_t" {
+                rect as "QRectF", single_line as "bool", line_height as "float", paragraph_spacing as "float"] -> usize as "size_t" {
             // we need to do the \n replacement in a copy because the original need to be kept to know the utf8 offset
             auto copy = string;
             if (!single_line) {
                 copy.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(copy, font);
-            auto line = do_text_layout(layout, flags, rect, pos.y());
+            auto line = do_text_layout(layout, flags, rect, pos.y(), line_height, paragraph_spacing);
             if (line < 0 || layout.lineCount() <= line)
                 return string.toUtf8().size();
             QTextLine textLine = layout.lineAt(line);
@@ -2309,6 +2632,7 @@ fn text_input_cursor_rect_for_byte_offset(
     ) -> LogicalRect {
         let size = LogicalSize::new(text_input.width().get(), text_input.height().get());
         let rect: qttypes::QRectF = check_geometry!(size);
+        let (line_height, paragraph_spacing) = line_spacing(&font_request);
         let font: QFont = get_font(font_request);
         let text = text_input.text();
         let mut string = qttypes::QString::from(text.as_str());
@@ -2327,13 +2651,13 @@ fn text_input_cursor_rect_for_byte_offset(
             TextWrap::CharWrap => key_generated::Qt_TextFlag_TextWrapAnywhere,
         };
         let single_line: bool = text_input.single_line();
-        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool"]
+        let r = cpp! { unsafe [font as "QFont", mut string as "QString", offset as "int", flags as "int", rect as "QRectF", single_line as "bool", line_height as "float", paragraph_spacing as "float"]
                 -> qttypes::QRectF as "QRectF" {
             if (!single_line) {
                 string.replace(QChar('\n'), QChar::LineSeparator);
             }
             QTextLayout layout(string, font);
-            do_text_layout(layout, flags, rect);
+            do_text_layout(layout, flags, rect, -1, line_height, paragraph_spacing);
 
             QTextLine textLine = layout.lineForTextPosition(offset);
             if (!textLine.isValid())
@@ -2438,6 +2762,75 @@ fn accessible_item(item: Option<ItemRc>) -> Option<ItemRc> {
     None
 }
 
+fn qt_alignment(
+    (horizontal_alignment, vertical_alignment): (TextHorizontalAlignment, TextVerticalAlignment),
+) -> key_generated::Qt_AlignmentFlag {
+    (match horizontal_alignment {
+        TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
+        TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
+        TextHorizontalAlignment::Right => key_generated::Qt_AlignmentFlag_AlignRight,
+    }) | match vertical_alignment {
+        TextVerticalAlignment::Top => key_generated::Qt_AlignmentFlag_AlignTop,
+        TextVerticalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignVCenter,
+        TextVerticalAlignment::Bottom => key_generated::Qt_AlignmentFlag_AlignBottom,
+    }
+}
+
+/// Converts the styled text to the HTML subset understood by QTextDocument.
+/// Every character of the text maps to exactly one character of the document.
+fn styled_text_to_html(styled_text: &StyledText) -> qttypes::QString {
+    use std::fmt::Write;
+    let mut html = String::from("<div style=\"white-space: pre-wrap\">");
+    for span in &styled_text.spans {
+        let style = &span.style;
+        html.push_str("<span style=\"");
+        if let Some(color) = style.color {
+            let _ = write!(
+                html,
+                "color: #{:02x}{:02x}{:02x}{:02x};",
+                color.alpha(),
+                color.red(),
+                color.green(),
+                color.blue()
+            );
+        }
+        if let Some(weight) = style.font_weight {
+            let _ = write!(html, "font-weight: {weight};");
+        }
+        if let Some(italic) = style.font_italic {
+            html.push_str(if italic { "font-style: italic;" } else { "font-style: normal;" });
+        }
+        if let Some(font_size) = style.font_size {
+            let _ = write!(html, "font-size: {}px;", font_size.get());
+        }
+        if style.underline {
+            html.push_str("text-decoration: underline;");
+        }
+        html.push_str("\">");
+        for c in styled_text.text[span.range.clone()].chars() {
+            match c {
+                '<' => html.push_str("&lt;"),
+                '>' => html.push_str("&gt;"),
+                '&' => html.push_str("&amp;"),
+                '"' => html.push_str("&quot;"),
+                '\n' => html.push_str("<br>"),
+                c => html.push(c),
+            }
+        }
+        html.push_str("</span>");
+    }
+    html.push_str("</div>");
+    html.as_str().into()
+}
+
+/// Returns the line height, or zero for the height of the font, and the paragraph spacing of the request.
+fn line_spacing(request: &FontRequest) -> (f32, f32) {
+    (
+        request.line_height.map_or(0., |line_height| line_height.get()),
+        request.paragraph_spacing.map_or(0., |paragraph_spacing| paragraph_spacing.get()),
+    )
+}
+
 fn get_font(request: FontRequest) -> QFont {
     let family: qttypes::QString = request.family.unwrap_or_default().as_str().into();
     let pixel_size: f32 = request.pixel_size.map_or(0., |logical_size| logical_size.get());
@@ -2473,6 +2866,26 @@ fn get_font(request: FontRequest) -> QFont {
     })
 }
 
+/// Enables the underline, overline and strikethrough on the font. Qt draws these with the text color,
+/// so the style and color of the decoration are not supported.
+/// Returns whether the underline is wavy, and its color (transparent for the text color).
+/// QFont only supports a solid underline, so these are applied with a QTextCharFormat.
+fn underline_style(decoration: &TextDecoration) -> (bool, u32) {
+    if !decoration.underline {
+        return (false, 0);
+    }
+    (decoration.style == TextDecorationStyle::Wavy, decoration.color.as_argb_encoded())
+}
+
+fn set_font_decoration(font: &mut QFont, decoration: &TextDecoration) {
+    let TextDecoration { underline, overline, strikethrough, .. } = *decoration;
+    cpp!(unsafe [font as "QFont*", underline as "bool", overline as "bool", strikethrough as "bool"] {
+        font->setUnderline(underline);
+        font->setOverline(overline);
+        font->setStrikeOut(strikethrough);
+    })
+}
+
 cpp_class! {pub unsafe struct QFontMetricsF as "QFontMetricsF"}
 
 impl QFontMetricsF {
@@ -1,11 +1,14 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use crate::{ElementHandle, ElementRoot};
+use crate::{ElementHandle, ElementQuery, ElementRoot};
 use i_slint_core::item_tree::ItemTreeRc;
+use i_slint_core::items::AccessibleRole;
+use i_slint_core::platform::{PointerEventButton, WindowEvent};
 use i_slint_core::slice::Slice;
 use i_slint_core::{SharedString, SharedVector};
 use std::os::raw::c_void;
+use std::time::Duration;
 
 struct RootWrapper<'a>(&'a ItemTreeRc);
 
@@ -104,3 +107,176 @@ pub extern "C" fn slint_testing_element_bases(
         false
     }
 }
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_root(root: &ItemTreeRc, out: &mut ElementHandle) {
+    *out = RootWrapper(root).root_element();
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_descendants(
+    element: &ElementHandle,
+) -> Box<ElementQuery> {
+    Box::new(element.query_descendants())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_drop(_query: Box<ElementQuery>) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_match_descendants(
+    query: Box<ElementQuery>,
+) -> Box<ElementQuery> {
+    Box::new(query.match_descendants())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_match_id(
+    query: Box<ElementQuery>,
+    id: &Slice<u8>,
+) -> Box<ElementQuery> {
+    Box::new(query.match_id(String::from_utf8_lossy(id.as_slice())))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_match_type_name(
+    query: Box<ElementQuery>,
+    type_name: &Slice<u8>,
+) -> Box<ElementQuery> {
+    Box::new(query.match_type_name(String::from_utf8_lossy(type_name.as_slice())))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_match_inherits(
+    query: Box<ElementQuery>,
+    type_name: &Slice<u8>,
+) -> Box<ElementQuery> {
+    Box::new(query.match_inherits(String::from_utf8_lossy(type_name.as_slice())))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_match_accessible_role(
+    query: Box<ElementQuery>,
+    role: AccessibleRole,
+) -> Box<ElementQuery> {
+    Box::new(query.match_accessible_role(role))
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_testing_element_query_match_predicate(
+    query: Box<ElementQuery>,
+    user_data: *mut c_void,
+    predicate: unsafe extern "C" fn(*mut c_void, &ElementHandle) -> bool,
+    drop_user_data: Option<unsafe extern "C" fn(*mut c_void)>,
+) -> Box<ElementQuery> {
+    struct UserData {
+        user_data: *mut c_void,
+        drop_user_data: Option<unsafe extern "C" fn(*mut c_void)>,
+    }
+    impl Drop for UserData {
+        fn drop(&mut self) {
+            if let Some(x) = self.drop_user_data {
+                unsafe { x(self.user_data) }
+            }
+        }
+    }
+    let ud = UserData { user_data, drop_user_data };
+    Box::new(query.match_predicate(move |element| unsafe { predicate(ud.user_data, element) }))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_find_first(
+    query: &ElementQuery,
+    out: &mut ElementHandle,
+) -> bool {
+    if let Some(element) = query.find_first() {
+        *out = element;
+        true
+    } else {
+        false
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_find_all(
+    query: &ElementQuery,
+    out: &mut SharedVector<ElementHandle>,
+) {
+    out.extend(query.find_all());
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_query_wait_for_first(
+    query: &ElementQuery,
+    timeout_ms: u64,
+    out: &mut ElementHandle,
+) -> bool {
+    if let Some(element) = query.wait_for_first(Duration::from_millis(timeout_ms)) {
+        *out = element;
+        true
+    } else {
+        false
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_is_visible(element: &ElementHandle) -> bool {
+    element.is_visible()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_computed_opacity(element: &ElementHandle) -> f32 {
+    element.computed_opacity()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_wait_until_visible(
+    element: &ElementHandle,
+    timeout_ms: u64,
+) -> bool {
+    element.wait_until_visible(Duration::from_millis(timeout_ms))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_wait_for_accessible_value(
+    element: &ElementHandle,
+    value: &Slice<u8>,
+    timeout_ms: u64,
+) -> bool {
+    let Ok(value) = core::str::from_utf8(value.as_slice()) else { return false };
+    element.wait_for_accessible_value(value, Duration::from_millis(timeout_ms))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_wait_until_idle(root: &ItemTreeRc, timeout_ms: u64) -> bool {
+    crate::wait_until_idle(&RootWrapper(root), Duration::from_millis(timeout_ms))
+}
+
+/// Simulates a click on the element, using the mock time to elapse between press and release.
+/// With `double_click`, two clicks are simulated within the platform's click interval.
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_testing_element_click(
+    element: &ElementHandle,
+    button: PointerEventButton,
+    double_click: bool,
+) {
+    let Some((window_adapter, position)) = element.window_adapter_and_center() else { return };
+    let window = window_adapter.window();
+    let click_duration = if double_click {
+        let click_interval = i_slint_core::with_global_context(
+            || Err(i_slint_core::platform::PlatformError::NoPlatform),
+            |ctx| ctx.platform().click_interval(),
+        )
+        .unwrap_or_default();
+        click_interval.saturating_sub(Duration::from_millis(10)) / 2
+    } else {
+        Duration::from_millis(50)
+    };
+
+    window.dispatch_event(WindowEvent::PointerMoved { position });
+    for _ in 0..if double_click { 2 } else { 1 } {
+        window.dispatch_event(WindowEvent::PointerPressed { position, button });
+        i_slint_core::tests::slint_mock_elapsed_time(click_duration.as_millis() as _);
+        window.dispatch_event(WindowEvent::PointerReleased { position, button });
+    }
+}
@@ -15,8 +15,12 @@
 pub use testing_backend::*;
 #[cfg(all(feature = "ffi", not(test)))]
 mod ffi;
+#[cfg(feature = "screenshot")]
+mod screenshot;
 #[cfg(feature = "system-testing")]
 pub mod systest;
+#[cfg(feature = "screenshot")]
+pub use screenshot::*;
 
 /// Initialize the testing backend without support for event loop.
 /// This means that each test thread can use its own backend, but global functions that needs
@@ -33,6 +37,23 @@ pub fn init_no_event_loop() {
     .expect("platform already initialized");
 }
 
+/// Initialize the testing backend without support for event loop, like [`init_no_event_loop()`],
+/// but render the windows with Slint's software renderer. Use this in combination with
+/// [`ScreenshotComparison`] to compare the rendering of windows against reference images.
+///
+/// Note that unlike with the other initialization functions, text is measured with real fonts.
+#[cfg(feature = "screenshot")]
+pub fn init_no_event_loop_with_software_renderer() {
+    i_slint_core::platform::set_platform(Box::new(
+        testing_backend::TestingBackend::new(testing_backend::TestingBackendOptions {
+            mock_time: true,
+            threading: false,
+        })
+        .with_software_renderer(),
+    ))
+    .expect("platform already initialized");
+}
+
 /// Initialize the testing backend with support for simple event loop.
 /// This function can only be called once per process, so make sure to use integration
 /// tests with only one `#[test]` function. (Or in a doc test)
@@ -0,0 +1,331 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Comparison of window renderings against reference images, for visual regression tests.
+
+use crate::ElementHandle;
+use i_slint_core::api::{PhysicalPosition, PhysicalSize, Window};
+use i_slint_core::graphics::{Rgb8Pixel, SharedPixelBuffer};
+use std::path::{Path, PathBuf};
+
+/// Set this environment variable to `1` to write the rendering of a window as the new reference
+/// image, when it doesn't match the existing reference (or when there is no reference yet).
+pub const UPDATE_REFERENCES_ENV_VAR: &str = "SLINT_CREATE_SCREENSHOTS";
+
+/// The error returned by [`ScreenshotComparison::compare()`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ScreenshotError {
+    /// The window could not be rendered.
+    Rendering(String),
+    /// The reference image could not be loaded.
+    LoadingReference { path: PathBuf, error: String },
+    /// The rendering has a different size than the reference image.
+    SizeMismatch { reference: PhysicalSize, rendered: PhysicalSize },
+    /// Some pixels differ more than the tolerance permits. A visualization of the differences was
+    /// written to `diff_path`.
+    Mismatch { differing_pixels: usize, max_channel_difference: u8, diff_path: PathBuf },
+    /// An image could not be written to disk.
+    Writing { path: PathBuf, error: String },
+}
+
+impl core::fmt::Display for ScreenshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScreenshotError::Rendering(error) => write!(f, "error rendering the window: {error}"),
+            ScreenshotError::LoadingReference { path, error } => {
+                write!(f, "error loading reference image {}: {error}", path.display())
+            }
+            ScreenshotError::SizeMismatch { reference, rendered } => write!(
+                f,
+                "image sizes don't match: reference is {}x{}, rendered is {}x{}",
+                reference.width, reference.height, rendered.width, rendered.height
+            ),
+            ScreenshotError::Mismatch { differing_pixels, max_channel_difference, diff_path } => {
+                write!(
+                    f,
+                    "images are not equal: {differing_pixels} pixels differ, maximum channel difference is {max_channel_difference}. See {} for the differences",
+                    diff_path.display()
+                )
+            }
+            ScreenshotError::Writing { path, error } => {
+                write!(f, "error writing image {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScreenshotError {}
+
+/// Use ScreenshotComparison to verify that a window renders the same as a reference PNG image.
+///
+/// The window must have been created after initializing the testing backend with
+/// [`init_no_event_loop_with_software_renderer()`](crate::init_no_event_loop_with_software_renderer).
+///
+/// Minor rendering differences, for example due to anti-aliasing or fonts, can be permitted with
+/// [`Self::with_tolerance()`]. Areas of the window that are expected to change, such as clocks or
+/// animated content, can be excluded with [`Self::with_masked_region()`] or
+/// [`Self::with_masked_element()`].
+///
+/// When the rendering doesn't match, a visualization of the differences is written next to the
+/// reference image, with `.diff.png` as extension, and the rendering itself with `.actual.png`.
+///
+/// When the environment variable named by [`UPDATE_REFERENCES_ENV_VAR`] is set to `1`, a rendering
+/// that doesn't match replaces the reference image instead of reporting an error, and no
+/// `.diff.png` or `.actual.png` files are written.
+///
+/// ```rust,no_run
+/// # i_slint_backend_testing::init_no_event_loop_with_software_renderer();
+/// # slint::slint!{ export component App { clock := Text { text: "12:00"; } } }
+/// use i_slint_backend_testing::{ElementHandle, ScreenshotComparison};
+/// let app = App::new().unwrap();
+/// let clock = ElementHandle::find_by_element_id(&app, "App::clock").next().unwrap();
+/// ScreenshotComparison::new("tests/references/app.png")
+///     .with_tolerance(2)
+///     .with_masked_element(&clock)
+///     .assert_matches(app.window());
+/// ```
+pub struct ScreenshotComparison {
+    reference_path: PathBuf,
+    tolerance: u8,
+    masked_regions: Vec<(PhysicalPosition, PhysicalSize)>,
+    masked_elements: Vec<ElementHandle>,
+}
+
+impl ScreenshotComparison {
+    /// Creates a new comparison against the PNG image at `reference_path`.
+    pub fn new(reference_path: impl Into<PathBuf>) -> Self {
+        Self {
+            reference_path: reference_path.into(),
+            tolerance: 0,
+            masked_regions: Vec::new(),
+            masked_elements: Vec::new(),
+        }
+    }
+
+    /// Permit each color channel of each pixel to differ by up to `tolerance` from the reference.
+    pub fn with_tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Excludes the rectangle at `position` with `size`, in physical pixels, from the comparison.
+    pub fn with_masked_region(mut self, position: PhysicalPosition, size: PhysicalSize) -> Self {
+        self.masked_regions.push((position, size));
+        self
+    }
+
+    /// Excludes the area covered by `element` from the comparison. The geometry of the element is
+    /// determined at the time of the comparison.
+    pub fn with_masked_element(mut self, element: &ElementHandle) -> Self {
+        self.masked_elements.push(element.clone());
+        self
+    }
+
+    /// Renders `window` and compares the rendering against the reference image.
+    pub fn compare(&self, window: &Window) -> Result<(), ScreenshotError> {
+        let snapshot = window
+            .take_snapshot()
+            .map_err(|error| ScreenshotError::Rendering(error.to_string()))?;
+        let mut rendering =
+            SharedPixelBuffer::<Rgb8Pixel>::new(snapshot.width(), snapshot.height());
+        for (target_pixel, source_pixel) in
+            rendering.make_mut_slice().iter_mut().zip(snapshot.as_slice().iter())
+        {
+            *target_pixel = source_pixel.rgb();
+        }
+
+        let update_references =
+            std::env::var(UPDATE_REFERENCES_ENV_VAR).is_ok_and(|var| var == "1");
+        let result =
+            self.compare_with_reference(&rendering, window.scale_factor(), !update_references);
+
+        if result.is_err() && update_references {
+            return save_png(&self.reference_path, &rendering);
+        }
+
+        result
+    }
+
+    /// Renders `window` and panics if the rendering doesn't match the reference image.
+    pub fn assert_matches(&self, window: &Window) {
+        if let Err(error) = self.compare(window) {
+            panic!("Screenshot comparison failure for {}: {error}", self.reference_path.display());
+        }
+    }
+
+    fn compare_with_reference(
+        &self,
+        rendering: &SharedPixelBuffer<Rgb8Pixel>,
+        scale_factor: f32,
+        write_diff_images: bool,
+    ) -> Result<(), ScreenshotError> {
+        let reference = image::open(&self.reference_path)
+            .map_err(|error| ScreenshotError::LoadingReference {
+                path: self.reference_path.clone(),
+                error: error.to_string(),
+            })?
+            .into_rgb8();
+
+        let reference_size = PhysicalSize::new(reference.width(), reference.height());
+        let rendered_size = PhysicalSize::new(rendering.width(), rendering.height());
+        if reference_size != rendered_size {
+            return Err(ScreenshotError::SizeMismatch {
+                reference: reference_size,
+                rendered: rendered_size,
+            });
+        }
+
+        let masks = self.masks(scale_factor);
+        let is_masked = |x: u32, y: u32| {
+            masks.iter().any(|(pos, size)| {
+                (x as i64) >= pos.x as i64
+                    && (x as i64) < pos.x as i64 + size.width as i64
+                    && (y as i64) >= pos.y as i64
+                    && (y as i64) < pos.y as i64 + size.height as i64
+            })
+        };
+
+        let mut diff = SharedPixelBuffer::<Rgb8Pixel>::new(rendering.width(), rendering.height());
+        let mut differing_pixels = 0;
+        let mut max_channel_difference = 0;
+        let width = rendering.width() as usize;
+        for (index, (rendered_pixel, diff_pixel)) in
+            rendering.as_slice().iter().zip(diff.make_mut_slice().iter_mut()).enumerate()
+        {
+            let (x, y) = ((index % width) as u32, (index / width) as u32);
+            let reference_pixel = reference.get_pixel(x, y).0;
+            if is_masked(x, y) {
+                *diff_pixel = Rgb8Pixel::new(0, 0, 0x80);
+                continue;
+            }
+            let channel_difference = [rendered_pixel.r, rendered_pixel.g, rendered_pixel.b]
+                .into_iter()
+                .zip(reference_pixel)
+                .map(|(a, b)| a.abs_diff(b))
+                .max()
+                .unwrap_or_default();
+            max_channel_difference = max_channel_difference.max(channel_difference);
+            *diff_pixel = if channel_difference > self.tolerance {
+                differing_pixels += 1;
+                Rgb8Pixel::new(0xff, 0, 0)
+            } else {
+                // Faded gray version of the reference, so that the differences stand out
+                let luma = (reference_pixel.iter().map(|c| *c as u32).sum::<u32>() / 3) as u8;
+                let faded = 0xc0 + luma / 4;
+                Rgb8Pixel::new(faded, faded, faded)
+            };
+        }
+
+        if differing_pixels == 0 {
+            return Ok(());
+        }
+
+        let diff_path = self.reference_path.with_extension("diff.png");
+        if write_diff_images {
+            save_png(&diff_path, &diff)?;
+            save_png(&self.reference_path.with_extension("actual.png"), rendering)?;
+        }
+
+        Err(ScreenshotError::Mismatch { differing_pixels, max_channel_difference, diff_path })
+    }
+
+    fn masks(&self, scale_factor: f32) -> Vec<(PhysicalPosition, PhysicalSize)> {
+        self.masked_regions
+            .iter()
+            .copied()
+            .chain(self.masked_elements.iter().filter(|element| element.is_valid()).map(
+                |element| {
+                    (
+                        element.absolute_position().to_physical(scale_factor),
+                        element.size().to_physical(scale_factor),
+                    )
+                },
+            ))
+            .collect()
+    }
+}
+
+fn save_png(path: &Path, buffer: &SharedPixelBuffer<Rgb8Pixel>) -> Result<(), ScreenshotError> {
+    image::save_buffer_with_format(
+        path,
+        buffer.as_bytes(),
+        buffer.width(),
+        buffer.height(),
+        image::ColorType::Rgb8,
+        image::ImageFormat::Png,
+    )
+    .map_err(|error| ScreenshotError::Writing { path: path.into(), error: error.to_string() })
+}
+
+#[test]
+fn test_screenshot_comparison() {
+    crate::init_no_event_loop_with_software_renderer();
+
+    slint::slint! {
+        export component App inherits Window {
+            width: 64px;
+            height: 32px;
+            background: white;
+            in property <color> box-color: red;
+            Rectangle {
+                x: 0px;
+                width: 32px;
+                background: root.box-color;
+            }
+            changing := Rectangle {
+                x: 32px;
+                width: 32px;
+                background: root.box-color;
+            }
+        }
+    }
+
+    let app = App::new().unwrap();
+    app.show().unwrap();
+
+    let dir = std::env::temp_dir().join(format!("slint-screenshot-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let reference = dir.join("reference.png");
+
+    assert!(matches!(
+        ScreenshotComparison::new(&reference).compare(app.window()),
+        Err(ScreenshotError::LoadingReference { .. })
+    ));
+
+    let mut image = SharedPixelBuffer::<Rgb8Pixel>::new(64, 32);
+    for (index, pixel) in image.make_mut_slice().iter_mut().enumerate() {
+        *pixel = Rgb8Pixel::new(0xff, 0, 0);
+        if index % 64 >= 32 {
+            pixel.g = 0x10;
+        }
+    }
+    save_png(&reference, &image).unwrap();
+
+    match ScreenshotComparison::new(&reference).compare(app.window()) {
+        Err(ScreenshotError::Mismatch { differing_pixels, max_channel_difference, diff_path }) => {
+            assert_eq!(differing_pixels, 32 * 32);
+            assert_eq!(max_channel_difference, 0x10);
+            assert!(diff_path.exists());
+            assert!(reference.with_extension("actual.png").exists());
+        }
+        result => panic!("unexpected comparison result {result:?}"),
+    }
+
+    ScreenshotComparison::new(&reference).with_tolerance(0x10).assert_matches(app.window());
+    ScreenshotComparison::new(&reference)
+        .with_masked_region(PhysicalPosition::new(32, 0), PhysicalSize::new(32, 32))
+        .assert_matches(app.window());
+    let changing = ElementHandle::find_by_element_id(&app, "App::changing").next().unwrap();
+    ScreenshotComparison::new(&reference)
+        .with_masked_element(&changing)
+        .assert_matches(app.window());
+
+    app.set_box_color(i_slint_core::Color::from_rgb_u8(0, 0, 0xff));
+    assert!(ScreenshotComparison::new(&reference)
+        .with_masked_element(&changing)
+        .compare(app.window())
+        .is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -6,7 +6,7 @@
 use i_slint_core::api::{ComponentHandle, LogicalPosition};
 use i_slint_core::item_tree::{ItemTreeRc, ItemWeak, ParentItemTraversalMode};
 use i_slint_core::items::{ItemRc, Opacity};
-use i_slint_core::window::WindowInner;
+use i_slint_core::window::{WindowAdapterRc, WindowInner};
 use i_slint_core::SharedString;
 
 fn warn_missing_debug_info() {
@@ -231,6 +231,19 @@ pub fn find_all(&self) -> Vec<ElementHandle> {
         )
         .1
     }
+
+    /// Runs the query repeatedly while advancing the mock time, until an element matches or
+    /// the `timeout` has elapsed. Returns the first matching element, or None on timeout.
+    ///
+    /// See [`wait_for_condition()`] for how time is advanced.
+    pub fn wait_for_first(&self, timeout: std::time::Duration) -> Option<ElementHandle> {
+        let mut result = None;
+        wait_for_condition(timeout, || {
+            result = self.find_first();
+            result.is_some()
+        });
+        result
+    }
 }
 
 /// `ElementHandle` wraps an existing element in a Slint UI. An ElementHandle does not keep
@@ -377,6 +390,28 @@ pub fn is_valid(&self) -> bool {
         self.item.upgrade().is_some()
     }
 
+    /// Returns true if the element is valid and visible from the root of the window, i.e. it is
+    /// not hidden and not clipped away by any of its parents; false otherwise.
+    pub fn is_visible(&self) -> bool {
+        self.item.upgrade().is_some_and(|item| item.is_visible())
+    }
+
+    /// Advances the mock time until [`Self::is_visible()`] returns true or the `timeout` has elapsed.
+    /// Returns true if the element became visible; false on timeout.
+    ///
+    /// See [`wait_for_condition()`] for how time is advanced.
+    pub fn wait_until_visible(&self, timeout: std::time::Duration) -> bool {
+        wait_for_condition(timeout, || self.is_visible())
+    }
+
+    /// Advances the mock time until [`Self::accessible_value()`] is equal to `value` or the `timeout`
+    /// has elapsed. Returns true if the value was reached; false on timeout.
+    ///
+    /// See [`wait_for_condition()`] for how time is advanced.
+    pub fn wait_for_accessible_value(&self, value: &str, timeout: std::time::Duration) -> bool {
+        wait_for_condition(timeout, || self.accessible_value().is_some_and(|v| v == value))
+    }
+
     /// Returns the element's qualified id. Returns None if the element is not valid anymore or the
     /// element does not have an id.
     /// A qualified id consists of the name of the surrounding component as well as the provided local
@@ -809,17 +844,9 @@ pub fn invoke_accessible_expand_action(&self) {
     /// Simulates a single click (or touch tap) on the element at its center point with the
     /// specified button.
     pub async fn single_click(&self, button: i_slint_core::platform::PointerEventButton) {
-        let Some(item) = self.item.upgrade() else { return };
-        let Some(window_adapter) = item.window_adapter() else { return };
+        let Some((window_adapter, position)) = self.window_adapter_and_center() else { return };
         let window = window_adapter.window();
 
-        let item_pos = self.absolute_position();
-        let item_size = self.size();
-        let position = LogicalPosition::new(
-            item_pos.x + item_size.width / 2.,
-            item_pos.y + item_size.height / 2.,
-        );
-
         window.dispatch_event(i_slint_core::platform::WindowEvent::PointerMoved { position });
         window.dispatch_event(i_slint_core::platform::WindowEvent::PointerPressed {
             position,
@@ -851,17 +878,9 @@ pub async fn double_click(&self, button: i_slint_core::platform::PointerEventBut
             return;
         };
 
-        let Some(item) = self.item.upgrade() else { return };
-        let Some(window_adapter) = item.window_adapter() else { return };
+        let Some((window_adapter, position)) = self.window_adapter_and_center() else { return };
         let window = window_adapter.window();
 
-        let item_pos = self.absolute_position();
-        let item_size = self.size();
-        let position = LogicalPosition::new(
-            item_pos.x + item_size.width / 2.,
-            item_pos.y + item_size.height / 2.,
-        );
-
         window.dispatch_event(i_slint_core::platform::WindowEvent::PointerMoved { position });
         window.dispatch_event(i_slint_core::platform::WindowEvent::PointerPressed {
             position,
@@ -886,6 +905,19 @@ pub async fn double_click(&self, button: i_slint_core::platform::PointerEventBut
         );
     }
 
+    /// Returns the window adapter of the element's window and the center of the element in window
+    /// coordinates, which is where simulated clicks are dispatched to.
+    pub(crate) fn window_adapter_and_center(&self) -> Option<(WindowAdapterRc, LogicalPosition)> {
+        let window_adapter = self.item.upgrade()?.window_adapter()?;
+        let item_pos = self.absolute_position();
+        let item_size = self.size();
+        let position = LogicalPosition::new(
+            item_pos.x + item_size.width / 2.,
+            item_pos.y + item_size.height / 2.,
+        );
+        Some((window_adapter, position))
+    }
+
     fn active_popups(&self) -> Vec<(ItemRc, ItemTreeRc)> {
         self.item
             .upgrade()
@@ -904,6 +936,95 @@ fn active_popups(&self) -> Vec<(ItemRc, ItemTreeRc)> {
     }
 }
 
+/// The duration of a simulated frame while animations are running.
+const MOCK_FRAME_DURATION: std::time::Duration = std::time::Duration::from_millis(16);
+
+/// Advances the mock time by at most `max_step`: by one frame if animations are running,
+/// otherwise directly to the next timer timeout. Returns the amount of time that was simulated.
+fn advance_mock_time(max_step: std::time::Duration) -> std::time::Duration {
+    let animating = i_slint_core::animations::CURRENT_ANIMATION_DRIVER
+        .with(|driver| driver.has_active_animations());
+    let until_next_timer = i_slint_core::timers::TimerList::next_timeout().map(|timeout| {
+        std::time::Duration::from_millis(
+            timeout.0.saturating_sub(i_slint_core::animations::current_tick().0),
+        )
+    });
+    let step = match until_next_timer {
+        Some(until_next_timer) if !animating => until_next_timer,
+        Some(until_next_timer) => until_next_timer.min(MOCK_FRAME_DURATION),
+        None => MOCK_FRAME_DURATION,
+    }
+    .max(std::time::Duration::from_millis(1))
+    .min(max_step);
+    i_slint_core::tests::slint_mock_elapsed_time(step.as_millis() as _);
+    step
+}
+
+/// Evaluates `condition` and advances the mock time until the condition returns true or
+/// the `timeout` has elapsed. Returns true if the condition was met; false on timeout.
+///
+/// While animations are running, time is advanced in steps of one frame (16ms), so that
+/// the condition observes intermediate animation states. Otherwise time jumps directly to
+/// the next timer timeout.
+///
+/// This function requires the testing backend to be initialized with mock time, through
+/// [`init_no_event_loop()`](crate::init_no_event_loop) or
+/// [`init_integration_test_with_mock_time()`](crate::init_integration_test_with_mock_time).
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///
+/// export component App {
+///     out property <bool> ready;
+///     Timer {
+///         interval: 500ms;
+///         triggered => { root.ready = true; self.running = false; }
+///     }
+/// }
+///
+/// }
+///
+/// let app = App::new().unwrap();
+/// assert!(!app.get_ready());
+/// assert!(i_slint_backend_testing::wait_for_condition(std::time::Duration::from_secs(1), || app.get_ready()));
+/// ```
+pub fn wait_for_condition(
+    timeout: std::time::Duration,
+    mut condition: impl FnMut() -> bool,
+) -> bool {
+    let mut remaining = timeout;
+    loop {
+        if condition() {
+            return true;
+        }
+        if remaining.is_zero() {
+            return false;
+        }
+        remaining -= advance_mock_time(remaining);
+    }
+}
+
+/// Advances the mock time until no animations are running and no timers are pending, or
+/// the `timeout` has elapsed. Returns true if the UI became idle; false on timeout.
+///
+/// Animations are detected while evaluating the geometry of all the elements of `root`,
+/// so animations of properties that have no effect on the geometry (such as colors) are
+/// only taken into account if they are otherwise read. Note that a repeated timer that
+/// is running keeps the UI from becoming idle.
+///
+/// See [`wait_for_condition()`] for how time is advanced.
+pub fn wait_until_idle(root: &impl ElementRoot, timeout: std::time::Duration) -> bool {
+    let root = root.root_element();
+    wait_for_condition(timeout, || {
+        // Visiting evaluates the geometry of every element, which registers running animations
+        root.visit_descendants(|_| ControlFlow::<()>::Continue(()));
+        !i_slint_core::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations())
+            && i_slint_core::timers::TimerList::next_timeout().is_none()
+    })
+}
+
 async fn wait_for(duration: std::time::Duration) {
     enum AsyncTimerState {
         Starting,
@@ -1248,3 +1369,65 @@ fn test_popups() {
         ["Nested", "Ok"]
     );
 }
+
+#[test]
+fn test_wait_for_condition() {
+    crate::init_no_event_loop();
+
+    slint::slint! {
+        export component App inherits Window {
+            in-out property <bool> loaded: false;
+            in-out property <string> status: "loading";
+            Timer {
+                interval: 300ms;
+                running: !root.loaded;
+                triggered => {
+                    root.loaded = true;
+                    root.status = "done";
+                }
+            }
+            if loaded: result := Rectangle {}
+            status-label := Text {
+                accessible-role: text;
+                accessible-value: root.status;
+                text: root.status;
+            }
+            panel := Rectangle {
+                x: root.loaded ? 0px : -500px;
+                width: 100px;
+                animate x { duration: 200ms; }
+            }
+        }
+    }
+
+    let app = App::new().unwrap();
+    let root = app.root_element();
+    let timeout = std::time::Duration::from_secs(1);
+
+    let start = i_slint_core::tests::slint_get_mocked_time();
+    assert!(root
+        .query_descendants()
+        .match_id("App::result")
+        .wait_for_first(std::time::Duration::from_millis(100))
+        .is_none());
+    assert_eq!(i_slint_core::tests::slint_get_mocked_time() - start, 100);
+
+    let result = root.query_descendants().match_id("App::result").wait_for_first(timeout);
+    assert!(result.is_some());
+    assert_eq!(i_slint_core::tests::slint_get_mocked_time() - start, 300);
+
+    let label = root.query_descendants().match_id("App::status-label").find_first().unwrap();
+    assert!(label.wait_for_accessible_value("done", timeout));
+
+    let panel = root.query_descendants().match_id("App::panel").wait_for_first(timeout).unwrap();
+    assert!(panel.absolute_position().x < 0.);
+
+    assert!(wait_until_idle(&app, timeout));
+    assert_eq!(panel.absolute_position().x, 0.);
+
+    app.set_loaded(false);
+    assert!(!wait_until_idle(&app, std::time::Duration::from_millis(100)));
+    assert!(wait_for_condition(timeout, || !panel.is_visible()));
+    assert!(!panel.is_visible());
+    assert!(panel.wait_until_visible(timeout));
+}
@@ -25,6 +25,8 @@ pub struct TestingBackend {
     clipboard: Mutex<Option<String>>,
     queue: Option<Queue>,
     mock_time: bool,
+    #[cfg(feature = "screenshot")]
+    software_renderer: bool,
 }
 
 impl TestingBackend {
@@ -33,8 +35,18 @@ pub fn new(options: TestingBackendOptions) -> Self {
             clipboard: Mutex::default(),
             queue: options.threading.then(|| Queue(Default::default(), std::thread::current())),
             mock_time: options.mock_time,
+            #[cfg(feature = "screenshot")]
+            software_renderer: false,
         }
     }
+
+    /// Render the windows with Slint's software renderer, so that they can be captured
+    /// with [`Window::take_snapshot()`](i_slint_core::api::Window::take_snapshot).
+    #[cfg(feature = "screenshot")]
+    pub fn with_software_renderer(mut self) -> Self {
+        self.software_renderer = true;
+        self
+    }
 }
 
 impl i_slint_core::platform::Platform for TestingBackend {
@@ -46,6 +58,10 @@ fn create_window_adapter(
             size: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
+            #[cfg(feature = "screenshot")]
+            software_renderer: self
+                .software_renderer
+                .then(i_slint_core::software_renderer::SoftwareRenderer::new),
         }))
     }
 
@@ -109,6 +125,8 @@ pub struct TestingWindow {
     size: Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    #[cfg(feature = "screenshot")]
+    software_renderer: Option<i_slint_core::software_renderer::SoftwareRenderer>,
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -146,6 +164,10 @@ fn set_size(&self, size: i_slint_core::api::WindowSize) {
     }
 
     fn renderer(&self) -> &dyn Renderer {
+        #[cfg(feature = "screenshot")]
+        if let Some(software_renderer) = &self.software_renderer {
+            return software_renderer;
+        }
         self
     }
 
@@ -164,13 +186,13 @@ fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterI
 impl RendererSealed for TestingWindow {
     fn text_size(
         &self,
-        _font_request: i_slint_core::graphics::FontRequest,
+        font_request: i_slint_core::graphics::FontRequest,
         text: &str,
         _max_width: Option<LogicalLength>,
         _scale_factor: ScaleFactor,
         _text_wrap: TextWrap,
     ) -> LogicalSize {
-        LogicalSize::new(text.len() as f32 * 10., 10.)
+        LogicalSize::new(text.len() as f32 * 10., line_height(&font_request))
     }
 
     fn font_metrics(
@@ -191,34 +213,35 @@ fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         pos: LogicalPoint,
-        _font_request: FontRequest,
+        font_request: FontRequest,
         _scale_factor: ScaleFactor,
     ) -> usize {
-        let text = text_input.text();
-        if pos.y < 0. {
-            return 0;
-        }
-        let line = (pos.y / 10.) as usize;
-        let offset =
-            if line >= 1 { text.split('\n').take(line - 1).map(|l| l.len() + 1).sum() } else { 0 };
-        let Some(line) = text.split('\n').nth(line) else {
-            return text.len();
-        };
-        let column = ((pos.x / 10.).max(0.) as usize).min(line.len());
-        offset + column
+        byte_offset_for_position(&text_input.text(), pos, line_advance(&font_request))
+    }
+
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        self_rc: &i_slint_core::items::ItemRc,
+        pos: LogicalPoint,
+        _scale_factor: ScaleFactor,
+    ) -> usize {
+        byte_offset_for_position(&text.text(), pos, line_advance(&text.font_request(self_rc)))
     }
 
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
         byte_offset: usize,
-        _font_request: FontRequest,
+        font_request: FontRequest,
         _scale_factor: ScaleFactor,
     ) -> LogicalRect {
         let text = text_input.text();
         let line = text[..byte_offset].chars().filter(|c| *c == '\n').count();
         let column = text[..byte_offset].split('\n').nth(line).unwrap_or("").len();
-        LogicalRect::new(Point2D::new(column as f32 * 10., line as f32 * 10.), Size2D::new(1., 10.))
+        let half_leading = (line_height(&font_request) - 10.) / 2.;
+        let y = line as f32 * line_advance(&font_request) + half_leading;
+        LogicalRect::new(Point2D::new(column as f32 * 10., y), Size2D::new(1., 10.))
     }
 
     fn register_font_from_memory(
@@ -244,6 +267,30 @@ fn set_window_adapter(&self, _window_adapter: &Rc<dyn WindowAdapter>) {
     }
 }
 
+fn line_height(font_request: &FontRequest) -> f32 {
+    font_request.line_height.map_or(10., |height| height.get())
+}
+
+/// The distance between the tops of two lines, which are always separated by a line break.
+fn line_advance(font_request: &FontRequest) -> f32 {
+    line_height(font_request) + font_request.paragraph_spacing.map_or(0., |spacing| spacing.get())
+}
+
+/// Every character is 10x10 logical pixels big.
+fn byte_offset_for_position(text: &str, pos: LogicalPoint, line_advance: f32) -> usize {
+    if pos.y < 0. {
+        return 0;
+    }
+    let line = (pos.y / line_advance) as usize;
+    let offset =
+        if line >= 1 { text.split('\n').take(line - 1).map(|l| l.len() + 1).sum() } else { 0 };
+    let Some(line) = text.split('\n').nth(line) else {
+        return text.len();
+    };
+    let column = ((pos.x / 10.).max(0.) as usize).min(line.len());
+    offset + column
+}
+
 enum Event {
     Quit,
     Event(Box<dyn FnOnce() + Send>),
@@ -236,11 +236,36 @@ macro_rules! winit_key_to_char {
             }
                 let text = i_slint_common::for_each_special_keys!(winit_key_to_char);
 
+                macro_rules! winit_key_code_to_physical_key {
+                    ($($name:ident # $_evdev:literal # $winit:ident ;)*) => {
+                        match event.physical_key {
+                            $(winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::$winit) => {
+                                corelib::platform::PhysicalKey::$name
+                            })*
+                            _ => corelib::platform::PhysicalKey::Unidentified,
+                        }
+                    };
+                }
+                let physical_key =
+                    i_slint_common::for_each_physical_keys!(winit_key_code_to_physical_key);
+                let location = match event.location {
+                    winit::keyboard::KeyLocation::Left => corelib::platform::KeyLocation::Left,
+                    winit::keyboard::KeyLocation::Right => corelib::platform::KeyLocation::Right,
+                    winit::keyboard::KeyLocation::Numpad => corelib::platform::KeyLocation::Numpad,
+                    winit::keyboard::KeyLocation::Standard => {
+                        corelib::platform::KeyLocation::Standard
+                    }
+                };
+
                 self.loop_error = window
                     .window()
                     .try_dispatch_event(match event.state {
                         winit::event::ElementState::Pressed if event.repeat => {
-                            corelib::platform::WindowEvent::KeyPressRepeated { text }
+                            corelib::platform::WindowEvent::PhysicalKeyPressRepeated {
+                                text,
+                                physical_key,
+                                location,
+                            }
                         }
                         winit::event::ElementState::Pressed => {
                             if is_synthetic {
@@ -254,10 +279,18 @@ macro_rules! winit_key_to_char {
                                     return;
                                 }
                             }
-                            corelib::platform::WindowEvent::KeyPressed { text }
+                            corelib::platform::WindowEvent::PhysicalKeyPressed {
+                                text,
+                                physical_key,
+                                location,
+                            }
                         }
                         winit::event::ElementState::Released => {
-                            corelib::platform::WindowEvent::KeyReleased { text }
+                            corelib::platform::WindowEvent::PhysicalKeyReleased {
+                                text,
+                                physical_key,
+                                location,
+                            }
                         }
                     })
                     .err();
@@ -89,7 +89,31 @@ fn generate_menu_entry(
                 Box::new(muda::PredefinedMenuItem::separator())
             } else if !entry.has_sub_menu && depth != 0 {
                 // the top level always has a sub menu regardless of entry.has_sub_menu
-                Box::new(muda::MenuItem::with_id(id.clone(), &entry.title, entry.enabled, None))
+                let accelerator = to_accelerator(&entry.shortcut);
+                if entry.checkable {
+                    Box::new(muda::CheckMenuItem::with_id(
+                        id.clone(),
+                        &entry.title,
+                        entry.enabled,
+                        entry.checked,
+                        accelerator,
+                    ))
+                } else if let Some(icon) = to_icon(&entry.icon) {
+                    Box::new(muda::IconMenuItem::with_id(
+                        id.clone(),
+                        &entry.title,
+                        entry.enabled,
+                        Some(icon),
+                        accelerator,
+                    ))
+                } else {
+                    Box::new(muda::MenuItem::with_id(
+                        id.clone(),
+                        &entry.title,
+                        entry.enabled,
+                        accelerator,
+                    ))
+                }
             } else {
                 let sub_menu = muda::Submenu::with_id(id.clone(), &entry.title, entry.enabled);
                 if depth < 15 {
@@ -167,6 +191,74 @@ pub fn window_activation_changed(&self, is_active: bool) {
     }
 }
 
+/// Convert a shortcut of a MenuItem (such as `"Ctrl+Shift+S"`) to a muda accelerator.
+/// Like in KeyboardModifiers, `Ctrl` is the Command key on macOS and `Meta` is the Control key.
+fn to_accelerator(shortcut: &str) -> Option<muda::accelerator::Accelerator> {
+    use i_slint_common::shortcuts::Shortcut;
+    let accelerator = match i_slint_common::shortcuts::parse_shortcut(shortcut)? {
+        Shortcut::Standard(name) => match name {
+            "Copy" => "CmdOrCtrl+C".into(),
+            "Cut" => "CmdOrCtrl+X".into(),
+            "Paste" => "CmdOrCtrl+V".into(),
+            "SelectAll" => "CmdOrCtrl+A".into(),
+            "Find" => "CmdOrCtrl+F".into(),
+            "Save" => "CmdOrCtrl+S".into(),
+            "Print" => "CmdOrCtrl+P".into(),
+            "Undo" => "CmdOrCtrl+Z".into(),
+            "Redo" if cfg!(target_os = "windows") => "Ctrl+Y".into(),
+            "Redo" => "CmdOrCtrl+Shift+Z".into(),
+            "Refresh" => "CmdOrCtrl+R".into(),
+            _ => return None,
+        },
+        Shortcut::Key { modifiers, key } => {
+            let mut accelerator = String::new();
+            if modifiers.control {
+                accelerator.push_str("CmdOrCtrl+");
+            }
+            if modifiers.shift {
+                accelerator.push_str("Shift+");
+            }
+            if modifiers.alt {
+                accelerator.push_str("Alt+");
+            }
+            if modifiers.meta {
+                accelerator.push_str(if cfg!(target_os = "macos") { "Ctrl+" } else { "Super+" });
+            }
+            accelerator.push_str(&accelerator_key(key));
+            accelerator
+        }
+    };
+    accelerator.parse().ok()
+}
+
+/// The name of the key in a muda accelerator: the name of the winit key for the special keys
+fn accelerator_key(key: char) -> String {
+    macro_rules! winit_key_name {
+        ($($char:literal # $name:ident # $($_qt:ident)|* # $($winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|*;)*) => {
+            match key {
+                $($char => {
+                    let names: &[&str] = &[$(stringify!($winit)),*];
+                    if let Some(name) = names.first() {
+                        return name.to_string();
+                    }
+                })*
+                _ => {}
+            }
+        };
+    }
+    i_slint_common::for_each_special_keys!(winit_key_name);
+    key.to_uppercase().collect()
+}
+
+fn to_icon(image: &i_slint_core::graphics::Image) -> Option<muda::Icon> {
+    let size = image.size();
+    if size.width == 0 || size.height == 0 {
+        return None;
+    }
+    let buffer = image.to_rgba8()?;
+    muda::Icon::from_rgba(buffer.as_bytes().to_vec(), buffer.width(), buffer.height()).ok()
+}
+
 #[cfg(target_os = "macos")]
 fn create_default_app_menu(menu_bar: &muda::Menu) -> Result<(), i_slint_core::api::PlatformError> {
     let app_menu = muda::Submenu::new("App", true);
@@ -21,7 +21,7 @@
 use std::rc::{Rc, Weak};
 
 use i_slint_core::input::{KeyEvent, KeyEventType};
-use i_slint_core::platform::WindowEvent;
+use i_slint_core::platform::{KeyLocation, PhysicalKey, WindowEvent};
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::SharedString;
 use wasm_bindgen::closure::Closure;
@@ -185,10 +185,11 @@ pub fn new(
                 }
 
                 shared_state2.borrow_mut().has_key_down = true;
+                let (physical_key, location) = event_physical_key(&e);
                 let win_event = if e.repeat() {
-                    WindowEvent::KeyPressRepeated { text }
+                    WindowEvent::PhysicalKeyPressRepeated { text, physical_key, location }
                 } else {
-                    WindowEvent::KeyPressed { text }
+                    WindowEvent::PhysicalKeyPressed { text, physical_key, location }
                 };
                 window_adapter.window().dispatch_event(win_event);
             }
@@ -202,7 +203,12 @@ pub fn new(
             {
                 e.prevent_default();
                 shared_state2.borrow_mut().has_key_down = false;
-                window_adapter.window().dispatch_event(WindowEvent::KeyReleased { text });
+                let (physical_key, location) = event_physical_key(&e);
+                window_adapter.window().dispatch_event(WindowEvent::PhysicalKeyReleased {
+                    text,
+                    physical_key,
+                    location,
+                });
             }
         });
 
@@ -289,6 +295,22 @@ fn add_event_listener<Arg: FromWasmAbi + 'static>(
     }
 }
 
+fn event_physical_key(e: &web_sys::KeyboardEvent) -> (PhysicalKey, KeyLocation) {
+    let location = match e.location() {
+        web_sys::KeyboardEvent::DOM_KEY_LOCATION_LEFT => KeyLocation::Left,
+        web_sys::KeyboardEvent::DOM_KEY_LOCATION_RIGHT => KeyLocation::Right,
+        web_sys::KeyboardEvent::DOM_KEY_LOCATION_NUMPAD => KeyLocation::Numpad,
+        _ => KeyLocation::Standard,
+    };
+    // Older versions of Firefox report the Meta keys as "OSLeft" and "OSRight"
+    let physical_key = match e.code().as_str() {
+        "OSLeft" => PhysicalKey::MetaLeft,
+        "OSRight" => PhysicalKey::MetaRight,
+        code => i_slint_core::input::physical_key_from_code(code),
+    };
+    (physical_key, location)
+}
+
 fn event_text(e: &web_sys::KeyboardEvent, is_apple: bool) -> Option<SharedString> {
     if e.is_composing() {
         return None;
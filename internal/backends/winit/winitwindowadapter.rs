@@ -1315,6 +1315,48 @@ fn bring_to_front(&self) -> Result<(), PlatformError> {
         }
         Ok(())
     }
+
+    fn set_transient_parent(&self, parent: &Rc<dyn WindowAdapter>, _modal: bool) {
+        let Some(parent_window) = parent
+            .internal(corelib::InternalToken)
+            .and_then(|x| x.as_any().downcast_ref::<WinitWindowAdapter>())
+            .and_then(|parent| parent.winit_window())
+        else {
+            return;
+        };
+        // The relationship can only be set when creating the winit window
+        if let WinitWindowOrNone::None(attributes) = &*self.winit_window_or_none.borrow() {
+            let mut attributes = attributes.borrow_mut();
+            *attributes = with_transient_parent(attributes.clone(), &parent_window);
+        }
+    }
+}
+
+/// Keeps the window created with the attributes on top of `parent`. Winit only supports this on Windows and macOS.
+#[cfg_attr(not(any(target_os = "windows", target_os = "macos")), allow(unused_variables))]
+fn with_transient_parent(
+    attributes: WindowAttributes,
+    parent: &winit::window::Window,
+) -> WindowAttributes {
+    #[cfg(target_os = "windows")]
+    {
+        use raw_window_handle::HasWindowHandle;
+        use winit::platform::windows::WindowAttributesExtWindows;
+        if let Ok(raw_window_handle::RawWindowHandle::Win32(handle)) =
+            parent.window_handle().map(|handle| handle.as_raw())
+        {
+            return attributes.with_owner_window(handle.hwnd.get());
+        }
+    }
+    #[cfg(target_os = "macos")]
+    {
+        use raw_window_handle::HasWindowHandle;
+        if let Ok(handle) = parent.window_handle() {
+            // Safety: the parent window is kept alive by its WinitWindowAdapter, which owns the secondary window.
+            return unsafe { attributes.with_parent_window(Some(handle.as_raw())) };
+        }
+    }
+    attributes
 }
 
 impl Drop for WinitWindowAdapter {
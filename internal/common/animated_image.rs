@@ -0,0 +1,66 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Decoding of the frames of animated GIF, APNG, and WebP images.
+//!
+//! This is shared between the compiler, which embeds the frames as textures,
+//! and the runtime, which decodes the images that are loaded from files or embedded as data.
+
+use image::AnimationDecoder;
+use std::time::Duration;
+
+/// Returns true if images in the given format may have several frames.
+pub fn is_animated_format(format: image::ImageFormat) -> bool {
+    match format {
+        #[cfg(feature = "image-default-formats")]
+        image::ImageFormat::Gif | image::ImageFormat::WebP => true,
+        image::ImageFormat::Png => true,
+        _ => false,
+    }
+}
+
+/// Decodes all the frames of an animated image, with how long each of them is shown.
+/// Returns None if the image has a single frame, so that it's loaded as a still image instead.
+pub fn decode_animation_frames(
+    data: &[u8],
+    format: image::ImageFormat,
+) -> image::ImageResult<Option<Vec<(image::RgbaImage, Duration)>>> {
+    let reader = std::io::Cursor::new(data);
+    let frames = match format {
+        #[cfg(feature = "image-default-formats")]
+        image::ImageFormat::Gif => image::codecs::gif::GifDecoder::new(reader)?.into_frames(),
+        image::ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(reader)?;
+            if !decoder.is_apng()? {
+                return Ok(None);
+            }
+            decoder.apng()?.into_frames()
+        }
+        #[cfg(feature = "image-default-formats")]
+        image::ImageFormat::WebP => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader)?;
+            if !decoder.has_animation() {
+                return Ok(None);
+            }
+            decoder.into_frames()
+        }
+        _ => return Ok(None),
+    };
+    let frames = frames.collect_frames()?;
+    if frames.len() < 2 {
+        return Ok(None);
+    }
+    Ok(Some(
+        frames
+            .into_iter()
+            .map(|frame| {
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+                let millis = numerator / denominator.max(1);
+                // Like web browsers, use a default for the very short delays that many files have
+                let duration =
+                    Duration::from_millis(if millis <= 10 { 100 } else { millis as u64 });
+                (frame.into_buffer(), duration)
+            })
+            .collect(),
+    ))
+}
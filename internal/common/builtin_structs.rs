@@ -97,6 +97,13 @@ struct KeyEvent {
                     /// This field is set to true for key press events that are repeated,
                     /// i.e. the key is held down. It's always false for key release events.
                     repeat: bool,
+                    /// The physical key, independent of the keyboard layout, such as `PhysicalKey::KeyW`
+                    /// for the key that produces a `W` on a US keyboard.
+                    /// `PhysicalKey::Unidentified` if the platform doesn't provide it. The Qt backend
+                    /// provides it only on Linux.
+                    physical_key: PhysicalKey,
+                    /// The location of the key on the keyboard.
+                    location: KeyLocation,
                 }
                 private {
                     /// Indicates whether the key was pressed or released
@@ -127,6 +134,24 @@ struct StandardListViewItem {
                 }
             }
 
+            /// Represents an item in a TreeView.
+            #[non_exhaustive]
+            struct TreeViewItem {
+                @name = "slint::TreeViewItem"
+                export {
+                    /// The text content of the item
+                    text: SharedString,
+                    /// The depth of the item in the tree. Top level items have the level 0.
+                    level: i32,
+                    /// Whether the item has children and can be expanded
+                    has_children: bool,
+                    /// Whether the children of the item are shown
+                    expanded: bool,
+                }
+                private {
+                }
+            }
+
             /// This is used to define the column and the column header of a TableView
             #[non_exhaustive]
             struct TableColumn {
@@ -189,13 +214,18 @@ struct MenuEntry {
                 export {
                     /// The text of the menu entry
                     title: SharedString,
-                    // /// the icon associated with the menu entry
-                    // icon: Image,
+                    /// the icon associated with the menu entry
+                    icon: Image,
                     /// an opaque id that can be used to identify the menu entry
                     id: SharedString,
-                    // keyboard_shortcut: KeySequence,
+                    /// The keyboard shortcut that activates the menu entry, such as `"Ctrl+S"`
+                    shortcut: SharedString,
                     /// whether the menu entry is enabled
                     enabled: bool,
+                    /// whether the menu entry can be checked
+                    checkable: bool,
+                    /// whether the menu entry is checked
+                    checked: bool,
                     /// Sub menu
                     has_sub_menu: bool,
                     /// The menu entry is a separator
@@ -58,6 +58,16 @@ enum TextOverflow {
                 Elide,
             }
 
+            /// This enum describes how the `text` property of a `Text` element is interpreted.
+            enum TextFormat {
+                /// The text is displayed as is.
+                PlainText,
+                /// The text is interpreted as markup, which allows styling parts of it.
+                /// The supported tags are `<b>`, `<i>`, `<u>`, `<a href="...">`, `<br>`, and `<span>` with the
+                /// `color`, `font-size`, and `font-weight` attributes.
+                Markup,
+            }
+
             /// This enum describes the positioning of a text stroke relative to the border of the glyphs in a `Text`.
             enum TextStrokeStyle {
                 /// The inside edge of the stroke is at the outer edge of the text.
@@ -66,6 +76,15 @@ enum TextStrokeStyle {
                 Center,
             }
 
+            /// This enum describes the style of the lines drawn by the text decorations of a `Text` or `TextInput`,
+            /// such as the underline.
+            enum TextDecorationStyle {
+                /// A straight line.
+                Solid,
+                /// A wavy line, as commonly used to mark spelling errors.
+                Wavy,
+            }
+
             /// This enum describes whether an event was rejected or accepted by an event handler.
             enum EventResult {
                 /// The event is rejected by this event handler and may then be handled by the parent item
@@ -158,6 +177,264 @@ enum PointerEventKind {
                 Move,
             }
 
+            /// This enum describes a physical key of the keyboard, independent of the keyboard layout.
+            /// The keys are named after the `code` values of the W3C UI Events, and the documentation
+            /// refers to the character the key produces on a US keyboard.
+            #[non_exhaustive]
+            enum PhysicalKey {
+                /// The platform didn't report the physical key, or the key isn't known to Slint.
+                Unidentified,
+                /// The Escape key.
+                Escape,
+                /// The `0` key of the main keyboard.
+                Digit0,
+                /// The `1` key of the main keyboard.
+                Digit1,
+                /// The `2` key of the main keyboard.
+                Digit2,
+                /// The `3` key of the main keyboard.
+                Digit3,
+                /// The `4` key of the main keyboard.
+                Digit4,
+                /// The `5` key of the main keyboard.
+                Digit5,
+                /// The `6` key of the main keyboard.
+                Digit6,
+                /// The `7` key of the main keyboard.
+                Digit7,
+                /// The `8` key of the main keyboard.
+                Digit8,
+                /// The `9` key of the main keyboard.
+                Digit9,
+                /// The `-` key on a US keyboard.
+                Minus,
+                /// The `=` key on a US keyboard.
+                Equal,
+                /// The Backspace key.
+                Backspace,
+                /// The Tab key.
+                Tab,
+                /// The `A` key on a US keyboard.
+                KeyA,
+                /// The `B` key on a US keyboard.
+                KeyB,
+                /// The `C` key on a US keyboard.
+                KeyC,
+                /// The `D` key on a US keyboard.
+                KeyD,
+                /// The `E` key on a US keyboard.
+                KeyE,
+                /// The `F` key on a US keyboard.
+                KeyF,
+                /// The `G` key on a US keyboard.
+                KeyG,
+                /// The `H` key on a US keyboard.
+                KeyH,
+                /// The `I` key on a US keyboard.
+                KeyI,
+                /// The `J` key on a US keyboard.
+                KeyJ,
+                /// The `K` key on a US keyboard.
+                KeyK,
+                /// The `L` key on a US keyboard.
+                KeyL,
+                /// The `M` key on a US keyboard.
+                KeyM,
+                /// The `N` key on a US keyboard.
+                KeyN,
+                /// The `O` key on a US keyboard.
+                KeyO,
+                /// The `P` key on a US keyboard.
+                KeyP,
+                /// The `Q` key on a US keyboard.
+                KeyQ,
+                /// The `R` key on a US keyboard.
+                KeyR,
+                /// The `S` key on a US keyboard.
+                KeyS,
+                /// The `T` key on a US keyboard.
+                KeyT,
+                /// The `U` key on a US keyboard.
+                KeyU,
+                /// The `V` key on a US keyboard.
+                KeyV,
+                /// The `W` key on a US keyboard.
+                KeyW,
+                /// The `X` key on a US keyboard.
+                KeyX,
+                /// The `Y` key on a US keyboard.
+                KeyY,
+                /// The `Z` key on a US keyboard.
+                KeyZ,
+                /// The `[` key on a US keyboard.
+                BracketLeft,
+                /// The `]` key on a US keyboard.
+                BracketRight,
+                /// The Enter key of the main keyboard.
+                Enter,
+                /// The left Control key.
+                ControlLeft,
+                /// The `;` key on a US keyboard.
+                Semicolon,
+                /// The `'` key on a US keyboard.
+                Quote,
+                /// The `` ` `` key on a US keyboard.
+                Backquote,
+                /// The left Shift key.
+                ShiftLeft,
+                /// The `\` key on a US keyboard.
+                Backslash,
+                /// The `,` key on a US keyboard.
+                Comma,
+                /// The `.` key on a US keyboard.
+                Period,
+                /// The `/` key on a US keyboard.
+                Slash,
+                /// The right Shift key.
+                ShiftRight,
+                /// The left Alt key.
+                AltLeft,
+                /// The space bar.
+                Space,
+                /// The Caps Lock key.
+                CapsLock,
+                /// The F1 key.
+                F1,
+                /// The F2 key.
+                F2,
+                /// The F3 key.
+                F3,
+                /// The F4 key.
+                F4,
+                /// The F5 key.
+                F5,
+                /// The F6 key.
+                F6,
+                /// The F7 key.
+                F7,
+                /// The F8 key.
+                F8,
+                /// The F9 key.
+                F9,
+                /// The F10 key.
+                F10,
+                /// The F11 key.
+                F11,
+                /// The F12 key.
+                F12,
+                /// The F13 key.
+                F13,
+                /// The F14 key.
+                F14,
+                /// The F15 key.
+                F15,
+                /// The F16 key.
+                F16,
+                /// The F17 key.
+                F17,
+                /// The F18 key.
+                F18,
+                /// The F19 key.
+                F19,
+                /// The F20 key.
+                F20,
+                /// The F21 key.
+                F21,
+                /// The F22 key.
+                F22,
+                /// The F23 key.
+                F23,
+                /// The F24 key.
+                F24,
+                /// The Num Lock key.
+                NumLock,
+                /// The Scroll Lock key.
+                ScrollLock,
+                /// The `0` key of the numeric keypad.
+                Numpad0,
+                /// The `1` key of the numeric keypad.
+                Numpad1,
+                /// The `2` key of the numeric keypad.
+                Numpad2,
+                /// The `3` key of the numeric keypad.
+                Numpad3,
+                /// The `4` key of the numeric keypad.
+                Numpad4,
+                /// The `5` key of the numeric keypad.
+                Numpad5,
+                /// The `6` key of the numeric keypad.
+                Numpad6,
+                /// The `7` key of the numeric keypad.
+                Numpad7,
+                /// The `8` key of the numeric keypad.
+                Numpad8,
+                /// The `9` key of the numeric keypad.
+                Numpad9,
+                /// The `+` key of the numeric keypad.
+                NumpadAdd,
+                /// The `-` key of the numeric keypad.
+                NumpadSubtract,
+                /// The `*` key of the numeric keypad.
+                NumpadMultiply,
+                /// The `/` key of the numeric keypad.
+                NumpadDivide,
+                /// The decimal separator key of the numeric keypad.
+                NumpadDecimal,
+                /// The `=` key of the numeric keypad.
+                NumpadEqual,
+                /// The Enter key of the numeric keypad.
+                NumpadEnter,
+                /// The additional key next to the left Shift key on ISO keyboards.
+                IntlBackslash,
+                /// The right Control key.
+                ControlRight,
+                /// The right Alt key, or AltGr.
+                AltRight,
+                /// The left Meta key, such as the Windows key or the Command key.
+                MetaLeft,
+                /// The right Meta key, such as the Windows key or the Command key.
+                MetaRight,
+                /// The context menu key.
+                ContextMenu,
+                /// The Print Screen key.
+                PrintScreen,
+                /// The Pause key.
+                Pause,
+                /// The Insert key.
+                Insert,
+                /// The Delete key.
+                Delete,
+                /// The Home key.
+                Home,
+                /// The End key.
+                End,
+                /// The Page Up key.
+                PageUp,
+                /// The Page Down key.
+                PageDown,
+                /// The up arrow key.
+                ArrowUp,
+                /// The down arrow key.
+                ArrowDown,
+                /// The left arrow key.
+                ArrowLeft,
+                /// The right arrow key.
+                ArrowRight,
+            }
+
+            /// This enum describes where a key is located on the keyboard, for the keys that
+            /// exist more than once, such as Shift or Enter.
+            enum KeyLocation {
+                /// The key is not duplicated, or its location is unknown.
+                Standard,
+                /// The left one of the keys, such as the left Shift key.
+                Left,
+                /// The right one of the keys, such as the right Shift key.
+                Right,
+                /// The key is on the numeric keypad.
+                Numpad,
+            }
+
             /// This enum describes the different types of buttons for a pointer event,
             /// typically on a mouse or a pencil.
             #[non_exhaustive]
@@ -315,7 +592,7 @@ enum InputType {
 
             /// Enum representing the `alignment` property of a
             /// `HorizontalBox`, a `VerticalBox`,
-            /// a `HorizontalLayout`, or `VerticalLayout`.
+            /// a `HorizontalLayout`, `VerticalLayout`, or `FlexLayout`.
             enum LayoutAlignment {
                 /// Use the minimum size of all elements in a layout, distribute remaining space
                 /// based on `*-stretch` among all elements.
@@ -336,6 +613,14 @@ enum LayoutAlignment {
                 SpaceAround,
             }
 
+            /// Enum representing the `direction` property of a `FlexLayout`.
+            enum FlexDirection {
+                /// Place the elements from left to right, and wrap them onto new rows.
+                Row,
+                /// Place the elements from top to bottom, and wrap them onto new columns.
+                Column,
+            }
+
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
             /// generated at compile time from a higher-level description, such as SVG commands.
             enum PathEvent {
@@ -385,7 +670,7 @@ enum AccessibleRole {
                 Text,
                 /// The role for a `TableView` or behaves like one.
                 Table,
-                /// The role for a TreeView or behaves like one. (Not provided yet)
+                /// The element is a `TreeView` or behaves like one.
                 Tree,
                 /// The element is a `ProgressIndicator` or behaves like one.
                 ProgressIndicator,
@@ -124,3 +124,145 @@ macro_rules! for_each_special_keys {
 ];
     };
 }
+
+/// Call a macro with every physical key known to Slint.
+///
+/// Each entry is the name of the `PhysicalKey` variant (following the `code` values of the W3C UI Events),
+/// the Linux evdev key code (as found in `linux/input-event-codes.h`) and the name of winit's `KeyCode`.
+///
+/// Note that X11 and XKB key codes are the evdev key codes plus 8.
+///
+/// ## Example
+/// ```rust
+/// macro_rules! print_physical_keys {
+///     ($($name:ident # $evdev:literal # $winit:ident ;)*) => {
+///         $(println!("{} => {}", stringify!($name), $evdev);)*
+///     }
+/// }
+/// i_slint_common::for_each_physical_keys!(print_physical_keys);
+/// ```
+#[macro_export]
+macro_rules! for_each_physical_keys {
+    ($macro:ident) => {
+        $macro![
+Escape         # 1   # Escape         ;
+Digit0         # 11  # Digit0         ;
+Digit1         # 2   # Digit1         ;
+Digit2         # 3   # Digit2         ;
+Digit3         # 4   # Digit3         ;
+Digit4         # 5   # Digit4         ;
+Digit5         # 6   # Digit5         ;
+Digit6         # 7   # Digit6         ;
+Digit7         # 8   # Digit7         ;
+Digit8         # 9   # Digit8         ;
+Digit9         # 10  # Digit9         ;
+Minus          # 12  # Minus          ;
+Equal          # 13  # Equal          ;
+Backspace      # 14  # Backspace      ;
+Tab            # 15  # Tab            ;
+KeyA           # 30  # KeyA           ;
+KeyB           # 48  # KeyB           ;
+KeyC           # 46  # KeyC           ;
+KeyD           # 32  # KeyD           ;
+KeyE           # 18  # KeyE           ;
+KeyF           # 33  # KeyF           ;
+KeyG           # 34  # KeyG           ;
+KeyH           # 35  # KeyH           ;
+KeyI           # 23  # KeyI           ;
+KeyJ           # 36  # KeyJ           ;
+KeyK           # 37  # KeyK           ;
+KeyL           # 38  # KeyL           ;
+KeyM           # 50  # KeyM           ;
+KeyN           # 49  # KeyN           ;
+KeyO           # 24  # KeyO           ;
+KeyP           # 25  # KeyP           ;
+KeyQ           # 16  # KeyQ           ;
+KeyR           # 19  # KeyR           ;
+KeyS           # 31  # KeyS           ;
+KeyT           # 20  # KeyT           ;
+KeyU           # 22  # KeyU           ;
+KeyV           # 47  # KeyV           ;
+KeyW           # 17  # KeyW           ;
+KeyX           # 45  # KeyX           ;
+KeyY           # 21  # KeyY           ;
+KeyZ           # 44  # KeyZ           ;
+BracketLeft    # 26  # BracketLeft    ;
+BracketRight   # 27  # BracketRight   ;
+Enter          # 28  # Enter          ;
+ControlLeft    # 29  # ControlLeft    ;
+Semicolon      # 39  # Semicolon      ;
+Quote          # 40  # Quote          ;
+Backquote      # 41  # Backquote      ;
+ShiftLeft      # 42  # ShiftLeft      ;
+Backslash      # 43  # Backslash      ;
+Comma          # 51  # Comma          ;
+Period         # 52  # Period         ;
+Slash          # 53  # Slash          ;
+ShiftRight     # 54  # ShiftRight     ;
+AltLeft        # 56  # AltLeft        ;
+Space          # 57  # Space          ;
+CapsLock       # 58  # CapsLock       ;
+F1             # 59  # F1             ;
+F2             # 60  # F2             ;
+F3             # 61  # F3             ;
+F4             # 62  # F4             ;
+F5             # 63  # F5             ;
+F6             # 64  # F6             ;
+F7             # 65  # F7             ;
+F8             # 66  # F8             ;
+F9             # 67  # F9             ;
+F10            # 68  # F10            ;
+F11            # 87  # F11            ;
+F12            # 88  # F12            ;
+F13            # 183 # F13            ;
+F14            # 184 # F14            ;
+F15            # 185 # F15            ;
+F16            # 186 # F16            ;
+F17            # 187 # F17            ;
+F18            # 188 # F18            ;
+F19            # 189 # F19            ;
+F20            # 190 # F20            ;
+F21            # 191 # F21            ;
+F22            # 192 # F22            ;
+F23            # 193 # F23            ;
+F24            # 194 # F24            ;
+NumLock        # 69  # NumLock        ;
+ScrollLock     # 70  # ScrollLock     ;
+Numpad0        # 82  # Numpad0        ;
+Numpad1        # 79  # Numpad1        ;
+Numpad2        # 80  # Numpad2        ;
+Numpad3        # 81  # Numpad3        ;
+Numpad4        # 75  # Numpad4        ;
+Numpad5        # 76  # Numpad5        ;
+Numpad6        # 77  # Numpad6        ;
+Numpad7        # 71  # Numpad7        ;
+Numpad8        # 72  # Numpad8        ;
+Numpad9        # 73  # Numpad9        ;
+NumpadAdd      # 78  # NumpadAdd      ;
+NumpadSubtract # 74  # NumpadSubtract ;
+NumpadMultiply # 55  # NumpadMultiply ;
+NumpadDivide   # 98  # NumpadDivide   ;
+NumpadDecimal  # 83  # NumpadDecimal  ;
+NumpadEqual    # 117 # NumpadEqual    ;
+NumpadEnter    # 96  # NumpadEnter    ;
+IntlBackslash  # 86  # IntlBackslash  ;
+ControlRight   # 97  # ControlRight   ;
+AltRight       # 100 # AltRight       ;
+MetaLeft       # 125 # SuperLeft      ;
+MetaRight      # 126 # SuperRight     ;
+ContextMenu    # 127 # ContextMenu    ;
+PrintScreen    # 99  # PrintScreen    ;
+Pause          # 119 # Pause          ;
+Insert         # 110 # Insert         ;
+Delete         # 111 # Delete         ;
+Home           # 102 # Home           ;
+End            # 107 # End            ;
+PageUp         # 104 # PageUp         ;
+PageDown       # 109 # PageDown       ;
+ArrowUp        # 103 # ArrowUp        ;
+ArrowDown      # 108 # ArrowDown      ;
+ArrowLeft      # 105 # ArrowLeft      ;
+ArrowRight     # 106 # ArrowRight     ;
+];
+    };
+}
@@ -3,11 +3,14 @@
 
 #![doc = include_str!("README.md")]
 #![doc(html_logo_url = "https://slint.dev/logo/slint-logo-square-light.svg")]
-#![cfg_attr(not(feature = "shared-fontdb"), no_std)]
+#![cfg_attr(not(any(feature = "shared-fontdb", feature = "image-decoders")), no_std)]
 
+#[cfg(feature = "image-decoders")]
+pub mod animated_image;
 pub mod builtin_structs;
 pub mod enums;
 pub mod key_codes;
+pub mod shortcuts;
 
 #[cfg(feature = "shared-fontdb")]
 pub mod sharedfontdb;
@@ -247,16 +247,41 @@ pub struct DesignFontMetrics {
     pub x_height: f32,
     pub cap_height: f32,
     pub units_per_em: f32,
+    /// Top edge of the underline, relative to the baseline.
+    pub underline_position: f32,
+    pub underline_thickness: f32,
+    /// Top edge of the strikethrough line, relative to the baseline.
+    pub strikethrough_position: f32,
+    pub strikethrough_thickness: f32,
 }
 
 impl DesignFontMetrics {
     pub fn new(face: ttf_parser::Face<'_>) -> Self {
+        let units_per_em = face.units_per_em() as f32;
+        let descent = face.descender() as f32;
+        let x_height = face.x_height().unwrap_or_default() as f32;
+        // Fallbacks for fonts that don't specify the decoration lines.
+        let default_thickness = units_per_em / 14.;
+        let (underline_position, underline_thickness) = face
+            .underline_metrics()
+            .filter(|m| m.thickness > 0)
+            .map(|m| (m.position as f32, m.thickness as f32))
+            .unwrap_or((descent / 3., default_thickness));
+        let (strikethrough_position, strikethrough_thickness) = face
+            .strikeout_metrics()
+            .filter(|m| m.thickness > 0)
+            .map(|m| (m.position as f32, m.thickness as f32))
+            .unwrap_or(((x_height + default_thickness) / 2., default_thickness));
         Self {
             ascent: face.ascender() as f32,
-            descent: face.descender() as f32,
-            x_height: face.x_height().unwrap_or_default() as f32,
+            descent,
+            x_height,
             cap_height: face.capital_height().unwrap_or_default() as f32,
-            units_per_em: face.units_per_em() as f32,
+            units_per_em,
+            underline_position,
+            underline_thickness,
+            strikethrough_position,
+            strikethrough_thickness,
         }
     }
 }
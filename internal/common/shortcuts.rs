@@ -0,0 +1,103 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Parsing of keyboard shortcuts, such as `"Ctrl+Shift+S"`, `"F5"` or `"Copy"`.
+//!
+//! This is shared between the compiler, which validates the shortcuts known at compile time,
+//! and the runtime, which matches them against key events.
+
+/// The names of the standard shortcuts, whose key combination depends on the platform
+pub const STANDARD_SHORTCUTS: &[&str] =
+    &["Copy", "Cut", "Paste", "SelectAll", "Find", "Save", "Print", "Undo", "Redo", "Refresh"];
+
+/// The modifiers of a [`Shortcut`]. They follow the same mapping as the `KeyboardModifiers`:
+/// on macOS, `control` is the Command key and `meta` is the Control key.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ShortcutModifiers {
+    pub control: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+}
+
+/// A parsed shortcut
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Shortcut {
+    /// A key pressed together with some modifiers. Letters are lowercase.
+    Key { modifiers: ShortcutModifiers, key: char },
+    /// One of the [`STANDARD_SHORTCUTS`]
+    Standard(&'static str),
+}
+
+/// Parse a shortcut.
+///
+/// The modifiers are `Ctrl` (alias `Control`, `Cmd`, `Command`), `Shift`, `Alt` (alias `Option`)
+/// and `Meta` (alias `Super`, `Win`), separated by `+`. The key is either a single character,
+/// or the name of a key of the `Key` namespace. The name of a standard shortcut, such as
+/// `"Copy"`, may also be used on its own.
+pub fn parse_shortcut(shortcut: &str) -> Option<Shortcut> {
+    let shortcut = shortcut.trim();
+    if let Some(standard) = STANDARD_SHORTCUTS.iter().find(|s| s.eq_ignore_ascii_case(shortcut)) {
+        return Some(Shortcut::Standard(standard));
+    }
+
+    let (modifiers_str, key) = if shortcut == "+" {
+        ("", "+")
+    } else if let Some(modifiers_str) = shortcut.strip_suffix("++") {
+        (modifiers_str, "+")
+    } else {
+        shortcut.rsplit_once('+').unwrap_or(("", shortcut))
+    };
+
+    let mut modifiers = ShortcutModifiers::default();
+    for modifier in modifiers_str.split('+').map(str::trim).filter(|m| !m.is_empty()) {
+        let is = |names: &[&str]| names.iter().any(|n| n.eq_ignore_ascii_case(modifier));
+        if is(&["ctrl", "control", "cmd", "command"]) {
+            modifiers.control = true;
+        } else if is(&["shift"]) {
+            modifiers.shift = true;
+        } else if is(&["alt", "option"]) {
+            modifiers.alt = true;
+        } else if is(&["meta", "super", "win"]) {
+            modifiers.meta = true;
+        } else {
+            return None;
+        }
+    }
+
+    let key = key.trim();
+    let mut chars = key.chars();
+    let key = match (chars.next(), chars.next()) {
+        (None, _) => return None,
+        (Some(c), None) => c.to_lowercase().next().unwrap_or(c),
+        (Some(_), Some(_)) => special_key_from_name(key)?,
+    };
+    Some(Shortcut::Key { modifiers, key })
+}
+
+/// Shorter names accepted for some of the keys of the `Key` namespace
+const KEY_ALIASES: &[(&str, &str)] = &[
+    ("Enter", "Return"),
+    ("Esc", "Escape"),
+    ("Del", "Delete"),
+    ("Up", "UpArrow"),
+    ("Down", "DownArrow"),
+    ("Left", "LeftArrow"),
+    ("Right", "RightArrow"),
+];
+
+fn special_key_from_name(name: &str) -> Option<char> {
+    let name = KEY_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, key)| key);
+    macro_rules! match_special_key_name {
+        ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|*;)*) => {
+            $(if name.eq_ignore_ascii_case(stringify!($name)) {
+                return Some($char);
+            })*
+        };
+    }
+    crate::for_each_special_keys!(match_special_key_name);
+    None
+}
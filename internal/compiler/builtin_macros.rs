@@ -66,11 +66,52 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
     }
 }
 
+fn spring_macro(
+    node: &dyn Spanned,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 2 {
+        diag.push_error("Not enough arguments".into(), node);
+        return Expression::EasingCurve(EasingCurve::Linear);
+    }
+    if let Some((_, n)) = args.get(3) {
+        diag.push_error("Too many arguments for spring".into(), n);
+        return Expression::EasingCurve(EasingCurve::Linear);
+    }
+    let mut values = [1.; 3];
+    for ((expr, n), value) in args.iter().zip(values.iter_mut()) {
+        *value = match expr {
+            Expression::NumberLiteral(val, Unit::None) => *val as f32,
+            // handle negative numbers
+            Expression::UnaryOp { sub, op: '-' } => match **sub {
+                Expression::NumberLiteral(val, Unit::None) => (-1.0 * val) as f32,
+                _ => f32::NAN,
+            },
+            _ => f32::NAN,
+        };
+        if value.is_nan() {
+            diag.push_error("Arguments to spring must be number literal".into(), n);
+            return Expression::EasingCurve(EasingCurve::Linear);
+        }
+        if *value <= 0. {
+            diag.push_error(
+                "The stiffness, damping and mass of a spring must be positive".into(),
+                n,
+            );
+            return Expression::EasingCurve(EasingCurve::Linear);
+        }
+    }
+    let [stiffness, damping, mass] = values;
+    Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass))
+}
+
 fn min_max_macro(
     node: &dyn Spanned,
     op: MinMaxOp,
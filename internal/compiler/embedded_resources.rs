@@ -98,6 +98,9 @@ pub enum EmbeddedResourcesKind {
     /// The data has been processed in a texture
     #[cfg(feature = "software-renderer")]
     TextureData(Texture),
+    /// The frames of an animated image, each processed in a texture, with how long they are shown in milliseconds
+    #[cfg(feature = "software-renderer")]
+    AnimatedTextureData(Vec<(Texture, u32)>),
     /// A set of pre-rendered glyphs of a TrueType font
     #[cfg(feature = "software-renderer")]
     BitmapFontData(BitmapFont),
@@ -48,6 +48,8 @@ pub enum BuiltinFunction {
     ClearFocusItem,
     ShowPopupWindow,
     ClosePopupWindow,
+    ShowSecondaryWindow,
+    CloseSecondaryWindow,
     /// Show a context popup menu.
     /// Arguments are `(parent, entries, position)`
     ///
@@ -56,6 +58,14 @@ pub enum BuiltinFunction {
     /// When it is a menu item tree, it is a ElementReference to the root of the tree, and in the LLR, a NumberLiteral to an index in  [`crate::llr::SubComponent::menu_item_trees`]
     ShowPopupMenu,
     SetSelectionOffsets,
+    /// the Flickable's `scroll-to(x, y)`
+    FlickableScrollTo,
+    /// the Flickable's `ensure-visible(x, y, width, height)`
+    FlickableEnsureVisible,
+    /// the ListView's `scroll-to-index(index)`, for the ListView whose binding contains the call.
+    /// Lowered to an assignment to [`crate::object_tree::ListViewInfo::requested_row`]
+    /// by the `lower_scroll_to_index` pass.
+    ListViewScrollToIndex,
     ItemFontMetrics,
     /// the "42".to_float()
     StringToFloat,
@@ -124,6 +134,8 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Abs,
     CubicBezier,
+    /// `spring(stiffness, damping[, mass])` with number literals
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
@@ -186,8 +198,13 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     ClearFocusItem: (Type::ElementReference) -> Type::Void,
     ShowPopupWindow: (Type::ElementReference) -> Type::Void,
     ClosePopupWindow: (Type::ElementReference) -> Type::Void,
+    ShowSecondaryWindow: (Type::ElementReference) -> Type::Void,
+    CloseSecondaryWindow: (Type::ElementReference) -> Type::Void,
     ShowPopupMenu: (Type::ElementReference, Type::Model, typeregister::logical_point_type()) -> Type::Void,
     SetSelectionOffsets: (Type::ElementReference, Type::Int32, Type::Int32) -> Type::Void,
+    FlickableScrollTo: (Type::ElementReference, Type::LogicalLength, Type::LogicalLength) -> Type::Void,
+    FlickableEnsureVisible: (Type::ElementReference, Type::LogicalLength, Type::LogicalLength, Type::LogicalLength, Type::LogicalLength) -> Type::Void,
+    ListViewScrollToIndex: (Type::Int32) -> Type::Void,
     ItemFontMetrics: (Type::ElementReference) -> typeregister::font_metrics_type(),
     StringToFloat: (Type::String) -> Type::Float32,
     StringIsFloat: (Type::String) -> Type::Bool,
@@ -312,8 +329,12 @@ fn is_const(&self) -> bool {
             BuiltinFunction::SetFocusItem | BuiltinFunction::ClearFocusItem => false,
             BuiltinFunction::ShowPopupWindow
             | BuiltinFunction::ClosePopupWindow
+            | BuiltinFunction::ShowSecondaryWindow
+            | BuiltinFunction::CloseSecondaryWindow
             | BuiltinFunction::ShowPopupMenu => false,
             BuiltinFunction::SetSelectionOffsets => false,
+            BuiltinFunction::FlickableScrollTo | BuiltinFunction::FlickableEnsureVisible => false,
+            BuiltinFunction::ListViewScrollToIndex => false,
             BuiltinFunction::ItemFontMetrics => false, // depends also on Window's font properties
             BuiltinFunction::StringToFloat
             | BuiltinFunction::StringIsFloat
@@ -392,8 +413,12 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::SetFocusItem | BuiltinFunction::ClearFocusItem => false,
             BuiltinFunction::ShowPopupWindow
             | BuiltinFunction::ClosePopupWindow
+            | BuiltinFunction::ShowSecondaryWindow
+            | BuiltinFunction::CloseSecondaryWindow
             | BuiltinFunction::ShowPopupMenu => false,
             BuiltinFunction::SetSelectionOffsets => false,
+            BuiltinFunction::FlickableScrollTo | BuiltinFunction::FlickableEnsureVisible => false,
+            BuiltinFunction::ListViewScrollToIndex => false,
             BuiltinFunction::ItemFontMetrics => true,
             BuiltinFunction::StringToFloat
             | BuiltinFunction::StringIsFloat
@@ -1600,6 +1625,8 @@ pub enum EasingCurve {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    /// stiffness, damping, mass
+    Spring(f32, f32, f32),
     // CubicBezierNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
@@ -1612,6 +1639,7 @@ pub enum ImageReference {
     AbsolutePath(SmolStr),
     EmbeddedData { resource_id: usize, extension: String },
     EmbeddedTexture { resource_id: usize },
+    EmbeddedAnimatedTextures { resource_id: usize },
 }
 
 /// Print the expression as a .slint code (not necessarily valid .slint)
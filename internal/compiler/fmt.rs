@@ -1,8 +1,17 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use super::writer::TokenWriter;
-use i_slint_compiler::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
+/*! The `.slint` formatter
+
+The formatted document is emitted token by token through a [`writer::TokenWriter`], so that
+callers can decide what to do with the changes: write the whole file, compute edits, or
+replace the content of some tokens.
+*/
+
+pub mod writer;
+
+use crate::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
+use writer::TokenWriter;
 
 pub fn format_document(
     doc: syntax_nodes::Document,
@@ -150,6 +159,9 @@ fn format_node(
         SyntaxKind::PropertyAnimation => {
             return format_property_animation(node, writer, state);
         }
+        SyntaxKind::Keyframe => {
+            return format_keyframe(node, writer, state);
+        }
         SyntaxKind::ObjectLiteral => {
             return format_object_literal(node, writer, state);
         }
@@ -1147,8 +1159,10 @@ fn format_property_animation(
         }
         acc
     });
+    let has_keyframes = node.children().any(|e| e.kind() == SyntaxKind::Keyframe);
+    let multi_line = bindings > 1 || has_keyframes;
 
-    if bindings > 1 {
+    if multi_line {
         state.indentation_level += 1;
         state.new_line();
     } else {
@@ -1158,7 +1172,7 @@ fn format_property_animation(
     for n in sub {
         if n.kind() == SyntaxKind::RBrace {
             state.whitespace_to_add = None;
-            if bindings > 1 {
+            if multi_line {
                 state.indentation_level -= 1;
                 state.new_line();
             } else {
@@ -1173,6 +1187,30 @@ fn format_property_animation(
     Ok(())
 }
 
+fn format_keyframe(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let _ok = whitespace_to(&mut sub, SyntaxKind::NumberLiteral, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?;
+    state.indentation_level += 1;
+    state.new_line();
+    for n in sub {
+        if n.kind() == SyntaxKind::RBrace {
+            state.whitespace_to_add = None;
+            state.indentation_level -= 1;
+            state.new_line();
+            fold(n, writer, state)?;
+            state.new_line();
+        } else {
+            fold(n, writer, state)?;
+        }
+    }
+    Ok(())
+}
+
 fn format_object_literal(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -1297,18 +1335,15 @@ fn format_member_access(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diagnostics::BuildDiagnostics;
     use crate::fmt::writer::FileWriter;
-    use i_slint_compiler::diagnostics::BuildDiagnostics;
 
     // FIXME more descriptive errors when an assertion fails
     #[track_caller]
     fn assert_formatting(unformatted: &str, formatted: &str) {
         // Parse the unformatted string
-        let syntax_node = i_slint_compiler::parser::parse(
-            String::from(unformatted),
-            None,
-            &mut BuildDiagnostics::default(),
-        );
+        let syntax_node =
+            crate::parser::parse(String::from(unformatted), None, &mut BuildDiagnostics::default());
         // Turn the syntax node into a document
         let doc = syntax_nodes::Document::new(syntax_node).unwrap();
         let mut file = Vec::new();
@@ -17,7 +17,7 @@
 use crate::langtype::ElementType;
 use crate::namedreference::NamedReference;
 use crate::object_tree::{Component, Document, ElementRc};
-use crate::CompilerConfiguration;
+use crate::typeloader::TypeLoader;
 
 #[cfg(feature = "cpp")]
 pub mod cpp;
@@ -25,6 +25,8 @@
 #[cfg(feature = "rust")]
 pub mod rust;
 
+pub mod slint;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum OutputFormat {
     #[cfg(feature = "cpp")]
@@ -33,6 +35,8 @@ pub enum OutputFormat {
     Rust,
     Interpreter,
     Llr,
+    /// A single self-contained `.slint` file with all the imports inlined
+    Slint,
 }
 
 impl OutputFormat {
@@ -58,6 +62,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             #[cfg(feature = "rust")]
             "rust" => Ok(Self::Rust),
             "llr" => Ok(Self::Llr),
+            "slint" => Ok(Self::Slint),
             _ => Err(format!("Unknown output format {s}")),
         }
     }
@@ -67,11 +72,13 @@ pub fn generate(
     format: OutputFormat,
     destination: &mut impl std::io::Write,
     doc: &Document,
-    compiler_config: &CompilerConfiguration,
+    type_loader: &TypeLoader,
 ) -> std::io::Result<()> {
     #![allow(unused_variables)]
     #![allow(unreachable_code)]
 
+    let compiler_config = &type_loader.compiler_config;
+
     match format {
         #[cfg(feature = "cpp")]
         OutputFormat::Cpp(config) => {
@@ -95,6 +102,10 @@ pub fn generate(
             crate::llr::pretty_print::pretty_print(&root, &mut output).unwrap();
             write!(destination, "{output}")?;
         }
+        OutputFormat::Slint => {
+            let output = slint::generate(doc, type_loader)?;
+            write!(destination, "{output}")?;
+        }
     }
     Ok(())
 }
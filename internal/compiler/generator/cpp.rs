@@ -596,13 +596,45 @@ fn property_set_value_code(
     let prop = access_member(property, ctx);
     if let Some((animation, map)) = &ctx.property_info(property).animation {
         let mut animation = (*animation).clone();
-        map.map_expression(&mut animation);
-        let animation_code = compile_expression(&animation, ctx);
-        return format!("{prop}.set_animated_value({value_expr}, {animation_code})");
+        animation.visit_expressions_mut(|e| map.map_expression(e));
+        return match &animation {
+            llr::Animation::Keyframes(animation, keyframes) => {
+                let animation_code = compile_expression(animation, ctx);
+                let keyframes = compile_keyframes(keyframes, ctx.property_ty(property), ctx);
+                format!(
+                    "{prop}.set_animated_value_with_keyframes({value_expr}, {animation_code}, {keyframes})"
+                )
+            }
+            llr::Animation::Static(animation) | llr::Animation::Transition(animation, _) => {
+                let animation_code = compile_expression(animation, ctx);
+                format!("{prop}.set_animated_value({value_expr}, {animation_code})")
+            }
+        };
     }
     format!("{prop}.set({value_expr})")
 }
 
+/// Returns the code for a `std::vector` of keyframes for a property of type `prop_type`
+fn compile_keyframes(
+    keyframes: &[llr::Keyframe],
+    prop_type: &Type,
+    ctx: &EvaluationContext,
+) -> String {
+    let cpp_type = prop_type.cpp_type().unwrap_or_default();
+    let keyframes = keyframes
+        .iter()
+        .map(|kf| {
+            format!(
+                "slint::cbindgen_private::Keyframe<{cpp_type}>{{ float({offset}), {easing}, {value} }}",
+                offset = kf.offset,
+                easing = compile_expression(&kf.easing, ctx),
+                value = compile_expression(&kf.value, ctx),
+            )
+        })
+        .join(", ");
+    format!("std::vector<slint::cbindgen_private::Keyframe<{cpp_type}>>{{ {keyframes} }}")
+}
+
 fn handle_property_init(
     prop: &llr::PropertyReference,
     binding_expression: &llr::BindingExpression,
@@ -654,16 +686,35 @@ fn handle_property_init(
                         let anim = compile_expression(anim, ctx);
                         format!("{prop_access}.set_animated_binding({binding_code}, {anim});")
                     }
+                    Some(llr::Animation::Keyframes(anim, keyframes)) => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(keyframes, prop_type, ctx);
+                        format!("{prop_access}.set_animated_binding_with_keyframes({binding_code}, {anim}, {keyframes});")
+                    }
                     Some(llr::Animation::Transition (
-                        anim
+                        anim, keyframes
                     )) => {
                         let anim = compile_expression(anim, ctx);
+                        let cpp_type = prop_type.cpp_type().unwrap_or_default();
+                        // The keyframes of the active transition are selected by its index
+                        let set_keyframes = keyframes
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, kf)| !kf.is_empty())
+                            .map(|(index, kf)| {
+                                format!(
+                                    "if (index == {index}) *keyframes = {}; else ",
+                                    compile_keyframes(kf, prop_type, ctx)
+                                )
+                            })
+                            .join("");
                         format!(
                             "{prop_access}.set_animated_binding_for_transition({binding_code},
-                            [this](uint64_t *start_time) -> slint::cbindgen_private::PropertyAnimation {{
+                            [this](uint64_t *start_time, std::vector<slint::cbindgen_private::Keyframe<{cpp_type}>> *keyframes) -> slint::cbindgen_private::PropertyAnimation {{
                                 [[maybe_unused]] auto self = this;
-                                auto [anim, time] = {anim};
+                                auto [anim, time, index] = {anim};
                                 *start_time = time;
+                                {set_keyframes}keyframes->clear();
                                 return anim;
                             }});",
                         )
@@ -939,63 +990,38 @@ fn embed_resource(
             }));
         }
         #[cfg(feature = "software-renderer")]
-        crate::embedded_resources::EmbeddedResourcesKind::TextureData(
-            crate::embedded_resources::Texture {
-                data,
-                format,
-                rect,
-                total_size: crate::embedded_resources::Size { width, height },
-                original_size:
-                    crate::embedded_resources::Size { width: unscaled_width, height: unscaled_height },
-            },
-        ) => {
-            let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
-            let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format
-            {
-                format!("slint::Color::from_rgb_uint8({r}, {g}, {b})")
-            } else {
-                "slint::Color{}".to_string()
-            };
-            let count = data.len();
-            let data = data.iter().map(ToString::to_string).join(", ");
-            let data_name = format_smolstr!("slint_embedded_resource_{}_data", resource.id);
-            declarations.push(Declaration::Var(Var {
-                ty: "const uint8_t".into(),
-                name: data_name.clone(),
-                array_size: Some(count),
-                init: Some(format!("{{ {data} }}")),
-                ..Default::default()
-            }));
-            let texture_name = format_smolstr!("slint_embedded_resource_{}_texture", resource.id);
-            declarations.push(Declaration::Var(Var {
-                ty: "const slint::cbindgen_private::types::StaticTexture".into(),
-                name: texture_name.clone(),
-                array_size: None,
-                init: Some(format!(
-                    "{{
-                            .rect = {{ {r_x}, {r_y}, {r_w}, {r_h} }},
-                            .format = slint::cbindgen_private::types::TexturePixelFormat::{format},
-                            .color = {color},
-                            .index = 0,
-                            }}"
-                )),
-                ..Default::default()
-            }));
-            let init = format!("slint::cbindgen_private::types::StaticTextures {{
-                        .size = {{ {width}, {height} }},
-                        .original_size = {{ {unscaled_width}, {unscaled_height} }},
-                        .data = slint::cbindgen_private::Slice<uint8_t>{{  {data_name} , {count} }},
-                        .textures = slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTexture>{{ &{texture_name}, 1 }}
-                    }}");
+        crate::embedded_resources::EmbeddedResourcesKind::TextureData(texture) => {
+            let name = format_smolstr!("slint_embedded_resource_{}", resource.id);
+            let init = embed_texture(texture, &name, declarations);
             declarations.push(Declaration::Var(Var {
                 ty: "const slint::cbindgen_private::types::StaticTextures".into(),
-                name: format_smolstr!("slint_embedded_resource_{}", resource.id),
+                name,
                 array_size: None,
                 init: Some(init),
                 ..Default::default()
             }))
         }
         #[cfg(feature = "software-renderer")]
+        crate::embedded_resources::EmbeddedResourcesKind::AnimatedTextureData(frames) => {
+            let name = format_smolstr!("slint_embedded_resource_{}", resource.id);
+            let init = frames
+                .iter()
+                .enumerate()
+                .map(|(index, (texture, duration))| {
+                    let textures =
+                        embed_texture(texture, &format_smolstr!("{name}_{index}"), declarations);
+                    format!("{{ .textures = {textures}, .duration = {duration} }}")
+                })
+                .join(",\n");
+            declarations.push(Declaration::Var(Var {
+                ty: "const slint::cbindgen_private::types::StaticAnimationFrame".into(),
+                name,
+                array_size: Some(frames.len()),
+                init: Some(format!("{{ {init} }}")),
+                ..Default::default()
+            }))
+        }
+        #[cfg(feature = "software-renderer")]
         crate::embedded_resources::EmbeddedResourcesKind::BitmapFontData(
             crate::embedded_resources::BitmapFont {
                 family_name,
@@ -1129,6 +1155,60 @@ fn embed_resource(
     }
 }
 
+/// Declares the data of the texture, and returns the initializer of the `StaticTextures` referencing it
+#[cfg(feature = "software-renderer")]
+fn embed_texture(
+    texture: &crate::embedded_resources::Texture,
+    name: &SmolStr,
+    declarations: &mut Vec<Declaration>,
+) -> String {
+    let crate::embedded_resources::Texture {
+        data,
+        format,
+        rect,
+        total_size: crate::embedded_resources::Size { width, height },
+        original_size:
+            crate::embedded_resources::Size { width: unscaled_width, height: unscaled_height },
+    } = texture;
+    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
+    let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
+        format!("slint::Color::from_rgb_uint8({r}, {g}, {b})")
+    } else {
+        "slint::Color{}".to_string()
+    };
+    let count = data.len();
+    let data = data.iter().map(ToString::to_string).join(", ");
+    let data_name = format_smolstr!("{name}_data");
+    declarations.push(Declaration::Var(Var {
+        ty: "const uint8_t".into(),
+        name: data_name.clone(),
+        array_size: Some(count),
+        init: Some(format!("{{ {data} }}")),
+        ..Default::default()
+    }));
+    let texture_name = format_smolstr!("{name}_texture");
+    declarations.push(Declaration::Var(Var {
+        ty: "const slint::cbindgen_private::types::StaticTexture".into(),
+        name: texture_name.clone(),
+        array_size: None,
+        init: Some(format!(
+            "{{
+                    .rect = {{ {r_x}, {r_y}, {r_w}, {r_h} }},
+                    .format = slint::cbindgen_private::types::TexturePixelFormat::{format},
+                    .color = {color},
+                    .index = 0,
+                    }}"
+        )),
+        ..Default::default()
+    }));
+    format!("slint::cbindgen_private::types::StaticTextures {{
+                .size = {{ {width}, {height} }},
+                .original_size = {{ {unscaled_width}, {unscaled_height} }},
+                .data = slint::cbindgen_private::Slice<uint8_t>{{  {data_name} , {count} }},
+                .textures = slint::cbindgen_private::Slice<slint::cbindgen_private::types::StaticTexture>{{ &{texture_name}, 1 }}
+            }}")
+}
+
 fn generate_struct(
     file: &mut File,
     name: &str,
@@ -2063,7 +2143,7 @@ fn generate_sub_component(
         ));
     }
 
-    for (i, _) in component.popup_windows.iter().enumerate() {
+    for (i, popup) in component.popup_windows.iter().enumerate() {
         target_struct.members.push((
             field_access,
             Declaration::Var(Var {
@@ -2072,6 +2152,19 @@ fn generate_sub_component(
                 ..Default::default()
             }),
         ));
+        if popup.is_secondary_window {
+            target_struct.members.push((
+                field_access,
+                Declaration::Var(Var {
+                    ty: format_smolstr!(
+                        "mutable std::optional<vtable::VRc<slint::private_api::ItemTreeVTable, {}>>",
+                        ident(&root.sub_components[popup.item_tree.root].name)
+                    ),
+                    name: format_smolstr!("secondary_window_instance_{}", i),
+                    ..Default::default()
+                }),
+            ));
+        }
     }
 
     for (prop1, prop2) in &component.two_way_bindings {
@@ -2132,15 +2225,32 @@ fn generate_sub_component(
         ));
 
         let ensure_updated = if let Some(listview) = &repeated.listview {
+            let vp_x = access_member(&listview.viewport_x, &ctx);
             let vp_y = access_member(&listview.viewport_y, &ctx);
             let vp_h = access_member(&listview.viewport_height, &ctx);
             let lv_h = access_member(&listview.listview_height, &ctx);
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
+            let req = match &listview.requested_row {
+                Some(req) => format!("&{}", access_member(req, &ctx)),
+                None => "nullptr".into(),
+            };
 
-            format!(
-                "self->{repeater_id}.ensure_updated_listview(self, &{vp_w}, &{vp_h}, &{vp_y}, {lv_w}.get(), {lv_h}.get());"
-            )
+            if let Some((cell_w, cell_h)) = &listview.cell_size {
+                let cell_w = access_member(cell_w, &ctx);
+                let cell_h = access_member(cell_h, &ctx);
+                format!(
+                    "self->{repeater_id}.ensure_updated_grid(self, &{vp_w}, &{vp_h}, &{vp_y}, {lv_w}.get(), {lv_h}.get(), {cell_w}.get(), {cell_h}.get(), {req});"
+                )
+            } else if listview.orientation == crate::layout::Orientation::Horizontal {
+                format!(
+                    "self->{repeater_id}.ensure_updated_listview_horizontal(self, &{vp_w}, &{vp_h}, &{vp_x}, {lv_w}.get(), {lv_h}.get(), {req});"
+                )
+            } else {
+                format!(
+                    "self->{repeater_id}.ensure_updated_listview(self, &{vp_w}, &{vp_h}, &{vp_y}, {lv_w}.get(), {lv_h}.get(), {req});"
+                )
+            }
         } else {
             format!("self->{repeater_id}.ensure_updated(self);")
         };
@@ -2528,18 +2638,25 @@ fn generate_repeated_component(
     ));
 
     if let Some(listview) = &repeated.listview {
+        let p_x = access_member(&listview.prop_x, &ctx);
         let p_y = access_member(&listview.prop_y, &ctx);
+        let p_width = access_member(&listview.prop_width, &ctx);
         let p_height = access_member(&listview.prop_height, &ctx);
 
         repeater_struct.members.push((
             Access::Public, // Because Repeater accesses it
             Declaration::Function(Function {
                 name: "listview_layout".into(),
-                signature: "(float *offset_y) const -> float".to_owned(),
+                signature: "(slint::cbindgen_private::Orientation o, float *offset) const -> float".to_owned(),
                 statements: Some(vec![
                     "[[maybe_unused]] auto self = this;".into(),
-                    format!("{}.set(*offset_y);", p_y),
-                    format!("*offset_y += {}.get();", p_height),
+                    "if (o == slint::cbindgen_private::Orientation::Horizontal) {".into(),
+                    format!("    {}.set(*offset);", p_x),
+                    format!("    *offset += {}.get();", p_width),
+                    "    return layout_info({&static_vtable, const_cast<void *>(static_cast<const void *>(this))}, slint::cbindgen_private::Orientation::Vertical).min;".into(),
+                    "}".into(),
+                    format!("{}.set(*offset);", p_y),
+                    format!("*offset += {}.get();", p_height),
                     "return layout_info({&static_vtable, const_cast<void *>(static_cast<const void *>(this))}, slint::cbindgen_private::Orientation::Horizontal).min;".into(),
                 ]),
                 ..Function::default()
@@ -2558,6 +2675,57 @@ fn generate_repeated_component(
         ));
     }
 
+    if let Some(factors) = &repeated.flex_factors {
+        let grow = access_member(&factors.grow, &ctx);
+        let shrink = access_member(&factors.shrink, &ctx);
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "flex_layout_data".into(),
+                signature: "(slint::cbindgen_private::Orientation o) const -> slint::cbindgen_private::FlexLayoutCellData".to_owned(),
+                statements: Some(vec![
+                    "[[maybe_unused]] auto self = this;".into(),
+                    format!("return {{ layout_info({{&static_vtable, const_cast<void *>(static_cast<const void *>(this))}}, o), {grow}.get(), {shrink}.get() }};"),
+                ]),
+                ..Function::default()
+            }),
+        ));
+    }
+
+    if let Some(exit) = &repeated.exit_transition {
+        let call = |function: &llr::PropertyReference| {
+            compile_expression(
+                &llr::Expression::FunctionCall { function: function.clone(), arguments: vec![] },
+                &ctx,
+            )
+        };
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "start_exit".into(),
+                signature: "() const -> bool".into(),
+                statements: Some(vec![
+                    "[[maybe_unused]] auto self = this;".into(),
+                    format!("{};", call(&exit.start)),
+                    "return true;".into(),
+                ]),
+                ..Function::default()
+            }),
+        ));
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "is_exit_done".into(),
+                signature: "() const -> bool".into(),
+                statements: Some(vec![
+                    "[[maybe_unused]] auto self = this;".into(),
+                    format!("return {};", call(&exit.is_done)),
+                ]),
+                ..Function::default()
+            }),
+        ));
+    }
+
     if let Some(index_prop) = repeated.index_prop {
         // Override default subtree_index function implementation
         let subtree_index_func = repeater_struct
@@ -3340,6 +3508,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 crate::expression_tree::ImageReference::EmbeddedTexture{resource_id} => {
                     format!("slint::private_api::image_from_embedded_textures(&slint_embedded_resource_{resource_id})")
                 },
+                crate::expression_tree::ImageReference::EmbeddedAnimatedTextures{resource_id} => {
+                    format!("slint::private_api::image_from_static_animation_frames(slint_embedded_resource_{resource_id})")
+                },
             };
             match &nine_slice {
                 Some([a, b, c, d]) => {
@@ -3414,6 +3585,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInBounce".into(),
         Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Spring_Body{{ {{ {stiffness}, {damping}, {mass} }} }})"
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -3458,12 +3632,14 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             repeater_indices,
             elements,
             orientation,
+            flex_cells,
             sub_expression,
         } => box_layout_function(
             cells_variable,
             repeater_indices.as_ref().map(SmolStr::as_str),
             elements.as_ref(),
             *orientation,
+            *flex_cells,
             sub_expression,
             ctx,
         ),
@@ -3720,6 +3896,7 @@ fn compile_builtin_function_call(
                     format!(r"{{
                         auto item_tree = {item_tree_id}::create(self);
                         auto item_tree_dyn = item_tree.into_dyn();
+                        {window}.setup_menubar_shortcuts(item_tree_dyn);
                         slint::private_api::setup_popup_menu_from_menu_item_tree(item_tree_dyn, {access_entries}, {access_sub_menu}, {access_activated});
                     }}")
                 } else {
@@ -3736,16 +3913,19 @@ fn compile_builtin_function_call(
                         }} else {{
                             auto item_tree = {item_tree_id}::create(self);
                             auto item_tree_dyn = item_tree.into_dyn();
+                            {window}.setup_menubar_shortcuts(item_tree_dyn);
                             slint::private_api::setup_popup_menu_from_menu_item_tree(item_tree_dyn, {access_entries}, {access_sub_menu}, {access_activated});
                         }}")
                 }
-            } else if let [entries, llr::Expression::PropertyReference(sub_menu), llr::Expression::PropertyReference(activated)] = arguments {
+            } else if let [entries, llr::Expression::PropertyReference(sub_menu), llr::Expression::PropertyReference(activated), no_native @ ..] = arguments {
+                let no_native = matches!(no_native, [llr::Expression::BoolLiteral(true)]);
                 let entries = compile_expression(entries, ctx);
                 let sub_menu = access_member(sub_menu, ctx);
                 let activated = access_member(activated, ctx);
+                let no_native = if no_native { ", true" } else { "" };
                 format!("{window}.setup_native_menu_bar(self,
                     [](auto &self, const slint::cbindgen_private::MenuEntry *parent){{ return parent ? {sub_menu}.call(*parent) : {entries}; }},
-                    [](auto &self, const slint::cbindgen_private::MenuEntry &entry){{ {activated}.call(entry); }})")
+                    [](auto &self, const slint::cbindgen_private::MenuEntry &entry){{ {activated}.call(entry); }}{no_native})")
             } else {
                 panic!("internal error: incorrect arguments to SetupNativeMenuBar")
             }
@@ -3837,6 +4017,47 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ClosePopupWindow {arguments:?}")
             }
         }
+        BuiltinFunction::ShowSecondaryWindow => {
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::BoolLiteral(transient), llr::Expression::BoolLiteral(modal), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut parent_ctx = ctx;
+                let mut component_access = "self".into();
+
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access = format!("{component_access}->parent.lock().value()");
+                        parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
+                    }
+                };
+
+                let window = access_window_field(ctx);
+                let current_sub_component = parent_ctx.current_sub_component().unwrap();
+                let popup = &current_sub_component.popup_windows[*popup_index as usize];
+                let secondary_window_id =
+                    ident(&ctx.compilation_unit.sub_components[popup.item_tree.root].name);
+                format!(
+                    "{window}.close_secondary_window({component_access}->popup_id_{popup_index}); {component_access}->popup_id_{popup_index} = {window}.show_secondary_window<{secondary_window_id}>({component_access}->secondary_window_instance_{popup_index}, &*({component_access}), {transient}, {modal})"
+                )
+            } else {
+                panic!("internal error: invalid args to ShowSecondaryWindow {arguments:?}")
+            }
+        }
+        BuiltinFunction::CloseSecondaryWindow => {
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::PropertyReference(parent_ref)] = arguments {
+                let mut component_access = "self".into();
+
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access = format!("{component_access}->parent.lock().value()");
+                    }
+                };
+                let window = access_window_field(ctx);
+                format!("{window}.close_secondary_window({component_access}->popup_id_{popup_index})")
+            } else {
+                panic!("internal error: invalid args to CloseSecondaryWindow {arguments:?}")
+            }
+        }
 
         BuiltinFunction::ShowPopupMenu => {
             let [llr::Expression::PropertyReference(context_menu_ref), entries, position] = arguments
@@ -3921,6 +4142,35 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to set-selection-offsets {arguments:?}")
             }
         }
+        BuiltinFunction::FlickableScrollTo => {
+            if let [llr::Expression::PropertyReference(pr), x, y] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+
+                format!("slint_flickable_scroll_to(&{item}, &{item_rc}, {x}, {y})")
+            } else {
+                panic!("internal error: invalid args to scroll-to {arguments:?}")
+            }
+        }
+        BuiltinFunction::FlickableEnsureVisible => {
+            if let [llr::Expression::PropertyReference(pr), x, y, w, h] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+                let w = compile_expression(w, ctx);
+                let h = compile_expression(h, ctx);
+
+                format!("slint_flickable_ensure_visible(&{item}, &{item_rc}, {x}, {y}, {w}, {h})")
+            } else {
+                panic!("internal error: invalid args to ensure-visible {arguments:?}")
+            }
+        }
+        BuiltinFunction::ListViewScrollToIndex => {
+            panic!("internal error: ListViewScrollToIndex should have been lowered")
+        }
         BuiltinFunction::ItemFontMetrics => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
                 let item_rc = access_item_rc(pr, ctx);
@@ -4003,12 +4253,17 @@ fn box_layout_function(
     repeated_indices: Option<&str>,
     elements: &[Either<llr::Expression, llr::RepeatedElementIdx>],
     orientation: Orientation,
+    flex_cells: bool,
     sub_expression: &llr::Expression,
     ctx: &llr_EvaluationContext<CppGeneratorContext>,
 ) -> String {
     let repeated_indices = repeated_indices.map(ident);
-    let mut push_code =
-        "std::vector<slint::cbindgen_private::BoxLayoutCellData> cells_vector;".to_owned();
+    let (cell_ty, layout_data_fn) = if flex_cells {
+        ("slint::cbindgen_private::FlexLayoutCellData", "flex_layout_data")
+    } else {
+        ("slint::cbindgen_private::BoxLayoutCellData", "box_layout_data")
+    };
+    let mut push_code = format!("std::vector<{cell_ty}> cells_vector;");
     let mut repeater_idx = 0usize;
 
     for item in elements {
@@ -4040,7 +4295,7 @@ fn box_layout_function(
                 repeater_idx += 1;
                 write!(
                     push_code,
-                    "self->repeater_{id}.for_each([&](const auto &sub_comp){{ cells_vector.push_back(sub_comp->box_layout_data({o})); }});",
+                    "self->repeater_{id}.for_each([&](const auto &sub_comp){{ cells_vector.push_back(sub_comp->{layout_data_fn}({o})); }});",
                     id = repeater,
                     o = to_cpp_orientation(orientation),
                 )
@@ -4058,9 +4313,10 @@ fn box_layout_function(
         format!("std::array<int, {}> {}_array;", 2 * repeater_idx, ri)
     });
     format!(
-        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::BoxLayoutCellData>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
+        "[&]{{ {} {} slint::cbindgen_private::Slice<{}>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
         ri,
         push_code,
+        cell_ty,
         ident(cells_variable),
         compile_expression(sub_expression, ctx)
     )
@@ -534,13 +534,32 @@ fn handle_property_init(
                             slint::private_unstable_api::set_animated_property_binding(#rust_property, &self_rc, #binding_tokens, #anim);
                         } }
                     }
-                    Some(llr::Animation::Transition(anim)) => {
+                    Some(llr::Animation::Keyframes(anim, keyframes)) => {
                         let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(keyframes, prop_type, ctx);
+                        quote! { {
+                            #init_self_pin_ref
+                            slint::private_unstable_api::set_animated_property_binding_with_keyframes(#rust_property, &self_rc, #binding_tokens, #anim, #keyframes);
+                        } }
+                    }
+                    Some(llr::Animation::Transition(anim, keyframes)) => {
+                        let anim = compile_expression(anim, ctx);
+                        // The keyframes of the active transition are selected by its index
+                        let keyframes = keyframes
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, kf)| !kf.is_empty())
+                            .map(|(index, kf)| {
+                                let index = index as i32;
+                                let kf = compile_keyframes(kf, prop_type, ctx);
+                                quote!(#index => #kf,)
+                            });
                         quote! {
                             slint::private_unstable_api::set_animated_property_binding_for_transition(
                                 #rust_property, &self_rc, #binding_tokens, move |self_rc| {
                                     #init_self_pin_ref
-                                    #anim
+                                    let (animation, change_time, index) = #anim;
+                                    (animation, change_time, match index { #(#keyframes)* _ => sp::vec![] })
                                 }
                             );
                         }
@@ -831,17 +850,43 @@ fn generate_sub_component(
                 });
             });
             let ensure_updated = if let Some(listview) = &repeated.listview {
+                let vp_x = access_member(&listview.viewport_x, &ctx).unwrap();
                 let vp_y = access_member(&listview.viewport_y, &ctx).unwrap();
                 let vp_h = access_member(&listview.viewport_height, &ctx).unwrap();
                 let lv_h = access_member(&listview.listview_height, &ctx).unwrap();
                 let vp_w = access_member(&listview.viewport_width, &ctx).unwrap();
                 let lv_w = access_member(&listview.listview_width, &ctx).unwrap();
+                let req = match &listview.requested_row {
+                    Some(req) => {
+                        let req = access_member(req, &ctx).unwrap();
+                        quote!(sp::take_requested_row(#req))
+                    }
+                    None => quote!(None),
+                };
 
-                quote! {
-                    #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview(
-                        || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
-                        #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h
-                    );
+                if let Some((cell_w, cell_h)) = &listview.cell_size {
+                    let cell_w = access_member(cell_w, &ctx).unwrap();
+                    let cell_h = access_member(cell_h, &ctx).unwrap();
+                    quote! {
+                        #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_grid(
+                            || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
+                            #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h, #cell_w.get(), #cell_h.get(), #req
+                        );
+                    }
+                } else if listview.orientation == crate::layout::Orientation::Horizontal {
+                    quote! {
+                        #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview_horizontal(
+                            || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
+                            #vp_w, #vp_h, #vp_x, #lv_w, #lv_h.get(), #req
+                        );
+                    }
+                } else {
+                    quote! {
+                        #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview(
+                            || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
+                            #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h, #req
+                        );
+                    }
                 }
             } else {
                 quote! {
@@ -1023,6 +1068,19 @@ fn generate_sub_component(
 
     let popup_id_names =
         component.popup_windows.iter().enumerate().map(|(i, _)| internal_popup_id(i));
+    let (secondary_window_instance_names, secondary_window_instance_types): (Vec<_>, Vec<_>) =
+        component
+            .popup_windows
+            .iter()
+            .enumerate()
+            .filter(|(_, popup)| popup.is_secondary_window)
+            .map(|(i, popup)| {
+                (
+                    internal_secondary_window_instance(i),
+                    inner_component_id(&root.sub_components[popup.item_tree.root]),
+                )
+            })
+            .unzip();
 
     for (prop1, prop2) in &component.two_way_bindings {
         let p1 = access_member(prop1, &ctx);
@@ -1140,6 +1198,7 @@ struct #inner_component_id {
             #(#item_names : sp::#item_types,)*
             #(#sub_component_names : #sub_component_types,)*
             #(#popup_id_names : ::core::cell::Cell<sp::Option<::core::num::NonZeroU32>>,)*
+            #(#secondary_window_instance_names : sp::OnceCell<sp::VRc<sp::ItemTreeVTable, #secondary_window_instance_types>>,)*
             #(#declared_property_vars : sp::Property<#declared_property_types>,)*
             #(#declared_callbacks : sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_components,)*
@@ -1776,17 +1835,29 @@ fn generate_repeated_component(
     let inner_component_id = self::inner_component_id(root_sc);
 
     let extra_fn = if let Some(listview) = &repeated.listview {
+        let p_x = access_member(&listview.prop_x, &ctx).unwrap();
         let p_y = access_member(&listview.prop_y, &ctx).unwrap();
+        let p_width = access_member(&listview.prop_width, &ctx).unwrap();
         let p_height = access_member(&listview.prop_height, &ctx).unwrap();
         quote! {
             fn listview_layout(
                 self: ::core::pin::Pin<&Self>,
-                offset_y: &mut sp::LogicalLength,
+                orientation: sp::Orientation,
+                offset: &mut sp::LogicalLength,
             ) -> sp::LogicalLength {
                 let _self = self;
-                #p_y.set(*offset_y);
-                *offset_y += #p_height.get();
-                sp::LogicalLength::new(self.as_ref().layout_info(sp::Orientation::Horizontal).min)
+                match orientation {
+                    sp::Orientation::Vertical => {
+                        #p_y.set(*offset);
+                        *offset += #p_height.get();
+                        sp::LogicalLength::new(self.as_ref().layout_info(sp::Orientation::Horizontal).min)
+                    }
+                    sp::Orientation::Horizontal => {
+                        #p_x.set(*offset);
+                        *offset += #p_width.get();
+                        sp::LogicalLength::new(self.as_ref().layout_info(sp::Orientation::Vertical).min)
+                    }
+                }
             }
         }
     } else {
@@ -1800,6 +1871,47 @@ fn box_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
         }
     };
 
+    let flex_fn = repeated.flex_factors.as_ref().map(|factors| {
+        let grow = access_member(&factors.grow, &ctx).unwrap();
+        let shrink = access_member(&factors.shrink, &ctx).unwrap();
+        quote! {
+            fn flex_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
+                -> sp::FlexLayoutCellData
+            {
+                let _self = self;
+                sp::FlexLayoutCellData {
+                    constraint: self.as_ref().layout_info(o),
+                    grow: #grow.get() as _,
+                    shrink: #shrink.get() as _,
+                }
+            }
+        }
+    });
+
+    let exit_fn = repeated.exit_transition.as_ref().map(|exit| {
+        let call = |function: &llr::PropertyReference| {
+            compile_expression(
+                &llr::Expression::FunctionCall { function: function.clone(), arguments: vec![] },
+                &ctx,
+            )
+        };
+        let start = call(&exit.start);
+        let is_done = call(&exit.is_done);
+        quote! {
+            fn start_exit(&self) -> bool {
+                let self_rc = self.self_weak.get().unwrap().upgrade().unwrap();
+                let _self = self_rc.as_pin_ref();
+                #start;
+                true
+            }
+            fn is_exit_done(&self) -> bool {
+                let self_rc = self.self_weak.get().unwrap().upgrade().unwrap();
+                let _self = self_rc.as_pin_ref();
+                #is_done
+            }
+        }
+    });
+
     let data_type = if let Some(data_prop) = repeated.data_prop {
         rust_primitive_type(&root_sc.properties[data_prop].ty).unwrap()
     } else {
@@ -1842,6 +1954,8 @@ fn init(&self) {
                 );
             }
             #extra_fn
+            #flex_fn
+            #exit_fn
         }
     )
 }
@@ -1857,6 +1971,12 @@ fn internal_popup_id(index: usize) -> proc_macro2::Ident {
     ident(&name)
 }
 
+fn internal_secondary_window_instance(index: usize) -> proc_macro2::Ident {
+    let mut name = index.to_string();
+    name.insert_str(0, "secondary_window_instance_");
+    ident(&name)
+}
+
 fn global_inner_name(g: &llr::GlobalComponent) -> TokenStream {
     if g.is_builtin {
         let i = ident(&g.name);
@@ -1877,14 +1997,41 @@ fn property_set_value_tokens(
     let value_tokens = set_primitive_property_value(prop_type, value_tokens);
     if let Some((animation, map)) = &ctx.property_info(property).animation {
         let mut animation = (*animation).clone();
-        map.map_expression(&mut animation);
-        let animation_tokens = compile_expression(&animation, ctx);
-        return prop
-            .then(|prop| quote!(#prop.set_animated_value(#value_tokens as _, #animation_tokens)));
+        animation.visit_expressions_mut(|e| map.map_expression(e));
+        match &animation {
+            llr::Animation::Keyframes(animation, keyframes) => {
+                let animation_tokens = compile_expression(animation, ctx);
+                let keyframes = compile_keyframes(keyframes, prop_type, ctx);
+                return prop.then(|prop| {
+                    quote!(#prop.set_animated_value_with_keyframes(#value_tokens as _, #animation_tokens, #keyframes))
+                });
+            }
+            llr::Animation::Static(animation) | llr::Animation::Transition(animation, _) => {
+                let animation_tokens = compile_expression(animation, ctx);
+                return prop.then(
+                    |prop| quote!(#prop.set_animated_value(#value_tokens as _, #animation_tokens)),
+                );
+            }
+        }
     }
     prop.then(|prop| quote!(#prop.set(#value_tokens as _)))
 }
 
+/// Returns the code for a `Vec<Keyframe<T>>` with the given keyframes, for a property of type `prop_type`
+fn compile_keyframes(
+    keyframes: &[llr::Keyframe],
+    prop_type: &Type,
+    ctx: &EvaluationContext,
+) -> TokenStream {
+    let keyframes = keyframes.iter().map(|kf| {
+        let offset = kf.offset;
+        let easing = compile_expression(&kf.easing, ctx);
+        let value = set_primitive_property_value(prop_type, compile_expression(&kf.value, ctx));
+        quote!(sp::Keyframe { offset: #offset, easing: #easing, value: (#value) as _ })
+    });
+    quote!(sp::vec![#(#keyframes),*])
+}
+
 /// Returns the code that can access the given property or callback
 fn access_member(reference: &llr::PropertyReference, ctx: &EvaluationContext) -> MemberAccess {
     fn in_native_item(
@@ -2456,6 +2603,10 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                         sp::Image::from(sp::ImageInner::StaticTextures(&#symbol))
                     )
                 }
+                crate::expression_tree::ImageReference::EmbeddedAnimatedTextures { resource_id } => {
+                    let symbol = format_ident!("SLINT_EMBEDDED_RESOURCE_{}", resource_id);
+                    quote!(sp::image_from_static_animation_frames(&#symbol))
+                }
             };
             match &nine_slice {
                 Some([a, b, c, d]) => {
@@ -2549,6 +2700,9 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
             quote!(sp::EasingCurve::EaseInOutBounce)
         }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => {
+            quote!(sp::EasingCurve::Spring([#stiffness, #damping, #mass]))
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -2597,12 +2751,14 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             repeater_indices,
             elements,
             orientation,
+            flex_cells,
             sub_expression,
         } => box_layout_function(
             cells_variable,
             repeater_indices.as_ref().map(SmolStr::as_str),
             elements.as_ref(),
             *orientation,
+            *flex_cells,
             sub_expression,
             ctx,
         ),
@@ -2763,6 +2919,74 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ClosePopupWindow {arguments:?}")
             }
         }
+        BuiltinFunction::ShowSecondaryWindow => {
+            if let [Expression::NumberLiteral(popup_index), Expression::BoolLiteral(transient), Expression::BoolLiteral(modal), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut parent_ctx = ctx;
+                let mut component_access_tokens = quote!(_self);
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access_tokens =
+                            quote!(#component_access_tokens.parent.upgrade().unwrap().as_pin_ref());
+                        parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
+                    }
+                }
+                let current_sub_component = parent_ctx.current_sub_component().unwrap();
+                let popup = &current_sub_component.popup_windows[*popup_index as usize];
+                let secondary_window_id =
+                    inner_component_id(&ctx.compilation_unit.sub_components[popup.item_tree.root]);
+
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                let popup_id_name = internal_popup_id(*popup_index as usize);
+                let instance_name = internal_secondary_window_instance(*popup_index as usize);
+                quote!({
+                    let mut created = false;
+                    let window_instance = #component_access_tokens.#instance_name.get_or_init(|| {
+                        created = true;
+                        #secondary_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).unwrap()
+                    }).clone();
+                    let window_instance_vrc = sp::VRc::map(window_instance.clone(), |x| x);
+                    if let Some(current_id) = #component_access_tokens.#popup_id_name.take() {
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_secondary_window(current_id);
+                    }
+                    #component_access_tokens.#popup_id_name.set(
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_secondary_window(
+                            &sp::VRc::into_dyn(window_instance),
+                            #transient,
+                            #modal,
+                        )
+                    );
+                    if created {
+                        #secondary_window_id::user_init(window_instance_vrc);
+                    }
+                })
+            } else {
+                panic!("internal error: invalid args to ShowSecondaryWindow {arguments:?}")
+            }
+        }
+        BuiltinFunction::CloseSecondaryWindow => {
+            if let [Expression::NumberLiteral(popup_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut component_access_tokens = quote!(_self);
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access_tokens =
+                            quote!(#component_access_tokens.parent.upgrade().unwrap().as_pin_ref());
+                    }
+                }
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                let popup_id_name = internal_popup_id(*popup_index as usize);
+                quote!(
+                    if let Some(current_id) = #component_access_tokens.#popup_id_name.take() {
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_secondary_window(current_id);
+                    }
+                )
+            } else {
+                panic!("internal error: invalid args to CloseSecondaryWindow {arguments:?}")
+            }
+        }
         BuiltinFunction::ShowPopupMenu => {
             let [Expression::PropertyReference(context_menu_ref), entries, position] = arguments
             else {
@@ -2901,6 +3125,47 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to set-selection-offsets {arguments:?}")
             }
         }
+        BuiltinFunction::FlickableScrollTo => {
+            if let [llr::Expression::PropertyReference(pr), x, y] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+
+                item.then(|item| quote!(
+                    #item.scroll_to(sp::LogicalPoint::new(#x as sp::Coord, #y as sp::Coord), #item_rc)
+                ))
+            } else {
+                panic!("internal error: invalid args to scroll-to {arguments:?}")
+            }
+        }
+        BuiltinFunction::FlickableEnsureVisible => {
+            if let [llr::Expression::PropertyReference(pr), x, y, w, h] = arguments {
+                let item = access_member(pr, ctx);
+                let item_rc = access_item_rc(pr, ctx);
+                let x = compile_expression(x, ctx);
+                let y = compile_expression(y, ctx);
+                let w = compile_expression(w, ctx);
+                let h = compile_expression(h, ctx);
+
+                item.then(|item| {
+                    quote!({
+                        #item.ensure_visible(
+                            sp::LogicalRect::new(
+                                sp::LogicalPoint::new(#x as sp::Coord, #y as sp::Coord),
+                                sp::LogicalSize::new(#w as sp::Coord, #h as sp::Coord),
+                            ),
+                            #item_rc,
+                        );
+                    })
+                })
+            } else {
+                panic!("internal error: invalid args to ensure-visible {arguments:?}")
+            }
+        }
+        BuiltinFunction::ListViewScrollToIndex => {
+            panic!("internal error: ListViewScrollToIndex should have been lowered")
+        }
         BuiltinFunction::ItemFontMetrics => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -3108,9 +3373,10 @@ fn compile_builtin_function_call(
 
                 quote!({
                     let menu_item_tree_instance = #item_tree_id::new(_self.self_weak.get().unwrap().clone()).unwrap();
-                    let menu_item_tree = sp::MenuFromItemTree::new(sp::VRc::into_dyn(menu_item_tree_instance));
+                    let menu_item_tree = sp::MenuFromItemTree::new(sp::VRc::into_dyn(menu_item_tree_instance.clone()));
                     #native_impl
                     /*else*/ {
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).setup_menubar_shortcuts(sp::VBox::new(sp::MenuFromItemTree::new(sp::VRc::into_dyn(menu_item_tree_instance))));
                         let menu_item_tree = sp::Rc::new(menu_item_tree);
                         let menu_item_tree_ = menu_item_tree.clone();
                         #access_entries.set_binding(move || {
@@ -3129,16 +3395,22 @@ fn compile_builtin_function_call(
                         });
                     }
                 })
-            } else if let [entries, Expression::PropertyReference(sub_menu), Expression::PropertyReference(activated)] =
+            } else if let [entries, Expression::PropertyReference(sub_menu), Expression::PropertyReference(activated), no_native @ ..] =
                 arguments
             {
+                let no_native = matches!(no_native, [Expression::BoolLiteral(true)]);
                 let entries = compile_expression(entries, ctx);
                 let sub_menu = access_member(sub_menu, ctx).unwrap();
                 let activated = access_member(activated, ctx).unwrap();
                 let inner_component_id =
                     self::inner_component_id(ctx.current_sub_component().unwrap());
+                let use_native = if no_native {
+                    quote!(false)
+                } else {
+                    quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).supports_native_menu_bar())
+                };
                 quote! {
-                    if sp::WindowInner::from_pub(#window_adapter_tokens.window()).supports_native_menu_bar() {
+                    {
                         // May seem overkill to have an instance of the struct for each call, but there should only be one call per component anyway
                         struct MenuBarWrapper(sp::VWeakMapped<sp::ItemTreeVTable, #inner_component_id>);
                         const _ : () = {
@@ -3161,7 +3433,12 @@ fn activate(&self, entry: &sp::MenuEntry) {
                                 #activated.call(&(entry.clone(),))
                             }
                         }
-                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).setup_menubar(sp::VBox::new(MenuBarWrapper(_self.self_weak.get().unwrap().clone())));
+                        let menubar = sp::VBox::new(MenuBarWrapper(_self.self_weak.get().unwrap().clone()));
+                        if #use_native {
+                            sp::WindowInner::from_pub(#window_adapter_tokens.window()).setup_menubar(menubar);
+                        } else {
+                            sp::WindowInner::from_pub(#window_adapter_tokens.window()).setup_menubar_shortcuts(menubar);
+                        }
                     }
                 }
             } else {
@@ -3240,6 +3517,7 @@ fn box_layout_function(
     repeated_indices: Option<&str>,
     elements: &[Either<Expression, llr::RepeatedElementIdx>],
     orientation: Orientation,
+    flex_cells: bool,
     sub_expression: &Expression,
     ctx: &EvaluationContext,
 ) -> TokenStream {
@@ -3249,6 +3527,11 @@ fn box_layout_function(
     let mut repeated_count = quote!();
     let mut push_code = vec![];
     let mut repeater_idx = 0usize;
+    let layout_data_fn = if flex_cells {
+        format_ident!("flex_layout_data")
+    } else {
+        format_ident!("box_layout_data")
+    };
     for item in elements {
         match item {
             Either::Left(value) => {
@@ -3277,7 +3560,7 @@ fn box_layout_function(
                         let internal_vec = _self.#repeater_id.instances_vec();
                         #ri
                         for sub_comp in &internal_vec {
-                            items_vec.push(sub_comp.as_pin_ref().box_layout_data(#orientation))
+                            items_vec.push(sub_comp.as_pin_ref().#layout_data_fn(#orientation))
                         }
                     ));
             }
@@ -3318,6 +3601,40 @@ fn embedded_file_tokens(path: &str) -> TokenStream {
     }
 }
 
+#[cfg(feature = "software-renderer")]
+fn static_textures_tokens(
+    texture: &crate::embedded_resources::Texture,
+    symbol_data: &Ident,
+) -> TokenStream {
+    let crate::embedded_resources::Texture {
+        format,
+        rect,
+        total_size: crate::embedded_resources::Size { width, height },
+        original_size:
+            crate::embedded_resources::Size { width: unscaled_width, height: unscaled_height },
+        ..
+    } = texture;
+    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
+    let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
+        quote!(sp::Color::from_rgb_u8(#r, #g, #b))
+    } else {
+        quote!(sp::Color::from_argb_encoded(0))
+    };
+    quote!(sp::StaticTextures {
+        size: sp::IntSize::new(#width as _, #height as _),
+        original_size: sp::IntSize::new(#unscaled_width as _, #unscaled_height as _),
+        data: sp::Slice::from_slice(&#symbol_data),
+        textures: sp::Slice::from_slice(&[
+            sp::StaticTexture {
+                rect: sp::euclid::rect(#r_x as _, #r_y as _, #r_w as _, #r_h as _),
+                format: #format,
+                color: #color,
+                index: 0,
+            }
+        ])
+    })
+}
+
 fn generate_resources(doc: &Document) -> Vec<TokenStream> {
     #[cfg(feature = "software-renderer")]
     let link_section = std::env::var("SLINT_ASSET_SECTION")
@@ -3338,36 +3655,38 @@ fn generate_resources(doc: &Document) -> Vec<TokenStream> {
                     quote!(static #symbol: &'static [u8] = #data;)
                 }
                 #[cfg(feature = "software-renderer")]
-                crate::embedded_resources::EmbeddedResourcesKind::TextureData(crate::embedded_resources::Texture {
-                    data, format, rect,
-                    total_size: crate::embedded_resources::Size{width, height},
-                    original_size: crate::embedded_resources::Size{width: unscaled_width, height: unscaled_height},
-                }) => {
-                    let (r_x, r_y, r_w, r_h) = (rect.x(), rect.y(), rect.width(), rect.height());
-                    let color = if let crate::embedded_resources::PixelFormat::AlphaMap([r, g, b]) = format {
-                        quote!(sp::Color::from_rgb_u8(#r, #g, #b))
-                    } else {
-                        quote!(sp::Color::from_argb_encoded(0))
-                    };
+                crate::embedded_resources::EmbeddedResourcesKind::TextureData(texture) => {
                     let symbol_data = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DATA", er.id);
+                    let data = &texture.data;
                     let data_size = data.len();
+                    let textures = static_textures_tokens(texture, &symbol_data);
                     quote!(
                         #link_section
                         static #symbol_data : [u8; #data_size]= [#(#data),*];
                         #link_section
-                        static #symbol: sp::StaticTextures = sp::StaticTextures{
-                            size: sp::IntSize::new(#width as _, #height as _),
-                            original_size: sp::IntSize::new(#unscaled_width as _, #unscaled_height as _),
-                            data: sp::Slice::from_slice(&#symbol_data),
-                            textures: sp::Slice::from_slice(&[
-                                sp::StaticTexture {
-                                    rect: sp::euclid::rect(#r_x as _, #r_y as _, #r_w as _, #r_h as _),
-                                    format: #format,
-                                    color: #color,
-                                    index: 0,
-                                }
-                            ])
-                        };
+                        static #symbol: sp::StaticTextures = #textures;
+                    )
+                },
+                #[cfg(feature = "software-renderer")]
+                crate::embedded_resources::EmbeddedResourcesKind::AnimatedTextureData(frames) => {
+                    let (data, frames): (Vec<_>, Vec<_>) = frames.iter().enumerate().map(|(index, (texture, duration))| {
+                        let symbol_data = format_ident!("SLINT_EMBEDDED_RESOURCE_{}_DATA_{}", er.id, index);
+                        let data = &texture.data;
+                        let data_size = data.len();
+                        let textures = static_textures_tokens(texture, &symbol_data);
+                        (
+                            quote!(
+                                #link_section
+                                static #symbol_data : [u8; #data_size]= [#(#data),*];
+                            ),
+                            quote!(sp::StaticAnimationFrame { textures: #textures, duration: #duration }),
+                        )
+                    }).unzip();
+                    let frame_count = frames.len();
+                    quote!(
+                        #(#data)*
+                        #link_section
+                        static #symbol: [sp::StaticAnimationFrame; #frame_count] = [#(#frames),*];
                     )
                 },
                 #[cfg(feature = "software-renderer")]
@@ -0,0 +1,557 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*! module for the `.slint` "code generator"
+
+This generator doesn't lower anything: it walks the imports of the root document and
+writes every loaded `.slint` file (including the style's widgets) into one self-contained
+document, dependencies first, with all the `import` statements removed.
+
+Each file goes through the formatter, with a [`TokenWriter`] that drops the imports and
+export lists. Top-level declarations that would clash once everything lives in the same
+file are renamed, and every reference to them in type position (element types, property
+types, global and enum accesses) is rewritten accordingly. Relative `@image-url` are made absolute.
+Only the exports of the root document are kept, in a single `export { ... }` list
+at the end of the output.
+*/
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use smol_str::{format_smolstr, SmolStr};
+
+use crate::fmt::writer::TokenWriter;
+use crate::object_tree::{Document, ExportedName};
+use crate::parser::{identifier_text, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken};
+use crate::typeloader::{ImportKind, ImportedName, TypeLoader};
+
+/// Returns the content of the flattened `.slint` file for the given root document
+pub fn generate(doc: &Document, type_loader: &TypeLoader) -> std::io::Result<String> {
+    let Some(root_node) = doc.node.clone() else {
+        return Ok(String::new());
+    };
+    let root_path = crate::pathutils::clean_path(root_node.source_file.path());
+
+    let mut state = FlattenState {
+        root_path: root_path.clone(),
+        root_doc: doc,
+        type_loader,
+        files: Vec::new(),
+        unique_names: HashMap::new(),
+    };
+
+    let mut visited = HashSet::new();
+    state.collect_files(&root_path, &mut visited)?;
+    state.assign_unique_names();
+
+    let mut output = String::new();
+    let mut font_imports = Vec::new();
+    for path in &state.files {
+        for import in &state.document(path).imports {
+            if matches!(import.import_kind, ImportKind::FileImport)
+                && !font_imports.contains(&import.file)
+            {
+                font_imports.push(import.file.clone());
+            }
+        }
+    }
+    for font in &font_imports {
+        writeln!(output, "import {};", quote_string(font)).unwrap();
+    }
+    if !font_imports.is_empty() {
+        writeln!(output).unwrap();
+    }
+
+    for path in &state.files {
+        state.write_file(&mut output, path)?;
+    }
+
+    let exports = doc
+        .exports
+        .iter()
+        .map(|(exported_name, _)| {
+            let name = state
+                .resolve_export(&root_path, &exported_name.name)
+                .unwrap_or_else(|| exported_name.name.clone());
+            if name == exported_name.name {
+                name.to_string()
+            } else {
+                format!("{name} as {}", exported_name.name)
+            }
+        })
+        .collect::<Vec<_>>();
+    if !exports.is_empty() {
+        writeln!(output, "export {{ {} }}", exports.join(", ")).unwrap();
+    }
+
+    Ok(output)
+}
+
+struct FlattenState<'a> {
+    root_path: PathBuf,
+    root_doc: &'a Document,
+    type_loader: &'a TypeLoader,
+    /// All the files to write, in dependency order (the root document is last)
+    files: Vec<PathBuf>,
+    /// Map from a (file, normalized declared name) to the name used in the output
+    unique_names: HashMap<(PathBuf, SmolStr), SmolStr>,
+}
+
+impl<'a> FlattenState<'a> {
+    fn document(&self, path: &Path) -> &'a Document {
+        if path == self.root_path {
+            self.root_doc
+        } else {
+            self.type_loader.get_document(path).expect("all imports were loaded")
+        }
+    }
+
+    fn node(&self, path: &Path) -> syntax_nodes::Document {
+        self.document(path).node.clone().expect("loaded documents have a syntax node")
+    }
+
+    fn collect_files(
+        &mut self,
+        path: &Path,
+        visited: &mut HashSet<PathBuf>,
+    ) -> std::io::Result<()> {
+        if !visited.insert(path.to_path_buf()) {
+            return Ok(());
+        }
+        let doc = if path == self.root_path {
+            self.root_doc
+        } else {
+            self.type_loader.get_document(path).ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("Cannot flatten: {} was not loaded", path.display()),
+                )
+            })?
+        };
+        for import in &doc.imports {
+            if matches!(import.import_kind, ImportKind::FileImport) {
+                continue;
+            }
+            self.collect_files(&crate::pathutils::clean_path(Path::new(&import.file)), visited)?;
+        }
+        self.files.push(path.to_path_buf());
+        Ok(())
+    }
+
+    /// The names declared in the root document keep their name, others get a suffix
+    /// if the name is already taken.
+    fn assign_unique_names(&mut self) {
+        let mut used = HashSet::new();
+        let ordered = std::iter::once(self.root_path.clone())
+            .chain(self.files.iter().filter(|p| **p != self.root_path).cloned())
+            .collect::<Vec<_>>();
+        for path in ordered {
+            for name in declared_names(&self.node(&path)) {
+                let mut unique = name.clone();
+                let mut count = 1;
+                while !used.insert(unique.clone()) {
+                    unique = format_smolstr!("{name}-{count}");
+                    count += 1;
+                }
+                self.unique_names.insert((path.clone(), name), unique);
+            }
+        }
+    }
+
+    /// Resolve a name exported by the file at `path` to its name in the output
+    fn resolve_export(&self, path: &Path, name: &str) -> Option<SmolStr> {
+        let doc = self.document(path);
+        let node = self.node(path);
+        for exports in node.ExportsList() {
+            if let Some(module) = exports.ExportModule() {
+                let target = doc.imports.iter().find_map(|import| match &import.import_kind {
+                    ImportKind::ModuleReexport(e) if e.node == exports.node => {
+                        Some(crate::pathutils::clean_path(Path::new(&import.file)))
+                    }
+                    _ => None,
+                })?;
+                if module.child_token(SyntaxKind::Star).is_some() {
+                    if self.document(&target).exports.find(name).is_some() {
+                        return self.resolve_export(&target, name);
+                    }
+                    continue;
+                }
+                for specifier in exports.ExportSpecifier() {
+                    let (internal_name, exported_name) =
+                        ExportedName::from_export_specifier(&specifier);
+                    if exported_name.name == name {
+                        return self.resolve_export(&target, &internal_name);
+                    }
+                }
+                continue;
+            }
+            let declared = exports
+                .Component()
+                .map(|c| c.DeclaredIdentifier())
+                .into_iter()
+                .chain(exports.StructDeclaration().map(|s| s.DeclaredIdentifier()))
+                .chain(exports.EnumDeclaration().map(|e| e.DeclaredIdentifier()));
+            for ident in declared {
+                if identifier_text(&ident).is_some_and(|n| n == name) {
+                    return self.resolve_local(path, name);
+                }
+            }
+            for specifier in exports.ExportSpecifier() {
+                let (internal_name, exported_name) =
+                    ExportedName::from_export_specifier(&specifier);
+                if exported_name.name == name {
+                    return self.resolve_local(path, &internal_name);
+                }
+            }
+        }
+        // The last component is implicitly exported if nothing is exported
+        self.resolve_local(path, name)
+    }
+
+    /// Resolve a name visible at the top level of the file at `path` to its name in the output
+    fn resolve_local(&self, path: &Path, name: &str) -> Option<SmolStr> {
+        if let Some(unique) = self.unique_names.get(&(path.to_path_buf(), SmolStr::new(name))) {
+            return Some(unique.clone());
+        }
+        self.document(path).imports.iter().find_map(|import| {
+            let ImportKind::ImportList(list) = &import.import_kind else { return None };
+            let imported = ImportedName::extract_imported_names(list)
+                .find(|imported| imported.internal_name == name)?;
+            self.resolve_export(
+                &crate::pathutils::clean_path(Path::new(&import.file)),
+                &imported.external_name,
+            )
+        })
+    }
+
+    fn write_file(&self, output: &mut String, path: &Path) -> std::io::Result<()> {
+        let node = self.node(path);
+        let mut writer = FlattenWriter {
+            state: self,
+            names: self.local_names(path, &node),
+            shadowed: HashSet::new(),
+            output: String::new(),
+        };
+        crate::fmt::format_document(node, &mut writer)?;
+        writeln!(output, "// {}", path.display()).unwrap();
+        writeln!(output, "{}\n", writer.output.trim()).unwrap();
+        Ok(())
+    }
+
+    /// All the names visible at the top level of the file, mapped to their name in the output
+    fn local_names(&self, path: &Path, node: &syntax_nodes::Document) -> HashMap<SmolStr, SmolStr> {
+        let mut names = HashMap::new();
+        for import in &self.document(path).imports {
+            let ImportKind::ImportList(list) = &import.import_kind else { continue };
+            let target = crate::pathutils::clean_path(Path::new(&import.file));
+            for imported in ImportedName::extract_imported_names(list) {
+                if let Some(unique) = self.resolve_export(&target, &imported.external_name) {
+                    names.insert(imported.internal_name, unique);
+                }
+            }
+        }
+        for name in declared_names(node) {
+            if let Some(unique) = self.unique_names.get(&(path.to_path_buf(), name.clone())) {
+                names.insert(name, unique.clone());
+            }
+        }
+        names
+    }
+
+    /// Same logic as the resolving pass, so that the image is found from the flattened file
+    fn absolute_image_url(&self, node: &SyntaxNode, literal: &str) -> String {
+        let Some(s) = crate::literals::unescape_string(literal) else {
+            return literal.into();
+        };
+        let path = Path::new(s.as_str());
+        if s.is_empty() || crate::pathutils::is_absolute(path) {
+            return literal.into();
+        }
+        let absolute = self
+            .type_loader
+            .resolve_import_path(Some(&node.clone().into()), &s)
+            .map(|i| i.0)
+            .or_else(|| {
+                crate::pathutils::join(&crate::pathutils::dirname(node.source_file.path()), path)
+            });
+        match absolute {
+            Some(p) => quote_string(&p.to_string_lossy()),
+            None => literal.into(),
+        }
+    }
+}
+
+/// Receives the tokens of a formatted file and only keeps its top-level declarations, with the
+/// references to other declarations renamed to their name in the output.
+struct FlattenWriter<'a, 'b> {
+    state: &'b FlattenState<'a>,
+    /// The top-level names visible in the file, mapped to their name in the output
+    names: HashMap<SmolStr, SmolStr>,
+    /// The names declared within the current declaration, which hide the top-level names
+    /// in expressions
+    shadowed: HashSet<SmolStr>,
+    output: String,
+}
+
+impl FlattenWriter<'_, '_> {
+    fn write(&mut self, token: &SyntaxToken, whitespace: &str, contents: Option<&str>) {
+        // Imports, export lists and the whitespace and comments in between are dropped
+        let Some(declaration) = top_level_declaration(token) else { return };
+        let first_token = std::iter::successors(declaration.first_token(), |t| t.next_token())
+            .find(|t| t.kind() != SyntaxKind::Whitespace);
+        match first_token {
+            Some(first) if first.token == token.token => {
+                self.shadowed = shadowing_names(&declaration);
+                self.output.push_str("\n\n");
+            }
+            Some(first) if token.text_range().start() < first.text_range().start() => return,
+            _ => self.output.push_str(whitespace),
+        }
+        match contents {
+            Some(contents) => self.output.push_str(contents),
+            None => self.write_token_text(token, &declaration),
+        }
+    }
+
+    fn write_token_text(&mut self, token: &SyntaxToken, declaration: &SyntaxNode) {
+        let parent = token.parent();
+        let renamed = match (token.kind(), parent.kind()) {
+            (SyntaxKind::Identifier, SyntaxKind::DeclaredIdentifier) => {
+                parent.parent().is_some_and(|p| p.node == declaration.node)
+            }
+            (SyntaxKind::Identifier, SyntaxKind::QualifiedName)
+                if parent.first_token().is_some_and(|first| first.token == token.token) =>
+            {
+                match parent.parent().map(|p| p.kind()) {
+                    Some(SyntaxKind::Element | SyntaxKind::Type) => true,
+                    // In expressions, the first identifier can also be an element id, a property
+                    // or a local variable that have the same name as a declaration
+                    Some(SyntaxKind::Expression) => {
+                        !self.shadowed.contains(&crate::parser::normalize_identifier(token.text()))
+                    }
+                    _ => false,
+                }
+            }
+            (SyntaxKind::StringLiteral, SyntaxKind::AtImageUrl) => {
+                let url = self.state.absolute_image_url(&parent, token.text());
+                self.output.push_str(&url);
+                return;
+            }
+            _ => false,
+        };
+        let unique = renamed
+            .then(|| self.names.get(&crate::parser::normalize_identifier(token.text())))
+            .flatten();
+        self.output.push_str(unique.map_or(token.text(), |u| u.as_str()));
+    }
+}
+
+impl TokenWriter for FlattenWriter<'_, '_> {
+    fn no_change(&mut self, token: SyntaxToken) -> std::io::Result<()> {
+        self.write(&token, "", None);
+        Ok(())
+    }
+
+    fn with_new_content(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        self.write(&token, "", Some(contents));
+        Ok(())
+    }
+
+    fn insert_before(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        self.write(&token, contents, None);
+        Ok(())
+    }
+}
+
+/// The component, struct or enum declared at the top level of the document that contains `token`
+fn top_level_declaration(token: &SyntaxToken) -> Option<SyntaxNode> {
+    token.parent_ancestors().find(|n| {
+        matches!(
+            n.kind(),
+            SyntaxKind::Component | SyntaxKind::StructDeclaration | SyntaxKind::EnumDeclaration
+        ) && n
+            .parent()
+            .is_some_and(|p| matches!(p.kind(), SyntaxKind::Document | SyntaxKind::ExportsList))
+    })
+}
+
+/// The names that an expression within `declaration` may refer to instead of a top-level
+/// declaration: element ids, properties, callbacks, functions, arguments and model data
+fn shadowing_names(declaration: &SyntaxNode) -> HashSet<SmolStr> {
+    declaration
+        .descendants()
+        .filter_map(|n| match n.kind() {
+            SyntaxKind::SubElement => n
+                .child_text(SyntaxKind::Identifier)
+                .map(|id| crate::parser::normalize_identifier(&id)),
+            SyntaxKind::DeclaredIdentifier
+                if n.parent().is_some_and(|p| p.node != declaration.node) =>
+            {
+                identifier_text(&n)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The normalized names of the components, structs and enums declared at the top level
+fn declared_names(node: &syntax_nodes::Document) -> Vec<SmolStr> {
+    node.children()
+        .flat_map(|child| match child.kind() {
+            SyntaxKind::ExportsList => child.children().collect::<Vec<_>>(),
+            _ => vec![child],
+        })
+        .filter(|n| {
+            matches!(
+                n.kind(),
+                SyntaxKind::Component | SyntaxKind::StructDeclaration | SyntaxKind::EnumDeclaration
+            )
+        })
+        .filter_map(|n| n.child_node(SyntaxKind::DeclaredIdentifier))
+        .filter_map(|n| identifier_text(&n))
+        .collect()
+}
+
+fn quote_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+fn flatten_for_test(main: &str, files: &[(&'static str, &'static str)]) -> String {
+    let files: HashMap<_, _> = files.iter().copied().collect();
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Slint);
+    compiler_config.style = Some("fluent".into());
+    compiler_config.enable_experimental = true;
+    compiler_config.open_import_fallback = Some(std::rc::Rc::new(move |path| {
+        let file_name = Path::new(&path).file_name().and_then(|f| f.to_str()).unwrap_or_default();
+        let content = files.get(file_name).map(|content| Ok(content.to_string()));
+        Box::pin(async move { content })
+    }));
+
+    let compile = |source: String| {
+        let mut diag = crate::diagnostics::BuildDiagnostics::default();
+        let node =
+            crate::parser::parse(source, Some(Path::new("/flatten-test/main.slint")), &mut diag);
+        let (doc, diag, loader) =
+            spin_on::spin_on(crate::compile_syntax_node(node, diag, compiler_config.clone()));
+        assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+        (doc, loader)
+    };
+
+    let (doc, loader) = compile(main.into());
+    let mut flattened = Vec::new();
+    crate::generator::generate(
+        crate::generator::OutputFormat::Slint,
+        &mut flattened,
+        &doc,
+        &loader,
+    )
+    .unwrap();
+    let flattened = String::from_utf8(flattened).unwrap();
+    assert!(!flattened.lines().any(|l| l.starts_with("import ")), "{flattened}");
+
+    // The result must compile on its own, with the same exports
+    let (flattened_doc, _) = compile(flattened.clone());
+    assert_eq!(
+        flattened_doc.exports.iter().map(|(n, _)| n.name.clone()).collect::<Vec<_>>(),
+        doc.exports.iter().map(|(n, _)| n.name.clone()).collect::<Vec<_>>(),
+    );
+    flattened
+}
+
+#[test]
+fn test_flatten_std_widgets() {
+    let flattened = flatten_for_test(
+        r#"
+import { Button, Palette } from "std-widgets.slint";
+component Button2 inherits Button { }
+export component Foo inherits Window {
+    background: Palette.background;
+    Button2 { text: "Hello"; }
+}
+"#,
+        &[],
+    );
+    assert!(flattened.ends_with("export { Foo }\n"));
+}
+
+#[test]
+fn test_flatten_name_collisions() {
+    let flattened = flatten_for_test(
+        r#"
+import { Card as LibCard, Theme as LibTheme } from "lib.slint";
+global Theme { out property <length> spacing: 4px; }
+component Card inherits Rectangle { }
+export component App inherits Window {
+    in property <length> padding: Theme.spacing;
+    background: LibTheme.accent;
+    Card { }
+    LibCard { }
+}
+"#,
+        &[(
+            "lib.slint",
+            r#"
+export global Theme { out property <color> accent: red; }
+export component Card inherits Rectangle {
+    background: Theme.accent;
+}
+export component Badge inherits Rectangle {
+    // An element id that has the same name as the global
+    Theme := Rectangle { }
+    width: Theme.width;
+}
+"#,
+        )],
+    );
+    // The names of the root document are kept, the other ones get a suffix
+    assert!(flattened.contains("global Theme {"), "{flattened}");
+    assert!(flattened.contains("component Card inherits Rectangle"), "{flattened}");
+    assert!(flattened.contains("global Theme-1 {"), "{flattened}");
+    assert!(flattened.contains("component Card-1 inherits Rectangle"), "{flattened}");
+    assert!(flattened.contains("background: Theme-1.accent;"), "{flattened}");
+    assert!(flattened.contains("Card-1 { }"), "{flattened}");
+    // References to the element id are not renamed
+    assert!(flattened.contains("Theme := Rectangle { }"), "{flattened}");
+    assert!(flattened.contains("width: Theme.width;"), "{flattened}");
+    assert!(flattened.contains("padding: Theme.spacing;"), "{flattened}");
+}
+
+#[test]
+fn test_flatten_reexports_and_aliases() {
+    let flattened = flatten_for_test(
+        r#"
+import { PushButton as Btn, Item } from "index.slint";
+export { Item }
+export component App inherits Window {
+    in property <[Item]> items;
+    Btn { text: root.items[0].name; }
+}
+"#,
+        &[
+            (
+                "index.slint",
+                r#"
+export { Button as PushButton } from "widgets.slint";
+export * from "item.slint";
+"#,
+            ),
+            (
+                "widgets.slint",
+                r#"
+export component Button inherits Rectangle { in property <string> text; }
+"#,
+            ),
+            ("item.slint", "export struct Item { name: string }"),
+        ],
+    );
+    assert!(flattened.contains("component Button inherits Rectangle"), "{flattened}");
+    assert!(flattened.contains("struct Item {"), "{flattened}");
+    assert!(flattened.contains("Button {"), "{flattened}");
+    assert!(flattened.contains("text: root.items[0].name;"), "{flattened}");
+    assert!(!flattened.contains("Btn"), "{flattened}");
+    assert!(!flattened.contains("PushButton"), "{flattened}");
+    assert!(flattened.ends_with("export { App, Item }\n"), "{flattened}");
+}
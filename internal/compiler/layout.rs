@@ -23,6 +23,7 @@ pub enum Orientation {
 pub enum Layout {
     GridLayout(GridLayout),
     BoxLayout(BoxLayout),
+    FlexLayout(FlexLayout),
 }
 
 impl Layout {
@@ -30,18 +31,21 @@ pub fn rect(&self) -> &LayoutRect {
         match self {
             Layout::GridLayout(g) => &g.geometry.rect,
             Layout::BoxLayout(g) => &g.geometry.rect,
+            Layout::FlexLayout(g) => &g.geometry.rect,
         }
     }
     pub fn rect_mut(&mut self) -> &mut LayoutRect {
         match self {
             Layout::GridLayout(g) => &mut g.geometry.rect,
             Layout::BoxLayout(g) => &mut g.geometry.rect,
+            Layout::FlexLayout(g) => &mut g.geometry.rect,
         }
     }
     pub fn geometry(&self) -> &LayoutGeometry {
         match self {
             Layout::GridLayout(l) => &l.geometry,
             Layout::BoxLayout(l) => &l.geometry,
+            Layout::FlexLayout(l) => &l.geometry,
         }
     }
 }
@@ -52,6 +56,7 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         match self {
             Layout::GridLayout(grid) => grid.visit_named_references(visitor),
             Layout::BoxLayout(l) => l.visit_named_references(visitor),
+            Layout::FlexLayout(l) => l.visit_named_references(visitor),
         }
     }
 }
@@ -425,7 +430,7 @@ fn find_binding<R>(
 }
 
 /// Return a named reference to a property if a binding is set on that property
-fn binding_reference(element: &ElementRc, name: &'static str) -> Option<NamedReference> {
+pub(crate) fn binding_reference(element: &ElementRc, name: &'static str) -> Option<NamedReference> {
     find_binding(element, name, |_, _, _| NamedReference::new(element, SmolStr::new_static(name)))
 }
 
@@ -490,6 +495,53 @@ fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReferenc
     }
 }
 
+/// Internal representation of a FlexLayout
+#[derive(Debug, Clone)]
+pub struct FlexLayout {
+    /// The main axis: Horizontal when the direction is `row`, Vertical for `column`
+    pub orientation: Orientation,
+    pub elems: Vec<FlexLayoutElement>,
+    pub geometry: LayoutGeometry,
+    /// Reference to the `wrap` property of the layout
+    pub wrap: NamedReference,
+}
+
+impl FlexLayout {
+    fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        for cell in &mut self.elems {
+            cell.item.constraints.visit_named_references(visitor);
+            if let Some(factors) = cell.factors.as_mut() {
+                factors.visit_named_references(visitor);
+            }
+        }
+        self.geometry.visit_named_references(visitor);
+        visitor(&mut self.wrap);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FlexLayoutElement {
+    pub item: LayoutItem,
+    /// The grow and shrink factors of the element.
+    /// None for repeated elements: they are stored in the RepeatedElementInfo instead, because
+    /// each instance has its own.
+    pub factors: Option<FlexFactors>,
+}
+
+/// References to the `flex-grow` and `flex-shrink` properties of an element in a FlexLayout
+#[derive(Debug, Clone)]
+pub struct FlexFactors {
+    pub grow: NamedReference,
+    pub shrink: NamedReference,
+}
+
+impl FlexFactors {
+    pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        visitor(&mut self.grow);
+        visitor(&mut self.shrink);
+    }
+}
+
 /// Get the implicit layout info of a particular element
 pub fn implicit_layout_info_call(elem: &ElementRc, orientation: Orientation) -> Expression {
     let mut elem_it = elem.clone();
@@ -579,7 +631,10 @@ pub fn is_layout(base_type: &ElementType) -> bool {
     match base_type {
         ElementType::Component(c) => is_layout(&c.root_element.borrow().base_type),
         ElementType::Builtin(be) => {
-            matches!(be.name.as_str(), "GridLayout" | "HorizontalLayout" | "VerticalLayout")
+            matches!(
+                be.name.as_str(),
+                "GridLayout" | "HorizontalLayout" | "VerticalLayout" | "FlexLayout"
+            )
         }
         _ => false,
     }
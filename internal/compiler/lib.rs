@@ -22,6 +22,7 @@
 pub mod embedded_resources;
 pub mod expression_tree;
 pub mod fileaccess;
+pub mod fmt;
 pub mod generator;
 pub mod langtype;
 pub mod layout;
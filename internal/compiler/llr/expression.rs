@@ -177,6 +177,8 @@ pub enum Expression {
         /// Either an expression of type BoxLayoutCellData, or an index to the repeater
         elements: Vec<Either<Expression, RepeatedElementIdx>>,
         orientation: Orientation,
+        /// When true, the cells are FlexLayoutCellData instead of BoxLayoutCellData
+        flex_cells: bool,
         sub_expression: Box<Expression>,
     },
 
@@ -442,7 +444,7 @@ pub fn visit_property_references(
                 Expression::PropertyAssignment { property, .. } => {
                     if let Some((a, map)) = &ctx.property_info(property).animation {
                         let ctx2 = map.map_context(ctx);
-                        a.visit_property_references(&ctx2, visitor);
+                        a.visit_expressions(|e| e.visit_property_references(&ctx2, visitor));
                     }
                     property
                 }
@@ -752,7 +754,7 @@ fn arg_type(&self, index: usize) -> &Type {
 pub(crate) struct PropertyInfoResult<'a> {
     pub analysis: Option<&'a crate::object_tree::PropertyAnalysis>,
     pub binding: Option<(&'a super::BindingExpression, ContextMap)>,
-    pub animation: Option<(&'a Expression, ContextMap)>,
+    pub animation: Option<(&'a super::Animation, ContextMap)>,
     pub property_decl: Option<&'a super::Property>,
 }
 
@@ -46,7 +46,64 @@ pub fn ty(&self, ctx: &dyn super::TypeResolutionContext) -> Type {
 pub enum Animation {
     /// The expression is a Struct with the animation fields
     Static(Expression),
-    Transition(Expression),
+    /// Like `Static`, with intermediate keyframes between the start and the end value
+    Keyframes(Expression, Vec<Keyframe>),
+    /// The expression is a tuple of the animation, the time of the state change, and the index
+    /// of the active transition (or -1). The keyframes of each transition are at that index.
+    Transition(Expression, Vec<Vec<Keyframe>>),
+}
+
+impl Animation {
+    /// Call the visitor for each expression in this animation
+    pub fn visit_expressions(&self, mut visitor: impl FnMut(&Expression)) {
+        match self {
+            Animation::Static(e) => visitor(e),
+            Animation::Keyframes(e, keyframes) => {
+                visitor(e);
+                for kf in keyframes {
+                    visitor(&kf.easing);
+                    visitor(&kf.value);
+                }
+            }
+            Animation::Transition(e, keyframes) => {
+                visitor(e);
+                for kf in keyframes.iter().flatten() {
+                    visitor(&kf.easing);
+                    visitor(&kf.value);
+                }
+            }
+        }
+    }
+
+    /// Call the visitor for each expression in this animation, allowing to modify them
+    pub fn visit_expressions_mut(&mut self, mut visitor: impl FnMut(&mut Expression)) {
+        match self {
+            Animation::Static(e) => visitor(e),
+            Animation::Keyframes(e, keyframes) => {
+                visitor(e);
+                for kf in keyframes {
+                    visitor(&mut kf.easing);
+                    visitor(&mut kf.value);
+                }
+            }
+            Animation::Transition(e, keyframes) => {
+                visitor(e);
+                for kf in keyframes.iter_mut().flatten() {
+                    visitor(&mut kf.easing);
+                    visitor(&mut kf.value);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Keyframe {
+    /// The position of the keyframe in the animation, between 0 and 1
+    pub offset: f32,
+    /// The easing curve used for the segment leading to this keyframe
+    pub easing: Expression,
+    pub value: Expression,
 }
 
 #[derive(Debug, Clone)]
@@ -140,6 +197,7 @@ pub struct Function {
 /// The property references might be either in the parent context, or in the
 /// repeated's component context
 pub struct ListViewInfo {
+    pub viewport_x: PropertyReference,
     pub viewport_y: PropertyReference,
     pub viewport_height: PropertyReference,
     pub viewport_width: PropertyReference,
@@ -147,13 +205,42 @@ pub struct ListViewInfo {
     pub listview_height: PropertyReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: PropertyReference,
+    /// The axis along which the items are laid out and virtualized
+    pub orientation: crate::layout::Orientation,
+    /// The cell width and height of a GridView
+    pub cell_size: Option<(PropertyReference, PropertyReference)>,
+    /// The row to scroll into view, reset to -1 by the runtime. None if `scroll-to-index()`
+    /// is never called on this ListView.
+    pub requested_row: Option<PropertyReference>,
 
+    // In the repeated component context
+    pub prop_x: PropertyReference,
     // In the repeated component context
     pub prop_y: PropertyReference,
     // In the repeated component context
+    pub prop_width: PropertyReference,
+    // In the repeated component context
     pub prop_height: PropertyReference,
 }
 
+#[derive(Debug, Clone)]
+/// The functions of a repeated component that handle its `exit { ... }` block
+pub struct ExitTransitionInfo {
+    /// In the repeated component context: starts the exit transition
+    pub start: PropertyReference,
+    /// In the repeated component context: returns true once the exit transition is over
+    pub is_done: PropertyReference,
+}
+
+#[derive(Debug, Clone)]
+/// The `flex-grow` and `flex-shrink` properties of a repeated component placed in a FlexLayout
+pub struct FlexFactorsInfo {
+    /// In the repeated component context
+    pub grow: PropertyReference,
+    /// In the repeated component context
+    pub shrink: PropertyReference,
+}
+
 #[derive(Debug)]
 pub struct RepeatedElement {
     pub model: MutExpression,
@@ -167,6 +254,10 @@ pub struct RepeatedElement {
 
     pub listview: Option<ListViewInfo>,
 
+    pub exit_transition: Option<ExitTransitionInfo>,
+
+    pub flex_factors: Option<FlexFactorsInfo>,
+
     /// Access through this in case of the element being a `is_component_placeholder`
     pub container_item_index: Option<ItemInstanceIdx>,
 }
@@ -268,7 +359,7 @@ pub struct SubComponent {
     pub property_init: Vec<(PropertyReference, BindingExpression)>,
     pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
     /// The animation for properties which are animated
-    pub animations: HashMap<PropertyReference, Expression>,
+    pub animations: HashMap<PropertyReference, Animation>,
     pub two_way_bindings: Vec<(PropertyReference, PropertyReference)>,
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
@@ -293,6 +384,8 @@ pub struct SubComponent {
 pub struct PopupWindow {
     pub item_tree: ItemTree,
     pub position: MutExpression,
+    /// The popup is shown in its own top-level window, from a `SecondaryWindow` element
+    pub is_secondary_window: bool,
 }
 
 #[derive(Debug)]
@@ -131,12 +131,12 @@ pub fn lower_expression(
             llr_Expression::CodeBlock(expr.iter().map(|e| lower_expression(e, ctx)).collect::<_>())
         }
         tree_Expression::FunctionCall { function, arguments, .. } => match function {
-            Callable::Builtin(BuiltinFunction::ShowPopupWindow) => {
-                lower_show_popup_window(arguments, ctx)
-            }
-            Callable::Builtin(BuiltinFunction::ClosePopupWindow) => {
-                lower_close_popup_window(arguments, ctx)
-            }
+            Callable::Builtin(
+                BuiltinFunction::ShowPopupWindow | BuiltinFunction::ShowSecondaryWindow,
+            ) => lower_show_popup_window(arguments, ctx),
+            Callable::Builtin(
+                BuiltinFunction::ClosePopupWindow | BuiltinFunction::CloseSecondaryWindow,
+            ) => lower_close_popup_window(arguments, ctx),
             Callable::Builtin(f) => {
                 let mut arguments =
                     arguments.iter().map(|e| lower_expression(e, ctx)).collect::<Vec<_>>();
@@ -415,6 +415,18 @@ fn lower_show_popup_window(
             ctx,
         );
 
+        if let Some(options) = popup.secondary_window {
+            return llr_Expression::BuiltinFunctionCall {
+                function: BuiltinFunction::ShowSecondaryWindow,
+                arguments: vec![
+                    llr_Expression::NumberLiteral(popup_index as _),
+                    llr_Expression::BoolLiteral(options.transient),
+                    llr_Expression::BoolLiteral(options.modal),
+                    item_ref,
+                ],
+            };
+        }
+
         llr_Expression::BuiltinFunctionCall {
             function: BuiltinFunction::ShowPopupWindow,
             arguments: vec![
@@ -455,7 +467,11 @@ fn lower_close_popup_window(
         );
 
         llr_Expression::BuiltinFunctionCall {
-            function: BuiltinFunction::ClosePopupWindow,
+            function: if popup.secondary_window.is_some() {
+                BuiltinFunction::CloseSecondaryWindow
+            } else {
+                BuiltinFunction::ClosePopupWindow
+            },
             arguments: vec![llr_Expression::NumberLiteral(popup_index as _), item_ref],
         }
     } else {
@@ -506,6 +522,10 @@ fn animation_ty() -> Rc<Struct> {
 
     match a {
         PropertyAnimation::Static(a) => Animation::Static(lower_animation_element(a, ctx)),
+        PropertyAnimation::Keyframes { animation, keyframes } => Animation::Keyframes(
+            lower_animation_element(animation, ctx),
+            lower_keyframes(keyframes, animation, ctx),
+        ),
         PropertyAnimation::Transition { state_ref, animations } => {
             let set_state = llr_Expression::StoreLocalVariable {
                 name: "state".into(),
@@ -513,7 +533,8 @@ fn animation_ty() -> Rc<Struct> {
             };
             let animation_ty = Type::Struct(animation_ty());
             let mut get_anim = llr_Expression::default_value_for_type(&animation_ty).unwrap();
-            for tr in animations.iter().rev() {
+            let mut get_index = llr_Expression::NumberLiteral(-1.);
+            for (index, tr) in animations.iter().enumerate().rev() {
                 let condition = lower_expression(
                     &tr.condition(tree_Expression::ReadLocalVariable {
                         name: "state".into(),
@@ -522,11 +543,20 @@ fn animation_ty() -> Rc<Struct> {
                     ctx,
                 );
                 get_anim = llr_Expression::Condition {
-                    condition: Box::new(condition),
+                    condition: Box::new(condition.clone()),
                     true_expr: Box::new(lower_animation_element(&tr.animation, ctx)),
                     false_expr: Box::new(get_anim),
-                }
+                };
+                get_index = llr_Expression::Condition {
+                    condition: Box::new(condition),
+                    true_expr: Box::new(llr_Expression::NumberLiteral(index as _)),
+                    false_expr: Box::new(get_index),
+                };
             }
+            let keyframes = animations
+                .iter()
+                .map(|tr| lower_keyframes(&tr.keyframes, &tr.animation, ctx))
+                .collect();
             let result = llr_Expression::Struct {
                 // This is going to be a tuple
                 ty: Rc::new(Struct {
@@ -534,6 +564,7 @@ fn animation_ty() -> Rc<Struct> {
                         (SmolStr::new_static("0"), animation_ty),
                         // The type is an instant, which does not exist in our type system
                         (SmolStr::new_static("1"), Type::Invalid),
+                        (SmolStr::new_static("2"), Type::Int32),
                     ])
                     .collect(),
                     name: None,
@@ -553,14 +584,38 @@ fn animation_ty() -> Rc<Struct> {
                             name: "change_time".into(),
                         },
                     ),
+                    (SmolStr::new_static("2"), get_index),
                 ])
                 .collect(),
             };
-            Animation::Transition(llr_Expression::CodeBlock(vec![set_state, result]))
+            Animation::Transition(llr_Expression::CodeBlock(vec![set_state, result]), keyframes)
         }
     }
 }
 
+/// Lower the keyframes of an animation. The keyframes without easing use the one of the animation.
+fn lower_keyframes(
+    keyframes: &[crate::object_tree::Keyframe],
+    animation: &ElementRc,
+    ctx: &mut ExpressionLoweringCtx,
+) -> Vec<super::Keyframe> {
+    let default_easing = animation.borrow().bindings.get("easing").map_or_else(
+        || llr_Expression::default_value_for_type(&Type::Easing).unwrap(),
+        |v| lower_expression(&v.borrow().expression, ctx),
+    );
+    keyframes
+        .iter()
+        .map(|kf| super::Keyframe {
+            offset: kf.offset,
+            easing: kf
+                .easing
+                .as_ref()
+                .map_or_else(|| default_easing.clone(), |e| lower_expression(e, ctx)),
+            value: lower_expression(&kf.value, ctx),
+        })
+        .collect()
+}
+
 fn compute_layout_info(
     l: &crate::layout::Layout,
     o: Orientation,
@@ -598,11 +653,45 @@ fn compute_layout_info(
                     repeater_indices: None,
                     elements,
                     orientation: o,
+                    flex_cells: false,
                     sub_expression: Box::new(sub_expression),
                 },
                 None => sub_expression,
             }
         }
+        crate::layout::Layout::FlexLayout(layout) => {
+            let (padding, spacing) =
+                generate_layout_padding_and_spacing(&layout.geometry, layout.orientation, ctx);
+            let (cells, compute_cells) =
+                flex_layout_cells(layout, layout.orientation, "cells", ctx);
+            let sub_expression = if o == layout.orientation {
+                let wrap =
+                    llr_Expression::PropertyReference(ctx.map_property_reference(&layout.wrap));
+                llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "flex_layout_info".into(),
+                    arguments: vec![cells, spacing, padding, wrap],
+                    return_ty: crate::typeregister::layout_info_type().into(),
+                }
+            } else {
+                let data = flex_layout_data(layout, cells, padding, spacing, ctx);
+                let (cross_padding, cross_spacing) =
+                    generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+                let (cross_cells, compute_cross_cells) =
+                    flex_layout_cells(layout, o, "cross_cells", ctx);
+                with_layout_cells(
+                    llr_Expression::ExtraBuiltinFunctionCall {
+                        function: "flex_layout_info_ortho".into(),
+                        arguments: vec![data, cross_cells, cross_spacing, cross_padding],
+                        return_ty: crate::typeregister::layout_info_type().into(),
+                    },
+                    compute_cross_cells,
+                    None,
+                    o,
+                    false,
+                )
+            };
+            with_layout_cells(sub_expression, compute_cells, None, layout.orientation, true)
+        }
     }
 }
 
@@ -702,6 +791,7 @@ fn solve_layout(
                     repeater_indices: Some("repeated_indices".into()),
                     elements,
                     orientation: o,
+                    flex_cells: false,
                     sub_expression: Box::new(llr_Expression::ExtraBuiltinFunctionCall {
                         function: "solve_box_layout".into(),
                         arguments: vec![
@@ -728,6 +818,59 @@ fn solve_layout(
                 },
             }
         }
+        crate::layout::Layout::FlexLayout(layout) => {
+            let (padding, spacing) =
+                generate_layout_padding_and_spacing(&layout.geometry, layout.orientation, ctx);
+            let (cells, compute_cells) =
+                flex_layout_cells(layout, layout.orientation, "cells", ctx);
+            let repeater_indices = if compute_cells.is_some() {
+                llr_Expression::ReadLocalVariable {
+                    name: "repeated_indices".into(),
+                    ty: Type::Array(Type::Int32.into()),
+                }
+            } else {
+                llr_Expression::Array { element_ty: Type::Int32, values: vec![], as_model: false }
+            };
+            let data = flex_layout_data(layout, cells, padding, spacing, ctx);
+            let sub_expression = if o == layout.orientation {
+                llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "solve_flex_layout".into(),
+                    arguments: vec![data, repeater_indices],
+                    return_ty: Type::LayoutCache,
+                }
+            } else {
+                let (cross_padding, cross_spacing) =
+                    generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+                let (cross_cells, compute_cross_cells) =
+                    flex_layout_cells(layout, o, "cross_cells", ctx);
+                let cross_size = layout_geometry_size(&layout.geometry.rect, o, ctx);
+                with_layout_cells(
+                    llr_Expression::ExtraBuiltinFunctionCall {
+                        function: "solve_flex_layout_ortho".into(),
+                        arguments: vec![
+                            data,
+                            cross_cells,
+                            cross_size,
+                            cross_spacing,
+                            cross_padding,
+                            repeater_indices,
+                        ],
+                        return_ty: Type::LayoutCache,
+                    },
+                    compute_cross_cells,
+                    None,
+                    o,
+                    false,
+                )
+            };
+            with_layout_cells(
+                sub_expression,
+                compute_cells,
+                Some("repeated_indices".into()),
+                layout.orientation,
+                true,
+            )
+        }
     }
 }
 
@@ -744,15 +887,7 @@ fn box_layout_data(
     orientation: Orientation,
     ctx: &mut ExpressionLoweringCtx,
 ) -> BoxLayoutDataResult {
-    let alignment = if let Some(expr) = &layout.geometry.alignment {
-        llr_Expression::PropertyReference(ctx.map_property_reference(expr))
-    } else {
-        let e = crate::typeregister::BUILTIN.with(|e| e.enums.LayoutAlignment.clone());
-        llr_Expression::EnumerationValue(EnumerationValue {
-            value: e.default_value,
-            enumeration: e,
-        })
-    };
+    let alignment = layout_alignment(&layout.geometry, ctx);
 
     let repeater_count =
         layout.elems.iter().filter(|i| i.element.borrow().repeated.is_some()).count();
@@ -808,6 +943,134 @@ fn box_layout_data(
     }
 }
 
+fn layout_alignment(
+    layout_geometry: &crate::layout::LayoutGeometry,
+    ctx: &ExpressionLoweringCtx,
+) -> llr_Expression {
+    if let Some(expr) = &layout_geometry.alignment {
+        llr_Expression::PropertyReference(ctx.map_property_reference(expr))
+    } else {
+        let e = crate::typeregister::BUILTIN.with(|e| e.enums.LayoutAlignment.clone());
+        llr_Expression::EnumerationValue(EnumerationValue {
+            value: e.default_value,
+            enumeration: e,
+        })
+    }
+}
+
+/// The cells of a FlexLayout in the given orientation: FlexLayoutCellData for the main axis,
+/// and BoxLayoutCellData for the cross axis.
+///
+/// Returns the expression of the cells and, when there are repeaters, the elements of the
+/// BoxLayoutFunction that computes them in the `cells_variable`
+fn flex_layout_cells(
+    layout: &crate::layout::FlexLayout,
+    orientation: Orientation,
+    cells_variable: &str,
+    ctx: &mut ExpressionLoweringCtx,
+) -> (llr_Expression, Option<(String, Vec<Either<llr_Expression, RepeatedElementIdx>>)>) {
+    let is_main_axis = orientation == layout.orientation;
+    let mut elements = vec![];
+    for elem in &layout.elems {
+        let item = &elem.item;
+        if item.element.borrow().repeated.is_some() {
+            let repeater_index =
+                match ctx.mapping.element_mapping.get(&item.element.clone().into()).unwrap() {
+                    LoweredElement::Repeated { repeated_index } => *repeated_index,
+                    _ => panic!(),
+                };
+            elements.push(Either::Right(repeater_index));
+            continue;
+        }
+        let layout_info = get_layout_info(&item.element, ctx, &item.constraints, orientation);
+        let constraint =
+            ("constraint", crate::typeregister::layout_info_type().into(), layout_info);
+        let cell = match elem.factors.as_ref().filter(|_| is_main_axis) {
+            Some(factors) => {
+                let factor = |nr| llr_Expression::PropertyReference(ctx.map_property_reference(nr));
+                make_struct(
+                    "FlexLayoutCellData",
+                    [
+                        constraint,
+                        ("grow", Type::Float32, factor(&factors.grow)),
+                        ("shrink", Type::Float32, factor(&factors.shrink)),
+                    ],
+                )
+            }
+            None => make_struct("BoxLayoutCellData", [constraint]),
+        };
+        elements.push(Either::Left(cell));
+    }
+
+    let element_ty = if is_main_axis {
+        flex_layout_cell_data_ty()
+    } else {
+        crate::typeregister::box_layout_cell_data_type()
+    };
+    if elements.iter().any(Either::is_right) {
+        let cells = llr_Expression::ReadLocalVariable {
+            name: cells_variable.into(),
+            ty: Type::Array(element_ty.into()),
+        };
+        (cells, Some((cells_variable.into(), elements)))
+    } else {
+        let values = elements.into_iter().filter_map(Either::left).collect();
+        (llr_Expression::Array { element_ty, values, as_model: false }, None)
+    }
+}
+
+/// Wrap the `sub_expression` in a BoxLayoutFunction when the cells need to be computed
+fn with_layout_cells(
+    sub_expression: llr_Expression,
+    compute_cells: Option<(String, Vec<Either<llr_Expression, RepeatedElementIdx>>)>,
+    repeater_indices: Option<SmolStr>,
+    orientation: Orientation,
+    flex_cells: bool,
+) -> llr_Expression {
+    match compute_cells {
+        Some((cells_variable, elements)) => llr_Expression::BoxLayoutFunction {
+            cells_variable,
+            repeater_indices,
+            elements,
+            orientation,
+            flex_cells,
+            sub_expression: Box::new(sub_expression),
+        },
+        None => sub_expression,
+    }
+}
+
+/// The FlexLayoutData describing the main axis of the layout
+fn flex_layout_data(
+    layout: &crate::layout::FlexLayout,
+    cells: llr_Expression,
+    padding: llr_Expression,
+    spacing: llr_Expression,
+    ctx: &mut ExpressionLoweringCtx,
+) -> llr_Expression {
+    let size = layout_geometry_size(&layout.geometry.rect, layout.orientation, ctx);
+    make_struct(
+        "FlexLayoutData",
+        [
+            ("size", Type::Float32, size),
+            ("spacing", Type::Float32, spacing),
+            ("padding", padding.ty(ctx), padding),
+            (
+                "alignment",
+                crate::typeregister::BUILTIN
+                    .with(|e| Type::Enumeration(e.enums.LayoutAlignment.clone())),
+                layout_alignment(&layout.geometry, ctx),
+            ),
+            (
+                "wrap",
+                Type::Bool,
+                llr_Expression::PropertyReference(ctx.map_property_reference(&layout.wrap)),
+            ),
+            ("cells", cells.ty(ctx), cells),
+        ],
+    )
+}
+
 fn grid_layout_cell_data(
     layout: &crate::layout::GridLayout,
     orientation: Orientation,
@@ -851,6 +1114,20 @@ pub(super) fn grid_layout_cell_data_ty() -> Type {
     }))
 }
 
+fn flex_layout_cell_data_ty() -> Type {
+    Type::Struct(Rc::new(Struct {
+        fields: IntoIterator::into_iter([
+            (SmolStr::new_static("constraint"), crate::typeregister::layout_info_type().into()),
+            (SmolStr::new_static("grow"), Type::Float32),
+            (SmolStr::new_static("shrink"), Type::Float32),
+        ])
+        .collect(),
+        name: Some("FlexLayoutCellData".into()),
+        node: None,
+        rust_attributes: None,
+    }))
+}
+
 fn generate_layout_padding_and_spacing(
     layout_geometry: &crate::layout::LayoutGeometry,
     orientation: Orientation,
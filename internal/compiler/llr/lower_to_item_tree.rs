@@ -455,10 +455,10 @@ fn lower_sub_component(
         {
             if let Some(anim) = binding.animation.as_ref() {
                 match super::lower_expression::lower_animation(anim, &mut ctx) {
-                    Animation::Static(anim) => {
+                    anim @ (Animation::Static(_) | Animation::Keyframes(..)) => {
                         sub_component.animations.insert(prop, anim);
                     }
-                    Animation::Transition(_) => {
+                    Animation::Transition(..) => {
                         // Cannot set a property with a transition anyway
                     }
                 }
@@ -654,16 +654,35 @@ fn lower_repeated_component(
     let listview = repeated.is_listview.as_ref().map(|lv| {
         let geom = component.root_element.borrow().geometry_props.clone().unwrap();
         ListViewInfo {
+            viewport_x: ctx.map_property_reference(&lv.viewport_x),
             viewport_y: ctx.map_property_reference(&lv.viewport_y),
             viewport_height: ctx.map_property_reference(&lv.viewport_height),
             viewport_width: ctx.map_property_reference(&lv.viewport_width),
             listview_height: ctx.map_property_reference(&lv.listview_height),
             listview_width: ctx.map_property_reference(&lv.listview_width),
+            orientation: lv.orientation,
+            cell_size: lv
+                .cell_size
+                .as_ref()
+                .map(|(w, h)| (ctx.map_property_reference(w), ctx.map_property_reference(h))),
+            requested_row: lv.requested_row.as_ref().map(|r| ctx.map_property_reference(r)),
+            prop_x: sc.mapping.map_property_reference(&geom.x, ctx.state),
             prop_y: sc.mapping.map_property_reference(&geom.y, ctx.state),
+            prop_width: sc.mapping.map_property_reference(&geom.width, ctx.state),
             prop_height: sc.mapping.map_property_reference(&geom.height, ctx.state),
         }
     });
 
+    let exit_transition = repeated.exit_transition.as_ref().map(|exit| ExitTransitionInfo {
+        start: sc.mapping.map_property_reference(&exit.start, ctx.state),
+        is_done: sc.mapping.map_property_reference(&exit.is_done, ctx.state),
+    });
+
+    let flex_factors = repeated.flex_factors.as_ref().map(|factors| FlexFactorsInfo {
+        grow: sc.mapping.map_property_reference(&factors.grow, ctx.state),
+        shrink: sc.mapping.map_property_reference(&factors.shrink, ctx.state),
+    });
+
     let parent_index = parent_component_container.map(|p| (*p.borrow().item_index.get().unwrap()));
     let container_item_index =
         parent_index.and_then(|pii| sub_component.items.position(|i| i.index_in_tree == pii));
@@ -679,6 +698,8 @@ fn lower_repeated_component(
         data_prop: (!repeated.is_conditional_element).then_some(0usize.into()),
         index_in_tree: *e.item_index.get().unwrap(),
         listview,
+        exit_transition,
+        flex_factors,
         container_item_index,
     }
 }
@@ -715,7 +736,11 @@ fn lower_popup_component(
                 .clone(),
         ),
     };
-    PopupWindow { item_tree, position: position.into() }
+    PopupWindow {
+        item_tree,
+        position: position.into(),
+        is_secondary_window: popup.secondary_window.is_some(),
+    }
 }
 
 fn lower_timer(timer: &object_tree::Timer, ctx: &ExpressionLoweringCtx) -> Timer {
@@ -6,8 +6,7 @@
 //! This pass assume that use_count of all properties is zero
 
 use crate::llr::{
-    Animation, BindingExpression, CompilationUnit, EvaluationContext, Expression, ParentCtx,
-    PropertyReference,
+    BindingExpression, CompilationUnit, EvaluationContext, Expression, ParentCtx, PropertyReference,
 };
 
 pub fn count_property_use(root: &CompilationUnit) {
@@ -53,11 +52,19 @@ pub fn count_property_use(root: &CompilationUnit) {
         for (idx, r) in sc.repeated.iter_enumerated() {
             r.model.borrow().visit_property_references(ctx, &mut visit_property);
             if let Some(lv) = &r.listview {
+                visit_property(&lv.viewport_x, ctx);
                 visit_property(&lv.viewport_y, ctx);
                 visit_property(&lv.viewport_width, ctx);
                 visit_property(&lv.viewport_height, ctx);
                 visit_property(&lv.listview_width, ctx);
                 visit_property(&lv.listview_height, ctx);
+                if let Some((w, h)) = &lv.cell_size {
+                    visit_property(w, ctx);
+                    visit_property(h, ctx);
+                }
+                if let Some(requested_row) = &lv.requested_row {
+                    visit_property(requested_row, ctx);
+                }
 
                 let rep_ctx = EvaluationContext::new_sub_component(
                     root,
@@ -65,9 +72,21 @@ pub fn count_property_use(root: &CompilationUnit) {
                     (),
                     Some(ParentCtx::new(ctx, Some(idx))),
                 );
+                visit_property(&lv.prop_x, &rep_ctx);
                 visit_property(&lv.prop_y, &rep_ctx);
+                visit_property(&lv.prop_width, &rep_ctx);
                 visit_property(&lv.prop_height, &rep_ctx);
             }
+            if let Some(factors) = &r.flex_factors {
+                let rep_ctx = EvaluationContext::new_sub_component(
+                    root,
+                    r.sub_tree.root,
+                    (),
+                    Some(ParentCtx::new(ctx, Some(idx))),
+                );
+                visit_property(&factors.grow, &rep_ctx);
+                visit_property(&factors.shrink, &rep_ctx);
+            }
             for idx in r.data_prop.iter().chain(r.index_prop.iter()) {
                 // prevent optimizing model properties
                 let p = &root.sub_components[r.sub_tree.root].properties[*idx];
@@ -165,11 +184,8 @@ fn visit_property(pr: &PropertyReference, ctx: &EvaluationContext) {
 
 fn visit_binding_expression(binding: &BindingExpression, ctx: &EvaluationContext) {
     binding.expression.borrow().visit_property_references(ctx, &mut visit_property);
-    match &binding.animation {
-        Some(Animation::Static(e) | Animation::Transition(e)) => {
-            e.visit_property_references(ctx, &mut visit_property)
-        }
-        None => (),
+    if let Some(animation) = &binding.animation {
+        animation.visit_expressions(|e| e.visit_property_references(ctx, &mut visit_property));
     }
 }
 
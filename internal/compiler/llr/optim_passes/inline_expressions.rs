@@ -110,8 +110,12 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::SetFocusItem | BuiltinFunction::ClearFocusItem => isize::MAX,
         BuiltinFunction::ShowPopupWindow
         | BuiltinFunction::ClosePopupWindow
+        | BuiltinFunction::ShowSecondaryWindow
+        | BuiltinFunction::CloseSecondaryWindow
         | BuiltinFunction::ShowPopupMenu => isize::MAX,
         BuiltinFunction::SetSelectionOffsets => isize::MAX,
+        BuiltinFunction::FlickableScrollTo | BuiltinFunction::FlickableEnsureVisible => isize::MAX,
+        BuiltinFunction::ListViewScrollToIndex => isize::MAX,
         BuiltinFunction::ItemFontMetrics => PROPERTY_ACCESS_COST,
         BuiltinFunction::StringToFloat => 50,
         BuiltinFunction::StringIsFloat => 50,
@@ -673,6 +673,7 @@ fn for_each_entry<R>(
         r.or_else(|| {
             f(&SmolStr::new_static("cubic-bezier"), BuiltinMacroFunction::CubicBezier.into())
         })
+        .or_else(|| f(&SmolStr::new_static("spring"), BuiltinMacroFunction::Spring.into()))
     }
 }
 
@@ -774,6 +775,7 @@ fn for_each_entry<R>(
         .or_else(|| f("date-now", b(BuiltinFunction::DateNow)))
         .or_else(|| f("valid-date", b(BuiltinFunction::ValidDate)))
         .or_else(|| f("parse-date", b(BuiltinFunction::ParseDate)))
+        .or_else(|| f("listview-scroll-to-index", b(BuiltinFunction::ListViewScrollToIndex)))
     }
 }
 
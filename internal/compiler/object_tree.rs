@@ -308,6 +308,16 @@ pub struct PopupWindow {
     pub y: NamedReference,
     pub close_policy: EnumerationValue,
     pub parent_element: ElementRc,
+    /// Set when this is a `SecondaryWindow`, which is shown in its own top-level window instead of as a popup
+    pub secondary_window: Option<SecondaryWindowOptions>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SecondaryWindowOptions {
+    /// The window is kept on top of the window it was opened from
+    pub transient: bool,
+    /// The window blocks the input to the window it was opened from while it is shown
+    pub modal: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -398,6 +408,9 @@ pub struct Component {
     /// This component actually inherits PopupWindow (although that has been changed to a Window by the lower_popups pass)
     pub inherits_popup_window: Cell<bool>,
 
+    /// This component actually inherits SecondaryWindow (although that has been changed to a Window by the lower_popups pass)
+    pub inherits_secondary_window: Cell<bool>,
+
     /// The names under which this component should be accessible
     /// if it is a global singleton and exported.
     pub exported_global_names: RefCell<Vec<ExportedName>>,
@@ -563,6 +576,8 @@ pub struct TransitionPropertyAnimation {
     pub direction: TransitionDirection,
     /// The content of the `animation` object
     pub animation: ElementRc,
+    /// The keyframes of the animation, sorted by offset. Empty if it has no keyframes
+    pub keyframes: Vec<Keyframe>,
 }
 
 impl TransitionPropertyAnimation {
@@ -609,10 +624,30 @@ pub fn condition(&self, state: Expression) -> Expression {
     }
 }
 
+/// An intermediate step of an animation with keyframes
+#[derive(Debug, Clone)]
+pub struct Keyframe {
+    /// The position of the keyframe within the animation, between 0 and 1
+    pub offset: f32,
+    /// The value of the property at this keyframe
+    pub value: Expression,
+    /// The easing curve used to reach this keyframe. If None, the easing of the animation is used
+    pub easing: Option<Expression>,
+}
+
 #[derive(Debug)]
 pub enum PropertyAnimation {
     Static(ElementRc),
-    Transition { state_ref: Expression, animations: Vec<TransitionPropertyAnimation> },
+    /// Like Static, but the property also goes through the intermediate values of the keyframes,
+    /// which are sorted by offset
+    Keyframes {
+        animation: ElementRc,
+        keyframes: Vec<Keyframe>,
+    },
+    Transition {
+        state_ref: Expression,
+        animations: Vec<TransitionPropertyAnimation>,
+    },
 }
 
 impl Clone for PropertyAnimation {
@@ -635,6 +670,10 @@ fn deep_clone(e: &ElementRc) -> ElementRc {
         }
         match self {
             PropertyAnimation::Static(e) => PropertyAnimation::Static(deep_clone(e)),
+            PropertyAnimation::Keyframes { animation, keyframes } => PropertyAnimation::Keyframes {
+                animation: deep_clone(animation),
+                keyframes: keyframes.clone(),
+            },
             PropertyAnimation::Transition { state_ref, animations } => {
                 PropertyAnimation::Transition {
                     state_ref: state_ref.clone(),
@@ -644,6 +683,7 @@ fn deep_clone(e: &ElementRc) -> ElementRc {
                             state_id: t.state_id,
                             direction: t.direction,
                             animation: deep_clone(&t.animation),
+                            keyframes: t.keyframes.clone(),
                         })
                         .collect(),
                 }
@@ -936,6 +976,7 @@ pub fn is_used(&self) -> bool {
 
 #[derive(Debug, Clone)]
 pub struct ListViewInfo {
+    pub viewport_x: NamedReference,
     pub viewport_y: NamedReference,
     pub viewport_height: NamedReference,
     pub viewport_width: NamedReference,
@@ -943,6 +984,40 @@ pub struct ListViewInfo {
     pub listview_height: NamedReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: NamedReference,
+    /// The axis along which the items are laid out and only the visible ones are instantiated
+    pub orientation: crate::layout::Orientation,
+    /// For a GridView, the width and height of the cells. The items fill rows of cells
+    /// and only the visible rows are instantiated.
+    pub cell_size: Option<(NamedReference, NamedReference)>,
+    /// The row that should be scrolled into view, or -1. Created by the `lower_scroll_to_index`
+    /// pass if `scroll-to-index()` is called on the ListView, and reset to -1 by the runtime.
+    pub requested_row: Option<NamedReference>,
+}
+
+impl ListViewInfo {
+    /// The name of the elements whose `for` children are virtualized
+    pub fn is_listview_type(type_name: &str) -> bool {
+        matches!(type_name, "ListView" | "HorizontalListView" | "GridView")
+    }
+
+    fn new(parent: &ElementRc, type_name: &str) -> Self {
+        let nr = |name: &'static str| NamedReference::new(parent, SmolStr::new_static(name));
+        ListViewInfo {
+            viewport_x: nr("viewport-x"),
+            viewport_y: nr("viewport-y"),
+            viewport_height: nr("viewport-height"),
+            viewport_width: nr("viewport-width"),
+            listview_height: nr("visible-height"),
+            listview_width: nr("visible-width"),
+            orientation: if type_name == "HorizontalListView" {
+                crate::layout::Orientation::Horizontal
+            } else {
+                crate::layout::Orientation::Vertical
+            },
+            cell_size: (type_name == "GridView").then(|| (nr("cell-width"), nr("cell-height"))),
+            requested_row: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -957,6 +1032,20 @@ pub struct RepeatedElementInfo {
     pub is_conditional_element: bool,
     /// When the for is the delegate of a ListView
     pub is_listview: Option<ListViewInfo>,
+    /// Set by the lower_enter_exit pass when the element has an `exit { ... }` block
+    pub exit_transition: Option<ExitTransition>,
+    /// Set by the lower_layout pass when the element is in a FlexLayout: the `flex-grow` and
+    /// `flex-shrink` properties of the root of the repeated component
+    pub flex_factors: Option<crate::layout::FlexFactors>,
+}
+
+#[derive(Debug, Clone)]
+/// The functions generated for the `exit { ... }` block of a repeated or conditional element
+pub struct ExitTransition {
+    /// Function called by the runtime when the instance is removed, to start the exit transition
+    pub start: NamedReference,
+    /// Function returning true once the exit transition is over and the instance can be destroyed
+    pub is_done: NamedReference,
 }
 
 pub type ElementRc = Rc<RefCell<Element>>;
@@ -1393,6 +1482,7 @@ pub fn from_node(
                     &star,
                 )
             };
+            let keyframes = keyframes_from_node(&anim, diag);
             for prop_name_token in anim.QualifiedName() {
                 match QualifiedTypeName::from_node(prop_name_token.clone()).members.as_slice() {
                     [unresolved_prop_name] => {
@@ -1426,6 +1516,14 @@ pub fn from_node(
                                 );
                             }
 
+                            let animation = match &keyframes {
+                                Some(keyframes) => PropertyAnimation::Keyframes {
+                                    animation: anim_element,
+                                    keyframes: keyframes.clone(),
+                                },
+                                None => PropertyAnimation::Static(anim_element),
+                            };
+
                             let expr_binding = r
                                 .bindings
                                 .entry(lookup_result.resolved_name.into())
@@ -1435,12 +1533,7 @@ pub fn from_node(
                                     r.span = Some(prop_name_token.to_source_location());
                                     r.into()
                                 });
-                            if expr_binding
-                                .get_mut()
-                                .animation
-                                .replace(PropertyAnimation::Static(anim_element))
-                                .is_some()
-                            {
+                            if expr_binding.get_mut().animation.replace(animation).is_some() {
                                 diag.push_error("Duplicated animation".into(), &prop_name_token)
                             }
                         }
@@ -1577,21 +1670,7 @@ pub fn from_node(
             let s = State {
                 id: parser::identifier_text(&state.DeclaredIdentifier()).unwrap_or_default(),
                 condition: state.Expression().map(|e| Expression::Uncompiled(e.into())),
-                property_changes: state
-                    .StatePropertyChange()
-                    .filter_map(|s| {
-                        lookup_property_from_qualified_name_for_state(s.QualifiedName(), &r, diag)
-                            .map(|(ne, ty)| {
-                                if !ty.is_property_type() && !matches!(ty, Type::Invalid) {
-                                    diag.push_error(
-                                        format!("'{}' is not a property", **s.QualifiedName()),
-                                        &s,
-                                    );
-                                }
-                                (ne, Expression::Uncompiled(s.BindingExpression().into()), s)
-                            })
-                    })
-                    .collect(),
+                property_changes: state_property_changes(state.StatePropertyChange(), &r, diag),
             };
             for trs in state.Transition() {
                 let mut t = Transition::from_node(trs, &r, tr, diag);
@@ -1601,6 +1680,41 @@ pub fn from_node(
             r.borrow_mut().states.push(s);
         }
 
+        let is_repeated = node.parent().and_then(|n| n.parent()).is_some_and(|n| {
+            matches!(n.kind(), SyntaxKind::RepeatedElement | SyntaxKind::ConditionalElement)
+        });
+        for trs in node.Transition() {
+            let is_enter = trs
+                .first_child_or_token()
+                .and_then(|t| t.as_token().map(|tok| tok.text() == "enter"))
+                .unwrap_or_default();
+            let (id, keyword) =
+                if is_enter { (ENTER_STATE_ID, "enter") } else { (EXIT_STATE_ID, "exit") };
+            if !is_repeated {
+                diag.push_error(
+                    "'enter' and 'exit' can only be used in an element created with 'for' or 'if'"
+                        .into(),
+                    &trs,
+                );
+                continue;
+            }
+            if r.borrow().states.iter().any(|s| s.id == id) {
+                diag.push_error(format!("Duplicated '{keyword}' block"), &trs);
+                continue;
+            }
+            // The condition is set by the lower_enter_exit pass
+            let s = State {
+                id: id.into(),
+                condition: None,
+                property_changes: state_property_changes(trs.StatePropertyChange(), &r, diag),
+            };
+            let mut t = Transition::from_node(trs, &r, tr, diag);
+            t.state_id.clone_from(&s.id);
+            r.borrow_mut().transitions.push(t);
+            // Before the other states so that they take precedence
+            r.borrow_mut().states.insert(0, s);
+        }
+
         for ts in node.Transitions() {
             if !is_legacy_syntax {
                 diag.push_error("'transitions' block are no longer supported. Use 'in {...}' and 'out {...}' directly in the state definition".into(), &ts);
@@ -1611,7 +1725,7 @@ pub fn from_node(
             }
         }
 
-        if r.borrow().base_type.to_smolstr() == "ListView" {
+        if ListViewInfo::is_listview_type(&r.borrow().base_type.to_smolstr()) {
             let mut seen_for = false;
             for se in node.children() {
                 if se.kind() == SyntaxKind::RepeatedElement && !seen_for {
@@ -1666,20 +1780,9 @@ fn from_repeated_node(
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> ElementRc {
-        let is_listview = if parent.borrow().base_type.to_string() == "ListView" {
-            Some(ListViewInfo {
-                viewport_y: NamedReference::new(parent, SmolStr::new_static("viewport-y")),
-                viewport_height: NamedReference::new(
-                    parent,
-                    SmolStr::new_static("viewport-height"),
-                ),
-                viewport_width: NamedReference::new(parent, SmolStr::new_static("viewport-width")),
-                listview_height: NamedReference::new(parent, SmolStr::new_static("visible-height")),
-                listview_width: NamedReference::new(parent, SmolStr::new_static("visible-width")),
-            })
-        } else {
-            None
-        };
+        let parent_type_name = parent.borrow().base_type.to_smolstr();
+        let is_listview = ListViewInfo::is_listview_type(&parent_type_name)
+            .then(|| ListViewInfo::new(parent, &parent_type_name));
         let rei = RepeatedElementInfo {
             model: Expression::Uncompiled(node.Expression().into()),
             model_data_id: node
@@ -1692,7 +1795,14 @@ fn from_repeated_node(
                 .unwrap_or_default(),
             is_conditional_element: false,
             is_listview,
+            exit_transition: None,
+            flex_factors: None,
         };
+        if rei.is_listview.is_some() {
+            for trs in node.SubElement().Element().Transition() {
+                diag.push_error("'enter' and 'exit' are not supported in a ListView".into(), &trs);
+            }
+        }
         let e = Element::from_sub_element_node(
             node.SubElement(),
             parent.borrow().base_type.clone(),
@@ -1719,6 +1829,8 @@ fn from_conditional_node(
             index_id: SmolStr::default(),
             is_conditional_element: true,
             is_listview: None,
+            exit_transition: None,
+            flex_factors: None,
         };
         let e = Element::from_sub_element_node(
             node.SubElement(),
@@ -2050,6 +2162,64 @@ fn animation_element_from_node(
     }
 }
 
+/// Returns the keyframes of the animation sorted by offset, or None if it has no keyframes.
+/// The expressions are not resolved yet.
+fn keyframes_from_node(
+    anim: &syntax_nodes::PropertyAnimation,
+    diag: &mut BuildDiagnostics,
+) -> Option<Vec<Keyframe>> {
+    let mut keyframes = Vec::new();
+    for kf in anim.Keyframe() {
+        let Some(offset_token) = kf.child_token(SyntaxKind::NumberLiteral) else { continue };
+        let offset = match crate::literals::parse_number_literal(offset_token.text().into()) {
+            Ok(Expression::NumberLiteral(val, Unit::Percent)) if (0. ..=100.).contains(&val) => {
+                (val / 100.) as f32
+            }
+            _ => {
+                diag.push_error(
+                    "The position of a keyframe must be a percentage between 0% and 100%".into(),
+                    &offset_token,
+                );
+                continue;
+            }
+        };
+        let mut value = None;
+        let mut easing = None;
+        for b in kf.Binding() {
+            let Some(name_token) = b.child_token(SyntaxKind::Identifier) else { continue };
+            let expr = Expression::Uncompiled(b.BindingExpression().into());
+            let slot = match crate::parser::normalize_identifier(name_token.text()).as_str() {
+                "value" => &mut value,
+                "easing" => &mut easing,
+                name => {
+                    diag.push_error(
+                        format!("Unknown property '{name}' in keyframe. Only 'value' and 'easing' are allowed"),
+                        &name_token,
+                    );
+                    continue;
+                }
+            };
+            if slot.replace(expr).is_some() {
+                diag.push_error("Duplicated property binding".into(), &name_token);
+            }
+        }
+        let Some(value) = value else {
+            diag.push_error("A keyframe must have a 'value'".into(), &kf);
+            continue;
+        };
+        if keyframes.iter().any(|k: &Keyframe| k.offset == offset) {
+            diag.push_error("Duplicated keyframe".into(), &offset_token);
+            continue;
+        }
+        keyframes.push(Keyframe { offset, value, easing });
+    }
+    if keyframes.is_empty() {
+        return None;
+    }
+    keyframes.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    Some(keyframes)
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct QualifiedTypeName {
     pub members: Vec<SmolStr>,
@@ -2073,6 +2243,26 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+/// Return the property changes of a state (or of an `enter` or `exit` block)
+fn state_property_changes(
+    changes: impl Iterator<Item = syntax_nodes::StatePropertyChange>,
+    r: &ElementRc,
+    diag: &mut BuildDiagnostics,
+) -> Vec<(NamedReference, Expression, syntax_nodes::StatePropertyChange)> {
+    changes
+        .filter_map(|s| {
+            lookup_property_from_qualified_name_for_state(s.QualifiedName(), r, diag).map(
+                |(ne, ty)| {
+                    if !ty.is_property_type() && !matches!(ty, Type::Invalid) {
+                        diag.push_error(format!("'{}' is not a property", **s.QualifiedName()), &s);
+                    }
+                    (ne, Expression::Uncompiled(s.BindingExpression().into()), s)
+                },
+            )
+        })
+        .collect()
+}
+
 /// Return a NamedReference for a qualified name used in a state (or transition),
 /// if the reference is invalid, there will be a diagnostic
 fn lookup_property_from_qualified_name_for_state(
@@ -2284,10 +2474,29 @@ fn visit_element_expressions_simple(
 
             match &mut expr.borrow_mut().animation {
                 Some(PropertyAnimation::Static(e)) => visit_element_expressions_simple(e, vis),
+                Some(PropertyAnimation::Keyframes { animation, keyframes }) => {
+                    visit_element_expressions_simple(animation, vis);
+                    for kf in keyframes {
+                        vis(&mut kf.value, None, &|| {
+                            elem.borrow().lookup_property(name).property_type
+                        });
+                        if let Some(easing) = &mut kf.easing {
+                            vis(easing, None, &|| Type::Easing);
+                        }
+                    }
+                }
                 Some(PropertyAnimation::Transition { animations, state_ref }) => {
                     vis(state_ref, None, &|| Type::Int32);
                     for a in animations {
-                        visit_element_expressions_simple(&a.animation, vis)
+                        visit_element_expressions_simple(&a.animation, vis);
+                        for kf in &mut a.keyframes {
+                            vis(&mut kf.value, None, &|| {
+                                elem.borrow().lookup_property(name).property_type
+                            });
+                            if let Some(easing) = &mut kf.easing {
+                                vis(easing, None, &|| Type::Easing);
+                            }
+                        }
                     }
                 }
                 None => (),
@@ -2327,8 +2536,16 @@ fn visit_element_expressions_simple(
 
     let mut transitions = std::mem::take(&mut elem.borrow_mut().transitions);
     for t in &mut transitions {
-        for (_, _, a) in &mut t.property_animations {
+        for (r, _, a, keyframes) in &mut t.property_animations {
             visit_element_expressions_simple(a, &mut vis);
+            for kf in keyframes {
+                vis(&mut kf.value, None, &|| {
+                    r.element().borrow().lookup_property(r.name()).property_type
+                });
+                if let Some(easing) = &mut kf.easing {
+                    vis(easing, None, &|| Type::Easing);
+                }
+            }
         }
     }
     elem.borrow_mut().transitions = transitions;
@@ -2388,7 +2605,7 @@ pub fn visit_all_named_references_in_element(
     elem.borrow_mut().states = states;
     let mut transitions = std::mem::take(&mut elem.borrow_mut().transitions);
     for t in &mut transitions {
-        for (r, _, _) in &mut t.property_animations {
+        for (r, _, _, _) in &mut t.property_animations {
             vis(r)
         }
     }
@@ -2396,11 +2613,26 @@ pub fn visit_all_named_references_in_element(
     let mut repeated = std::mem::take(&mut elem.borrow_mut().repeated);
     if let Some(r) = &mut repeated {
         if let Some(lv) = &mut r.is_listview {
+            vis(&mut lv.viewport_x);
             vis(&mut lv.viewport_y);
             vis(&mut lv.viewport_height);
             vis(&mut lv.viewport_width);
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
+            if let Some((w, h)) = &mut lv.cell_size {
+                vis(w);
+                vis(h);
+            }
+            if let Some(requested_row) = &mut lv.requested_row {
+                vis(requested_row);
+            }
+        }
+        if let Some(exit) = &mut r.exit_transition {
+            vis(&mut exit.start);
+            vis(&mut exit.is_done);
+        }
+        if let Some(factors) = &mut r.flex_factors {
+            factors.visit_named_references(&mut vis);
         }
     }
     elem.borrow_mut().repeated = repeated;
@@ -2503,11 +2735,18 @@ pub struct State {
     pub property_changes: Vec<(NamedReference, Expression, syntax_nodes::StatePropertyChange)>,
 }
 
+/// The id of the state created for the `enter { ... }` block of an element.
+/// It is not a valid identifier so it can't clash with the user's states.
+pub const ENTER_STATE_ID: &str = "@enter";
+/// The id of the state created for the `exit { ... }` block of an element.
+pub const EXIT_STATE_ID: &str = "@exit";
+
 #[derive(Debug, Clone)]
 pub struct Transition {
     pub direction: TransitionDirection,
     pub state_id: SmolStr,
-    pub property_animations: Vec<(NamedReference, SourceLocation, ElementRc)>,
+    /// The animated properties, with their `animate` element and keyframes (sorted by offset)
+    pub property_animations: Vec<(NamedReference, SourceLocation, ElementRc, Vec<Keyframe>)>,
     pub node: syntax_nodes::Transition,
 }
 
@@ -2526,12 +2765,38 @@ fn from_node(
             .and_then(|t| t.as_token().map(|tok| tok.text().to_string()))
             .unwrap_or_default();
 
+        let mut property_animations = Vec::new();
+        for pa in trs.PropertyAnimation() {
+            let keyframes = keyframes_from_node(&pa, diag).unwrap_or_default();
+            for qn in pa.QualifiedName() {
+                let Some((ne, prop_type)) =
+                    lookup_property_from_qualified_name_for_state(qn.clone(), r, diag)
+                else {
+                    continue;
+                };
+                if let Some(anim_element) =
+                    animation_element_from_node(&pa, &qn, prop_type, diag, tr)
+                {
+                    property_animations.push((
+                        ne,
+                        qn.to_source_location(),
+                        anim_element,
+                        keyframes.clone(),
+                    ));
+                }
+            }
+        }
+
         Transition {
             direction: match direction_text.as_str() {
                 "in" => TransitionDirection::In,
                 "out" => TransitionDirection::Out,
                 "in-out" => TransitionDirection::InOut,
                 "in_out" => TransitionDirection::InOut,
+                // The enter animation is played when leaving the enter state,
+                // and the exit animation when going into the exit state
+                "enter" => TransitionDirection::Out,
+                "exit" => TransitionDirection::In,
                 _ => {
                     unreachable!("Unknown transition direction: '{}'", direction_text);
                 }
@@ -2540,18 +2805,7 @@ fn from_node(
                 .DeclaredIdentifier()
                 .and_then(|x| parser::identifier_text(&x))
                 .unwrap_or_default(),
-            property_animations: trs
-                .PropertyAnimation()
-                .flat_map(|pa| pa.QualifiedName().map(move |qn| (pa.clone(), qn)))
-                .filter_map(|(pa, qn)| {
-                    lookup_property_from_qualified_name_for_state(qn.clone(), r, diag).and_then(
-                        |(ne, prop_type)| {
-                            animation_element_from_node(&pa, &qn, prop_type, diag, tr)
-                                .map(|anim_element| (ne, qn.to_source_location(), anim_element))
-                        },
-                    )
-                })
-                .collect(),
+            property_animations,
             node: trs.clone(),
         }
     }
@@ -338,7 +338,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *ConditionalElement, *Function, *SubElement,
                      *RepeatedElement, *PropertyAnimation, *PropertyChangedCallback,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *TwoWayBinding, *States, *Transitions, *Transition, ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -353,7 +353,9 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *Keyframe ],
+        /// `25% { value: ...; easing: ...; }` within a PropertyAnimation. The percentage is a NumberLiteral
+        Keyframe -> [ *Binding ],
         /// `changed xxx => {...}`  where `xxx` is the DeclaredIdentifier
         PropertyChangedCallback-> [ DeclaredIdentifier, CodeBlock ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
@@ -414,8 +416,9 @@ fn source_file(&self) -> Option<&SourceFile> {
         StatePropertyChange -> [ QualifiedName, BindingExpression ],
         /// `transitions: [...]`
         Transitions -> [*Transition],
-        /// There is an identifier "in", "out", "in-out", the DeclaredIdentifier is the state name
-        Transition -> [?DeclaredIdentifier, *PropertyAnimation],
+        /// There is an identifier "in", "out", "in-out", the DeclaredIdentifier is the state name.
+        /// Or "enter" or "exit" directly in an Element, with the property changes of that transition
+        Transition -> [?DeclaredIdentifier, *StatePropertyChange, *PropertyAnimation],
         /// Export a set of declared components by name
         ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, ?ExportModule, *EnumDeclaration ],
         /// Declare the first identifier to be exported, either under its name or instead
@@ -51,6 +51,7 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// double_binding <=> element.property;
 /// public pure function foo() {}
 /// changed foo => {}
+/// enter { opacity: 0; }
 /// ```
 pub fn parse_element_content(p: &mut impl Parser) {
     let mut had_parse_error = false;
@@ -60,6 +61,9 @@ pub fn parse_element_content(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::LBrace if matches!(p.peek().as_str(), "enter" | "exit") => {
+                    had_parse_error |= !parse_enter_exit_transition(&mut *p)
+                }
                 SyntaxKind::ColonEqual | SyntaxKind::LBrace => {
                     had_parse_error |= !parse_sub_element(&mut *p)
                 }
@@ -433,6 +437,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1s; 50% { value: 5px; easing: ease-in; } }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -463,9 +468,41 @@ fn parse_property_animation(p: &mut impl Parser) {
                     p.error("Only bindings are allowed in animations");
                 }
             },
+            SyntaxKind::NumberLiteral if p.nth(1).kind() == SyntaxKind::LBrace => {
+                parse_keyframe(&mut *p)
+            }
+            _ => {
+                p.consume();
+                p.error("Only bindings and keyframes are allowed in animations");
+            }
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,Keyframe
+/// 50% { value: 5px; }
+/// 25% { value: red; easing: ease-in-out; }
+/// 0% { }
+/// ```
+fn parse_keyframe(p: &mut impl Parser) {
+    debug_assert_eq!(p.nth(0).kind(), SyntaxKind::NumberLiteral);
+    let mut p = p.start_node(SyntaxKind::Keyframe);
+    p.consume(); // the percentage
+    p.expect(SyntaxKind::LBrace);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            SyntaxKind::Identifier if p.nth(1).kind() == SyntaxKind::Colon => {
+                parse_property_binding(&mut *p)
+            }
             _ => {
                 p.consume();
-                p.error("Only bindings are allowed in animations");
+                p.error("Only bindings are allowed in keyframes");
             }
         }
     }
@@ -598,6 +635,48 @@ fn parse_transition(p: &mut impl Parser) -> bool {
     parse_transition_inner(&mut *p)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Transition
+/// enter { }
+/// enter { opacity: 0; animate opacity { duration: 250ms; } }
+/// exit { animate x, foo.y { duration: 88ms; } x: -100px; foo.y: 1px + 2px; }
+/// ```
+/// Parses the `enter { ... }` or `exit { ... }` block of a repeated or conditional element.
+/// It contains property changes, like a state, and the animations of these properties.
+fn parse_enter_exit_transition(p: &mut impl Parser) -> bool {
+    debug_assert!(matches!(p.peek().as_str(), "enter" | "exit"));
+    let mut p = p.start_node(SyntaxKind::Transition);
+    p.consume(); // "enter" or "exit"
+    if !p.expect(SyntaxKind::LBrace) {
+        return false;
+    }
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return true;
+            }
+            SyntaxKind::Eof => return false,
+            SyntaxKind::Identifier
+                if p.peek().as_str() == "animate" && p.nth(1).kind() != SyntaxKind::Colon =>
+            {
+                parse_property_animation(&mut *p);
+            }
+            _ => {
+                let checkpoint = p.checkpoint();
+                if !parse_qualified_name(&mut *p)
+                    || !p.expect(SyntaxKind::Colon)
+                    || !parse_binding_expression(&mut *p)
+                {
+                    p.test(SyntaxKind::RBrace);
+                    return false;
+                }
+                let _ = p.start_node_at(checkpoint, SyntaxKind::StatePropertyChange);
+            }
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test
 /// }
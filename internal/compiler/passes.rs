@@ -7,6 +7,7 @@
 mod check_expressions;
 mod check_public_api;
 mod check_rotation;
+mod check_shortcuts;
 mod clip;
 mod collect_custom_fonts;
 mod collect_globals;
@@ -30,11 +31,13 @@
 mod lower_absolute_coordinates;
 mod lower_accessibility;
 mod lower_component_container;
+mod lower_enter_exit;
 mod lower_layout;
 mod lower_menus;
 mod lower_platform;
 mod lower_popups;
 mod lower_property_to_element;
+mod lower_scroll_to_index;
 mod lower_shadows;
 mod lower_states;
 mod lower_tabwidget;
@@ -121,6 +124,7 @@ pub async fn run_passes(
             &palette,
             diag,
         );
+        lower_enter_exit::lower_enter_exit(component);
         lower_states::lower_states(component, &doc.local_registry, diag);
         lower_text_input_interface::lower_text_input_interface(component);
         lower_platform::lower_platform(component, type_loader);
@@ -144,6 +148,7 @@ pub async fn run_passes(
     doc.visit_all_used_components(|component| {
         border_radius::handle_border_radius(component, diag);
         flickable::handle_flickable(component, &global_type_registry.borrow());
+        lower_scroll_to_index::lower_scroll_to_index(component);
         lower_layout::lower_layouts(component, type_loader, &style_metrics, diag);
         default_geometry::default_geometry(component, diag);
         lower_absolute_coordinates::lower_absolute_coordinates(component);
@@ -334,5 +339,6 @@ pub fn run_import_passes(
     focus_handling::replace_forward_focus_bindings_with_focus_functions(doc, diag);
     check_expressions::check_expressions(doc, diag);
     check_rotation::check_rotation(doc, diag);
+    check_shortcuts::check_shortcuts(doc, diag);
     unique_id::check_unique_id(doc, diag);
 }
@@ -211,11 +211,29 @@ fn analyze_element(
             process_property(prop, r, context, reverse_aliases, diag);
         });
         if let Some(lv) = &repeated.is_listview {
+            process_property(&lv.viewport_x.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.viewport_y.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.viewport_height.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.viewport_width.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.listview_height.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.listview_width.clone().into(), P, context, reverse_aliases, diag);
+            if let Some((w, h)) = &lv.cell_size {
+                process_property(&w.clone().into(), P, context, reverse_aliases, diag);
+                process_property(&h.clone().into(), P, context, reverse_aliases, diag);
+            }
+            if let Some(requested_row) = &lv.requested_row {
+                process_property(&requested_row.clone().into(), P, context, reverse_aliases, diag);
+                // The runtime resets the requested row once it is handled
+                requested_row.mark_as_set();
+            }
+        }
+        if let Some(exit) = &repeated.exit_transition {
+            process_property(&exit.start.clone().into(), P, context, reverse_aliases, diag);
+            process_property(&exit.is_done.clone().into(), P, context, reverse_aliases, diag);
+        }
+        if let Some(factors) = &repeated.flex_factors {
+            process_property(&factors.grow.clone().into(), P, context, reverse_aliases, diag);
+            process_property(&factors.shrink.clone().into(), P, context, reverse_aliases, diag);
         }
     }
     if let Some((h, v)) = &elem.borrow().layout_info_prop {
@@ -354,9 +372,24 @@ fn analyze_binding(
 
     match &binding.borrow().animation {
         Some(PropertyAnimation::Static(e)) => analyze_element(e, context, reverse_aliases, diag),
+        Some(PropertyAnimation::Keyframes { animation, keyframes }) => {
+            for kf in keyframes {
+                recurse_expression(&kf.value, &mut process_prop);
+                if let Some(easing) = &kf.easing {
+                    recurse_expression(easing, &mut process_prop);
+                }
+            }
+            analyze_element(animation, context, reverse_aliases, diag);
+        }
         Some(PropertyAnimation::Transition { animations, state_ref }) => {
             recurse_expression(state_ref, &mut process_prop);
             for a in animations {
+                for kf in &a.keyframes {
+                    recurse_expression(&kf.value, &mut process_prop);
+                    if let Some(easing) = &kf.easing {
+                        recurse_expression(easing, &mut process_prop);
+                    }
+                }
                 analyze_element(&a.animation, context, reverse_aliases, diag);
             }
         }
@@ -461,6 +494,25 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath, Rea
                 crate::layout::Layout::BoxLayout(l) => {
                     visit_layout_items_dependencies(l.elems.iter(), *o, vis)
                 }
+                crate::layout::Layout::FlexLayout(l) => {
+                    if *o != l.orientation {
+                        // The lines depend on the size of the items in the main axis
+                        if let Some(nr) = l.geometry.rect.size_reference(l.orientation) {
+                            vis(&nr.clone().into(), P);
+                        }
+                        visit_layout_items_dependencies(
+                            l.elems.iter().map(|it| &it.item),
+                            l.orientation,
+                            vis,
+                        );
+                    }
+                    visit_layout_items_dependencies(l.elems.iter().map(|it| &it.item), *o, vis);
+                    for factors in l.elems.iter().filter_map(|it| it.factors.as_ref()) {
+                        vis(&factors.grow.clone().into(), P);
+                        vis(&factors.shrink.clone().into(), P);
+                    }
+                    vis(&l.wrap.clone().into(), P);
+                }
             }
 
             let mut g = l.geometry().clone();
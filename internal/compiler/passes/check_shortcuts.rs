@@ -0,0 +1,104 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Validate the keys of the `Shortcut` elements and of the `MenuItem`s of the `MenuBar`,
+//! and warn when the same shortcut is used twice in an exported component, including
+//! in the sub-components it instantiates.
+
+use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::expression_tree::Expression;
+use crate::langtype::ElementType;
+use crate::object_tree::{recurse_elem, Element, ElementRc};
+use i_slint_common::shortcuts::{parse_shortcut, Shortcut};
+use smol_str::SmolStr;
+use std::collections::HashSet;
+
+pub fn check_shortcuts(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
+    for cmp in &doc.inner_components {
+        recurse_elem(&cmp.root_element, &false, &mut |elem: &ElementRc, in_menubar: &bool| {
+            let e = elem.borrow();
+            let Some(prop) = shortcut_property(&e, *in_menubar) else {
+                return *in_menubar || is_menubar(&e);
+            };
+            if e.builtin_type().is_some_and(|b| b.name == "Shortcut") && !e.children.is_empty() {
+                diag.push_error(
+                    "Shortcut cannot have children elements".into(),
+                    &*e.children[0].borrow(),
+                );
+            }
+            if let Some(keys) = shortcut_keys(&e, prop) {
+                if parse_shortcut(&keys).is_none() {
+                    let location = e.bindings[prop].borrow().to_source_location();
+                    diag.push_error(format!("'{keys}' is not a valid shortcut"), &location);
+                }
+            }
+            *in_menubar
+        });
+    }
+
+    for cmp in doc.exported_roots() {
+        let mut seen = Vec::new();
+        let mut reported = HashSet::new();
+        check_duplicates(&cmp.root_element, false, &mut seen, &mut reported, diag);
+    }
+}
+
+fn is_menubar(e: &Element) -> bool {
+    e.builtin_type().is_some_and(|b| b.name == "MenuBar")
+}
+
+/// The name of the property holding the shortcut of the element, if it has one.
+fn shortcut_property(e: &Element, in_menubar: bool) -> Option<&'static str> {
+    match e.builtin_type()?.name.as_str() {
+        "Shortcut" => Some("keys"),
+        "MenuItem" if in_menubar => Some("shortcut"),
+        _ => None,
+    }
+}
+
+/// The non-empty string literal bound to the shortcut property.
+fn shortcut_keys(e: &Element, prop: &str) -> Option<SmolStr> {
+    let binding = e.bindings.get(prop)?.borrow();
+    match binding.expression.ignore_debug_hooks() {
+        Expression::StringLiteral(keys) if !keys.is_empty() => Some(keys.clone()),
+        _ => None,
+    }
+}
+
+/// Warns about the shortcuts used twice in the element tree, looking into the sub-components
+/// that are instantiated. Each binding is only reported once, even if its component is
+/// instantiated several times.
+fn check_duplicates(
+    elem: &ElementRc,
+    in_menubar: bool,
+    seen: &mut Vec<Shortcut>,
+    reported: &mut HashSet<*const Element>,
+    diag: &mut BuildDiagnostics,
+) {
+    let e = elem.borrow();
+    // A shortcut in a `for` may legitimately be instantiated several times
+    if e.repeated.is_some() {
+        return;
+    }
+    if let Some(prop) = shortcut_property(&e, in_menubar) {
+        if let Some(keys) = shortcut_keys(&e, prop) {
+            if let Some(shortcut) = parse_shortcut(&keys) {
+                if !seen.contains(&shortcut) {
+                    seen.push(shortcut);
+                } else if reported.insert(&*e as *const Element) {
+                    diag.push_warning(
+                        format!("The shortcut '{keys}' is already used in this component"),
+                        &e.bindings[prop].borrow().to_source_location(),
+                    );
+                }
+            }
+        }
+    }
+    let in_menubar = in_menubar || is_menubar(&e);
+    if let ElementType::Component(base) = &e.base_type {
+        check_duplicates(&base.root_element, in_menubar, seen, reported, diag);
+    }
+    for child in &e.children {
+        check_duplicates(child, in_menubar, seen, reported, diag);
+    }
+}
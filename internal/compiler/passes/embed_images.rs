@@ -148,14 +148,35 @@ fn embed_image(
                 let mut kind = EmbeddedResourcesKind::RawData;
                 #[cfg(feature = "software-renderer")]
                 if embed_files == EmbedResourcesKind::EmbedTextures {
-                    match load_image(_file, _scale_factor) {
-                        Ok((img, source_format, original_size)) => {
-                            kind = EmbeddedResourcesKind::TextureData(generate_texture(
-                                img,
-                                source_format,
-                                original_size,
-                            ))
-                        }
+                    let loaded = match load_animation_frames(&_file, _scale_factor) {
+                        Ok(Some(frames)) => Ok(EmbeddedResourcesKind::AnimatedTextureData(
+                            frames
+                                .into_iter()
+                                .map(|(img, duration, original_size)| {
+                                    let total_size =
+                                        Size { width: img.width(), height: img.height() };
+                                    let mut texture =
+                                        generate_texture(img, SourceFormat::Rgba, original_size);
+                                    // All the frames have the size of the image, even the empty ones
+                                    texture.total_size = total_size;
+                                    texture.original_size = original_size;
+                                    (texture, duration)
+                                })
+                                .collect(),
+                        )),
+                        Ok(None) => load_image(_file, _scale_factor).map(
+                            |(img, source_format, original_size)| {
+                                EmbeddedResourcesKind::TextureData(generate_texture(
+                                    img,
+                                    source_format,
+                                    original_size,
+                                ))
+                            },
+                        ),
+                        Err(err) => Err(err),
+                    };
+                    match loaded {
+                        Ok(loaded) => kind = loaded,
                         Err(err) => {
                             diag.push_error(
                                 format!("Cannot load image file {path}: {err}"),
@@ -178,6 +199,10 @@ fn embed_image(
         EmbeddedResourcesKind::TextureData { .. } => {
             ImageReference::EmbeddedTexture { resource_id: e.id }
         }
+        #[cfg(feature = "software-renderer")]
+        EmbeddedResourcesKind::AnimatedTextureData { .. } => {
+            ImageReference::EmbeddedAnimatedTextures { resource_id: e.id }
+        }
         _ => ImageReference::EmbeddedData {
             resource_id: e.id,
             extension: std::path::Path::new(path)
@@ -362,6 +387,46 @@ enum SourceFormat {
     Rgba,
 }
 
+/// Decodes the frames of an animated GIF, APNG, or WebP file, with how long each of them is shown
+/// in milliseconds. Returns None if the file isn't an animated image.
+#[cfg(feature = "software-renderer")]
+fn load_animation_frames(
+    file: &crate::fileaccess::VirtualFile,
+    scale_factor: f64,
+) -> image::ImageResult<Option<Vec<(image::RgbaImage, u32, Size)>>> {
+    use i_slint_common::animated_image::{decode_animation_frames, is_animated_format};
+    let Some(format) = image::ImageFormat::from_path(&file.canon_path)
+        .ok()
+        .filter(|format| is_animated_format(*format))
+    else {
+        return Ok(None);
+    };
+    let data = match file.builtin_contents {
+        Some(data) => std::borrow::Cow::Borrowed(data),
+        None => std::fs::read(&file.canon_path).map_err(image::ImageError::IoError)?.into(),
+    };
+    let Some(frames) = decode_animation_frames(&data, format)? else {
+        return Ok(None);
+    };
+    Ok(Some(
+        frames
+            .into_iter()
+            .map(|(mut image, duration)| {
+                let original_size = Size { width: image.width(), height: image.height() };
+                if scale_factor < 1. {
+                    image = image::imageops::resize(
+                        &image,
+                        (original_size.width as f64 * scale_factor) as u32,
+                        (original_size.height as f64 * scale_factor) as u32,
+                        image::imageops::FilterType::Gaussian,
+                    );
+                }
+                (image, duration.as_millis() as u32, original_size)
+            })
+            .collect(),
+    ))
+}
+
 #[cfg(feature = "software-renderer")]
 fn load_image(
     file: crate::fileaccess::VirtualFile,
@@ -12,7 +12,7 @@
 
 use crate::expression_tree::{BindingExpression, Expression, MinMaxOp, NamedReference};
 use crate::langtype::{ElementType, NativeClass, Type};
-use crate::layout::is_layout;
+use crate::layout::{is_layout, Orientation};
 use crate::object_tree::{Component, Element, ElementRc};
 use crate::typeregister::TypeRegister;
 use core::cell::RefCell;
@@ -45,14 +45,19 @@ pub fn handle_flickable(root_component: &Rc<Component>, tr: &TypeRegister) {
 
 fn create_viewport_element(flickable: &ElementRc, native_empty: &Rc<NativeClass>) {
     let children = std::mem::take(&mut flickable.borrow_mut().children);
-    let is_listview = children
-        .iter()
-        .any(|c| c.borrow().repeated.as_ref().is_some_and(|r| r.is_listview.is_some()));
+    let listview_orientation = children.iter().find_map(|c| {
+        c.borrow().repeated.as_ref().and_then(|r| r.is_listview.as_ref().map(|lv| lv.orientation))
+    });
 
-    if is_listview {
+    if let Some(orientation) = listview_orientation {
         // Fox Listview, we don't bind the y property to the geometry because for large listview, we want to support coordinate with more precision than f32
         // so the actual geometry is relative to the Flickable instead of the viewport
         // We still assign a binding to the y property in case it is read by someone
+        // (For horizontal list views, the same applies to the x property)
+        let (pos, viewport_pos) = match orientation {
+            Orientation::Vertical => ("y", "viewport-y"),
+            Orientation::Horizontal => ("x", "viewport-x"),
+        };
         for c in &children {
             if c.borrow().repeated.is_none() {
                 // Normally should not happen, listview should only have one children, and it should be repeated
@@ -60,20 +65,20 @@ fn create_viewport_element(flickable: &ElementRc, native_empty: &Rc<NativeClass>
             }
             let ElementType::Component(base) = c.borrow().base_type.clone() else { continue };
             let inner_elem = &base.root_element;
-            let new_y = crate::layout::create_new_prop(
+            let new_pos = crate::layout::create_new_prop(
                 inner_elem,
-                SmolStr::new_static("actual-y"),
+                format_smolstr!("actual-{pos}"),
                 Type::LogicalLength,
             );
-            new_y.mark_as_set();
+            new_pos.mark_as_set();
             inner_elem.borrow_mut().bindings.insert(
-                "y".into(),
+                pos.into(),
                 RefCell::new(
                     Expression::BinaryExpression {
-                        lhs: Expression::PropertyReference(new_y.clone()).into(),
+                        lhs: Expression::PropertyReference(new_pos.clone()).into(),
                         rhs: Expression::PropertyReference(NamedReference::new(
                             flickable,
-                            SmolStr::new_static("viewport-y"),
+                            SmolStr::new_static(viewport_pos),
                         ))
                         .into(),
                         op: '-',
@@ -81,7 +86,12 @@ fn create_viewport_element(flickable: &ElementRc, native_empty: &Rc<NativeClass>
                     .into(),
                 ),
             );
-            inner_elem.borrow_mut().geometry_props.as_mut().unwrap().y = new_y;
+            let mut inner_elem = inner_elem.borrow_mut();
+            let geometry_props = inner_elem.geometry_props.as_mut().unwrap();
+            match orientation {
+                Orientation::Vertical => geometry_props.y = new_pos,
+                Orientation::Horizontal => geometry_props.x = new_pos,
+            }
         }
     }
 
@@ -97,7 +107,12 @@ fn create_viewport_element(flickable: &ElementRc, native_empty: &Rc<NativeClass>
     for prop in element_type.as_builtin().properties.keys() {
         // bind the viewport's property to the flickable property, such as:  `width <=> parent.viewport-width`
         if let Some(vp_prop) = prop.strip_prefix("viewport-") {
-            if is_listview && matches!(vp_prop, "y" | "height") {
+            let is_virtualized = match listview_orientation {
+                Some(Orientation::Vertical) => matches!(vp_prop, "y" | "height"),
+                Some(Orientation::Horizontal) => matches!(vp_prop, "x" | "width"),
+                None => false,
+            };
+            if is_virtualized {
                 //don't bind viewport-y for ListView because the layout is handled by the runtime
                 continue;
             }
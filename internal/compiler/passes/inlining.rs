@@ -435,6 +435,7 @@ fn duplicate_sub_component(
         used: component_to_duplicate.used.clone(),
         private_properties: Default::default(),
         inherits_popup_window: core::cell::Cell::new(false),
+        inherits_secondary_window: core::cell::Cell::new(false),
     };
 
     let new_component = Rc::new(new_component);
@@ -491,6 +492,7 @@ fn duplicate_popup(p: &PopupWindow, mapping: &mut Mapping, priority_delta: i32)
             .get(&element_key(p.parent_element.clone()))
             .expect("Parent element must be in the mapping")
             .clone(),
+        secondary_window: p.secondary_window,
     }
 }
 
@@ -530,6 +532,15 @@ fn duplicate_property_animation(
             root_component,
             priority_delta,
         )),
+        PropertyAnimation::Keyframes { animation, keyframes } => PropertyAnimation::Keyframes {
+            animation: duplicate_element_with_mapping(
+                animation,
+                mapping,
+                root_component,
+                priority_delta,
+            ),
+            keyframes: keyframes.clone(),
+        },
         PropertyAnimation::Transition { state_ref, animations } => PropertyAnimation::Transition {
             state_ref: state_ref.clone(),
             animations: animations
@@ -543,6 +554,7 @@ fn duplicate_property_animation(
                         root_component,
                         priority_delta,
                     ),
+                    keyframes: a.keyframes.clone(),
                 })
                 .collect(),
         },
@@ -579,6 +591,11 @@ fn fixup_element_references(expr: &mut Expression, mapping: &Mapping) {
                     fxe(&mut e.element);
                 }
             }
+            crate::layout::Layout::FlexLayout(l) => {
+                for e in &mut l.elems {
+                    fxe(&mut e.item.element);
+                }
+            }
         },
         Expression::RepeaterModelReference { element }
         | Expression::RepeaterIndexReference { element } => fx(element),
@@ -598,11 +615,12 @@ fn duplicate_transition(
         property_animations: t
             .property_animations
             .iter()
-            .map(|(r, loc, anim)| {
+            .map(|(r, loc, anim, keyframes)| {
                 (
                     r.clone(),
                     loc.clone(),
                     duplicate_element_with_mapping(anim, mapping, root_component, priority_delta),
+                    keyframes.clone(),
                 )
             })
             .collect(),
@@ -659,7 +677,8 @@ fn element_require_inlining(elem: &ElementRc) -> bool {
     }
 
     // Popup windows need to be inlined for root.close() to work properly.
-    if super::lower_popups::is_popup_window(elem) {
+    if super::lower_popups::is_popup_window(elem) || super::lower_popups::is_secondary_window(elem)
+    {
         return true;
     }
 
@@ -96,6 +96,8 @@ fn process_component_container(element: &ElementRc, empty_type: &ElementType) {
             index_id: Default::default(),
             is_conditional_element: true,
             is_listview: None,
+            exit_transition: None,
+            flex_factors: None,
         }),
         is_component_placeholder: true,
         ..Default::default()
@@ -0,0 +1,166 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the `enter { ... }` and `exit { ... }` blocks of repeated and conditional elements.
+//!
+//! The blocks were turned into states by the object tree. This pass adds a `presence` property
+//! (0 while entering, 1 when present, 2 while exiting) that drives these states, sets it to 1 in the
+//! `init` callback so that the enter transition starts, and generates the functions the runtime calls
+//! to start the exit transition and to know when the instance can be destroyed.
+//! It must run before lower_states.
+
+use crate::expression_tree::{Expression, NamedReference, Unit};
+use crate::langtype::{Function, Type};
+use crate::object_tree::*;
+use smol_str::SmolStr;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const ENTERING: f64 = 0.;
+const PRESENT: f64 = 1.;
+const EXITING: f64 = 2.;
+
+pub fn lower_enter_exit(component: &Rc<Component>) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| lower_enter_exit_in_element(elem));
+}
+
+fn lower_enter_exit_in_element(elem: &ElementRc) {
+    let (enter_changes, exit_changes) = {
+        let e = elem.borrow();
+        if e.repeated.is_none() {
+            return;
+        }
+        let changes = |id: &str| {
+            e.states.iter().find(|s| s.id == id).map(|s| {
+                s.property_changes.iter().map(|(nr, expr, _)| (nr.clone(), expr.clone())).collect()
+            })
+        };
+        let enter: Option<Vec<_>> = changes(ENTER_STATE_ID);
+        (enter, changes(EXIT_STATE_ID))
+    };
+    if enter_changes.is_none() && exit_changes.is_none() {
+        return;
+    }
+
+    let presence = NamedReference::new(elem, unique_name(elem, "presence"));
+    elem.borrow_mut().property_declarations.insert(presence.name().clone(), Type::Int32.into());
+    let presence_is = |value: f64| Expression::BinaryExpression {
+        lhs: Box::new(Expression::PropertyReference(presence.clone())),
+        rhs: Box::new(Expression::NumberLiteral(value, Unit::None)),
+        op: '=',
+    };
+    let set_presence = |value: f64| Expression::SelfAssignment {
+        lhs: Box::new(Expression::PropertyReference(presence.clone())),
+        rhs: Box::new(Expression::NumberLiteral(value, Unit::None)),
+        op: '=',
+        node: None,
+    };
+
+    for state in elem.borrow_mut().states.iter_mut() {
+        if state.id == ENTER_STATE_ID {
+            state.condition = Some(presence_is(ENTERING));
+        } else if state.id == EXIT_STATE_ID {
+            state.condition = Some(presence_is(EXITING));
+        }
+    }
+
+    if let Some(enter_changes) = enter_changes {
+        // Evaluate the properties with their enter values before leaving the enter state,
+        // so that they are animated from there.
+        let mut code = enter_changes
+            .into_iter()
+            .map(|(nr, _)| Expression::PropertyReference(nr))
+            .collect::<Vec<_>>();
+        code.push(set_presence(PRESENT));
+        let mut elem = elem.borrow_mut();
+        match elem.bindings.get_mut("init") {
+            Some(init) => {
+                let init = &mut init.get_mut().expression;
+                code.push(std::mem::take(init));
+                *init = Expression::CodeBlock(code);
+            }
+            None => {
+                elem.bindings
+                    .insert("init".into(), RefCell::new(Expression::CodeBlock(code).into()));
+            }
+        }
+    }
+
+    if let Some(exit_changes) = exit_changes {
+        // The instance is no longer part of the layout (if any), so keep its geometry
+        let mut start = ["x", "y", "width", "height"]
+            .into_iter()
+            .filter(|p| {
+                elem.borrow().lookup_property(p).property_type == Type::LogicalLength
+                    && !exit_changes
+                        .iter()
+                        .any(|(nr, _)| Rc::ptr_eq(&nr.element(), elem) && nr.name() == *p)
+            })
+            .map(|p| {
+                let nr = NamedReference::new(elem, SmolStr::new_static(p));
+                Expression::SelfAssignment {
+                    lhs: Box::new(Expression::PropertyReference(nr.clone())),
+                    rhs: Box::new(Expression::PropertyReference(nr)),
+                    op: '=',
+                    node: None,
+                }
+            })
+            .collect::<Vec<_>>();
+        start.push(set_presence(EXITING));
+        // Evaluate the properties so that their animation starts now
+        start.extend(exit_changes.iter().map(|(nr, _)| Expression::PropertyReference(nr.clone())));
+
+        // The exit transition is over once all the properties reached their exit value
+        let is_done = exit_changes.into_iter().fold(presence_is(EXITING), |acc, (nr, expr)| {
+            Expression::BinaryExpression {
+                lhs: Box::new(acc),
+                rhs: Box::new(Expression::BinaryExpression {
+                    lhs: Box::new(Expression::PropertyReference(nr)),
+                    rhs: Box::new(expr),
+                    op: '=',
+                }),
+                op: '&',
+            }
+        });
+
+        let start = declare_function(elem, "start-exit", Type::Void, Expression::CodeBlock(start));
+        let is_done = declare_function(elem, "is-exit-done", Type::Bool, is_done);
+        elem.borrow_mut().repeated.as_mut().unwrap().exit_transition =
+            Some(ExitTransition { start, is_done });
+    }
+}
+
+fn declare_function(
+    elem: &ElementRc,
+    name: &'static str,
+    return_type: Type,
+    code: Expression,
+) -> NamedReference {
+    let name = unique_name(elem, name);
+    let pure = return_type != Type::Void;
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut.property_declarations.insert(
+        name.clone(),
+        PropertyDeclaration {
+            property_type: Type::Function(Rc::new(Function {
+                return_type,
+                args: vec![],
+                arg_names: vec![],
+            })),
+            pure: Some(pure),
+            ..Default::default()
+        },
+    );
+    elem_mut.bindings.insert(name.clone(), RefCell::new(code.into()));
+    drop(elem_mut);
+    NamedReference::new(elem, name)
+}
+
+/// Returns a name based on `base` that is not yet used by a property of the element
+fn unique_name(elem: &ElementRc, base: &str) -> SmolStr {
+    let mut name = base.to_owned();
+    while elem.borrow().lookup_property(&name).property_type != Type::Invalid {
+        name += "-";
+    }
+    name.into()
+}
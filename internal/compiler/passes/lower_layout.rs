@@ -111,6 +111,7 @@ fn lower_element_layout(
         "GridLayout" => lower_grid_layout(component, elem, diag, type_register),
         "HorizontalLayout" => lower_box_layout(elem, diag, Orientation::Horizontal),
         "VerticalLayout" => lower_box_layout(elem, diag, Orientation::Vertical),
+        "FlexLayout" => lower_flex_layout(elem, diag),
         "Dialog" => {
             lower_dialog_layout(elem, style_metrics, diag);
             return; // the Dialog stays in the tree as a Dialog
@@ -464,6 +465,129 @@ fn lower_box_layout(
     }
 }
 
+fn lower_flex_layout(layout_element: &ElementRc, diag: &mut BuildDiagnostics) {
+    let orientation = flex_layout_orientation(layout_element, diag);
+
+    let mut layout = FlexLayout {
+        orientation,
+        elems: Default::default(),
+        geometry: LayoutGeometry::new(layout_element),
+        wrap: NamedReference::new(layout_element, SmolStr::new_static("wrap")),
+    };
+
+    let layout_cache_prop_h =
+        create_new_prop(layout_element, SmolStr::new_static("layout-cache-h"), Type::LayoutCache);
+    let layout_cache_prop_v =
+        create_new_prop(layout_element, SmolStr::new_static("layout-cache-v"), Type::LayoutCache);
+    let layout_info_prop_h = create_new_prop(
+        layout_element,
+        SmolStr::new_static("layoutinfo-h"),
+        layout_info_type().into(),
+    );
+    let layout_info_prop_v = create_new_prop(
+        layout_element,
+        SmolStr::new_static("layoutinfo-v"),
+        layout_info_type().into(),
+    );
+
+    let layout_children = std::mem::take(&mut layout_element.borrow_mut().children);
+    for layout_child in &layout_children {
+        if let Some(item) = create_layout_item(layout_child, diag) {
+            let index = layout.elems.len() * 2;
+            let rep_idx = &item.repeater_index;
+            let e = &item.elem;
+            set_prop_from_cache(e, "x", &layout_cache_prop_h, index, rep_idx, diag);
+            if !item.item.constraints.fixed_width {
+                set_prop_from_cache(e, "width", &layout_cache_prop_h, index + 1, rep_idx, diag);
+            }
+            set_prop_from_cache(e, "y", &layout_cache_prop_v, index, rep_idx, diag);
+            if !item.item.constraints.fixed_height {
+                set_prop_from_cache(e, "height", &layout_cache_prop_v, index + 1, rep_idx, diag);
+            }
+
+            let factors = FlexFactors {
+                grow: NamedReference::new(e, SmolStr::new_static("flex-grow")),
+                shrink: NamedReference::new(e, SmolStr::new_static("flex-shrink")),
+            };
+            let factors = if let Some(repeated) = layout_child.borrow_mut().repeated.as_mut() {
+                // Each instance has its own factors, so they are queried from the repeated component
+                repeated.flex_factors = Some(factors);
+                None
+            } else {
+                Some(factors)
+            };
+            layout.elems.push(FlexLayoutElement { item: item.item, factors });
+        }
+    }
+    layout_element.borrow_mut().children = layout_children;
+    let span = layout_element.borrow().to_source_location();
+    layout_cache_prop_h.element().borrow_mut().bindings.insert(
+        layout_cache_prop_h.name().clone(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlexLayout(layout.clone()), Orientation::Horizontal),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_cache_prop_v.element().borrow_mut().bindings.insert(
+        layout_cache_prop_v.name().clone(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlexLayout(layout.clone()), Orientation::Vertical),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_h.element().borrow_mut().bindings.insert(
+        layout_info_prop_h.name().clone(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlexLayout(layout.clone()),
+                Orientation::Horizontal,
+            ),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_v.element().borrow_mut().bindings.insert(
+        layout_info_prop_v.name().clone(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlexLayout(layout.clone()),
+                Orientation::Vertical,
+            ),
+            span,
+        )
+        .into(),
+    );
+    layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
+    for d in layout_element.borrow_mut().debug.iter_mut() {
+        d.layout = Some(Layout::FlexLayout(layout.clone()));
+    }
+}
+
+/// Returns the main axis of a FlexLayout. The direction decides how the layout is
+/// lowered, so it must be known at compile time.
+fn flex_layout_orientation(layout_element: &ElementRc, diag: &mut BuildDiagnostics) -> Orientation {
+    let elem = layout_element.borrow();
+    let Some(binding) = elem.bindings.get("direction") else {
+        return Orientation::Horizontal;
+    };
+    let binding = binding.borrow();
+    match super::ignore_debug_hooks(&binding.expression) {
+        Expression::EnumerationValue(v) if v.to_string() == "column" => Orientation::Vertical,
+        Expression::EnumerationValue(_) => Orientation::Horizontal,
+        // An error was already reported for that binding
+        Expression::Invalid if binding.two_way_bindings.is_empty() => Orientation::Horizontal,
+        _ => {
+            diag.push_error(
+                "The direction of a FlexLayout must be either 'row' or 'column'".into(),
+                &*binding,
+            );
+            Orientation::Horizontal
+        }
+    }
+}
+
 fn lower_dialog_layout(
     dialog_element: &ElementRc,
     style_metrics: &Rc<Component>,
@@ -737,6 +861,11 @@ fn create_layout_item(
             PropertyDeclaration { property_type: Type::Percent, ..PropertyDeclaration::default() },
         );
     };
+    if item_element.borrow().builtin_type().is_some_and(|b| b.name == "Shortcut") {
+        // Shortcuts are not visual and don't take part in the layout
+        return None;
+    }
+
     fix_explicit_percent("width", item_element);
     fix_explicit_percent("height", item_element);
 
@@ -375,6 +375,8 @@ fn process_window(
             index_id: SmolStr::default(),
             is_conditional_element: true,
             is_listview: None,
+            exit_transition: None,
+            flex_factors: None,
         }),
         ..Default::default()
     }
@@ -454,33 +456,32 @@ fn process_window(
     // except for the actual geometry
     win.borrow_mut().geometry_props.as_mut().unwrap().height = win_height;
 
-    if !no_native_menu || item_tree_root.is_some() {
-        let mut arguments = vec![
-            Expression::PropertyReference(NamedReference::new(
-                &menu_bar,
-                SmolStr::new_static(ENTRIES),
-            )),
-            Expression::PropertyReference(NamedReference::new(
-                &menu_bar,
-                SmolStr::new_static(SUB_MENU),
-            )),
-            Expression::PropertyReference(NamedReference::new(
-                &menu_bar,
-                SmolStr::new_static(ACTIVATED),
-            )),
-        ];
-
-        if let Some(item_tree_root) = item_tree_root {
-            arguments.push(item_tree_root.into());
-            arguments.push(Expression::BoolLiteral(no_native_menu));
-        }
-        let setup_menubar = Expression::FunctionCall {
-            function: BuiltinFunction::SetupNativeMenuBar.into(),
-            arguments,
-            source_location,
-        };
-        component.init_code.borrow_mut().constructor_code.push(setup_menubar.into());
+    // Even without native menubar, the window needs the menu to handle the shortcuts
+    let mut arguments = vec![
+        Expression::PropertyReference(NamedReference::new(&menu_bar, SmolStr::new_static(ENTRIES))),
+        Expression::PropertyReference(NamedReference::new(
+            &menu_bar,
+            SmolStr::new_static(SUB_MENU),
+        )),
+        Expression::PropertyReference(NamedReference::new(
+            &menu_bar,
+            SmolStr::new_static(ACTIVATED),
+        )),
+    ];
+
+    if let Some(item_tree_root) = item_tree_root {
+        arguments.push(item_tree_root.into());
+        arguments.push(Expression::BoolLiteral(no_native_menu));
+    } else if no_native_menu {
+        // The window only handles the shortcuts of the menu
+        arguments.push(Expression::BoolLiteral(true));
     }
+    let setup_menubar = Expression::FunctionCall {
+        function: BuiltinFunction::SetupNativeMenuBar.into(),
+        arguments,
+        source_location,
+    };
+    component.init_code.borrow_mut().constructor_code.push(setup_menubar.into());
     true
 }
 
@@ -655,16 +656,14 @@ fn generate_menu_entries(
         let id_str = format_smolstr!("{}", state.id);
         values.insert(SmolStr::new_static("id"), Expression::StringLiteral(id_str.clone()));
 
-        if let Some(callback) = borrow_mut.bindings.remove(ACTIVATED) {
-            state.activate.push((id_str.clone(), callback.into_inner().expression));
-        }
-
+        let checkable = !is_separator && borrow_mut.bindings.contains_key("checkable");
+        let callback = borrow_mut.bindings.remove(ACTIVATED).map(|c| c.into_inner().expression);
         if is_sub_menu {
             let sub_entries =
                 generate_menu_entries(std::mem::take(&mut borrow_mut.children).into_iter(), state);
 
             state.sub_menu.push((
-                id_str,
+                id_str.clone(),
                 Expression::Array { element_ty: state.menu_entry.clone(), values: sub_entries },
             ));
             values
@@ -672,9 +671,40 @@ fn generate_menu_entries(
         }
 
         drop(borrow_mut);
+        if checkable {
+            // Activating a checkable item toggles its `checked` property before calling the callback
+            let checked = || {
+                Expression::PropertyReference(NamedReference::new(
+                    &item,
+                    SmolStr::new_static("checked"),
+                ))
+            };
+            let toggle = Expression::Condition {
+                condition: Expression::PropertyReference(NamedReference::new(
+                    &item,
+                    SmolStr::new_static("checkable"),
+                ))
+                .into(),
+                true_expr: Expression::SelfAssignment {
+                    lhs: checked().into(),
+                    rhs: Expression::UnaryOp { sub: checked().into(), op: '!' }.into(),
+                    op: '=',
+                    node: None,
+                }
+                .into(),
+                false_expr: Expression::CodeBlock(vec![]).into(),
+            };
+            state.activate.push((
+                id_str.clone(),
+                Expression::CodeBlock([toggle].into_iter().chain(callback).collect()),
+            ));
+        } else if let Some(callback) = callback {
+            state.activate.push((id_str.clone(), callback));
+        }
+
         if !is_separator {
-            for prop in ["title", "enabled"] {
-                if item.borrow().bindings.contains_key(prop) {
+            for prop in ["title", "enabled", "shortcut", "icon", "checkable", "checked"] {
+                if item.borrow().bindings.contains_key(prop) || (checkable && prop == "checked") {
                     let n = SmolStr::new_static(prop);
                     values.insert(
                         n.clone(),
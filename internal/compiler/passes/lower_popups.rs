@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Passe that transform the PopupWindow element into a component
+//! Passe that transform the PopupWindow and SecondaryWindow elements into a component
 
 use crate::diagnostics::{BuildDiagnostics, SourceLocation};
 use crate::expression_tree::{BindingExpression, Expression, NamedReference};
@@ -13,6 +13,8 @@
 
 const CLOSE_ON_CLICK: &str = "close-on-click";
 const CLOSE_POLICY: &str = "close-policy";
+const TRANSIENT: &str = "transient";
+const MODAL: &str = "modal";
 
 pub fn lower_popups(
     component: &Rc<Component>,
@@ -27,6 +29,8 @@ pub fn lower_popups(
         &mut |elem, parent_element: &Option<ElementRc>| {
             if is_popup_window(elem) {
                 lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
+            } else if is_secondary_window(elem) {
+                lower_secondary_window(elem, parent_element.as_ref(), &window_type, diag);
             }
             Some(elem.clone())
         },
@@ -41,6 +45,14 @@ pub fn is_popup_window(element: &ElementRc) -> bool {
     }
 }
 
+pub fn is_secondary_window(element: &ElementRc) -> bool {
+    match &element.borrow().base_type {
+        ElementType::Builtin(base_type) => base_type.name == "SecondaryWindow",
+        ElementType::Component(base_type) => base_type.inherits_secondary_window.get(),
+        _ => false,
+    }
+}
+
 fn lower_popup_window(
     popup_window_element: &ElementRc,
     parent_element: Option<&ElementRc>,
@@ -76,41 +88,18 @@ fn lower_popup_window(
     }
 
     let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
-    let parent_element = match parent_element {
-        None => {
-            if matches!(popup_window_element.borrow().base_type, ElementType::Builtin(_)) {
-                popup_window_element.borrow_mut().base_type = window_type.clone();
-            }
-            parent_component.inherits_popup_window.set(true);
-            return;
+    let Some(parent_element) = parent_element else {
+        if matches!(popup_window_element.borrow().base_type, ElementType::Builtin(_)) {
+            popup_window_element.borrow_mut().base_type = window_type.clone();
         }
-        Some(parent_element) => parent_element,
+        parent_component.inherits_popup_window.set(true);
+        return;
     };
 
-    if Rc::ptr_eq(&parent_component.root_element, popup_window_element) {
-        diag.push_error(
-            "PopupWindow cannot be directly repeated or conditional".into(),
-            &*popup_window_element.borrow(),
-        );
+    if !detach_from_parent(popup_window_element, parent_element, "PopupWindow", diag) {
         return;
     }
 
-    // Remove the popup_window_element from its parent
-    let mut parent_element_borrowed = parent_element.borrow_mut();
-    let index = parent_element_borrowed
-        .children
-        .iter()
-        .position(|child| Rc::ptr_eq(child, popup_window_element))
-        .expect("PopupWindow must be a child of its parent");
-    parent_element_borrowed.children.remove(index);
-    parent_element_borrowed.has_popup_child = true;
-    drop(parent_element_borrowed);
-    if let Some(parent_cip) = &mut *parent_component.child_insertion_point.borrow_mut() {
-        if Rc::ptr_eq(&parent_cip.parent, parent_element) && parent_cip.insertion_index > index {
-            parent_cip.insertion_index -= 1;
-        }
-    }
-
     if matches!(popup_window_element.borrow().base_type, ElementType::Builtin(_)) {
         popup_window_element.borrow_mut().base_type = window_type.clone();
     }
@@ -182,6 +171,141 @@ fn lower_popup_window(
             enumeration: crate::typeregister::BUILTIN.with(|e| e.enums.PopupClosePolicy.clone()),
         });
 
+    lower_popup_component(
+        popup_window_element,
+        parent_element,
+        close_policy,
+        None,
+        "PopupWindow",
+        diag,
+    );
+}
+
+fn lower_secondary_window(
+    window_element: &ElementRc,
+    parent_element: Option<&ElementRc>,
+    window_type: &ElementType,
+    diag: &mut BuildDiagnostics,
+) {
+    for prop in [TRANSIENT, MODAL] {
+        if let Some(binding) = window_element.borrow().bindings.get(prop) {
+            if !matches!(
+                super::ignore_debug_hooks(&binding.borrow().expression),
+                Expression::BoolLiteral(_)
+            ) {
+                report_const_error(prop, &binding.borrow().span, diag);
+            }
+        }
+    }
+
+    let parent_component = window_element.borrow().enclosing_component.upgrade().unwrap();
+    let Some(parent_element) = parent_element else {
+        if matches!(window_element.borrow().base_type, ElementType::Builtin(_)) {
+            window_element.borrow_mut().base_type = window_type.clone();
+        }
+        parent_component.inherits_secondary_window.set(true);
+        return;
+    };
+
+    if !detach_from_parent(window_element, parent_element, "SecondaryWindow", diag) {
+        return;
+    }
+
+    let bool_value =
+        |binding: &BindingExpression| match super::ignore_debug_hooks(&binding.expression) {
+            Expression::BoolLiteral(v) => Some(*v),
+            _ => {
+                assert!(diag.has_errors());
+                None
+            }
+        };
+    let lookup = |prop: &str| {
+        let binding = window_element.borrow_mut().bindings.remove(prop);
+        binding.and_then(|b| bool_value(&b.borrow())).or_else(|| {
+            // check bases
+            let mut base = window_element.borrow().base_type.clone();
+            while let ElementType::Component(b) = base {
+                let value = b
+                    .root_element
+                    .borrow()
+                    .bindings
+                    .get(prop)
+                    .and_then(|b| bool_value(&b.borrow()));
+                if value.is_some() {
+                    return value;
+                }
+                base = b.root_element.borrow().base_type.clone();
+            }
+            None
+        })
+    };
+    let modal = lookup(MODAL).unwrap_or_default();
+    // A modal window is always transient for its parent
+    let transient = lookup(TRANSIENT).unwrap_or_default() || modal;
+
+    if matches!(window_element.borrow().base_type, ElementType::Builtin(_)) {
+        window_element.borrow_mut().base_type = window_type.clone();
+    }
+
+    // Not used, but the llr expects a close policy
+    let close_policy = EnumerationValue {
+        value: 0,
+        enumeration: crate::typeregister::BUILTIN.with(|e| e.enums.PopupClosePolicy.clone()),
+    };
+
+    lower_popup_component(
+        window_element,
+        parent_element,
+        close_policy,
+        Some(SecondaryWindowOptions { transient, modal }),
+        "SecondaryWindow",
+        diag,
+    );
+}
+
+/// Remove the popup_window_element from its parent. Returns false if the element is the root of its component.
+fn detach_from_parent(
+    popup_window_element: &ElementRc,
+    parent_element: &ElementRc,
+    type_name: &str,
+    diag: &mut BuildDiagnostics,
+) -> bool {
+    let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
+    if Rc::ptr_eq(&parent_component.root_element, popup_window_element) {
+        diag.push_error(
+            format!("{type_name} cannot be directly repeated or conditional"),
+            &*popup_window_element.borrow(),
+        );
+        return false;
+    }
+
+    let mut parent_element_borrowed = parent_element.borrow_mut();
+    let index = parent_element_borrowed
+        .children
+        .iter()
+        .position(|child| Rc::ptr_eq(child, popup_window_element))
+        .expect("PopupWindow must be a child of its parent");
+    parent_element_borrowed.children.remove(index);
+    parent_element_borrowed.has_popup_child = true;
+    drop(parent_element_borrowed);
+    if let Some(parent_cip) = &mut *parent_component.child_insertion_point.borrow_mut() {
+        if Rc::ptr_eq(&parent_cip.parent, parent_element) && parent_cip.insertion_index > index {
+            parent_cip.insertion_index -= 1;
+        }
+    }
+    true
+}
+
+fn lower_popup_component(
+    popup_window_element: &ElementRc,
+    parent_element: &ElementRc,
+    close_policy: EnumerationValue,
+    secondary_window: Option<SecondaryWindowOptions>,
+    type_name: &str,
+    diag: &mut BuildDiagnostics,
+) {
+    let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
+
     let popup_comp = Rc::new(Component {
         root_element: popup_window_element.clone(),
         parent_element: Rc::downgrade(parent_element),
@@ -219,7 +343,7 @@ fn lower_popup_window(
     // - Maybe this should actually be allowed
     visit_all_named_references(&parent_component, &mut |nr| {
         let element = &nr.element();
-        if check_element(element, &weak, diag, popup_window_element) {
+        if check_element(element, &weak, diag, popup_window_element, type_name) {
             // just set it to whatever is a valid NamedReference, otherwise we'll panic later
             *nr = coord_x.clone();
         }
@@ -229,7 +353,7 @@ fn lower_popup_window(
             if let Expression::ElementReference(ref element) = exp {
                 let elem = element.upgrade().unwrap();
                 if !Rc::ptr_eq(&elem, popup_window_element) {
-                    check_element(&elem, &weak, diag, popup_window_element);
+                    check_element(&elem, &weak, diag, popup_window_element, type_name);
                 }
             }
         });
@@ -243,6 +367,7 @@ fn lower_popup_window(
         y: coord_y,
         close_policy,
         parent_element: parent_element.clone(),
+        secondary_window,
     });
 }
 
@@ -255,10 +380,11 @@ fn check_element(
     popup_comp: &Weak<Component>,
     diag: &mut BuildDiagnostics,
     popup_window_element: &ElementRc,
+    type_name: &str,
 ) -> bool {
     if Weak::ptr_eq(&element.borrow().enclosing_component, popup_comp) {
         diag.push_error(
-            "Cannot access the inside of a PopupWindow from enclosing component".into(),
+            format!("Cannot access the inside of a {type_name} from enclosing component"),
             &*popup_window_element.borrow(),
         );
         true
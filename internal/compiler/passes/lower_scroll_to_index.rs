@@ -0,0 +1,89 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that lowers the `ListViewScrollToIndex` builtin function called by the ListView's
+//! `scroll-to-index()`.
+//!
+//! The call is replaced by an assignment to a new property of the ListView, which is then
+//! referenced by the [`ListViewInfo::requested_row`] of the repeated element. The runtime
+//! scrolls to that row when it updates the ListView and resets it to -1.
+//!
+//! This pass must be run after inlining, such that the `scroll-to-index()` function is in the
+//! same component as the ListView's `for`.
+
+use crate::expression_tree::{BuiltinFunction, Callable, Expression, NamedReference, Unit};
+use crate::langtype::Type;
+use crate::object_tree::*;
+use smol_str::SmolStr;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub fn lower_scroll_to_index(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let mut has_call = false;
+        visit_element_expressions(elem, |expr, _, _| {
+            expr.visit_recursive(&mut |e| has_call |= is_scroll_to_index_call(e))
+        });
+        if has_call {
+            let requested_row = create_requested_row(elem);
+            visit_element_expressions(elem, |expr, _, _| {
+                expr.visit_recursive_mut(&mut |e| {
+                    if is_scroll_to_index_call(e) {
+                        lower_call(e, requested_row.as_ref())
+                    }
+                })
+            });
+        }
+    })
+}
+
+fn is_scroll_to_index_call(e: &Expression) -> bool {
+    matches!(
+        e,
+        Expression::FunctionCall {
+            function: Callable::Builtin(BuiltinFunction::ListViewScrollToIndex),
+            ..
+        }
+    )
+}
+
+/// Create the `requested-row` property of the `listview` and register it in the ListViewInfo
+/// of its repeated element. Returns None if the ListView has no `for`.
+fn create_requested_row(listview: &ElementRc) -> Option<NamedReference> {
+    let mut repeated_element = None;
+    recurse_elem(listview, &(), &mut |elem, _| {
+        let is_in_listview = elem.borrow().repeated.as_ref().is_some_and(|r| {
+            r.is_listview.as_ref().is_some_and(|lv| Rc::ptr_eq(&lv.viewport_y.element(), listview))
+        });
+        if is_in_listview {
+            repeated_element = Some(elem.clone());
+        }
+    });
+    let repeated_element = repeated_element?;
+
+    let requested_row =
+        crate::layout::create_new_prop(listview, SmolStr::new_static("requested-row"), Type::Int32);
+    listview.borrow_mut().bindings.insert(
+        requested_row.name().clone(),
+        RefCell::new(Expression::NumberLiteral(-1., Unit::None).into()),
+    );
+    let mut repeated_element = repeated_element.borrow_mut();
+    let lv = repeated_element.repeated.as_mut().unwrap().is_listview.as_mut().unwrap();
+    lv.requested_row = Some(requested_row.clone());
+    Some(requested_row)
+}
+
+fn lower_call(call: &mut Expression, requested_row: Option<&NamedReference>) {
+    let Expression::FunctionCall { arguments, .. } = call else { unreachable!() };
+    let [index] = std::mem::take(arguments).try_into().expect("one argument");
+    *call = match requested_row {
+        Some(requested_row) => Expression::SelfAssignment {
+            lhs: Box::new(Expression::PropertyReference(requested_row.clone())),
+            rhs: Box::new(index),
+            op: '=',
+            node: None,
+        },
+        // Without a `for`, there is nothing to scroll to
+        None => Expression::CodeBlock(vec![]),
+    };
+}
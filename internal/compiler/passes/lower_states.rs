@@ -150,7 +150,7 @@ fn lower_transitions_in_element(
             &0
         });
 
-        for (p, span, animation) in transition.property_animations {
+        for (p, span, animation, keyframes) in transition.property_animations {
             if !affected_properties.contains(&p) {
                 diag.push_error(
                     "The property is not changed as part of this transition".into(),
@@ -163,6 +163,7 @@ fn lower_transitions_in_element(
                 state_id: *state,
                 direction: transition.direction,
                 animation,
+                keyframes,
             };
             props.entry(p).or_insert_with(|| (span.clone(), vec![])).1.push(t);
         }
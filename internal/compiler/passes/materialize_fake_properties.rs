@@ -111,6 +111,9 @@ fn should_materialize(
             return Some(Type::Enumeration(
                 crate::typeregister::BUILTIN.with(|e| e.enums.PopupClosePolicy.clone()),
             ));
+        } else if prop == "transient" || prop == "modal" {
+            // SecondaryWindow::transient and SecondaryWindow::modal
+            return Some(Type::Bool);
         } else {
             let ty = base_type.lookup_property(prop).property_type.clone();
             return (ty != Type::Invalid).then_some(ty);
@@ -183,6 +186,7 @@ pub fn initialize(elem: &ElementRc, name: &str) -> Option<Expression> {
         "preferred-height" => layout_constraint_prop(elem, "preferred", Orientation::Vertical),
         "preferred-width" => layout_constraint_prop(elem, "preferred", Orientation::Horizontal),
         "opacity" => Expression::NumberLiteral(1., Unit::None),
+        "flex-shrink" => Expression::NumberLiteral(1., Unit::None),
         "visible" => Expression::BoolLiteral(true),
         _ => return None,
     };
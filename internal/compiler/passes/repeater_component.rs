@@ -7,6 +7,7 @@
 
 use crate::expression_tree::{Expression, NamedReference};
 use crate::langtype::ElementType;
+use crate::layout::Orientation;
 use crate::object_tree::*;
 use smol_str::SmolStr;
 use std::cell::RefCell;
@@ -69,21 +70,24 @@ fn create_repeater_components(component: &Rc<Component>) {
         });
 
         if let Some(listview) = is_listview {
-            if !comp.root_element.borrow().is_binding_set("height", false) {
-                let preferred = Expression::PropertyReference(NamedReference::new(
-                    &comp.root_element,
-                    SmolStr::new_static("preferred-height"),
-                ));
-                comp.root_element
-                    .borrow_mut()
-                    .bindings
-                    .insert("height".into(), RefCell::new(preferred.into()));
-            }
-            if !comp.root_element.borrow().is_binding_set("width", false) {
-                comp.root_element.borrow_mut().bindings.insert(
-                    "width".into(),
-                    RefCell::new(Expression::PropertyReference(listview.listview_width).into()),
-                );
+            let root = &comp.root_element;
+            let set_default = |prop: &'static str, expr: Expression| {
+                if !root.borrow().is_binding_set(prop, false) {
+                    root.borrow_mut().bindings.insert(prop.into(), RefCell::new(expr.into()));
+                }
+            };
+            let preferred = |prop: &'static str| {
+                Expression::PropertyReference(NamedReference::new(root, SmolStr::new_static(prop)))
+            };
+            if let Some((cell_width, cell_height)) = listview.cell_size {
+                set_default("width", Expression::PropertyReference(cell_width));
+                set_default("height", Expression::PropertyReference(cell_height));
+            } else if listview.orientation == Orientation::Horizontal {
+                set_default("width", preferred("preferred-width"));
+                set_default("height", Expression::PropertyReference(listview.listview_height));
+            } else {
+                set_default("height", preferred("preferred-height"));
+                set_default("width", Expression::PropertyReference(listview.listview_width));
             }
         }
 
@@ -361,6 +361,9 @@ pub(crate) fn create_component(
                 used: component.used.clone(),
                 init_code: RefCell::new(component.init_code.borrow().clone()),
                 inherits_popup_window: std::cell::Cell::new(component.inherits_popup_window.get()),
+                inherits_secondary_window: std::cell::Cell::new(
+                    component.inherits_secondary_window.get(),
+                ),
                 optimized_elements,
                 parent_element,
                 popup_windows,
@@ -453,8 +456,13 @@ fn snapshot_element(
                 property_animations: t
                     .property_animations
                     .iter()
-                    .map(|(nr, sl, el)| {
-                        (nr.snapshot(self), sl.clone(), self.create_and_snapshot_element(el))
+                    .map(|(nr, sl, el, keyframes)| {
+                        (
+                            nr.snapshot(self),
+                            sl.clone(),
+                            self.create_and_snapshot_element(el),
+                            self.snapshot_keyframes(keyframes),
+                        )
                     })
                     .collect(),
                 node: t.node.clone(),
@@ -494,11 +502,28 @@ fn snapshot_element(
                 index_id: r.index_id.clone(),
                 is_conditional_element: r.is_conditional_element,
                 is_listview: r.is_listview.as_ref().map(|lv| object_tree::ListViewInfo {
+                    viewport_x: lv.viewport_x.snapshot(self),
                     viewport_y: lv.viewport_y.snapshot(self),
                     viewport_height: lv.viewport_height.snapshot(self),
                     viewport_width: lv.viewport_width.snapshot(self),
                     listview_height: lv.listview_height.snapshot(self),
                     listview_width: lv.listview_width.snapshot(self),
+                    orientation: lv.orientation,
+                    cell_size: lv
+                        .cell_size
+                        .as_ref()
+                        .map(|(w, h)| (w.snapshot(self), h.snapshot(self))),
+                    requested_row: lv.requested_row.as_ref().map(|r| r.snapshot(self)),
+                }),
+                exit_transition: r.exit_transition.as_ref().map(|exit| {
+                    object_tree::ExitTransition {
+                        start: exit.start.snapshot(self),
+                        is_done: exit.is_done.snapshot(self),
+                    }
+                }),
+                flex_factors: r.flex_factors.as_ref().map(|factors| crate::layout::FlexFactors {
+                    grow: factors.grow.snapshot(self),
+                    shrink: factors.shrink.snapshot(self),
                 }),
             });
 
@@ -558,6 +583,12 @@ fn snapshot_binding_expression(
                         self.create_and_snapshot_element(element),
                     )
                 }
+                object_tree::PropertyAnimation::Keyframes { animation, keyframes } => {
+                    object_tree::PropertyAnimation::Keyframes {
+                        animation: self.create_and_snapshot_element(animation),
+                        keyframes: self.snapshot_keyframes(keyframes),
+                    }
+                }
                 object_tree::PropertyAnimation::Transition { state_ref, animations } => {
                     object_tree::PropertyAnimation::Transition {
                         state_ref: self.snapshot_expression(state_ref),
@@ -567,6 +598,7 @@ fn snapshot_binding_expression(
                                 state_id: tpa.state_id,
                                 direction: tpa.direction,
                                 animation: self.create_and_snapshot_element(&tpa.animation),
+                                keyframes: self.snapshot_keyframes(&tpa.keyframes),
                             })
                             .collect(),
                     }
@@ -637,6 +669,7 @@ fn snapshot_popup_window(
             y: popup_window.y.snapshot(self),
             close_policy: popup_window.close_policy.clone(),
             parent_element: self.use_element(&popup_window.parent_element),
+            secondary_window: popup_window.secondary_window,
         }
     }
 
@@ -678,6 +711,20 @@ fn snapshot_layout_constraints(
         }
     }
 
+    fn snapshot_keyframes(
+        &mut self,
+        keyframes: &[object_tree::Keyframe],
+    ) -> Vec<object_tree::Keyframe> {
+        keyframes
+            .iter()
+            .map(|kf| object_tree::Keyframe {
+                offset: kf.offset,
+                value: self.snapshot_expression(&kf.value),
+                easing: kf.easing.as_ref().map(|e| self.snapshot_expression(e)),
+            })
+            .collect()
+    }
+
     fn snapshot_expression(
         &mut self,
         expr: &expression_tree::Expression,
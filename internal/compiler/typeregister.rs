@@ -38,6 +38,8 @@
     ("preferred-height", Type::LogicalLength),
     ("horizontal-stretch", Type::Float32),
     ("vertical-stretch", Type::Float32),
+    ("flex-grow", Type::Float32),
+    ("flex-shrink", Type::Float32),
 ];
 
 pub const RESERVED_GRIDLAYOUT_PROPERTIES: &[(&str, Type)] = &[
@@ -488,6 +490,28 @@ struct $Name:ident {
             _ => unreachable!(),
         };
 
+        match &mut register.elements.get_mut("SecondaryWindow").unwrap() {
+            ElementType::Builtin(ref mut b) => {
+                let window = Rc::get_mut(b).unwrap();
+                window.properties.insert(
+                    "show".into(),
+                    BuiltinPropertyInfo::from(BuiltinFunction::ShowSecondaryWindow),
+                );
+
+                window.properties.insert(
+                    "close".into(),
+                    BuiltinPropertyInfo::from(BuiltinFunction::CloseSecondaryWindow),
+                );
+
+                window.properties.get_mut("transient").unwrap().property_visibility =
+                    PropertyVisibility::Constexpr;
+
+                window.properties.get_mut("modal").unwrap().property_visibility =
+                    PropertyVisibility::Constexpr;
+            }
+            _ => unreachable!(),
+        };
+
         let font_metrics_prop = crate::langtype::BuiltinPropertyInfo {
             ty: font_metrics_type(),
             property_visibility: PropertyVisibility::Output,
@@ -515,6 +539,22 @@ struct $Name:ident {
             _ => unreachable!(),
         };
 
+        match &mut register.elements.get_mut("Flickable").unwrap() {
+            ElementType::Builtin(ref mut b) => {
+                let flickable = Rc::get_mut(b).unwrap();
+                flickable.properties.insert(
+                    "scroll-to".into(),
+                    BuiltinPropertyInfo::from(BuiltinFunction::FlickableScrollTo),
+                );
+                flickable.properties.insert(
+                    "ensure-visible".into(),
+                    BuiltinPropertyInfo::from(BuiltinFunction::FlickableEnsureVisible),
+                );
+            }
+
+            _ => unreachable!(),
+        };
+
         match &mut register.elements.get_mut("Text").unwrap() {
             ElementType::Builtin(ref mut b) => {
                 let text = Rc::get_mut(b).unwrap();
@@ -159,9 +159,97 @@ pub enum EasingCurve {
     EaseOutBounce,
     /// Easing curve as defined at: <https://easings.net/#easeInOutBounce>
     EaseInOutBounce,
+    /// A damped spring with its stiffness, damping and mass.
+    /// When used in a property animation, the duration is given by the physics of the spring
+    /// and the velocity is preserved when the animation is retargeted.
+    Spring([f32; 3]),
     // Custom(Box<dyn Fn(f32) -> f32>),
 }
 
+/// A damped harmonic oscillator that moves the progress of an animation from 0 towards 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Spring {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+}
+
+impl Spring {
+    /// Distance to the target (in progress unit) under which the spring is considered at rest
+    const REST_THRESHOLD: f32 = 0.001;
+
+    /// Returns None if any of the parameters is not strictly positive
+    pub(crate) fn new([stiffness, damping, mass]: [f32; 3]) -> Option<Self> {
+        (stiffness > 0. && damping > 0. && mass > 0.).then_some(Self { stiffness, damping, mass })
+    }
+
+    /// Natural angular frequency
+    fn omega0(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    /// Damping ratio
+    fn zeta(&self) -> f32 {
+        self.damping / (2. * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Returns the displacement from the target and its derivative after `t` seconds,
+    /// starting at a displacement of 1 while moving towards the target with `initial_velocity`.
+    fn displacement(&self, initial_velocity: f32, t: f32) -> (f32, f32) {
+        let omega0 = self.omega0();
+        let zeta = self.zeta();
+        let x0 = 1.;
+        let v0 = -initial_velocity;
+        if (zeta - 1.).abs() < 1e-3 {
+            // critically damped
+            let b = v0 + omega0 * x0;
+            let e = (-omega0 * t).exp();
+            (e * (x0 + b * t), e * (b - omega0 * (x0 + b * t)))
+        } else if zeta < 1. {
+            // under damped
+            let alpha = zeta * omega0;
+            let omega_d = omega0 * (1. - zeta * zeta).sqrt();
+            let a = x0;
+            let b = (v0 + alpha * x0) / omega_d;
+            let e = (-alpha * t).exp();
+            let (sin, cos) = (omega_d * t).sin_cos();
+            (
+                e * (a * cos + b * sin),
+                e * ((b * omega_d - alpha * a) * cos - (a * omega_d + alpha * b) * sin),
+            )
+        } else {
+            // over damped
+            let root = (zeta * zeta - 1.).sqrt();
+            let r1 = -omega0 * (zeta - root);
+            let r2 = -omega0 * (zeta + root);
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+
+    /// Returns the progress (0 at the start, 1 at the target) and the velocity of the progress
+    /// (per second) after `t` seconds, or None if the spring has come to rest.
+    pub(crate) fn state_at(&self, initial_velocity: f32, t: f32) -> Option<(f32, f32)> {
+        let (x, v) = self.displacement(initial_velocity, t);
+        if x.abs() < Self::REST_THRESHOLD && v.abs() < Self::REST_THRESHOLD * self.omega0() {
+            None
+        } else {
+            Some((1. - x, -v))
+        }
+    }
+
+    /// The time in seconds it takes for the envelope of a spring at rest to decay under the rest threshold
+    fn settling_time(&self) -> f32 {
+        let omega0 = self.omega0();
+        let zeta = self.zeta();
+        let decay =
+            if zeta < 1. { zeta * omega0 } else { omega0 * (zeta - (zeta * zeta - 1.).sqrt()) };
+        (1. / Self::REST_THRESHOLD).ln() / decay
+    }
+}
+
 /// Represent an instant, in milliseconds since the AnimationDriver's initial_instant
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Ord, PartialOrd, Eq)]
@@ -374,6 +462,14 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                 (1.0 + ease_out_bounce_curve(2.0 * value - 1.0)) / 2.0
             }
         }
+        EasingCurve::Spring(params) => match Spring::new(*params) {
+            // Outside of a property animation, the spring is scaled to fit in the duration
+            Some(spring) if value < 1.0 => {
+                1.0 - spring.displacement(0., value * spring.settling_time()).0
+            }
+            Some(_) => 1.0,
+            None => value,
+        },
     }
 }
 
@@ -650,6 +650,37 @@ pub fn try_dispatch_event(
                     ..Default::default()
                 })
             }
+            crate::platform::WindowEvent::PhysicalKeyPressed { text, physical_key, location } => {
+                self.0.process_key_input(crate::input::KeyEvent {
+                    text,
+                    physical_key,
+                    location,
+                    repeat: false,
+                    event_type: KeyEventType::KeyPressed,
+                    ..Default::default()
+                })
+            }
+            crate::platform::WindowEvent::PhysicalKeyPressRepeated {
+                text,
+                physical_key,
+                location,
+            } => self.0.process_key_input(crate::input::KeyEvent {
+                text,
+                physical_key,
+                location,
+                repeat: true,
+                event_type: KeyEventType::KeyPressed,
+                ..Default::default()
+            }),
+            crate::platform::WindowEvent::PhysicalKeyReleased { text, physical_key, location } => {
+                self.0.process_key_input(crate::input::KeyEvent {
+                    text,
+                    physical_key,
+                    location,
+                    event_type: KeyEventType::KeyReleased,
+                    ..Default::default()
+                })
+            }
             crate::platform::WindowEvent::ScaleFactorChanged { scale_factor } => {
                 self.0.set_scale_factor(scale_factor);
             }
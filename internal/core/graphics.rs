@@ -151,6 +151,11 @@ pub struct FontRequest {
     pub letter_spacing: Option<LogicalLength>,
     /// Whether to select an italic face of the font family.
     pub italic: bool,
+    /// The distance between the tops of two consecutive lines of text. If None, the height of the font is
+    /// used. Like `letter_spacing`, this is not submitted to the font-subsystem.
+    pub line_height: Option<LogicalLength>,
+    /// The additional space between paragraphs, i.e. after each line that ends with a line break.
+    pub paragraph_spacing: Option<LogicalLength>,
 }
 
 #[cfg(feature = "shared-fontdb")]
@@ -263,6 +263,8 @@ pub fn line_for_angle(angle: f32, size: Size2D<f32>) -> (Point2D<f32>, Point2D<f
     }
 }
 
+/// Like colors, brushes don't have a [`distance`](InterpolatedPropertyValue::distance), so a spring
+/// animation of a brush that is retargeted restarts from rest.
 impl InterpolatedPropertyValue for Brush {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         match (self, target_value) {
@@ -316,6 +316,9 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
     }
 }
 
+/// Colors don't have a [`distance`](InterpolatedPropertyValue::distance): they have several channels,
+/// and a single signed distance can't tell in which direction they move. So a spring animation
+/// of a color that is retargeted restarts from rest.
 impl InterpolatedPropertyValue for Color {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         target_value.mix(self, t)
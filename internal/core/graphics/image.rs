@@ -49,6 +49,11 @@ pub struct OpaqueImageVTable {
     pub static NINE_SLICE_VT for NineSliceImage
 }
 
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped animated image helper struct.
+    pub static ANIMATED_IMAGE_VT for AnimatedImage
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -284,6 +289,16 @@ pub struct StaticTextures {
     pub textures: Slice<'static, StaticTexture>,
 }
 
+/// A frame of an animated image that is stored in read-only memory
+#[repr(C)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct StaticAnimationFrame {
+    /// The textures of the frame
+    pub textures: StaticTextures,
+    /// How long the frame is shown, in milliseconds
+    pub duration: u32,
+}
+
 /// A struct that provides a path as a string as well as the last modification
 /// time of the file it points to.
 #[derive(PartialEq, Eq, Debug, Hash, Clone)]
@@ -323,6 +338,12 @@ pub enum ImageCacheKey {
     URL(SharedString) = 2,
     /// The image is identified by the static address of its encoded data.
     EmbeddedData(usize) = 3,
+    /// A frame of an animated image that is identified by its path, the last modification time stamp,
+    /// and the index of the frame.
+    PathFrame(CachedPath, u32) = 4,
+    /// A frame of an animated image that is identified by the static address of its encoded data and
+    /// the index of the frame.
+    EmbeddedDataFrame(usize, u32) = 5,
 }
 
 impl ImageCacheKey {
@@ -345,6 +366,7 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             ImageInner::NineSlice(nine) => vtable::VRc::borrow(nine).cache_key(),
             #[cfg(feature = "unstable-wgpu-24")]
             ImageInner::WGPUTexture(..) => return None,
+            ImageInner::AnimatedImage(animated) => vtable::VRc::borrow(animated).cache_key(),
         };
         if matches!(key, ImageCacheKey::Invalid) {
             None
@@ -357,6 +379,15 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
     pub fn from_embedded_image_data(data: &'static [u8]) -> Self {
         Self::EmbeddedData(data.as_ptr() as usize)
     }
+
+    /// Returns the cache key of the frame at the given index of the animated image that has this key.
+    pub fn animation_frame(&self, index: u32) -> Self {
+        match self {
+            Self::Path(path) => Self::PathFrame(path.clone(), index),
+            Self::EmbeddedData(address) => Self::EmbeddedDataFrame(*address, index),
+            _ => Self::Invalid,
+        }
+    }
 }
 
 /// Represent a nine-slice image with the base image and the 4 borders
@@ -378,6 +409,51 @@ fn cache_key(&self) -> ImageCacheKey {
     }
 }
 
+/// An image made of several frames that are shown one after the other, such as an animated GIF.
+///
+/// The `Image` element picks the frame to show according to its `current-frame` property.
+/// Used on its own, the image shows its first frame.
+pub struct AnimatedImage {
+    frames: alloc::vec::Vec<(ImageInner, core::time::Duration)>,
+    cache_key: ImageCacheKey,
+}
+
+impl AnimatedImage {
+    /// Creates an animated image from its frames and how long each of them is shown.
+    /// There must be at least one frame.
+    pub fn new(
+        frames: alloc::vec::Vec<(ImageInner, core::time::Duration)>,
+        cache_key: ImageCacheKey,
+    ) -> Self {
+        assert!(!frames.is_empty());
+        Self { frames, cache_key }
+    }
+
+    /// Returns the number of frames
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the frame at the given index, wrapping around after the last frame
+    pub fn frame(&self, index: usize) -> Image {
+        Image(self.frames[index % self.frames.len()].0.clone())
+    }
+
+    /// Returns how long the frame at the given index is shown, wrapping around after the last frame
+    pub fn frame_duration(&self, index: usize) -> core::time::Duration {
+        self.frames[index % self.frames.len()].1
+    }
+}
+
+impl OpaqueImage for AnimatedImage {
+    fn size(&self) -> IntSize {
+        self.frames[0].0.size()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        self.cache_key.clone()
+    }
+}
+
 /// Represents a `wgpu::Texture` for each version of WGPU we support.
 #[cfg(feature = "unstable-wgpu-24")]
 #[derive(Clone, Debug)]
@@ -428,6 +504,7 @@ pub enum ImageInner {
     NineSlice(vtable::VRc<OpaqueImageVTable, NineSliceImage>) = 7,
     #[cfg(feature = "unstable-wgpu-24")]
     WGPUTexture(WGPUTexture) = 8,
+    AnimatedImage(vtable::VRc<OpaqueImageVTable, AnimatedImage>) = 9,
 }
 
 impl ImageInner {
@@ -516,6 +593,9 @@ pub fn render_to_buffer(
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
             ImageInner::NineSlice(nine) => nine.0.render_to_buffer(None),
+            ImageInner::AnimatedImage(animated) => {
+                animated.frames[0].0.render_to_buffer(_target_size_for_scalable_source)
+            }
             _ => None,
         }
     }
@@ -547,6 +627,7 @@ pub fn size(&self) -> IntSize {
             ImageInner::NineSlice(nine) => nine.0.size(),
             #[cfg(feature = "unstable-wgpu-24")]
             ImageInner::WGPUTexture(texture) => texture.size(),
+            ImageInner::AnimatedImage(animated) => vtable::VRc::borrow(animated).size(),
         }
     }
 }
@@ -567,6 +648,7 @@ fn eq(&self, other: &Self) -> bool {
             #[cfg(not(target_arch = "wasm32"))]
             (Self::BorrowedOpenGLTexture(l0), Self::BorrowedOpenGLTexture(r0)) => l0 == r0,
             (Self::NineSlice(l), Self::NineSlice(r)) => l.0 == r.0 && l.1 == r.1,
+            (Self::AnimatedImage(l0), Self::AnimatedImage(r0)) => vtable::VRc::ptr_eq(l0, r0),
             _ => false,
         }
     }
@@ -916,6 +998,12 @@ pub fn path(&self) -> Option<&std::path::Path> {
                 } => Some(std::path::Path::new(path.as_str())),
                 _ => None,
             },
+            ImageInner::AnimatedImage(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. }) => {
+                    Some(std::path::Path::new(path.as_str()))
+                }
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1000,6 +1088,27 @@ pub fn load_image_from_embedded_data(data: Slice<'static, u8>, format: Slice<'_,
     })
 }
 
+/// Creates an animated image from frames embedded in the binary.
+/// This is called by the generated code.
+pub fn image_from_static_animation_frames(frames: &'static [StaticAnimationFrame]) -> Image {
+    match frames {
+        [] => Image::default(),
+        [frame] => Image(ImageInner::StaticTextures(&frame.textures)),
+        _ => Image(ImageInner::AnimatedImage(vtable::VRc::new(AnimatedImage::new(
+            frames
+                .iter()
+                .map(|frame| {
+                    (
+                        ImageInner::StaticTextures(&frame.textures),
+                        core::time::Duration::from_millis(frame.duration as u64),
+                    )
+                })
+                .collect(),
+            ImageCacheKey::from_embedded_image_data(frames[0].textures.data.as_slice()),
+        )))),
+    }
+}
+
 #[test]
 fn test_image_size_from_buffer_without_backend() {
     {
@@ -1351,6 +1460,10 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
                 },
                 _ => None,
             },
+            ImageInner::AnimatedImage(animated) => match &animated.cache_key {
+                ImageCacheKey::Path(CachedPath { path, .. }) => Some(path),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1363,6 +1476,14 @@ pub extern "C" fn slint_image_path(image: &Image) -> Option<&SharedString> {
         core::ptr::write(image, Image::from(ImageInner::StaticTextures(textures)));
     }
 
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_image_from_static_animation_frames(
+        frames: Slice<'static, StaticAnimationFrame>,
+        image: *mut Image,
+    ) {
+        core::ptr::write(image, image_from_static_animation_frames(frames.as_slice()));
+    }
+
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_image_compare_equal(image1: &Image, image2: &Image) -> bool {
         image1.eq(image2)
@@ -30,6 +30,9 @@ fn weight(&self, _key: &ImageCacheKey, value: &ImageInner) -> usize {
             ImageInner::NineSlice(nine) => self.weight(_key, &nine.0),
             #[cfg(feature = "unstable-wgpu-24")]
             ImageInner::WGPUTexture(..) => 0, // The texture is imported from the application and will never reside in our cache.
+            ImageInner::AnimatedImage(animated) => {
+                animated.frames.iter().map(|(frame, _)| self.weight(_key, frame)).sum()
+            }
         }
     }
 }
@@ -97,6 +100,18 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
                 )));
             }
 
+            if let Some(format) = image::ImageFormat::from_path(path.as_str())
+                .ok()
+                .filter(|format| i_slint_common::animated_image::is_animated_format(*format))
+            {
+                if let Some(animated) = std::fs::read(path.as_str())
+                    .ok()
+                    .and_then(|data| decode_animated_image(&data, format, &cache_key))
+                {
+                    return Some(animated);
+                }
+            }
+
             image::open(std::path::Path::new(&path.as_str())).map_or_else(
                 |decode_err| {
                     crate::debug_log!("Error loading image from {}: {}", &path, decode_err);
@@ -135,6 +150,12 @@ pub(crate) fn load_image_from_embedded_data(
             let format = std::str::from_utf8(format.as_slice())
                 .ok()
                 .and_then(image::ImageFormat::from_extension);
+            if let Some(animated) = format
+                .or_else(|| image::guess_format(data.as_slice()).ok())
+                .and_then(|format| decode_animated_image(data.as_slice(), format, &cache_key))
+            {
+                return Some(animated);
+            }
             let maybe_image = if let Some(format) = format {
                 image::load_from_memory_with_format(data.as_slice(), format)
             } else {
@@ -155,6 +176,38 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
+/// Decodes all the frames of an animated GIF, APNG, or WebP image.
+/// Returns None if the image has a single frame, so that it's loaded as a still image instead.
+fn decode_animated_image(
+    data: &[u8],
+    format: image::ImageFormat,
+    cache_key: &ImageCacheKey,
+) -> Option<ImageInner> {
+    let frames = i_slint_common::animated_image::decode_animation_frames(data, format)
+        .map_err(|decode_err| {
+            crate::debug_log!("Error decoding animated image: {}", decode_err);
+        })
+        .ok()??;
+    let frames = frames
+        .into_iter()
+        .enumerate()
+        .map(|(index, (buffer, duration))| {
+            let buffer = SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
+                buffer.as_raw(),
+                buffer.width(),
+                buffer.height(),
+            ));
+            // Each frame has its own key, so that the renderers cache a texture for each of them.
+            let cache_key = cache_key.animation_frame(index as u32);
+            (ImageInner::EmbeddedImage { cache_key, buffer }, duration)
+        })
+        .collect();
+    Some(ImageInner::AnimatedImage(vtable::VRc::new(super::AnimatedImage::new(
+        frames,
+        cache_key.clone(),
+    ))))
+}
+
 fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
@@ -226,4 +279,33 @@ fn test_path_cache_invalidation() {
             .iter()
             .all(|pixel| *pixel == Rgba8Pixel { r: 0, g: 255, b: 0, a: 255 }));
     }
+
+    #[cfg(feature = "image-default-formats")]
+    #[test]
+    fn test_load_animated_gif() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_path = temp_dir.path().join("animated.gif");
+
+        let frame = |color, delay_ms| {
+            image::Frame::from_parts(
+                image::RgbaImage::from_pixel(4, 4, image::Rgba(color)),
+                0,
+                0,
+                image::Delay::from_numer_denom_ms(delay_ms, 1),
+            )
+        };
+        image::codecs::gif::GifEncoder::new(std::fs::File::create(&test_path).unwrap())
+            .encode_frames([frame([255, 0, 0, 255], 100), frame([0, 255, 0, 255], 200)])
+            .unwrap();
+
+        let image = crate::graphics::Image::load_from_path(&test_path).unwrap();
+        assert_eq!(image.size(), crate::graphics::IntSize::new(4, 4));
+        assert_eq!(image.path(), Some(test_path.as_path()));
+        let crate::graphics::ImageInner::AnimatedImage(animated) = &image.0 else {
+            panic!("The image should be animated");
+        };
+        assert_eq!(animated.frame_count(), 2);
+        assert_eq!(animated.frame_duration(0), std::time::Duration::from_millis(100));
+        assert_eq!(animated.frame_duration(1), std::time::Duration::from_millis(200));
+    }
 }
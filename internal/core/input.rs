@@ -8,7 +8,7 @@
 use crate::item_tree::ItemTreeRc;
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
 pub use crate::items::PointerEventButton;
-pub use crate::items::{FocusReason, KeyEvent, KeyboardModifiers};
+pub use crate::items::{FocusReason, KeyEvent, KeyLocation, KeyboardModifiers, PhysicalKey};
 use crate::items::{ItemRef, TextCursorDirection};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
@@ -277,6 +277,44 @@ pub enum KeyEventType {
     CommitComposition = 3,
 }
 
+macro_rules! declare_physical_key_conversions {
+    ($($name:ident # $evdev:literal # $winit:ident ;)*) => {
+        /// Returns the physical key for a Linux evdev key code (as found in `linux/input-event-codes.h`).
+        ///
+        /// Note that X11 and XKB key codes are the evdev key codes plus 8.
+        pub fn physical_key_from_evdev_code(code: u32) -> PhysicalKey {
+            match code {
+                $($evdev => PhysicalKey::$name,)*
+                _ => PhysicalKey::Unidentified,
+            }
+        }
+
+        /// Returns the physical key for a `code` value of the W3C UI Events, such as `"KeyW"` or `"ShiftLeft"`.
+        pub fn physical_key_from_code(code: &str) -> PhysicalKey {
+            match code {
+                $(stringify!($name) => PhysicalKey::$name,)*
+                _ => PhysicalKey::Unidentified,
+            }
+        }
+    };
+}
+
+i_slint_common::for_each_physical_keys!(declare_physical_key_conversions);
+
+/// Returns the location of a physical key on the keyboard.
+/// For example [`PhysicalKey::ShiftRight`] is [`KeyLocation::Right`] and [`PhysicalKey::NumpadEnter`] is [`KeyLocation::Numpad`].
+pub fn key_location_from_physical_key(physical_key: PhysicalKey) -> KeyLocation {
+    use PhysicalKey::*;
+    match physical_key {
+        ShiftLeft | ControlLeft | AltLeft | MetaLeft => KeyLocation::Left,
+        ShiftRight | ControlRight | AltRight | MetaRight => KeyLocation::Right,
+        Numpad0 | Numpad1 | Numpad2 | Numpad3 | Numpad4 | Numpad5 | Numpad6 | Numpad7 | Numpad8
+        | Numpad9 | NumpadAdd | NumpadSubtract | NumpadMultiply | NumpadDivide | NumpadDecimal
+        | NumpadEqual | NumpadEnter => KeyLocation::Numpad,
+        _ => KeyLocation::Standard,
+    }
+}
+
 impl KeyEvent {
     /// If a shortcut was pressed, this function returns `Some(StandardShortcut)`.
     /// Otherwise it returns None.
@@ -417,6 +455,24 @@ pub enum StandardShortcut {
     Refresh,
 }
 
+impl StandardShortcut {
+    /// The name of the shortcut, as in [`i_slint_common::shortcuts::STANDARD_SHORTCUTS`]
+    pub fn name(&self) -> &'static str {
+        match self {
+            StandardShortcut::Copy => "Copy",
+            StandardShortcut::Cut => "Cut",
+            StandardShortcut::Paste => "Paste",
+            StandardShortcut::SelectAll => "SelectAll",
+            StandardShortcut::Find => "Find",
+            StandardShortcut::Save => "Save",
+            StandardShortcut::Print => "Print",
+            StandardShortcut::Undo => "Undo",
+            StandardShortcut::Redo => "Redo",
+            StandardShortcut::Refresh => "Refresh",
+        }
+    }
+}
+
 /// Shortcuts that are used when editing text
 pub enum TextShortcut {
     /// Move the cursor
@@ -911,3 +967,22 @@ pub fn stop(&self) {
         self.cursor_blink_timer.stop()
     }
 }
+
+#[test]
+fn test_physical_key() {
+    assert_eq!(physical_key_from_evdev_code(17), PhysicalKey::KeyW);
+    assert_eq!(physical_key_from_evdev_code(125), PhysicalKey::MetaLeft);
+    assert_eq!(physical_key_from_evdev_code(0), PhysicalKey::Unidentified);
+    assert_eq!(physical_key_from_code("NumpadEnter"), PhysicalKey::NumpadEnter);
+    assert_eq!(physical_key_from_code("Digit0"), PhysicalKey::Digit0);
+    assert_eq!(physical_key_from_code("Unknown"), PhysicalKey::Unidentified);
+    assert_eq!(physical_key_from_code(""), PhysicalKey::Unidentified);
+
+    assert_eq!(key_location_from_physical_key(PhysicalKey::ShiftLeft), KeyLocation::Left);
+    assert_eq!(key_location_from_physical_key(PhysicalKey::ControlRight), KeyLocation::Right);
+    assert_eq!(key_location_from_physical_key(PhysicalKey::NumpadEnter), KeyLocation::Numpad);
+    assert_eq!(key_location_from_physical_key(PhysicalKey::Numpad7), KeyLocation::Numpad);
+    assert_eq!(key_location_from_physical_key(PhysicalKey::Enter), KeyLocation::Standard);
+    assert_eq!(key_location_from_physical_key(PhysicalKey::ArrowLeft), KeyLocation::Standard);
+    assert_eq!(key_location_from_physical_key(PhysicalKey::Unidentified), KeyLocation::Standard);
+}
@@ -14,8 +14,9 @@
     LogicalSize, LogicalVector, SizeLengths,
 };
 use crate::properties::PropertyTracker;
+use crate::styled_text::StyledText;
 use crate::window::{WindowAdapter, WindowInner};
-use crate::{Brush, Coord, SharedString};
+use crate::{Brush, Color, Coord, SharedString};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use core::cell::{Cell, RefCell};
@@ -310,6 +311,38 @@ pub trait RenderImage {
     fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling);
 }
 
+/// The selected part of a [`RenderText`] and the colors to highlight it with.
+pub struct TextSelection {
+    /// The selected range, as byte offsets within the text.
+    pub range: core::ops::Range<usize>,
+    /// The color of the selected text.
+    pub foreground: Color,
+    /// The background color of the selected text.
+    pub background: Color,
+}
+
+/// The lines to draw along a [`RenderText`], such as the underline.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextDecoration {
+    /// Draw a line below the baseline.
+    pub underline: bool,
+    /// Draw a line above the text.
+    pub overline: bool,
+    /// Draw a line through the middle of the text.
+    pub strikethrough: bool,
+    /// The style of the lines.
+    pub style: TextDecorationStyle,
+    /// The color of the lines. Transparent means that the text color is used.
+    pub color: Color,
+}
+
+impl TextDecoration {
+    /// Returns true if no line needs to be drawn.
+    pub fn is_empty(&self) -> bool {
+        !self.underline && !self.overline && !self.strikethrough
+    }
+}
+
 /// Trait for an item that represents an Text towards the renderer
 #[allow(missing_docs)]
 pub trait RenderText {
@@ -322,6 +355,18 @@ pub trait RenderText {
     fn overflow(self: Pin<&Self>) -> TextOverflow;
     fn letter_spacing(self: Pin<&Self>) -> LogicalLength;
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle);
+    /// Returns the styled text to render instead of the plain [`Self::text`], when the text is markup.
+    fn styled_text(self: Pin<&Self>) -> Option<Rc<StyledText>> {
+        None
+    }
+    /// Returns the selection to highlight, if the text is selectable and a part of it is selected.
+    fn selection(self: Pin<&Self>) -> Option<TextSelection> {
+        None
+    }
+    /// Returns the lines to draw along the text.
+    fn decoration(self: Pin<&Self>) -> TextDecoration {
+        TextDecoration::default()
+    }
 
     fn text_bounding_rect(
         self: Pin<&Self>,
@@ -330,22 +375,27 @@ fn text_bounding_rect(
         mut geometry: euclid::Rect<f32, crate::lengths::LogicalPx>,
     ) -> euclid::Rect<f32, crate::lengths::LogicalPx> {
         let window_inner = WindowInner::from_pub(window_adapter.window());
-        let text_string = self.text();
         let font_request = self.font_request(self_rc);
         let scale_factor = crate::lengths::ScaleFactor::new(window_inner.scale_factor());
         let max_width = geometry.size.width_length();
-        geometry.size = geometry.size.max(
-            window_adapter
-                .renderer()
-                .text_size(
-                    font_request.clone(),
-                    text_string.as_str(),
-                    Some(max_width.cast()),
-                    scale_factor,
-                    self.wrap(),
-                )
-                .cast(),
-        );
+        let renderer = window_adapter.renderer();
+        let text_size = match self.styled_text() {
+            Some(styled_text) => renderer.styled_text_size(
+                font_request,
+                &styled_text,
+                Some(max_width.cast()),
+                scale_factor,
+                self.wrap(),
+            ),
+            None => renderer.text_size(
+                font_request,
+                self.text().as_str(),
+                Some(max_width.cast()),
+                scale_factor,
+                self.wrap(),
+            ),
+        };
+        geometry.size = geometry.size.max(text_size.cast());
         geometry
     }
 }
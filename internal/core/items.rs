@@ -20,7 +20,7 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
-use crate::graphics::{Brush, Color, FontRequest};
+use crate::graphics::{Brush, Color, FontRequest, Image};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
     KeyEventType, MouseEvent,
@@ -48,6 +48,7 @@
 pub use self::component_container::*;
 mod flickable;
 pub use flickable::Flickable;
+pub(crate) use flickable::{scroll_item_into_view, viewport_position_showing};
 mod text;
 pub use text::*;
 mod input_items;
@@ -69,6 +70,7 @@
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
 type PointArg = (crate::api::LogicalPosition,);
+type StringArg = (SharedString,);
 type MenuEntryArg = (MenuEntry,);
 type MenuEntryModel = crate::model::ModelRc<MenuEntry>;
 
@@ -613,6 +615,10 @@ fn slint_get_TouchAreaVTable() -> TouchAreaVTable for TouchArea
     fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
 }
 
+declare_item_vtable! {
+    fn slint_get_ShortcutVTable() -> ShortcutVTable for Shortcut
+}
+
 declare_item_vtable! {
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
@@ -1286,6 +1292,8 @@ pub fn resolved_font_request(
             },
             letter_spacing: Some(local_letter_spacing),
             italic: local_italic,
+            line_height: None,
+            paragraph_spacing: None,
         }
     }
 }
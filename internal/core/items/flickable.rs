@@ -50,6 +50,15 @@ pub struct Flickable {
 
     pub interactive: Property<bool>,
 
+    pub bounce: Property<bool>,
+    pub snap_interval_x: Property<LogicalLength>,
+    pub snap_interval_y: Property<LogicalLength>,
+    pub flick_duration: Property<i64>,
+    pub max_flick_velocity: Property<f32>,
+
+    pub dragging: Property<bool>,
+    pub moving: Property<bool>,
+
     pub flicked: Callback<VoidArg>,
 
     data: FlickableDataBox,
@@ -67,6 +76,11 @@ fn init(self: Pin<&Self>, self_rc: &ItemRc) {
                 let Some(flick_rc) = self_weak.upgrade() else { return false };
                 let Some(flick) = flick_rc.downcast::<Flickable>() else { return false };
                 let flick = flick.as_pin_ref();
+                // While the user drags or the viewport is animated, it is allowed to be
+                // out of bounds. It is clamped when it stops moving.
+                if flick.moving() {
+                    return false;
+                }
                 let geo = flick_rc.geometry();
                 let zero = LogicalLength::zero();
                 let vpx = flick.viewport_x();
@@ -196,6 +210,43 @@ fn clips_children(self: core::pin::Pin<&Self>) -> bool {
     }
 }
 
+impl Flickable {
+    /// Scrolls, with an animation, such that the point `pos` of the viewport is at the
+    /// top-left corner of the Flickable, or as close as possible without going out of bounds.
+    pub fn scroll_to(self: Pin<&Self>, pos: LogicalPoint, self_rc: &ItemRc) {
+        let target = ensure_in_bound(self, LogicalPoint::default() - pos.to_vector(), self_rc);
+        animate_viewport_to(self, target, self_rc);
+    }
+
+    /// Scrolls, with an animation, by the smallest amount such that `rect` (in viewport
+    /// coordinates) becomes visible.
+    ///
+    /// Returns the position the viewport is animated to.
+    pub fn ensure_visible(self: Pin<&Self>, rect: LogicalRect, self_rc: &ItemRc) -> LogicalPoint {
+        let geo = self_rc.geometry();
+        let target = ensure_in_bound(
+            self,
+            LogicalPoint::from_lengths(
+                viewport_position_showing(
+                    self.viewport_x(),
+                    rect.origin.x_length(),
+                    rect.width_length(),
+                    geo.width_length(),
+                ),
+                viewport_position_showing(
+                    self.viewport_y(),
+                    rect.origin.y_length(),
+                    rect.height_length(),
+                    geo.height_length(),
+                ),
+            ),
+            self_rc,
+        );
+        animate_viewport_to(self, target, self_rc);
+        target
+    }
+}
+
 impl ItemConsts for Flickable {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
         Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
@@ -242,11 +293,13 @@ struct FlickableDataInner {
     capture_events: bool,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default)]
 pub struct FlickableData {
     inner: RefCell<FlickableDataInner>,
     /// Tracker that tracks the property to make sure that the flickable is in bounds
     in_bound_change_handler: crate::properties::ChangeTracker,
+    /// Timer that resets the `moving` property once the viewport animation is over
+    moving_timer: crate::timers::Timer,
 }
 
 impl FlickableData {
@@ -273,7 +326,7 @@ fn handle_mouse_filter(
             }
             MouseEvent::Exit | MouseEvent::Released { button: PointerEventButton::Left, .. } => {
                 let was_capturing = inner.capture_events;
-                Self::mouse_released(&mut inner, flick, event, flick_rc);
+                self.mouse_released(&mut inner, flick, event, flick_rc);
                 if was_capturing {
                     InputEventFilterResult::Intercept
                 } else {
@@ -331,7 +384,7 @@ fn handle_mouse(
             }
             MouseEvent::Exit | MouseEvent::Released { .. } => {
                 let was_capturing = inner.capture_events;
-                Self::mouse_released(&mut inner, flick, event, flick_rc);
+                self.mouse_released(&mut inner, flick, event, flick_rc);
                 if was_capturing {
                     InputEventResult::EventAccepted
                 } else {
@@ -357,7 +410,11 @@ fn handle_mouse(
                     };
 
                     if inner.capture_events || should_capture() {
-                        let new_pos = ensure_in_bound(flick, new_pos, flick_rc);
+                        let new_pos = if flick.bounce() {
+                            rubber_band(flick, new_pos, flick_rc)
+                        } else {
+                            ensure_in_bound(flick, new_pos, flick_rc)
+                        };
 
                         let old_pos = (x.get(), y.get());
                         x.set(new_pos.x_length());
@@ -367,6 +424,9 @@ fn handle_mouse(
                         }
 
                         inner.capture_events = true;
+                        self.moving_timer.stop();
+                        (Flickable::FIELD_OFFSETS.dragging).apply_pin(flick).set(true);
+                        (Flickable::FIELD_OFFSETS.moving).apply_pin(flick).set(true);
                         InputEventResult::GrabMouse
                     } else if abs(x.get() - new_pos.x_length()) > DISTANCE_THRESHOLD
                         || abs(y.get() - new_pos.y_length()) > DISTANCE_THRESHOLD
@@ -410,42 +470,41 @@ fn handle_mouse(
     }
 
     fn mouse_released(
+        &self,
         inner: &mut FlickableDataInner,
         flick: Pin<&Flickable>,
         event: MouseEvent,
         flick_rc: &ItemRc,
     ) {
-        if let (Some(pressed_time), Some(pos)) = (inner.pressed_time, event.position()) {
-            let dist = (pos - inner.pressed_pos).cast::<f32>();
-
-            let millis = (crate::animations::current_tick() - pressed_time).as_millis();
-            if inner.capture_events
-                && dist.square_length() > (DISTANCE_THRESHOLD.get() * DISTANCE_THRESHOLD.get()) as _
-                && millis > 1
-            {
-                let speed = dist / (millis as f32);
-
-                let duration = 250;
-                let final_pos = ensure_in_bound(
-                    flick,
-                    (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast(),
-                    flick_rc,
-                );
-                let anim = PropertyAnimation {
-                    duration,
-                    easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
-                    ..PropertyAnimation::default()
-                };
-
-                let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
-                let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
-                let old_pos = (viewport_x.get(), viewport_y.get());
-                viewport_x.set_animated_value(final_pos.x_length(), anim.clone());
-                viewport_y.set_animated_value(final_pos.y_length(), anim);
-                if old_pos.0 != final_pos.x_length() || old_pos.1 != final_pos.y_length() {
-                    (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+        // Before animating, so that `moving` is reset if the viewport doesn't move anymore
+        (Flickable::FIELD_OFFSETS.dragging).apply_pin(flick).set(false);
+        if inner.capture_events {
+            let duration = flick.flick_duration().clamp(0, i32::MAX as i64) as i32;
+            let mut target = LogicalPoint::from_lengths(
+                (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
+                (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
+            );
+            if let (Some(pressed_time), Some(pos)) = (inner.pressed_time, event.position()) {
+                let dist = (pos - inner.pressed_pos).cast::<f32>();
+
+                let millis = (crate::animations::current_tick() - pressed_time).as_millis();
+                if dist.square_length() > (DISTANCE_THRESHOLD.get() * DISTANCE_THRESHOLD.get()) as _
+                    && millis > 1
+                {
+                    // in pixels per millisecond
+                    let mut speed = dist / (millis as f32);
+                    let max_speed = flick.max_flick_velocity() / 1000.;
+                    if max_speed > 0. && speed.length() > max_speed {
+                        speed = speed * (max_speed / speed.length());
+                    }
+                    target = (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32))
+                        .cast();
                 }
             }
+            let final_pos = ensure_in_bound(flick, snap_to_interval(flick, target), flick_rc);
+            if animate_viewport_to_with_duration(flick, final_pos, duration, flick_rc) {
+                (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+            }
         }
         inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
         inner.pressed_time = None;
@@ -469,6 +528,160 @@ fn ensure_in_bound(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -
     p.max(min).min(max)
 }
 
+/// Returns the point moved to the closest multiple of the `snap-interval-x` and
+/// `snap-interval-y` properties, for the axes where they are set.
+fn snap_to_interval(flick: Pin<&Flickable>, p: LogicalPoint) -> LogicalPoint {
+    LogicalPoint::from_lengths(
+        snap_length(p.x_length(), flick.snap_interval_x()),
+        snap_length(p.y_length(), flick.snap_interval_y()),
+    )
+}
+
+/// Returns the multiple of `interval` that is the closest to `pos`, or `pos` if the interval isn't positive.
+fn snap_length(pos: LogicalLength, interval: LogicalLength) -> LogicalLength {
+    if interval > LogicalLength::zero() {
+        LogicalLength::new((pos.get() / interval.get()).round() * interval.get())
+    } else {
+        pos
+    }
+}
+
+/// Like `ensure_in_bound`, but instead of clamping, let the point go past the bounds with
+/// a resistance that increases with the distance, up to at most the size of the Flickable.
+fn rubber_band(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -> LogicalPoint {
+    let geo = flick_rc.geometry();
+    let bounded = ensure_in_bound(flick, p, flick_rc);
+    LogicalPoint::from_lengths(
+        rubber_band_length(
+            p.x_length(),
+            bounded.x_length(),
+            geo.width_length(),
+            flick.viewport_width(),
+        ),
+        rubber_band_length(
+            p.y_length(),
+            bounded.y_length(),
+            geo.height_length(),
+            flick.viewport_height(),
+        ),
+    )
+}
+
+/// The position along one axis for `rubber_band`, where `bounded` is `pos` clamped within the bounds.
+/// There is no overshoot if the viewport isn't bigger than the Flickable of size `size`.
+fn rubber_band_length(
+    pos: LogicalLength,
+    bounded: LogicalLength,
+    size: LogicalLength,
+    viewport_size: LogicalLength,
+) -> LogicalLength {
+    let overshoot = (pos - bounded).get();
+    if viewport_size <= size || overshoot == 0. || size <= LogicalLength::zero() {
+        return bounded;
+    }
+    let size = size.get();
+    let distance = (1. - 1. / (overshoot.abs() * 0.55 / size + 1.)) * size;
+    bounded + LogicalLength::new(if overshoot < 0. { -distance } else { distance })
+}
+
+/// The duration of the animation of the programmatic scrolling
+const SCROLL_ANIMATION_DURATION: i32 = 250;
+
+fn animate_viewport_to(flick: Pin<&Flickable>, pos: LogicalPoint, flick_rc: &ItemRc) {
+    animate_viewport_to_with_duration(flick, pos, SCROLL_ANIMATION_DURATION, flick_rc);
+}
+
+/// Animates the viewport position to `pos`, setting the `moving` property for the duration
+/// of the animation if the position changes. Returns true if the position changes.
+fn animate_viewport_to_with_duration(
+    flick: Pin<&Flickable>,
+    pos: LogicalPoint,
+    duration: i32,
+    flick_rc: &ItemRc,
+) -> bool {
+    let anim = PropertyAnimation {
+        duration,
+        easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+        ..PropertyAnimation::default()
+    };
+
+    let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+    let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+    let changed = viewport_x.get() != pos.x_length() || viewport_y.get() != pos.y_length();
+    viewport_x.set_animated_value(pos.x_length(), anim.clone());
+    viewport_y.set_animated_value(pos.y_length(), anim);
+
+    let moving = (Flickable::FIELD_OFFSETS.moving).apply_pin(flick);
+    if duration > 0 && changed {
+        moving.set(true);
+        let self_weak = flick_rc.downgrade();
+        flick.data.moving_timer.start(
+            crate::timers::TimerMode::SingleShot,
+            Duration::from_millis(duration as u64),
+            move || {
+                let Some(flick_rc) = self_weak.upgrade() else { return };
+                let Some(flick) = flick_rc.downcast::<Flickable>() else { return };
+                let flick = flick.as_pin_ref();
+                (Flickable::FIELD_OFFSETS.moving).apply_pin(flick).set(flick.dragging());
+            },
+        );
+    } else {
+        flick.data.moving_timer.stop();
+        moving.set(flick.dragging());
+    }
+    changed
+}
+
+/// Returns the viewport position along one axis that scrolls by the smallest amount such that
+/// the item at `item_pos` with `item_size` is visible in a Flickable of size `visible_size`.
+///
+/// If the item is bigger than the Flickable and already covers it, the position is unchanged.
+pub(crate) fn viewport_position_showing(
+    viewport_pos: LogicalLength,
+    item_pos: LogicalLength,
+    item_size: LogicalLength,
+    visible_size: LogicalLength,
+) -> LogicalLength {
+    let zero = LogicalLength::zero();
+    let top = item_pos + viewport_pos;
+    let bottom = top + item_size;
+    if top < zero && bottom < visible_size {
+        -item_pos
+    } else if top > zero && bottom > visible_size {
+        (visible_size - item_pos - item_size).max(-item_pos)
+    } else {
+        viewport_pos
+    }
+}
+
+/// Scrolls all the Flickable ancestors of the item such that the item becomes visible.
+pub(crate) fn scroll_item_into_view(item: &ItemRc) {
+    use crate::item_tree::ParentItemTraversalMode;
+    let mut rect = item.geometry();
+    let Some(mut current) = item.parent_item(ParentItemTraversalMode::StopAtPopups) else {
+        return;
+    };
+    // `rect` is in the coordinates of `current`
+    while let Some(parent) = current.parent_item(ParentItemTraversalMode::StopAtPopups) {
+        let origin = match parent.downcast::<Flickable>() {
+            // `current` is the viewport of the Flickable
+            Some(flick) => {
+                let flick = flick.as_pin_ref();
+                // The viewport's geometry is normally at the viewport position, but the rows of
+                // a virtualized ListView are positioned relative to the Flickable and the viewport
+                // stays at 0 along that axis. Either way, this gives the rect in viewport coordinates.
+                let viewport_pos =
+                    LogicalPoint::from_lengths(flick.viewport_x(), flick.viewport_y());
+                rect = rect.translate(current.geometry().origin - viewport_pos);
+                flick.ensure_visible(rect, &parent)
+            }
+            None => current.geometry().origin,
+        };
+        rect = rect.translate(origin.to_vector());
+        current = parent;
+    }
+}
+
 /// # Safety
 /// This must be called using a non-null pointer pointing to a chunk of memory big enough to
 /// hold a FlickableDataBox
@@ -485,3 +698,89 @@ fn ensure_in_bound(flick: Pin<&Flickable>, p: LogicalPoint, flick_rc: &ItemRc) -
 pub unsafe extern "C" fn slint_flickable_data_free(data: *mut FlickableDataBox) {
     core::ptr::drop_in_place(data);
 }
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_flickable_scroll_to(
+    flickable: Pin<&Flickable>,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+    x: f32,
+    y: f32,
+) {
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    flickable.scroll_to(LogicalPoint::new(x as _, y as _), &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_flickable_ensure_visible(
+    flickable: Pin<&Flickable>,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+) {
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    flickable.ensure_visible(
+        LogicalRect::new(
+            LogicalPoint::new(x as _, y as _),
+            LogicalSize::new(width as _, height as _),
+        ),
+        &self_rc,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rubber_band_length, snap_length, viewport_position_showing};
+    use crate::lengths::LogicalLength;
+
+    #[test]
+    fn test_viewport_position_showing() {
+        let l = LogicalLength::new;
+        // Already visible
+        assert_eq!(viewport_position_showing(l(-100.), l(120.), l(20.), l(100.)), l(-100.));
+        // Above the visible area: align the top
+        assert_eq!(viewport_position_showing(l(-100.), l(50.), l(20.), l(100.)), l(-50.));
+        // Below the visible area: align the bottom
+        assert_eq!(viewport_position_showing(l(-100.), l(250.), l(20.), l(100.)), l(-170.));
+        // Bigger than the visible area: align the top
+        assert_eq!(viewport_position_showing(l(0.), l(250.), l(200.), l(100.)), l(-250.));
+        // Bigger than the visible area and covering it: unchanged
+        assert_eq!(viewport_position_showing(l(-300.), l(250.), l(200.), l(100.)), l(-300.));
+    }
+
+    #[test]
+    fn test_snap_length() {
+        let l = LogicalLength::new;
+        assert_eq!(snap_length(l(-130.), l(100.)), l(-100.));
+        assert_eq!(snap_length(l(-160.), l(100.)), l(-200.));
+        assert_eq!(snap_length(l(-200.), l(100.)), l(-200.));
+        // No interval
+        assert_eq!(snap_length(l(-130.), l(0.)), l(-130.));
+        assert_eq!(snap_length(l(-130.), l(-10.)), l(-130.));
+    }
+
+    #[test]
+    fn test_rubber_band_length() {
+        let l = LogicalLength::new;
+        // Within the bounds
+        assert_eq!(rubber_band_length(l(-50.), l(-50.), l(100.), l(300.)), l(-50.));
+        // The viewport isn't bigger than the Flickable
+        assert_eq!(rubber_band_length(l(30.), l(0.), l(100.), l(100.)), l(0.));
+        // Past the start: moves less than the overshoot, in the same direction
+        let p = rubber_band_length(l(30.), l(0.), l(100.), l(300.));
+        assert!(p > l(0.) && p < l(30.));
+        // Past the end
+        let p = rubber_band_length(l(-230.), l(-200.), l(100.), l(300.));
+        assert!(p < l(-200.) && p > l(-230.));
+        // The resistance increases with the distance, up to the size of the Flickable
+        let near = rubber_band_length(l(10.), l(0.), l(100.), l(300.));
+        let far = rubber_band_length(l(1000.), l(0.), l(100.), l(300.));
+        assert!(far - near < l(990.));
+        assert!(rubber_band_length(l(1e9), l(0.), l(100.), l(300.)) <= l(100.));
+    }
+}
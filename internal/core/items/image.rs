@@ -9,7 +9,7 @@
 */
 use super::{
     ImageFit, ImageHorizontalAlignment, ImageRendering, ImageTiling, ImageVerticalAlignment, Item,
-    ItemConsts, ItemRc, RenderingResult,
+    ItemConsts, ItemRc, ItemVTable, RenderingResult,
 };
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
@@ -23,10 +23,12 @@
 use crate::rtti::*;
 use crate::window::WindowAdapter;
 use crate::{Brush, Coord, Property};
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
 use i_slint_core_macros::*;
+use vtable::HasStaticVTable;
 
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
@@ -39,11 +41,16 @@ pub struct ImageItem {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub current_frame: Property<i32>,
     pub cached_rendering_data: CachedRenderingData,
+    data: ImageDataBox,
 }
 
 impl Item for ImageItem {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        init_frame_tracker::<Self>(&self.data, self_rc);
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -130,7 +137,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        displayed_frame(self.source(), self.current_frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -158,6 +165,22 @@ fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
     }
 }
 
+impl AnimatedImageItem for ImageItem {
+    fn animation(
+        self: Pin<&Self>,
+    ) -> (Pin<&Property<crate::graphics::Image>>, Pin<&Property<bool>>, Pin<&Property<i32>>) {
+        (
+            Self::FIELD_OFFSETS.source.apply_pin(self),
+            Self::FIELD_OFFSETS.playing.apply_pin(self),
+            Self::FIELD_OFFSETS.current_frame.apply_pin(self),
+        )
+    }
+
+    fn data(&self) -> &ImageData {
+        &self.data
+    }
+}
+
 impl ItemConsts for ImageItem {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<
         ImageItem,
@@ -176,6 +199,8 @@ pub struct ClippedImage {
     pub image_fit: Property<ImageFit>,
     pub image_rendering: Property<ImageRendering>,
     pub colorize: Property<Brush>,
+    pub playing: Property<bool>,
+    pub current_frame: Property<i32>,
     pub source_clip_x: Property<i32>,
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
@@ -187,10 +212,13 @@ pub struct ClippedImage {
     pub vertical_tiling: Property<ImageTiling>,
 
     pub cached_rendering_data: CachedRenderingData,
+    data: ImageDataBox,
 }
 
 impl Item for ClippedImage {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        init_frame_tracker::<Self>(&self.data, self_rc);
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -281,7 +309,7 @@ fn target_size(self: Pin<&Self>) -> LogicalSize {
     }
 
     fn source(self: Pin<&Self>) -> crate::graphics::Image {
-        self.source()
+        displayed_frame(self.source(), self.current_frame())
     }
 
     fn source_clip(self: Pin<&Self>) -> Option<crate::graphics::IntRect> {
@@ -314,9 +342,172 @@ fn tiling(self: Pin<&Self>) -> (ImageTiling, ImageTiling) {
     }
 }
 
+impl AnimatedImageItem for ClippedImage {
+    fn animation(
+        self: Pin<&Self>,
+    ) -> (Pin<&Property<crate::graphics::Image>>, Pin<&Property<bool>>, Pin<&Property<i32>>) {
+        (
+            Self::FIELD_OFFSETS.source.apply_pin(self),
+            Self::FIELD_OFFSETS.playing.apply_pin(self),
+            Self::FIELD_OFFSETS.current_frame.apply_pin(self),
+        )
+    }
+
+    fn data(&self) -> &ImageData {
+        &self.data
+    }
+}
+
 impl ItemConsts for ClippedImage {
     const cached_rendering_data_offset: const_field_offset::FieldOffset<
         ClippedImage,
         CachedRenderingData,
     > = ClippedImage::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
+
+/// Returns the image to render for the source of an image element. For an animated image, that's the
+/// frame at `current_frame`.
+fn displayed_frame(source: crate::graphics::Image, current_frame: i32) -> crate::graphics::Image {
+    match &source.0 {
+        crate::graphics::ImageInner::AnimatedImage(animated) => {
+            animated.frame(current_frame.rem_euclid(animated.frame_count() as i32) as usize)
+        }
+        _ => source,
+    }
+}
+
+/// An image item that can play an animated source
+trait AnimatedImageItem: HasStaticVTable<ItemVTable> + 'static {
+    /// Returns the `source`, `playing`, and `current-frame` properties
+    fn animation(
+        self: Pin<&Self>,
+    ) -> (Pin<&Property<crate::graphics::Image>>, Pin<&Property<bool>>, Pin<&Property<i32>>);
+
+    fn data(&self) -> &ImageData;
+}
+
+/// Tracks the frame of an animated source that the item shows while it's playing, and starts a timer
+/// that moves `current-frame` to the next frame once that frame has been shown for its duration.
+/// Nothing is advanced if `current-frame` has a binding: the binding decides which frame is shown.
+fn init_frame_tracker<T: AnimatedImageItem>(data: &ImageData, self_rc: &ItemRc) {
+    data.frame_tracker.init_delayed(
+        self_rc.downgrade(),
+        // The frame that is shown, and for how long, or None if the frame isn't advanced
+        |self_weak| {
+            let self_rc = self_weak.upgrade()?;
+            let item = self_rc.downcast::<T>()?;
+            let (source, playing, current_frame) = item.as_pin_ref().animation();
+            if !playing.get() || current_frame.has_binding() {
+                return None;
+            }
+            let crate::graphics::ImageInner::AnimatedImage(animated) = source.get().0 else {
+                return None;
+            };
+            let frame = current_frame.get().rem_euclid(animated.frame_count() as i32);
+            Some((frame, animated.frame_duration(frame as usize)))
+        },
+        |self_weak, shown| {
+            let Some(self_rc) = self_weak.upgrade() else { return };
+            let Some(item) = self_rc.downcast::<T>() else { return };
+            let timer = &item.as_pin_ref().data().frame_timer;
+            let Some((frame, duration)) = *shown else {
+                timer.stop();
+                return;
+            };
+            let self_weak = self_weak.clone();
+            timer.start(crate::timers::TimerMode::SingleShot, duration, move || {
+                let Some(self_rc) = self_weak.upgrade() else { return };
+                let Some(item) = self_rc.downcast::<T>() else { return };
+                let (source, playing, current_frame) = item.as_pin_ref().animation();
+                let crate::graphics::ImageInner::AnimatedImage(animated) = source.get_untracked().0
+                else {
+                    return;
+                };
+                let frame_count = animated.frame_count() as i32;
+                // The change handler that restarts or stops the timer may not have run yet
+                if playing.get_untracked()
+                    && !current_frame.has_binding()
+                    && current_frame.get_untracked().rem_euclid(frame_count) == frame
+                {
+                    current_frame.set((frame + 1) % frame_count);
+                }
+            });
+        },
+    );
+}
+
+/// The data of an [`ImageItem`] or a [`ClippedImage`] that is not exposed as properties
+#[derive(Default)]
+pub struct ImageData {
+    /// Tracks the frame of an animated source that is shown while it's playing
+    frame_tracker: crate::properties::ChangeTracker,
+    /// Moves `current-frame` to the next frame once the shown frame has been shown for its duration
+    frame_timer: crate::timers::Timer,
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the ImageItem and the ClippedImage
+pub struct ImageDataBox(core::ptr::NonNull<ImageData>);
+
+impl Default for ImageDataBox {
+    fn default() -> Self {
+        ImageDataBox(Box::leak(Box::<ImageData>::default()).into())
+    }
+}
+
+impl Drop for ImageDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in ImageDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for ImageDataBox {
+    type Target = ImageData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in ImageDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold an ImageDataBox
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_image_data_init(data: *mut ImageDataBox) {
+    core::ptr::write(data, ImageDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized ImageDataBox
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_image_data_free(data: *mut ImageDataBox) {
+    core::ptr::drop_in_place(data);
+}
+
+#[test]
+fn test_animated_image_frames() {
+    use crate::graphics::{AnimatedImage, Image, ImageCacheKey, ImageInner, SharedPixelBuffer};
+    use core::time::Duration;
+    // The frames are told apart by their width
+    let frame = |width| Image::from_rgba8(SharedPixelBuffer::new(width, 1)).0;
+    let source = Image(ImageInner::AnimatedImage(vtable::VRc::new(AnimatedImage::new(
+        alloc::vec![
+            (frame(1), Duration::from_millis(100)),
+            (frame(2), Duration::from_millis(50)),
+            (frame(3), Duration::from_millis(100)),
+        ],
+        ImageCacheKey::Invalid,
+    ))));
+    let shown = |current_frame| displayed_frame(source.clone(), current_frame).size().width;
+    assert_eq!(shown(0), 1);
+    assert_eq!(shown(1), 2);
+    assert_eq!(shown(2), 3);
+    // Out of range frames wrap around
+    assert_eq!(shown(3), 1);
+    assert_eq!(shown(-1), 3);
+    let still = Image::from_rgba8(SharedPixelBuffer::new(4, 1));
+    assert_eq!(displayed_frame(still, 2).size().width, 4);
+}
@@ -17,7 +17,8 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::{WindowAdapter, WindowInner};
-use crate::{Callback, Coord, Property};
+use crate::{Callback, Coord, Property, SharedString};
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
@@ -386,6 +387,187 @@ impl ItemConsts for FocusScope {
     > = FocusScope::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+/// The implementation of the `Shortcut` element: the window calls its `activated` callback
+/// when a key press that matches its `keys` is not handled by the focused item.
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct Shortcut {
+    pub keys: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub activated: Callback<VoidArg>,
+    pub cached_rendering_data: CachedRenderingData,
+    data: ShortcutDataBox,
+}
+
+impl Item for Shortcut {
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let self_weak = self_rc.downgrade();
+        self.data.parsed_keys.as_ref().set_binding(move || {
+            let self_rc = self_weak.upgrade()?;
+            let shortcut = self_rc.downcast::<Shortcut>()?;
+            crate::menus::parse_shortcut(&shortcut.as_pin_ref().keys())
+        });
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for Shortcut {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        Shortcut,
+        CachedRenderingData,
+    > = Shortcut::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl Shortcut {
+    /// Calls the `activated` callback of the first enabled `Shortcut` in the item tree
+    /// whose keys match the event. Returns true if one was found.
+    ///
+    /// Popups are separate item trees, so only the `Shortcut`s of `item_tree` are considered.
+    pub fn activate_matching(item_tree: &crate::item_tree::ItemTreeRc, event: &KeyEvent) -> bool {
+        if event.event_type != KeyEventType::KeyPressed {
+            return false;
+        }
+        ItemRc::new(item_tree.clone(), 0)
+            .visit_descendants(|item| match item.downcast::<Shortcut>() {
+                Some(shortcut)
+                    if shortcut.as_pin_ref().enabled()
+                        && shortcut.data.parsed_keys.as_ref().get_untracked().is_some_and(
+                            |keys| crate::menus::parsed_shortcut_matches(&keys, event),
+                        ) =>
+                {
+                    core::ops::ControlFlow::Break(shortcut)
+                }
+                _ => core::ops::ControlFlow::Continue(()),
+            })
+            .map(|shortcut| {
+                Shortcut::FIELD_OFFSETS.activated.apply_pin(shortcut.as_pin_ref()).call(&())
+            })
+            .is_some()
+    }
+}
+
+/// The data of a [`Shortcut`] that is not exposed as properties
+pub struct ShortcutData {
+    /// The `keys`, parsed in a binding so that they are only parsed again when they change
+    parsed_keys: Pin<Box<Property<Option<crate::menus::Shortcut>>>>,
+}
+
+impl Default for ShortcutData {
+    fn default() -> Self {
+        Self { parsed_keys: Box::pin(Property::new(None)) }
+    }
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the Shortcut
+pub struct ShortcutDataBox(core::ptr::NonNull<ShortcutData>);
+
+impl Default for ShortcutDataBox {
+    fn default() -> Self {
+        ShortcutDataBox(Box::leak(Box::<ShortcutData>::default()).into())
+    }
+}
+
+impl Drop for ShortcutDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in ShortcutDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for ShortcutDataBox {
+    type Target = ShortcutData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in ShortcutDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a ShortcutDataBox
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_shortcut_data_init(data: *mut ShortcutDataBox) {
+    core::ptr::write(data, ShortcutDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized ShortcutDataBox
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_shortcut_data_free(data: *mut ShortcutDataBox) {
+    core::ptr::drop_in_place(data);
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
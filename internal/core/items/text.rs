@@ -9,16 +9,18 @@
 */
 use super::{
     EventResult, FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventArg,
-    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult,
-    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
-    VoidArg, WindowItem,
+    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult, StringArg,
+    TextDecorationStyle, TextFormat, TextHorizontalAlignment, TextOverflow, TextStrokeStyle,
+    TextVerticalAlignment, TextWrap, VoidArg, WindowItem,
 };
 use crate::graphics::{Brush, Color, FontRequest};
 use crate::input::{
     key_codes, FocusEvent, FocusEventResult, FocusReason, InputEventFilterResult, InputEventResult,
     KeyEvent, KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
 };
-use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
+use crate::item_rendering::{
+    CachedRenderingData, ItemRenderer, RenderText, TextDecoration, TextSelection,
+};
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor, SizeLengths,
@@ -26,10 +28,13 @@
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::styled_text::StyledText;
 use crate::window::{InputMethodProperties, InputMethodRequest, WindowAdapter, WindowInner};
 use crate::{Callback, Coord, Property, SharedString, SharedVector};
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
 use core::pin::Pin;
@@ -57,14 +62,40 @@ pub struct ComplexText {
     pub wrap: Property<TextWrap>,
     pub overflow: Property<TextOverflow>,
     pub letter_spacing: Property<LogicalLength>,
+    pub line_height: Property<LogicalLength>,
+    pub line_height_factor: Property<f32>,
+    pub paragraph_spacing: Property<LogicalLength>,
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub underline: Property<bool>,
+    pub overline: Property<bool>,
+    pub strikethrough: Property<bool>,
+    pub decoration_style: Property<TextDecorationStyle>,
+    pub decoration_color: Property<Color>,
+    pub text_format: Property<TextFormat>,
+    pub link_clicked: Callback<StringArg>,
+    pub selectable: Property<bool>,
+    pub selection_foreground_color: Property<Color>,
+    pub selection_background_color: Property<Color>,
+    pub has_focus: Property<bool>,
     pub cached_rendering_data: CachedRenderingData,
+    /// 0 = not pressed, 1 = single press, 2 = double clicked+press , ...
+    pressed: Cell<u8>,
+    data: ComplexTextDataBox,
 }
 
 impl Item for ComplexText {
-    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let self_weak = self_rc.downgrade();
+        self.data.styled_text.as_ref().set_binding(move || {
+            let self_rc = self_weak.upgrade()?;
+            let text = self_rc.downcast::<ComplexText>()?;
+            let text = text.as_pin_ref();
+            (text.text_format() == TextFormat::Markup)
+                .then(|| Rc::new(StyledText::parse_markup(&text.text())))
+        });
+    }
 
     fn layout_info(
         self: Pin<&Self>,
@@ -87,34 +118,145 @@ fn input_event_filter_before_children(
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> InputEventFilterResult {
-        InputEventFilterResult::ForwardAndIgnore
+        if self.selectable() || self.text_format() == TextFormat::Markup {
+            InputEventFilterResult::ForwardEvent
+        } else {
+            InputEventFilterResult::ForwardAndIgnore
+        }
     }
 
     fn input_event(
         self: Pin<&Self>,
-        _: MouseEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count } => {
+                if self.link_at(position, window_adapter, self_rc).is_some() {
+                    return InputEventResult::GrabMouse;
+                }
+                if self.selectable() {
+                    let clicked_offset =
+                        self.byte_offset_for_position(position, window_adapter, self_rc) as i32;
+                    self.pressed.set((click_count % 3) + 1);
+
+                    if !window_adapter.window().0.modifiers.get().shift() {
+                        self.data.anchor_position_byte_offset.as_ref().set(clicked_offset);
+                    }
+                    self.data.cursor_position_byte_offset.as_ref().set(clicked_offset);
+
+                    if !self.has_focus() {
+                        WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                            self_rc,
+                            true,
+                            FocusReason::PointerClick,
+                        );
+                    }
+
+                    match click_count % 3 {
+                        0 => (),
+                        1 => self.select_word(),
+                        2 => self.select_paragraph(),
+                        _ => unreachable!(),
+                    }
+                    return InputEventResult::GrabMouse;
+                }
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                if self.pressed.get() > 0 {
+                    self.pressed.set(0);
+                    self.copy_clipboard(window_adapter, Clipboard::SelectionClipboard);
+                    return InputEventResult::EventAccepted;
+                }
+                if let Some(link) = self.link_at(position, window_adapter, self_rc) {
+                    Self::FIELD_OFFSETS.link_clicked.apply_pin(self).call(&(link,));
+                    return InputEventResult::EventAccepted;
+                }
+            }
+            MouseEvent::Moved { position } if self.selectable() => {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(super::MouseCursor::Text);
+                }
+                let pressed = self.pressed.get();
+                if pressed > 0 {
+                    let offset =
+                        self.byte_offset_for_position(position, window_adapter, self_rc) as i32;
+                    self.data.cursor_position_byte_offset.as_ref().set(offset);
+                    match (pressed - 1) % 3 {
+                        0 => (),
+                        1 => self.select_word(),
+                        2 => self.select_paragraph(),
+                        _ => unreachable!(),
+                    }
+                    return InputEventResult::GrabMouse;
+                }
+            }
+            MouseEvent::Exit if self.selectable() => {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(super::MouseCursor::Default);
+                }
+                self.pressed.set(0);
+            }
+            _ => {}
+        }
         InputEventResult::EventIgnored
     }
 
     fn key_event(
         self: Pin<&Self>,
-        _: &KeyEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: &KeyEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+        if !self.selectable() || !matches!(event.event_type, KeyEventType::KeyPressed) {
+            return KeyEventResult::EventIgnored;
+        }
+
+        if let Some(TextShortcut::Move(direction)) = event.text_shortcut() {
+            return if self.move_cursor(direction, event.modifiers.into()) {
+                KeyEventResult::EventAccepted
+            } else {
+                KeyEventResult::EventIgnored
+            };
+        }
+
+        match event.shortcut() {
+            Some(StandardShortcut::SelectAll) => {
+                self.select_all(window_adapter, self_rc);
+                KeyEventResult::EventAccepted
+            }
+            Some(StandardShortcut::Copy) => {
+                self.copy(window_adapter, self_rc);
+                KeyEventResult::EventAccepted
+            }
+            _ => KeyEventResult::EventIgnored,
+        }
     }
 
     fn focus_event(
         self: Pin<&Self>,
-        _: &FocusEvent,
+        event: &FocusEvent,
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> FocusEventResult {
-        FocusEventResult::FocusIgnored
+        match event {
+            FocusEvent::FocusIn(_) if self.selectable() => {
+                self.has_focus.set(true);
+                FocusEventResult::FocusAccepted
+            }
+            FocusEvent::FocusOut(reason) if self.has_focus() => {
+                self.has_focus.set(false);
+                if !matches!(reason, FocusReason::WindowActivation | FocusReason::PopupActivation) {
+                    self.data
+                        .anchor_position_byte_offset
+                        .as_ref()
+                        .set(self.data.cursor_position_byte_offset.as_ref().get());
+                }
+                FocusEventResult::FocusAccepted
+            }
+            _ => FocusEventResult::FocusIgnored,
+        }
     }
 
     fn render(
@@ -148,6 +290,58 @@ impl ItemConsts for ComplexText {
     > = ComplexText::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+#[derive(Default)]
+pub struct ComplexTextData {
+    /// The `text` parsed as markup in a binding, so that it's only parsed again when it changes
+    styled_text: Pin<Box<Property<Option<Rc<StyledText>>>>>,
+    /// The end of the selection that moves, as a byte offset in the text
+    cursor_position_byte_offset: Pin<Box<Property<i32>>>,
+    /// The end of the selection that stays in place while the selection is extended
+    anchor_position_byte_offset: Pin<Box<Property<i32>>>,
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the ComplexText
+pub struct ComplexTextDataBox(core::ptr::NonNull<ComplexTextData>);
+
+impl Default for ComplexTextDataBox {
+    fn default() -> Self {
+        ComplexTextDataBox(Box::leak(Box::<ComplexTextData>::default()).into())
+    }
+}
+
+impl Drop for ComplexTextDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in ComplexTextDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for ComplexTextDataBox {
+    type Target = ComplexTextData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in ComplexTextDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a ComplexTextDataBox
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_complex_text_data_init(data: *mut ComplexTextDataBox) {
+    core::ptr::write(data, ComplexTextDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized ComplexTextDataBox
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_complex_text_data_free(data: *mut ComplexTextDataBox) {
+    core::ptr::drop_in_place(data);
+}
+
 impl RenderText for ComplexText {
     fn target_size(self: Pin<&Self>) -> LogicalSize {
         LogicalSize::from_lengths(self.width(), self.height())
@@ -158,13 +352,19 @@ fn text(self: Pin<&Self>) -> SharedString {
     }
 
     fn font_request(self: Pin<&Self>, self_rc: &ItemRc) -> FontRequest {
-        WindowItem::resolved_font_request(
+        with_line_spacing(
             self_rc,
-            self.font_family(),
-            self.font_weight(),
-            self.font_size(),
-            self.letter_spacing(),
-            self.font_italic(),
+            WindowItem::resolved_font_request(
+                self_rc,
+                self.font_family(),
+                self.font_weight(),
+                self.font_size(),
+                self.letter_spacing(),
+                self.font_italic(),
+            ),
+            self.line_height(),
+            self.line_height_factor(),
+            self.paragraph_spacing(),
         )
     }
 
@@ -193,9 +393,186 @@ fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
     fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
         (self.stroke(), self.stroke_width(), self.stroke_style())
     }
+
+    fn styled_text(self: Pin<&Self>) -> Option<Rc<StyledText>> {
+        self.data.styled_text.as_ref().get()
+    }
+
+    fn selection(self: Pin<&Self>) -> Option<TextSelection> {
+        if !self.selectable() || self.text_format() != TextFormat::PlainText {
+            return None;
+        }
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        (anchor != cursor).then(|| TextSelection {
+            range: anchor..cursor,
+            foreground: self.selection_foreground_color(),
+            background: self.selection_background_color(),
+        })
+    }
+
+    fn decoration(self: Pin<&Self>) -> TextDecoration {
+        TextDecoration {
+            underline: self.underline(),
+            overline: self.overline(),
+            strikethrough: self.strikethrough(),
+            style: self.decoration_style(),
+            color: self.decoration_color(),
+        }
+    }
 }
 
 impl ComplexText {
+    /// Returns the start and the end of the selection as byte offsets, in this order.
+    pub fn selection_anchor_and_cursor(self: Pin<&Self>) -> (usize, usize) {
+        let text = self.text();
+        let cursor_pos =
+            safe_byte_offset(self.data.cursor_position_byte_offset.as_ref().get(), &text);
+        let anchor_pos =
+            safe_byte_offset(self.data.anchor_position_byte_offset.as_ref().get(), &text);
+        (anchor_pos.min(cursor_pos), anchor_pos.max(cursor_pos))
+    }
+
+    pub fn select_all(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        if !self.selectable() {
+            return;
+        }
+        self.data.anchor_position_byte_offset.as_ref().set(0);
+        self.data.cursor_position_byte_offset.as_ref().set(self.text().len() as i32);
+    }
+
+    pub fn clear_selection(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        self.data
+            .anchor_position_byte_offset
+            .as_ref()
+            .set(self.data.cursor_position_byte_offset.as_ref().get());
+    }
+
+    pub fn copy(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        self.copy_clipboard(window_adapter, Clipboard::DefaultClipboard);
+    }
+
+    fn copy_clipboard(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        clipboard: Clipboard,
+    ) {
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        if anchor == cursor {
+            return;
+        }
+        let text = self.text();
+
+        WindowInner::from_pub(window_adapter.window())
+            .ctx
+            .platform()
+            .set_clipboard_text(&text[anchor..cursor], clipboard);
+    }
+
+    fn select_word(self: Pin<&Self>) {
+        let text = self.text();
+        let anchor = safe_byte_offset(self.data.anchor_position_byte_offset.as_ref().get(), &text);
+        let cursor = safe_byte_offset(self.data.cursor_position_byte_offset.as_ref().get(), &text);
+        let (new_a, new_c) = if anchor <= cursor {
+            (prev_word_boundary(&text, anchor), next_word_boundary(&text, cursor))
+        } else {
+            (next_word_boundary(&text, anchor), prev_word_boundary(&text, cursor))
+        };
+        self.data.anchor_position_byte_offset.as_ref().set(new_a as i32);
+        self.data.cursor_position_byte_offset.as_ref().set(new_c as i32);
+    }
+
+    fn select_paragraph(self: Pin<&Self>) {
+        let text = self.text();
+        let anchor = safe_byte_offset(self.data.anchor_position_byte_offset.as_ref().get(), &text);
+        let cursor = safe_byte_offset(self.data.cursor_position_byte_offset.as_ref().get(), &text);
+        let (new_a, new_c) = if anchor <= cursor {
+            (prev_paragraph_boundary(&text, anchor), next_paragraph_boundary(&text, cursor))
+        } else {
+            (next_paragraph_boundary(&text, anchor), prev_paragraph_boundary(&text, cursor))
+        };
+        self.data.anchor_position_byte_offset.as_ref().set(new_a as i32);
+        self.data.cursor_position_byte_offset.as_ref().set(new_c as i32);
+    }
+
+    /// Moves the (invisible) cursor that delimits the selection. Returns false if the direction
+    /// isn't supported.
+    fn move_cursor(
+        self: Pin<&Self>,
+        direction: TextCursorDirection,
+        anchor_mode: AnchorMode,
+    ) -> bool {
+        let text = self.text();
+        let cursor = safe_byte_offset(self.data.cursor_position_byte_offset.as_ref().get(), &text);
+        let mut grapheme_cursor =
+            unicode_segmentation::GraphemeCursor::new(cursor, text.len(), true);
+
+        let new_cursor_pos = match direction {
+            TextCursorDirection::Forward => {
+                grapheme_cursor.next_boundary(&text, 0).ok().flatten().unwrap_or_else(|| text.len())
+            }
+            TextCursorDirection::Backward => {
+                grapheme_cursor.prev_boundary(&text, 0).ok().flatten().unwrap_or(0)
+            }
+            TextCursorDirection::ForwardByWord => next_word_boundary(&text, cursor + 1),
+            TextCursorDirection::BackwardByWord => {
+                prev_word_boundary(&text, cursor.saturating_sub(1))
+            }
+            // The Text doesn't track the position of the cursor on screen, so lines are paragraphs
+            TextCursorDirection::StartOfLine | TextCursorDirection::StartOfParagraph => {
+                prev_paragraph_boundary(&text, cursor.saturating_sub(1))
+            }
+            TextCursorDirection::EndOfLine | TextCursorDirection::EndOfParagraph => {
+                next_paragraph_boundary(&text, cursor + 1)
+            }
+            TextCursorDirection::StartOfText => 0,
+            TextCursorDirection::EndOfText => text.len(),
+            _ => return false,
+        };
+
+        if anchor_mode == AnchorMode::MoveAnchor {
+            self.data.anchor_position_byte_offset.as_ref().set(new_cursor_pos as i32);
+        }
+        self.data.cursor_position_byte_offset.as_ref().set(new_cursor_pos as i32);
+        true
+    }
+
+    fn byte_offset_for_position(
+        self: Pin<&Self>,
+        pos: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> usize {
+        window_adapter.renderer().text_byte_offset_for_position(
+            self,
+            self_rc,
+            pos,
+            ScaleFactor::new(window_adapter.window().scale_factor()),
+        )
+    }
+
+    /// Returns the target of the link at the given position, if the text is markup and there is a link there.
+    fn link_at(
+        self: Pin<&Self>,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> Option<SharedString> {
+        let styled_text = self.styled_text()?;
+        if styled_text.spans.iter().all(|span| span.style.link.is_none()) {
+            return None;
+        }
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+        let byte_offset = window_adapter.renderer().styled_text_byte_offset_for_position(
+            self,
+            self_rc,
+            &styled_text,
+            position,
+            scale_factor,
+        )?;
+        styled_text.link_at(byte_offset).cloned()
+    }
+
     pub fn font_metrics(
         self: Pin<&Self>,
         window_adapter: &Rc<dyn WindowAdapter>,
@@ -379,16 +756,24 @@ fn text_layout_info(
 ) -> LayoutInfo {
     let window_inner = WindowInner::from_pub(window_adapter.window());
     let text_string = text.text();
+    let styled_text = text.styled_text();
     let font_request = text.font_request(self_rc);
     let scale_factor = ScaleFactor::new(window_inner.scale_factor());
-    let implicit_size = |max_width, text_wrap| {
-        window_adapter.renderer().text_size(
+    let implicit_size = |max_width, text_wrap| match &styled_text {
+        Some(styled_text) => window_adapter.renderer().styled_text_size(
+            font_request.clone(),
+            styled_text,
+            max_width,
+            scale_factor,
+            text_wrap,
+        ),
+        None => window_adapter.renderer().text_size(
             font_request.clone(),
             text_string.as_str(),
             max_width,
             scale_factor,
             text_wrap,
-        )
+        ),
     };
 
     // Stretch uses `round_layout` to explicitly align the top left and bottom right of layout nodes
@@ -427,6 +812,32 @@ fn text_layout_info(
     }
 }
 
+/// Adds the line height and the paragraph spacing to the font request. A non-zero `line_height` takes
+/// precedence over `line_height_factor`, which is relative to the font size.
+fn with_line_spacing(
+    self_rc: &ItemRc,
+    mut font_request: FontRequest,
+    line_height: LogicalLength,
+    line_height_factor: f32,
+    paragraph_spacing: LogicalLength,
+) -> FontRequest {
+    font_request.line_height = if line_height.get() > 0 as Coord {
+        Some(line_height)
+    } else if line_height_factor > 0. {
+        font_request
+            .pixel_size
+            .or_else(|| {
+                self_rc.window_adapter().map(|adapter| adapter.renderer().default_font_size())
+            })
+            .map(|font_size| font_size * line_height_factor as Coord)
+    } else {
+        None
+    };
+    font_request.paragraph_spacing =
+        (paragraph_spacing.get() != 0 as Coord).then_some(paragraph_spacing);
+    font_request
+}
+
 #[repr(C)]
 #[derive(Default, Clone, Copy, PartialEq)]
 /// Similar as `Option<core::ops::Range<i32>>` but `repr(C)`
@@ -485,6 +896,14 @@ pub struct TextInput {
     pub wrap: Property<TextWrap>,
     pub input_type: Property<InputType>,
     pub letter_spacing: Property<LogicalLength>,
+    pub line_height: Property<LogicalLength>,
+    pub line_height_factor: Property<f32>,
+    pub paragraph_spacing: Property<LogicalLength>,
+    pub underline: Property<bool>,
+    pub overline: Property<bool>,
+    pub strikethrough: Property<bool>,
+    pub decoration_style: Property<TextDecorationStyle>,
+    pub decoration_color: Property<Color>,
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub cursor_position_byte_offset: Property<i32>,
@@ -502,6 +921,12 @@ pub struct TextInput {
     pub single_line: Property<bool>,
     pub read_only: Property<bool>,
     pub preedit_text: Property<SharedString>,
+    pub can_undo: Property<bool>,
+    pub can_redo: Property<bool>,
+    pub undo_limit: Property<i32>,
+    pub max_length: Property<i32>,
+    pub input_mask: Property<SharedString>,
+    pub accept_input: Callback<StringArg, bool>,
     /// A selection within the preedit (cursor and anchor)
     preedit_selection: Property<PreEditSelection>,
     pub cached_rendering_data: CachedRenderingData,
@@ -816,9 +1241,9 @@ fn key_event(
                     (self.cursor_position(&text), self.anchor_position(&text))
                 };
 
-                if !self.accept_text_input(event.text.as_str()) {
+                let Some(text_to_insert) = self.filter_text_input(event.text.as_str()) else {
                     return KeyEventResult::EventIgnored;
-                }
+                };
 
                 self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
 
@@ -826,18 +1251,18 @@ fn key_event(
 
                 // FIXME: respect grapheme boundaries
                 let insert_pos = self.selection_anchor_and_cursor().1;
-                text.insert_str(insert_pos, &event.text);
+                text.insert_str(insert_pos, &text_to_insert);
 
                 self.add_undo_item(UndoItem {
                     pos: insert_pos,
-                    text: event.text.clone(),
+                    text: text_to_insert.clone(),
                     cursor: real_cursor,
                     anchor: real_anchor,
                     kind: UndoItemKind::TextInsert,
                 });
 
                 self.as_ref().text.set(text.into());
-                let new_cursor_pos = (insert_pos + event.text.len()) as i32;
+                let new_cursor_pos = (insert_pos + text_to_insert.len()) as i32;
                 self.as_ref().anchor_position_byte_offset.set(new_cursor_pos);
                 self.set_cursor_position(
                     new_cursor_pos,
@@ -867,8 +1292,6 @@ fn key_event(
                 }
 
                 let cursor = self.cursor_position(&self.text()) as i32;
-                self.preedit_text.set(event.preedit_text.clone());
-                self.preedit_selection.set(event.preedit_selection.clone().into());
 
                 if let Some(r) = &event.replacement_range {
                     // Set the selection so the call to insert erases it
@@ -898,6 +1321,21 @@ fn key_event(
                         self_rc,
                     );
                 }
+
+                // The pre-edit text is shown as if it was inserted at the cursor, so it must pass the
+                // same filters as the committed text.
+                let preedit_text = if event.preedit_text.is_empty() {
+                    SharedString::default()
+                } else {
+                    self.filter_text_input(&event.preedit_text).unwrap_or_default()
+                };
+                let preedit_selection = if preedit_text == event.preedit_text {
+                    event.preedit_selection.clone()
+                } else {
+                    None
+                };
+                self.preedit_text.set(preedit_text);
+                self.preedit_selection.set(preedit_selection.into());
                 KeyEventResult::EventAccepted
             }
         }
@@ -948,7 +1386,10 @@ fn focus_event(
                     #[cfg(target_os = "android")]
                     {
                         let preedit_text = self.preedit_text();
-                        if !preedit_text.is_empty() {
+                        if let Some(preedit_text) = (!preedit_text.is_empty())
+                            .then(|| self.filter_text_input(&preedit_text))
+                            .flatten()
+                        {
                             let mut text = String::from(self.text());
                             let cursor_position = self.cursor_position(&text);
                             text.insert_str(cursor_position, &preedit_text);
@@ -1397,7 +1838,8 @@ fn select_and_delete(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) {
-        if !self.has_selection() {
+        let had_selection = self.has_selection();
+        if !had_selection {
             self.move_cursor(
                 step,
                 AnchorMode::KeepAnchor,
@@ -1406,23 +1848,38 @@ fn select_and_delete(
                 self_rc,
             );
         }
-        self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks);
+        if !self.delete_selection(window_adapter, self_rc, TextChangeNotify::TriggerCallbacks)
+            && !had_selection
+        {
+            // The deletion was rejected: don't leave the characters it would have removed selected
+            self.cursor_position_byte_offset.set(self.anchor_position_byte_offset());
+        }
     }
 
+    /// Deletes the selected text. Returns false if nothing was deleted, because there was no selection
+    /// or because the `input-mask` or the `accept-input` callback rejected the deletion.
+    /// These are only checked with [`TextChangeNotify::TriggerCallbacks`]: otherwise the text that
+    /// replaces the selection was already checked.
     pub fn delete_selection(
         self: Pin<&Self>,
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
         trigger_callbacks: TextChangeNotify,
-    ) {
+    ) -> bool {
         let text: String = self.text().into();
         if text.is_empty() {
-            return;
+            return false;
         }
 
         let (anchor, cursor) = self.selection_anchor_and_cursor();
         if anchor == cursor {
-            return;
+            return false;
+        }
+
+        if trigger_callbacks == TextChangeNotify::TriggerCallbacks
+            && !self.accept_text_removal(&text, anchor, cursor)
+        {
+            return false;
         }
 
         let removed_text: SharedString = text[anchor..cursor].into();
@@ -1456,6 +1913,7 @@ pub fn delete_selection(
         } else {
             self.cursor_position_byte_offset.set(anchor as i32);
         }
+        true
     }
 
     pub fn anchor_position(self: Pin<&Self>, text: &str) -> usize {
@@ -1530,6 +1988,14 @@ fn insert(
             return;
         }
 
+        let mut inserted_text: SharedString = text_to_insert.into();
+        if text_to_insert.contains('\n') && self.single_line() {
+            inserted_text = text_to_insert.replace('\n', " ").into();
+        }
+        let Some(inserted_text) = self.filter_text_input(&inserted_text) else {
+            return;
+        };
+
         let (real_cursor, real_anchor) = {
             let text = self.text();
             (self.cursor_position(&text), self.anchor_position(&text))
@@ -1538,14 +2004,9 @@ fn insert(
         self.delete_selection(window_adapter, self_rc, TextChangeNotify::SkipCallbacks);
         let mut text: String = self.text().into();
         let cursor_pos = self.selection_anchor_and_cursor().1;
-        let mut inserted_text: SharedString = text_to_insert.into();
-        if text_to_insert.contains('\n') && self.single_line() {
-            inserted_text = text_to_insert.replace('\n', " ").into();
-            text.insert_str(cursor_pos, &inserted_text);
-        } else {
-            text.insert_str(cursor_pos, text_to_insert);
-        }
+        text.insert_str(cursor_pos, &inserted_text);
 
+        let cursor_pos_after = cursor_pos + inserted_text.len();
         self.add_undo_item(UndoItem {
             pos: cursor_pos,
             text: inserted_text,
@@ -1554,7 +2015,7 @@ fn insert(
             kind: UndoItemKind::TextInsert,
         });
 
-        let cursor_pos = cursor_pos + text_to_insert.len();
+        let cursor_pos = cursor_pos_after;
         self.text.set(text.into());
         self.anchor_position_byte_offset.set(cursor_pos as i32);
         self.set_cursor_position(
@@ -1696,16 +2157,33 @@ fn paste_clipboard(
     }
 
     pub fn font_request(self: Pin<&Self>, self_rc: &ItemRc) -> FontRequest {
-        WindowItem::resolved_font_request(
+        with_line_spacing(
             self_rc,
-            self.font_family(),
-            self.font_weight(),
-            self.font_size(),
-            self.letter_spacing(),
-            self.font_italic(),
+            WindowItem::resolved_font_request(
+                self_rc,
+                self.font_family(),
+                self.font_weight(),
+                self.font_size(),
+                self.letter_spacing(),
+                self.font_italic(),
+            ),
+            self.line_height(),
+            self.line_height_factor(),
+            self.paragraph_spacing(),
         )
     }
 
+    /// Returns the lines to draw along the text, such as the underline.
+    pub fn decoration(self: Pin<&Self>) -> TextDecoration {
+        TextDecoration {
+            underline: self.underline(),
+            overline: self.overline(),
+            strikethrough: self.strikethrough(),
+            style: self.decoration_style(),
+            color: self.decoration_color(),
+        }
+    }
+
     /// Returns a [`TextInputVisualRepresentation`] struct that contains all the fields necessary for rendering the text input,
     /// after making adjustments such as applying a substitution of characters for password input fields, or making sure
     /// that the selection start is always less or equal than the selection end.
@@ -1821,6 +2299,10 @@ fn ensure_focus_and_ime(
     }
 
     fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
+        let limit = self.undo_limit();
+        if limit <= 0 {
+            return;
+        }
         let mut items = self.undo_items.take();
         // try to merge with the last item
         if let Some(last) = items.make_mut_slice().last_mut() {
@@ -1829,15 +2311,22 @@ fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
                     let is_new_line = item.text == "\n";
                     let last_is_new_line = last.text == "\n";
                     // if the last item or current item is a new_line
-                    // we insert it as a standalone item, no merging
-                    if item.pos == last.pos + last.text.len() && !is_new_line && !last_is_new_line {
+                    // we insert it as a standalone item, no merging.
+                    // Typing the first character of a word also starts a new item.
+                    if item.pos == last.pos + last.text.len()
+                        && !is_new_line
+                        && !last_is_new_line
+                        && !starts_new_word(&last.text, &item.text)
+                    {
                         last.text += &item.text;
                     } else {
                         items.push(item);
                     }
                 }
                 (UndoItemKind::TextRemove, UndoItemKind::TextRemove) => {
-                    if item.pos + item.text.len() == last.pos {
+                    if item.pos + item.text.len() == last.pos
+                        && !starts_new_word(&item.text, &last.text)
+                    {
                         last.pos = item.pos;
                         let old_text = last.text.clone();
                         last.text = item.text;
@@ -1855,96 +2344,103 @@ fn add_undo_item(self: Pin<&Self>, item: UndoItem) {
             items.push(item);
         }
 
+        let excess = items.len().saturating_sub(limit as usize);
+        if excess > 0 {
+            items = items.iter().skip(excess).cloned().collect();
+        }
+
         self.undo_items.set(items);
+        // A new edit invalidates what was undone before
+        self.redo_items.take();
+        self.update_undo_state();
     }
 
-    fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
-        let mut items = self.undo_items.take();
-        let Some(last) = items.pop() else {
-            return;
-        };
+    fn update_undo_state(self: Pin<&Self>) {
+        let undo_items = self.undo_items.take();
+        self.can_undo.set(!undo_items.is_empty());
+        self.undo_items.set(undo_items);
+        let redo_items = self.redo_items.take();
+        self.can_redo.set(!redo_items.is_empty());
+        self.redo_items.set(redo_items);
+    }
 
-        match last.kind {
-            UndoItemKind::TextInsert => {
-                let text: String = self.text().into();
-                let text = [text.split_at(last.pos).0, text.split_at(last.pos + last.text.len()).1]
-                    .concat();
-                self.text.set(text.into());
+    /// Forget the undo and redo history, for example after the text was set programmatically.
+    pub fn clear_undo_history(self: Pin<&Self>, _: &Rc<dyn WindowAdapter>, _: &ItemRc) {
+        self.undo_items.take();
+        self.redo_items.take();
+        self.update_undo_state();
+    }
 
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
+    /// Reverts `item` if `revert` is true, otherwise applies it again.
+    /// Returns false if the item doesn't fit the current text, because the text was changed
+    /// programmatically since the item was recorded.
+    fn apply_undo_item(
+        self: Pin<&Self>,
+        item: &UndoItem,
+        revert: bool,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> bool {
+        let mut text: String = self.text().into();
+        let range = item.pos..item.pos + item.text.len();
+        if matches!(item.kind, UndoItemKind::TextInsert) != revert {
+            if !text.is_char_boundary(item.pos) {
+                return false;
             }
-            UndoItemKind::TextRemove => {
-                let mut text: String = self.text().into();
-                text.insert_str(last.pos, &last.text);
-                self.text.set(text.into());
-
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
+            text.insert_str(item.pos, &item.text);
+        } else {
+            if text.get(range.clone()) != Some(item.text.as_str()) {
+                return false;
             }
+            text.replace_range(range, "");
         }
+        self.text.set(text.into());
+
+        self.anchor_position_byte_offset.set(item.anchor as i32);
+        self.set_cursor_position(
+            item.cursor as i32,
+            true,
+            TextChangeNotify::TriggerCallbacks,
+            window_adapter,
+            self_rc,
+        );
+        true
+    }
+
+    pub fn undo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        let mut items = self.undo_items.take();
+        let Some(last) = items.pop() else {
+            return;
+        };
         self.undo_items.set(items);
 
+        if !self.apply_undo_item(&last, true, window_adapter, self_rc) {
+            self.clear_undo_history(window_adapter, self_rc);
+            return;
+        }
+
         let mut redo = self.redo_items.take();
         redo.push(last);
         self.redo_items.set(redo);
+        self.update_undo_state();
     }
 
-    fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+    pub fn redo(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
         let mut items = self.redo_items.take();
         let Some(last) = items.pop() else {
             return;
         };
+        self.redo_items.set(items);
 
-        match last.kind {
-            UndoItemKind::TextInsert => {
-                let mut text: String = self.text().into();
-                text.insert_str(last.pos, &last.text);
-                self.text.set(text.into());
-
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
-            }
-            UndoItemKind::TextRemove => {
-                let text: String = self.text().into();
-                let text = [text.split_at(last.pos).0, text.split_at(last.pos + last.text.len()).1]
-                    .concat();
-                self.text.set(text.into());
-
-                self.anchor_position_byte_offset.set(last.anchor as i32);
-                self.set_cursor_position(
-                    last.cursor as i32,
-                    true,
-                    TextChangeNotify::TriggerCallbacks,
-                    window_adapter,
-                    self_rc,
-                );
-            }
+        if !self.apply_undo_item(&last, false, window_adapter, self_rc) {
+            self.clear_undo_history(window_adapter, self_rc);
+            return;
         }
 
-        self.redo_items.set(items);
-
         let mut undo_items = self.undo_items.take();
         undo_items.push(last);
         self.undo_items.set(undo_items);
+        self.update_undo_state();
     }
 
     pub fn font_metrics(
@@ -1958,6 +2454,57 @@ pub fn font_metrics(
         window_adapter.renderer().font_metrics(font_request, scale_factor)
     }
 
+    /// Returns the text to insert in place of the selection when the user enters `text_to_insert`,
+    /// or None if the input is rejected by the `input-type`, the `max-length`, the `input-mask`
+    /// or the `accept-input` callback.
+    fn filter_text_input(self: Pin<&Self>, text_to_insert: &str) -> Option<SharedString> {
+        if !self.accept_text_input(text_to_insert) {
+            return None;
+        }
+        let text = self.text();
+        let (anchor, cursor) = self.selection_anchor_and_cursor();
+        let (before, after) = (&text[..anchor], &text[cursor..]);
+
+        let input_mask = self.input_mask();
+        let mut text_to_insert: SharedString = if input_mask.is_empty() {
+            text_to_insert.into()
+        } else {
+            apply_input_mask(&input_mask, before, text_to_insert, after)?.into()
+        };
+
+        let max_length = self.max_length();
+        if max_length > 0 {
+            let remaining = (max_length as usize)
+                .saturating_sub(before.chars().count() + after.chars().count());
+            if remaining == 0 {
+                return None;
+            }
+            if let Some((truncate_at, _)) = text_to_insert.char_indices().nth(remaining) {
+                text_to_insert = text_to_insert[..truncate_at].into();
+            }
+        }
+
+        let accept_input = &Self::FIELD_OFFSETS.accept_input.apply_pin(self);
+        if accept_input.has_handler()
+            && !accept_input.call(&([before, text_to_insert.as_str(), after].concat().into(),))
+        {
+            return None;
+        }
+        Some(text_to_insert)
+    }
+
+    /// Returns false if removing the text between `anchor` and `cursor` is rejected by the
+    /// `input-mask` or the `accept-input` callback.
+    fn accept_text_removal(self: Pin<&Self>, text: &str, anchor: usize, cursor: usize) -> bool {
+        let new_text = [&text[..anchor], &text[cursor..]].concat();
+        let input_mask = self.input_mask();
+        if !input_mask.is_empty() && !fits_input_mask(&input_mask, &new_text) {
+            return false;
+        }
+        let accept_input = &Self::FIELD_OFFSETS.accept_input.apply_pin(self);
+        !accept_input.has_handler() || accept_input.call(&(new_text.into(),))
+    }
+
     fn accept_text_input(self: Pin<&Self>, text_to_insert: &str) -> bool {
         let input_type = self.input_type();
         if input_type == InputType::Number && !text_to_insert.chars().all(|ch| ch.is_ascii_digit())
@@ -1975,6 +2522,81 @@ fn accept_text_input(self: Pin<&Self>, text_to_insert: &str) -> bool {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum InputMaskChar {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Any,
+    Literal(char),
+}
+
+impl InputMaskChar {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Any => !c.is_control(),
+            Self::Literal(l) => l == c,
+        }
+    }
+}
+
+/// Parses an input mask: `0` is a digit, `A` a letter, `N` a letter or a digit, `*` any character,
+/// and any other character (or one escaped with a `\`) stands for itself.
+fn parse_input_mask(mask: &str) -> Vec<InputMaskChar> {
+    let mut result = Vec::new();
+    let mut chars = mask.chars();
+    while let Some(c) = chars.next() {
+        result.push(match c {
+            '0' => InputMaskChar::Digit,
+            'A' => InputMaskChar::Letter,
+            'N' => InputMaskChar::Alphanumeric,
+            '*' => InputMaskChar::Any,
+            '\\' => InputMaskChar::Literal(chars.next().unwrap_or('\\')),
+            c => InputMaskChar::Literal(c),
+        });
+    }
+    result
+}
+
+/// Returns the text to insert between `before` and `after` when the user types `to_insert`
+/// in a `TextInput` with the given input mask, or None if it doesn't fit the mask.
+/// The literal characters of the mask are inserted automatically.
+fn apply_input_mask(mask: &str, before: &str, to_insert: &str, after: &str) -> Option<String> {
+    let mask = parse_input_mask(mask);
+    let mut pos = before.chars().count();
+    let mut result = String::new();
+    for c in to_insert.chars() {
+        loop {
+            let mask_char = *mask.get(pos)?;
+            pos += 1;
+            if mask_char.accepts(c) {
+                result.push(c);
+                break;
+            }
+            let InputMaskChar::Literal(literal) = mask_char else { return None };
+            result.push(literal);
+        }
+    }
+    // What follows the insertion must still fit the mask
+    let mask_after = mask.get(pos..)?;
+    if after.chars().count() > mask_after.len()
+        || !after.chars().zip(mask_after).all(|(c, m)| m.accepts(c))
+    {
+        return None;
+    }
+    Some(result)
+}
+
+/// Returns true if the text fits the start of the input mask, as what remains of a text that was
+/// entered with the mask when some of it is deleted must.
+fn fits_input_mask(mask: &str, text: &str) -> bool {
+    let mask = parse_input_mask(mask);
+    text.chars().count() <= mask.len() && text.chars().zip(mask).all(|(c, m)| m.accepts(c))
+}
+
 fn next_paragraph_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text.as_bytes()
         .iter()
@@ -2010,6 +2632,13 @@ fn prev_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     word_offset
 }
 
+/// Returns true if `next` begins a new word after `previous`, that is if `previous` ends with
+/// a whitespace and `next` doesn't start with one. Used to group the undo items by words.
+fn starts_new_word(previous: &str, next: &str) -> bool {
+    previous.chars().next_back().is_some_and(char::is_whitespace)
+        && next.chars().next().is_some_and(|c| !c.is_whitespace())
+}
+
 fn next_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text.unicode_word_indices()
         .find(|(offset, slice)| *offset + slice.len() >= last_cursor_pos)
@@ -2096,6 +2725,84 @@ fn next_word_boundary(text: &str, last_cursor_pos: usize) -> usize {
     text_input.paste(window_adapter, &self_rc);
 }
 
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_textinput_undo(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.undo(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_textinput_redo(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.redo(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_textinput_clear_undo_history(
+    text_input: Pin<&TextInput>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text_input.clear_undo_history(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_complextext_select_all(
+    text: Pin<&ComplexText>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text.select_all(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_complextext_clear_selection(
+    text: Pin<&ComplexText>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text.clear_selection(window_adapter, &self_rc);
+}
+
+#[cfg(feature = "ffi")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn slint_complextext_copy(
+    text: Pin<&ComplexText>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    text.copy(window_adapter, &self_rc);
+}
+
 pub fn slint_text_item_fontmetrics(
     window_adapter: &Rc<dyn WindowAdapter>,
     item_ref: Pin<ItemRef<'_>>,
@@ -2124,3 +2831,32 @@ pub fn slint_text_item_fontmetrics(
     let self_ref = self_rc.borrow();
     slint_text_item_fontmetrics(window_adapter, self_ref, &self_rc)
 }
+
+#[test]
+fn test_apply_input_mask() {
+    assert_eq!(apply_input_mask("0000-00-00", "", "2024", "").as_deref(), Some("2024"));
+    assert_eq!(apply_input_mask("0000-00-00", "2024", "1", "").as_deref(), Some("-1"));
+    assert_eq!(apply_input_mask("0000-00-00", "2024", "-", "").as_deref(), Some("-"));
+    assert_eq!(apply_input_mask("0000-00-00", "", "20241231", "").as_deref(), Some("2024-12-31"));
+    assert_eq!(apply_input_mask("0000-00-00", "", "2024-12-31", "").as_deref(), Some("2024-12-31"));
+    assert_eq!(apply_input_mask("0000-00-00", "2024-12-31", "1", ""), None);
+    assert_eq!(apply_input_mask("0000-00-00", "20", "x", ""), None);
+    assert_eq!(apply_input_mask("AA-000", "", "ab1", "").as_deref(), Some("ab-1"));
+    assert_eq!(apply_input_mask("\\A0", "", "7", "").as_deref(), Some("A7"));
+    // inserting in the middle shifts what follows, which must still fit
+    assert_eq!(apply_input_mask("000", "1", "2", "3").as_deref(), Some("2"));
+    assert_eq!(apply_input_mask("000", "1", "2", "34"), None);
+    assert_eq!(apply_input_mask("00-00", "1", "2", "-34"), Some("2".into()));
+}
+
+#[test]
+fn test_fits_input_mask() {
+    assert!(fits_input_mask("0000-00-00", "2024-12-31"));
+    assert!(fits_input_mask("0000-00-00", "2024-12-"));
+    assert!(fits_input_mask("0000-00-00", ""));
+    // Deleting the `-` of "2024-12-31"
+    assert!(!fits_input_mask("0000-00-00", "202412-31"));
+    // Deleting the "12" of "2024-12-31"
+    assert!(!fits_input_mask("0000-00-00", "2024--31"));
+    assert!(!fits_input_mask("00", "123"));
+}
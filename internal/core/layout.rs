@@ -579,8 +579,23 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
         }
     }
 
-    let res = result.make_mut_slice();
+    fill_layout_result(
+        result.make_mut_slice(),
+        layout_data.iter().map(|it| (it.pos, it.size)),
+        repeater_indexes,
+    );
+    result
+}
 
+/// Write the position and size of each item into `res`.
+///
+/// The items of the repeaters are put at the end of the result, and the slot of the first item
+/// of a repeater contains the offset at which its items can be found.
+fn fill_layout_result(
+    res: &mut [Coord],
+    items: impl Iterator<Item = (Coord, Coord)>,
+    repeater_indexes: Slice<u32>,
+) {
     // The index/2 in result in which we should add the next repeated item
     let mut repeat_offset =
         res.len() / 2 - repeater_indexes.iter().skip(1).step_by(2).sum::<u32>() as usize;
@@ -588,7 +603,7 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
     let mut next_rep = 0;
     // The index/2 in result in which we should add the next non-repeated item
     let mut current_offset = 0;
-    for (idx, layout) in layout_data.iter().enumerate() {
+    for (idx, (pos, size)) in items.enumerate() {
         let o = loop {
             if let Some(nr) = repeater_indexes.get(next_rep * 2) {
                 let nr = *nr as usize;
@@ -610,10 +625,9 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
             current_offset += 1;
             break current_offset - 1;
         };
-        res[o * 2] = layout.pos;
-        res[o * 2 + 1] = layout.size;
+        res[o * 2] = pos;
+        res[o * 2 + 1] = size;
     }
-    result
 }
 
 /// Return the LayoutInfo for a BoxLayout with the given cells.
@@ -659,6 +673,369 @@ pub fn box_layout_info_ortho(cells: Slice<BoxLayoutCellData>, padding: &Padding)
     fold
 }
 
+mod flex_internal {
+    use super::*;
+    use core::ops::Range;
+
+    /// Overflows smaller than this don't cause an item to wrap, so that rounding errors
+    /// in the sizes don't move items to the next line.
+    const TOLERANCE: Coord = 1 as Coord / 100 as Coord;
+
+    #[derive(Debug, Clone)]
+    pub struct FlexItem {
+        // inputs
+        pub min: Coord,
+        pub max: Coord,
+        /// The preferred size, within the min and max bounds
+        pub basis: Coord,
+        pub grow: f32,
+        pub shrink: f32,
+
+        // outputs
+        pub pos: Coord,
+        pub size: Coord,
+    }
+
+    /// Create the FlexItem for each cell. The percentages are resolved against `size` if it is known.
+    pub fn to_flex_items(cells: &[FlexLayoutCellData], size: Option<Coord>) -> Vec<FlexItem> {
+        cells
+            .iter()
+            .map(|c| {
+                let (mut min, mut max) = (c.constraint.min, c.constraint.max);
+                if let Some(size) = size {
+                    min = min.max(c.constraint.min_percent * size / 100 as Coord);
+                    max = max.min(c.constraint.max_percent * size / 100 as Coord);
+                }
+                let max = max.max(min);
+                FlexItem {
+                    min,
+                    max,
+                    basis: c.constraint.preferred.min(max).max(min),
+                    grow: c.grow,
+                    shrink: c.shrink,
+                    pos: 0 as _,
+                    size: 0 as _,
+                }
+            })
+            .collect()
+    }
+
+    /// Split the items in lines that fit in `available`, based on their preferred size.
+    /// Every line contains at least one item.
+    pub fn break_lines(
+        items: &[FlexItem],
+        available: Coord,
+        spacing: Coord,
+        wrap: bool,
+    ) -> Vec<Range<usize>> {
+        if !wrap {
+            return alloc::vec![0..items.len()];
+        }
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut line_size = 0 as Coord;
+        for (idx, it) in items.iter().enumerate() {
+            if idx > start && line_size + spacing + it.basis > available + TOLERANCE {
+                lines.push(start..idx);
+                start = idx;
+            }
+            line_size = if idx == start { it.basis } else { line_size + spacing + it.basis };
+        }
+        lines.push(start..items.len());
+        lines
+    }
+
+    /// Grow or shrink the items of a line according to their factors, so that they fill `available`.
+    #[allow(clippy::unnecessary_cast)] // Coord
+    pub fn resolve_line(items: &mut [FlexItem], available: Coord, spacing: Coord) {
+        let available = available - spacing * (items.len() - 1) as Coord;
+        let mut frozen = alloc::vec![false; items.len()];
+        loop {
+            let mut used = 0 as Coord;
+            for (it, frozen) in items.iter_mut().zip(&frozen) {
+                if !frozen {
+                    it.size = it.basis;
+                }
+                used += it.size;
+            }
+            let free = available - used;
+            let growing = free > 0 as Coord;
+            let weight =
+                |it: &FlexItem| if growing { it.grow } else { it.shrink * it.basis as f32 };
+            let total: f32 =
+                items.iter().zip(&frozen).filter(|(_, f)| !**f).map(|(it, _)| weight(it)).sum();
+            if total <= 0. {
+                return;
+            }
+
+            // Items that reach their min or max are frozen, and the space is distributed again
+            // among the other ones.
+            let mut clamped = false;
+            for (it, frozen) in items.iter_mut().zip(frozen.iter_mut()).filter(|(_, f)| !**f) {
+                let size = it.basis + (free as f32 * weight(&*it) / total) as Coord;
+                it.size = size.min(it.max).max(it.min);
+                if size < it.min || size > it.max {
+                    *frozen = true;
+                    clamped = true;
+                }
+            }
+            if !clamped {
+                return;
+            }
+        }
+    }
+
+    /// Set the position of the items of a line according to the alignment.
+    pub fn position_line(
+        items: &mut [FlexItem],
+        start: Coord,
+        available: Coord,
+        spacing: Coord,
+        alignment: LayoutAlignment,
+    ) {
+        let count = items.len() as Coord;
+        let size: Coord = items.iter().map(|it| it.size).sum();
+        let free = available - size - spacing * (count - 1 as Coord);
+        let (mut pos, spacing) = match alignment {
+            _ if free <= 0 as Coord => (start, spacing),
+            LayoutAlignment::Stretch | LayoutAlignment::Start => (start, spacing),
+            LayoutAlignment::Center => (start + free / 2 as Coord, spacing),
+            LayoutAlignment::End => (start + free, spacing),
+            LayoutAlignment::SpaceBetween if items.len() > 1 => {
+                (start, (available - size) / (count - 1 as Coord))
+            }
+            LayoutAlignment::SpaceBetween => (start, spacing),
+            LayoutAlignment::SpaceAround => {
+                let spacing = (available - size) / count;
+                (start + spacing / 2 as Coord, spacing)
+            }
+        };
+        for it in items.iter_mut() {
+            it.pos = pos;
+            pos += it.size + spacing;
+        }
+    }
+
+    /// Return the lines of the layout, and the minimum and preferred cross size of each of them.
+    pub fn lines_with_cross_size(
+        data: &FlexLayoutData,
+        cross_cells: &[BoxLayoutCellData],
+    ) -> Vec<(Range<usize>, Coord, Coord)> {
+        let items = to_flex_items(&data.cells, Some(data.size));
+        let available = data.size - data.padding.begin - data.padding.end;
+        break_lines(&items, available, data.spacing, data.wrap)
+            .into_iter()
+            .map(|line| {
+                let (min, pref) = cross_cells[line.clone()].iter().fold(
+                    (0 as Coord, 0 as Coord),
+                    |(min, pref), c| {
+                        (min.max(c.constraint.min), pref.max(c.constraint.preferred_bounded()))
+                    },
+                );
+                (line, min, pref)
+            })
+            .collect()
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // We want bit-wise equality here
+    fn test_flex_lines() {
+        let cell = |min, preferred, grow| FlexLayoutCellData {
+            constraint: LayoutInfo { min, preferred, ..Default::default() },
+            grow,
+            shrink: 1.,
+        };
+        let cells = [
+            cell(30., 40., 0.),
+            cell(0., 40., 1.),
+            cell(0., 30., 0.),
+            cell(0., 50., 1.),
+            cell(0., 40., 0.),
+        ];
+        let mut items = to_flex_items(&cells, Some(100.));
+
+        assert_eq!(break_lines(&items, 100., 10., true), [0..2, 2..4, 4..5]);
+        assert_eq!(break_lines(&items, 100., 10., false), [0..5]);
+
+        resolve_line(&mut items[0..2], 100., 10.);
+        assert_eq!((items[0].size, items[1].size), (40., 50.));
+
+        resolve_line(&mut items[4..5], 100., 10.);
+        position_line(&mut items[4..5], 0., 100., 10., LayoutAlignment::Center);
+        assert_eq!((items[4].pos, items[4].size), (30., 40.));
+
+        // All in one line: everything shrinks, but the first item doesn't go below its minimum
+        resolve_line(&mut items, 100., 0.);
+        let sizes: Vec<_> = items.iter().map(|it| it.size).collect();
+        assert_eq!(sizes, [30., 17.5, 13.125, 21.875, 17.5]);
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+/// The FlexLayoutData describes a FlexLayout along its main axis: the direction in which the
+/// items are placed before wrapping onto a new line.
+/// The width/height x/y correspond to that of a row.
+/// For columns, they are inverted
+pub struct FlexLayoutData<'a> {
+    pub size: Coord,
+    pub spacing: Coord,
+    pub padding: Padding,
+    pub alignment: LayoutAlignment,
+    pub wrap: bool,
+    pub cells: Slice<'a, FlexLayoutCellData>,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FlexLayoutCellData {
+    /// The constraint along the main axis
+    pub constraint: LayoutInfo,
+    /// The share of the remaining space of the line that the item takes
+    pub grow: f32,
+    /// How much the item shrinks, relative to its preferred size, when the line is too small
+    pub shrink: f32,
+}
+
+impl Default for FlexLayoutCellData {
+    fn default() -> Self {
+        FlexLayoutCellData { constraint: LayoutInfo::default(), grow: 0., shrink: 1. }
+    }
+}
+
+/// Solve a FlexLayout along its main axis.
+/// Return, for each cell, the position and size along the main axis
+pub fn solve_flex_layout(
+    data: &FlexLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
+
+    if data.cells.is_empty() {
+        return result;
+    }
+
+    let mut items = flex_internal::to_flex_items(&data.cells, Some(data.size));
+    let available = data.size - data.padding.begin - data.padding.end;
+    for line in flex_internal::break_lines(&items, available, data.spacing, data.wrap) {
+        let line = &mut items[line];
+        flex_internal::resolve_line(line, available, data.spacing);
+        flex_internal::position_line(
+            line,
+            data.padding.begin,
+            available,
+            data.spacing,
+            data.alignment,
+        );
+    }
+
+    fill_layout_result(
+        result.make_mut_slice(),
+        items.iter().map(|it| (it.pos, it.size)),
+        repeater_indexes,
+    );
+    result
+}
+
+/// Solve a FlexLayout along its cross axis.
+///
+/// The lines are computed from the main axis `data`, and the space left in the cross axis
+/// is shared among them. Each item fills its line within its own min and max bounds.
+pub fn solve_flex_layout_ortho(
+    data: &FlexLayoutData,
+    cross_cells: Slice<BoxLayoutCellData>,
+    cross_size: Coord,
+    cross_spacing: Coord,
+    cross_padding: &Padding,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
+
+    if data.cells.is_empty() {
+        return result;
+    }
+
+    let lines = flex_internal::lines_with_cross_size(data, &cross_cells);
+    let available = cross_size - cross_padding.begin - cross_padding.end;
+    let used = lines.iter().map(|(_, _, pref)| *pref).sum::<Coord>()
+        + cross_spacing * (lines.len() - 1) as Coord;
+    let extra = ((available - used) / lines.len() as Coord).max(0 as _);
+
+    let mut layout_data = Vec::with_capacity(cross_cells.len());
+    let mut pos = cross_padding.begin;
+    for (line, _, pref) in lines {
+        let line_size = pref + extra;
+        layout_data.extend(cross_cells[line].iter().map(|c| {
+            let size = line_size.min(c.constraint.max).max(c.constraint.min);
+            (pos, size)
+        }));
+        pos += line_size + cross_spacing;
+    }
+
+    fill_layout_result(result.make_mut_slice(), layout_data.into_iter(), repeater_indexes);
+    result
+}
+
+/// Return the LayoutInfo of a FlexLayout along its main axis.
+pub fn flex_layout_info(
+    cells: Slice<FlexLayoutCellData>,
+    spacing: Coord,
+    padding: &Padding,
+    wrap: bool,
+) -> LayoutInfo {
+    let count = cells.len();
+    if count < 1 {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    };
+    let items = flex_internal::to_flex_items(&cells, None);
+    let extra_w = padding.begin + padding.end + spacing * (count - 1) as Coord;
+    let item_min = |it: &flex_internal::FlexItem| if it.shrink > 0. { it.min } else { it.basis };
+    let min = if wrap {
+        items.iter().map(item_min).fold(0 as Coord, |a, b| a.max(b)) + padding.begin + padding.end
+    } else {
+        items.iter().map(item_min).sum::<Coord>() + extra_w
+    };
+    let preferred = items.iter().map(|it| it.basis).sum::<Coord>() + extra_w;
+    let stretch = cells.iter().map(|c| c.constraint.stretch).sum::<f32>();
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred,
+        stretch,
+    }
+}
+
+/// Return the LayoutInfo of a FlexLayout along its cross axis, when its main axis is described
+/// by `data`.
+pub fn flex_layout_info_ortho(
+    data: &FlexLayoutData,
+    cross_cells: Slice<BoxLayoutCellData>,
+    cross_spacing: Coord,
+    cross_padding: &Padding,
+) -> LayoutInfo {
+    if data.cells.is_empty() {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    };
+    let lines = flex_internal::lines_with_cross_size(data, &cross_cells);
+    let extra_w =
+        cross_padding.begin + cross_padding.end + cross_spacing * (lines.len() - 1) as Coord;
+    let min = lines.iter().map(|(_, min, _)| *min).sum::<Coord>() + extra_w;
+    let preferred = lines.iter().map(|(_, _, pref)| *pref).sum::<Coord>() + extra_w;
+    let stretch = cross_cells.iter().map(|c| c.constraint.stretch).fold(f32::MAX, f32::min);
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred,
+        stretch,
+    }
+}
+
 /// Given the cells of a layout of a Dialog, re-order the button according to the platform
 ///
 /// This function assume that the `roles` contains the roles of the button which are the first `cells`
@@ -780,6 +1157,57 @@ pub extern "C" fn slint_box_layout_info_ortho(
         super::box_layout_info_ortho(cells, padding)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_solve_flex_layout(
+        data: &FlexLayoutData,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flex_layout(data, repeater_indexes)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_solve_flex_layout_ortho(
+        data: &FlexLayoutData,
+        cross_cells: Slice<BoxLayoutCellData>,
+        cross_size: Coord,
+        cross_spacing: Coord,
+        cross_padding: &Padding,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flex_layout_ortho(
+            data,
+            cross_cells,
+            cross_size,
+            cross_spacing,
+            cross_padding,
+            repeater_indexes,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    /// Return the LayoutInfo of a FlexLayout along its main axis.
+    pub extern "C" fn slint_flex_layout_info(
+        cells: Slice<FlexLayoutCellData>,
+        spacing: Coord,
+        padding: &Padding,
+        wrap: bool,
+    ) -> LayoutInfo {
+        super::flex_layout_info(cells, spacing, padding, wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    /// Return the LayoutInfo of a FlexLayout along its cross axis.
+    pub extern "C" fn slint_flex_layout_info_ortho(
+        data: &FlexLayoutData,
+        cross_cells: Slice<BoxLayoutCellData>,
+        cross_spacing: Coord,
+        cross_padding: &Padding,
+    ) -> LayoutInfo {
+        super::flex_layout_info_ortho(data, cross_cells, cross_spacing, cross_padding)
+    }
+
     /// Calls [`reorder_dialog_button_layout`].
     ///
     /// Safety: `cells` must be a pointer to a mutable array of cell data, the array must have at
@@ -52,6 +52,7 @@
 #[cfg(feature = "software-renderer")]
 pub mod software_renderer;
 pub mod string;
+pub mod styled_text;
 pub mod tests;
 pub mod textlayout;
 pub mod timers;
@@ -4,6 +4,8 @@
 // for MenuVTable_static
 #![allow(unsafe_code)]
 
+use crate::graphics::Image;
+use crate::input::{KeyEvent, KeyEventType, PhysicalKey};
 use crate::item_rendering::CachedRenderingData;
 use crate::item_tree::{ItemTreeRc, ItemWeak, VisitChildrenResult};
 use crate::items::{ItemRc, ItemRef, MenuEntry, VoidArg};
@@ -18,6 +20,7 @@
 use alloc::rc::Rc;
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
+pub use i_slint_common::shortcuts::{parse_shortcut, Shortcut};
 use i_slint_core_macros::SlintElement;
 use vtable::{VRef, VRefMut};
 
@@ -58,6 +61,11 @@ pub fn new(item_tree: ItemTreeRc) -> Self {
         }
     }
 
+    /// The item tree containing the MenuItem elements
+    pub fn item_tree(&self) -> &ItemTreeRc {
+        &self.item_tree
+    }
+
     fn update_shadow_tree(&self) {
         self.tracker.as_ref().evaluate_if_dirty(|| {
             self.item_cache.replace(Default::default());
@@ -87,11 +95,25 @@ fn update_shadow_tree_recursive(&self, parent: &ItemRc) -> SharedVector<MenuEntr
                     let children = self.update_shadow_tree_recursive(&item);
                     let has_sub_menu = !children.is_empty();
                     let enabled = menu_item.enabled();
+                    let shortcut = menu_item.shortcut();
+                    let checkable = menu_item.checkable();
+                    let checked = menu_item.checked();
+                    let icon = menu_item.icon();
                     self.item_cache.borrow_mut().insert(
                         id.clone(),
                         ShadowTreeNode { item: ItemRc::downgrade(&item), children },
                     );
-                    result.push(MenuEntry { title, id, has_sub_menu, is_separator, enabled });
+                    result.push(MenuEntry {
+                        title,
+                        icon,
+                        id,
+                        shortcut,
+                        enabled,
+                        checkable,
+                        checked,
+                        has_sub_menu,
+                        is_separator,
+                    });
                 }
                 VisitChildrenResult::CONTINUE
             };
@@ -129,6 +151,10 @@ fn activate(&self, entry: &MenuEntry) {
             self.item_cache.borrow().get(entry.id.as_str()).and_then(|e| e.item.upgrade())
         {
             if let Some(menu_item) = menu_item.downcast::<MenuItem>() {
+                let menu_item = menu_item.as_pin_ref();
+                if menu_item.checkable() {
+                    menu_item.checked.set(!menu_item.checked());
+                }
                 menu_item.activated.call(&());
             }
         }
@@ -137,6 +163,104 @@ fn activate(&self, entry: &MenuEntry) {
 
 MenuVTable_static!(static MENU_FROM_ITEM_TREE_VT for MenuFromItemTree);
 
+/// Look for an enabled entry in the menu (or any of its sub menus) whose shortcut matches
+/// the key event, and activate it. Returns true if an entry was activated.
+pub fn activate_shortcut(menu: VRef<'_, MenuVTable>, event: &KeyEvent) -> bool {
+    fn find_entry(
+        menu: VRef<'_, MenuVTable>,
+        parent: Option<&MenuEntry>,
+        event: &KeyEvent,
+        depth: usize,
+    ) -> Option<MenuEntry> {
+        let mut entries = SharedVector::default();
+        menu.sub_menu(parent, &mut entries);
+        for entry in entries.iter().filter(|e| e.enabled && !e.is_separator) {
+            if entry.has_sub_menu {
+                // infinite menu depth is possible, so limit the recursion
+                if depth < 15 {
+                    if let Some(found) = find_entry(menu, Some(entry), event, depth + 1) {
+                        return Some(found);
+                    }
+                }
+            } else if shortcut_matches(&entry.shortcut, event) {
+                return Some(entry.clone());
+            }
+        }
+        None
+    }
+
+    if event.event_type != KeyEventType::KeyPressed {
+        return false;
+    }
+    match find_entry(menu, None, event, 0) {
+        Some(entry) => {
+            menu.activate(&entry);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns true if the key event is a key press that corresponds to the shortcut,
+/// such as `"Ctrl+S"`, `"F5"` or `"Copy"` (as parsed by [`parse_shortcut`])
+pub fn shortcut_matches(shortcut: &str, event: &KeyEvent) -> bool {
+    !shortcut.is_empty()
+        && parse_shortcut(shortcut)
+            .is_some_and(|shortcut| parsed_shortcut_matches(&shortcut, event))
+}
+
+/// Same as [`shortcut_matches`], for a shortcut that was already parsed
+pub fn parsed_shortcut_matches(shortcut: &Shortcut, event: &KeyEvent) -> bool {
+    if event.event_type != KeyEventType::KeyPressed {
+        return false;
+    }
+    match shortcut {
+        Shortcut::Key { modifiers, key } => {
+            event.modifiers.control == modifiers.control
+                && event.modifiers.alt == modifiers.alt
+                && event.modifiers.shift == modifiers.shift
+                && event.modifiers.meta == modifiers.meta
+                && (event.text.chars().flat_map(char::to_lowercase).eq(core::iter::once(*key))
+                    // Shift changes the character of digits and symbols, such as `!` for Shift+1
+                    || (modifiers.shift
+                        && !key.is_alphabetic()
+                        && unshifted_character(event.physical_key) == Some(*key)))
+        }
+        Shortcut::Standard(name) => {
+            event.shortcut().is_some_and(|standard| standard.name() == *name)
+        }
+    }
+}
+
+/// Returns the character that a digit or symbol key produces without Shift on a US keyboard
+fn unshifted_character(physical_key: PhysicalKey) -> Option<char> {
+    use PhysicalKey::*;
+    Some(match physical_key {
+        Digit0 => '0',
+        Digit1 => '1',
+        Digit2 => '2',
+        Digit3 => '3',
+        Digit4 => '4',
+        Digit5 => '5',
+        Digit6 => '6',
+        Digit7 => '7',
+        Digit8 => '8',
+        Digit9 => '9',
+        Minus => '-',
+        Equal => '=',
+        BracketLeft => '[',
+        BracketRight => ']',
+        Semicolon => ';',
+        Quote => '\'',
+        Backquote => '`',
+        Backslash => '\\',
+        Comma => ',',
+        Period => '.',
+        Slash => '/',
+        _ => return None,
+    })
+}
+
 #[repr(C)]
 #[derive(const_field_offset::FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -146,6 +270,10 @@ pub struct MenuItem {
     pub title: Property<SharedString>,
     pub activated: Callback<VoidArg>,
     pub enabled: Property<bool>,
+    pub shortcut: Property<SharedString>,
+    pub checkable: Property<bool>,
+    pub checked: Property<bool>,
+    pub icon: Property<Image>,
 }
 
 impl crate::items::Item for MenuItem {
@@ -226,6 +354,56 @@ impl crate::items::ItemConsts for MenuItem {
     > = MenuItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+#[test]
+fn test_shortcut_matches() {
+    let mut event = KeyEvent { text: "S".into(), ..Default::default() };
+    event.modifiers.control = true;
+    assert!(shortcut_matches("Ctrl+S", &event));
+    assert!(shortcut_matches("control + s", &event));
+    assert!(!shortcut_matches("Ctrl+Shift+S", &event));
+    assert!(!shortcut_matches("S", &event));
+    assert!(!shortcut_matches("", &event));
+    assert!(!shortcut_matches("Hyper+S", &event));
+
+    event.text = crate::input::key_codes::F5.into();
+    event.modifiers = Default::default();
+    assert!(shortcut_matches("F5", &event));
+    assert!(!shortcut_matches("F6", &event));
+
+    event.text = "+".into();
+    event.modifiers.alt = true;
+    assert!(shortcut_matches("Alt++", &event));
+
+    event.text = crate::input::key_codes::Return.into();
+    event.modifiers = Default::default();
+    assert!(shortcut_matches("Enter", &event));
+    assert!(shortcut_matches("Return", &event));
+
+    event.text = crate::input::key_codes::UpArrow.into();
+    assert!(shortcut_matches("Up", &event));
+    assert!(shortcut_matches("UpArrow", &event));
+
+    event.text = "z".into();
+    event.modifiers.control = true;
+    assert!(shortcut_matches("Undo", &event));
+    assert!(!shortcut_matches("Redo", &event));
+
+    // Shift changes the text of digits and symbols, the physical key tells which key was pressed
+    event.text = "!".into();
+    event.physical_key = PhysicalKey::Digit1;
+    event.modifiers.shift = true;
+    assert!(shortcut_matches("Ctrl+Shift+1", &event));
+    assert!(!shortcut_matches("Ctrl+Shift+2", &event));
+    assert!(!shortcut_matches("Ctrl+1", &event));
+    event.text = "+".into();
+    event.physical_key = PhysicalKey::Equal;
+    assert!(shortcut_matches("Ctrl+Shift+=", &event));
+    assert!(shortcut_matches("Ctrl+Shift++", &event));
+
+    event.event_type = KeyEventType::KeyReleased;
+    assert!(!shortcut_matches("Ctrl+Z", &event));
+}
+
 #[cfg(feature = "ffi")]
 pub mod ffi {
     use super::*;
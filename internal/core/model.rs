@@ -7,7 +7,7 @@
 
 use crate::item_tree::ItemTreeVTable;
 use crate::item_tree::TraversalOrder;
-pub use crate::items::{StandardListViewItem, TableColumn};
+pub use crate::items::{StandardListViewItem, TableColumn, TreeViewItem};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
@@ -23,9 +23,14 @@
 pub use model_peer::*;
 use once_cell::unsync::OnceCell;
 use pin_project::pin_project;
+pub use tree_model::{
+    FlattenedTreeModel, FlattenedTreeRow, TreeModel, TreeModelListener, TreeModelNotify,
+    TreeModelTracker,
+};
 
 mod adapters;
 mod model_peer;
+mod tree_model;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -437,8 +442,22 @@ pub fn swap(&self, a: usize, b: usize) {
         }
 
         self.array.borrow_mut().swap(a, b);
-        self.notify.row_changed(a);
-        self.notify.row_changed(b);
+        let (a, b) = (a.min(b), a.max(b));
+        // Report the swap as two moves so that views can keep their items
+        self.notify.row_moved(b, 1, a);
+        self.notify.row_moved(a + 1, 1, b);
+    }
+
+    /// Moves the row at index `from` so that it ends up at index `to`.
+    /// The rows in between are shifted.
+    /// This function panics if `from` or `to` is >= row_count().
+    pub fn move_row(&self, from: usize, to: usize) {
+        let mut array = self.array.borrow_mut();
+        assert!(to < array.len(), "move_row: destination index out of bounds");
+        let value = array.remove(from);
+        array.insert(to, value);
+        drop(array);
+        self.notify.row_moved(from, 1, to);
     }
 }
 
@@ -817,11 +836,17 @@ fn init(&self) {}
 
     /// Layout this item in the listview
     ///
-    /// offset_y is the `y` position where this item should be placed.
-    /// it should be updated to be to the y position of the next item.
+    /// offset is the `y` position (or the `x` position for the Horizontal orientation)
+    /// where this item should be placed.
+    /// it should be updated to be to the position of the next item.
     ///
-    /// Returns the minimum item width which will be used to compute the listview's viewport width
-    fn listview_layout(self: Pin<&Self>, _offset_y: &mut LogicalLength) -> LogicalLength {
+    /// Returns the minimum item width (or height for the Horizontal orientation) which will
+    /// be used to compute the listview's viewport width (or height)
+    fn listview_layout(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _offset: &mut LogicalLength,
+    ) -> LogicalLength {
         LogicalLength::default()
     }
 
@@ -832,6 +857,77 @@ fn box_layout_data(
     ) -> crate::layout::BoxLayoutCellData {
         crate::layout::BoxLayoutCellData::default()
     }
+
+    /// Returns what's needed to perform the layout if this ItemTrees is in a flex layout
+    fn flex_layout_data(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+    ) -> crate::layout::FlexLayoutCellData {
+        crate::layout::FlexLayoutCellData::default()
+    }
+
+    /// Called when this ItemTree is removed from the model. Starts its exit transition and
+    /// returns true if it has one, in which case the ItemTree is kept alive until
+    /// [`Self::is_exit_done()`] returns true.
+    fn start_exit(&self) -> bool {
+        false
+    }
+
+    /// Returns true once the exit transition started by [`Self::start_exit()`] is over.
+    fn is_exit_done(&self) -> bool {
+        true
+    }
+}
+
+/// The instances of a Repeater or a Conditional that were removed from the model, but are kept
+/// alive while they play their exit transition.
+struct LeavingInstances<C: RepeatedItemTree>(RefCell<Vec<ItemTreeRc<C>>>);
+
+impl<C: RepeatedItemTree> Default for LeavingInstances<C> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<C: RepeatedItemTree> LeavingInstances<C> {
+    /// Starts the exit transition of the instances that were removed.
+    /// The ones without exit transition are dropped right away.
+    fn retire(&self, instances: impl IntoIterator<Item = ItemTreeRc<C>>) {
+        let leaving = instances.into_iter().filter(|c| c.start_exit()).collect::<Vec<_>>();
+        self.0.borrow_mut().extend(leaving);
+    }
+
+    /// Drops the instances whose exit transition is over
+    fn drop_finished(&self) {
+        let leaving = core::mem::take(&mut *self.0.borrow_mut());
+        if leaving.is_empty() {
+            return;
+        }
+        let mut still_leaving = leaving.into_iter().filter(|c| !c.is_exit_done()).collect();
+        let mut leaving = self.0.borrow_mut();
+        // Instances might have been retired while we were checking
+        core::mem::swap(&mut *leaving, &mut still_leaving);
+        leaving.extend(still_leaving);
+    }
+
+    /// The leaving instances are rendered below the other instances, but they don't get any
+    /// input event, so they are only visited in the back to front order.
+    fn visit(
+        &self,
+        order: TraversalOrder,
+        mut visitor: crate::item_tree::ItemVisitorRefMut,
+    ) -> crate::item_tree::VisitChildrenResult {
+        if order == TraversalOrder::BackToFront {
+            let leaving = self.0.borrow().clone();
+            for c in leaving {
+                if c.as_pin_ref().visit_children_item(-1, order, visitor.borrow_mut()).has_aborted()
+                {
+                    return crate::item_tree::VisitChildrenResult::abort(0, 0);
+                }
+            }
+        }
+        crate::item_tree::VisitChildrenResult::CONTINUE
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -847,13 +943,13 @@ struct RepeaterInner<C: RepeatedItemTree> {
     // The remaining properties only make sense for ListView
     /// The model row (index) of the first ItemTree in the `instances` vector.
     offset: usize,
-    /// The average visible item height.
-    cached_item_height: LogicalLength,
-    /// The viewport_y last time the layout of the ListView was done
-    previous_viewport_y: LogicalLength,
+    /// The average visible item height (or width for a horizontal ListView).
+    cached_item_size: LogicalLength,
+    /// The viewport_y (or viewport_x) last time the layout of the ListView was done
+    previous_viewport_pos: LogicalLength,
     /// the position of the item in the row `offset` (which corresponds to `instances[0]`).
     /// We will try to keep this constant when re-layouting items
-    anchor_y: LogicalLength,
+    anchor_pos: LogicalLength,
 }
 
 impl<C: RepeatedItemTree> Default for RepeaterInner<C> {
@@ -861,9 +957,9 @@ fn default() -> Self {
         RepeaterInner {
             instances: Default::default(),
             offset: 0,
-            cached_item_height: Default::default(),
-            previous_viewport_y: Default::default(),
-            anchor_y: Default::default(),
+            cached_item_size: Default::default(),
+            previous_viewport_pos: Default::default(),
+            anchor_pos: Default::default(),
         }
     }
 }
@@ -880,6 +976,20 @@ pub struct RepeaterTracker<T: RepeatedItemTree> {
     /// Only used for the list view to track if the scrollbar has changed and item needs to be laid out again.
     #[pin]
     listview_geometry_tracker: crate::properties::PropertyTracker,
+    leaving: LeavingInstances<T>,
+}
+
+/// Returns the new index of `row` after `count` rows starting at `from` were moved to `to`.
+fn moved_row_index(row: usize, from: usize, count: usize, to: usize) -> usize {
+    if (from..from + count).contains(&row) {
+        return row - from + to;
+    }
+    let row = if row >= from + count { row - count } else { row };
+    if row >= to {
+        row + count
+    } else {
+        row
+    }
 }
 
 impl<T: RepeatedItemTree> ModelChangeListener for RepeaterTracker<T> {
@@ -945,16 +1055,45 @@ fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
             count = inner.instances.len() - index;
         }
         self.is_dirty.set(true);
-        inner.instances.drain(index..(index + count));
+        let removed = inner.instances.drain(index..(index + count)).collect::<Vec<_>>();
         for c in inner.instances[index..].iter_mut() {
             // Because all the indexes are dirty
             c.0 = RepeatedInstanceState::Dirty;
         }
+        drop(inner);
+        self.leaving.retire(removed.into_iter().filter_map(|c| c.1));
+    }
+
+    /// Notify the peers that rows were moved. The existing instances are kept.
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        let mut inner = self.inner.borrow_mut();
+        let begin = from.min(to);
+        let end = from.max(to) + count;
+        if count == 0 || end <= inner.offset || begin >= inner.offset + inner.instances.len() {
+            return;
+        }
+        if begin < inner.offset || end > inner.offset + inner.instances.len() {
+            // Part of the moved rows are not instantiated, so we can't just move the instances
+            drop(inner);
+            self.row_removed(from, count);
+            self.row_added(to, count);
+            return;
+        }
+        self.is_dirty.set(true);
+        let (from, to, begin, end) =
+            (from - inner.offset, to - inner.offset, begin - inner.offset, end - inner.offset);
+        let moved = inner.instances.drain(from..from + count).collect::<Vec<_>>();
+        inner.instances.splice(to..to, moved);
+        for c in inner.instances[begin..end].iter_mut() {
+            // Because the indexes are dirty
+            c.0 = RepeatedInstanceState::Dirty;
+        }
     }
 
     fn reset(self: Pin<&Self>) {
         self.is_dirty.set(true);
-        self.inner.borrow_mut().instances.clear();
+        let removed = core::mem::take(&mut self.inner.borrow_mut().instances);
+        self.leaving.retire(removed.into_iter().filter_map(|c| c.1));
     }
 }
 
@@ -965,6 +1104,7 @@ fn default() -> Self {
             model: Property::new_named(ModelRc::default(), "i_slint_core::Repeater::model"),
             is_dirty: Property::new_named(false, "i_slint_core::Repeater::is_dirty"),
             listview_geometry_tracker: Default::default(),
+            leaving: Default::default(),
         }
     }
 }
@@ -978,6 +1118,16 @@ fn default() -> Self {
     }
 }
 
+/// Returns the row requested by the ListView's `scroll-to-index()`, if any, and resets the
+/// request to -1. The result is passed to [`Repeater::ensure_updated_listview`] and its variants.
+pub fn take_requested_row(requested_row: Pin<&Property<i32>>) -> Option<usize> {
+    let row = requested_row.get();
+    if row >= 0 {
+        requested_row.set(-1);
+    }
+    usize::try_from(row).ok()
+}
+
 impl<C: RepeatedItemTree + 'static> Repeater<C> {
     fn data(self: Pin<&Self>) -> Pin<&RepeaterTracker<C>> {
         self.project_ref().0.get()
@@ -990,7 +1140,8 @@ fn model(self: Pin<&Self>) -> ModelRc<C::Data> {
             let old_model = model.get_internal();
             let m = model.get();
             if old_model != m {
-                *self.data().inner.borrow_mut() = RepeaterInner::default();
+                let old_inner = core::mem::take(&mut *self.data().inner.borrow_mut());
+                self.data().leaving.retire(old_inner.instances.into_iter().filter_map(|c| c.1));
                 self.data().is_dirty.set(true);
                 let peer = self.project_ref().0.model_peer();
                 m.model_tracker().attach_peer(peer);
@@ -1008,6 +1159,7 @@ pub fn ensure_updated(self: Pin<&Self>, init: impl Fn() -> ItemTreeRc<C>) {
         if self.data().project_ref().is_dirty.get() {
             self.ensure_updated_impl(init, &model, model.row_count());
         }
+        self.data().leaving.drop_finished();
     }
 
     // returns true if new items were created
@@ -1047,6 +1199,9 @@ fn ensure_updated_impl(
     }
 
     /// Same as `Self::ensure_updated` but for a ListView
+    ///
+    /// `requested_row` is a row that should be scrolled into view.
+    #[allow(clippy::too_many_arguments)]
     pub fn ensure_updated_listview(
         self: Pin<&Self>,
         init: impl Fn() -> ItemTreeRc<C>,
@@ -1055,35 +1210,192 @@ pub fn ensure_updated_listview(
         viewport_y: Pin<&Property<LogicalLength>>,
         listview_width: LogicalLength,
         listview_height: Pin<&Property<LogicalLength>>,
+        requested_row: Option<usize>,
+    ) {
+        self.ensure_updated_virtualized(
+            init,
+            Orientation::Vertical,
+            viewport_width,
+            viewport_height,
+            viewport_y,
+            listview_width,
+            listview_height,
+            requested_row,
+        )
+    }
+
+    /// Same as `Self::ensure_updated_listview` but for a HorizontalListView: the items are
+    /// laid out from left to right and only the visible columns are instantiated.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ensure_updated_listview_horizontal(
+        self: Pin<&Self>,
+        init: impl Fn() -> ItemTreeRc<C>,
+        viewport_width: Pin<&Property<LogicalLength>>,
+        viewport_height: Pin<&Property<LogicalLength>>,
+        viewport_x: Pin<&Property<LogicalLength>>,
+        listview_width: Pin<&Property<LogicalLength>>,
+        listview_height: LogicalLength,
+        requested_row: Option<usize>,
+    ) {
+        self.ensure_updated_virtualized(
+            init,
+            Orientation::Horizontal,
+            viewport_height,
+            viewport_width,
+            viewport_x,
+            listview_height,
+            listview_width,
+            requested_row,
+        )
+    }
+
+    /// Same as `Self::ensure_updated_listview` but for a GridView: all the items have the
+    /// size of a cell, fill rows from left to right, and only the visible rows are instantiated.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ensure_updated_grid(
+        self: Pin<&Self>,
+        init: impl Fn() -> ItemTreeRc<C>,
+        viewport_width: Pin<&Property<LogicalLength>>,
+        viewport_height: Pin<&Property<LogicalLength>>,
+        viewport_y: Pin<&Property<LogicalLength>>,
+        listview_width: LogicalLength,
+        listview_height: Pin<&Property<LogicalLength>>,
+        cell_width: LogicalLength,
+        cell_height: LogicalLength,
+        requested_row: Option<usize>,
+    ) {
+        // Query is_dirty to track model changes
+        self.data().project_ref().is_dirty.get();
+        self.data().project_ref().is_dirty.set(false);
+
+        let model = self.model();
+        let row_count = model.row_count();
+        let zero = LogicalLength::zero();
+        let columns = if cell_width > zero {
+            ((listview_width / cell_width).get().floor() as usize).max(1)
+        } else {
+            1
+        };
+        let grid_rows = row_count.div_ceil(columns);
+        let content_height = cell_height * grid_rows as Coord;
+        viewport_width.set(listview_width.max(cell_width * columns as Coord));
+        viewport_height.set(content_height);
+
+        let listview_height = listview_height.get();
+        let mut vp_y = viewport_y.get();
+        if let Some(row) = requested_row.filter(|row| *row < row_count) {
+            let row_y = cell_height * (row / columns) as Coord;
+            vp_y =
+                crate::items::viewport_position_showing(vp_y, row_y, cell_height, listview_height);
+        }
+        let vp_y = vp_y.min(zero).max((listview_height - content_height).min(zero));
+        viewport_y.set(vp_y);
+
+        let mut inner = self.0.inner.borrow_mut();
+        if row_count == 0 || cell_height <= zero {
+            inner.instances.clear();
+            return;
+        }
+
+        let first_row = ((-vp_y / cell_height).get().floor() as usize).min(grid_rows - 1);
+        let end_row = (((-vp_y + listview_height) / cell_height).get().ceil() as usize)
+            .clamp(first_row + 1, grid_rows);
+        let first = first_row * columns;
+        let end = (end_row * columns).min(row_count);
+
+        // Keep the instances that are still visible, and add empty slots for the other ones
+        let mut old_instances = core::mem::take(&mut inner.instances);
+        let keep = first.max(inner.offset)..end.min(inner.offset + old_instances.len());
+        let mut instances = Vec::with_capacity(end - first);
+        if keep.is_empty() {
+            instances.extend((first..end).map(|_| (RepeatedInstanceState::Dirty, None)));
+        } else {
+            old_instances.truncate(keep.end - inner.offset);
+            old_instances.drain(..keep.start - inner.offset);
+            instances.extend((first..keep.start).map(|_| (RepeatedInstanceState::Dirty, None)));
+            instances.extend(old_instances);
+            instances.extend((keep.end..end).map(|_| (RepeatedInstanceState::Dirty, None)));
+        }
+        inner.instances = instances;
+        inner.offset = first;
+
+        let mut indices_to_init = Vec::new();
+        for (i, c) in inner.instances.iter_mut().enumerate() {
+            let idx = first + i;
+            if c.0 == RepeatedInstanceState::Dirty {
+                if c.1.is_none() {
+                    c.1 = Some(init());
+                    indices_to_init.push(i);
+                }
+                if let Some(data) = model.row_data(idx) {
+                    c.1.as_ref().unwrap().update(idx, data);
+                }
+                c.0 = RepeatedInstanceState::Clean;
+            }
+            // Like for the ListView, the y position is relative to the visible area
+            let mut x = cell_width * (idx % columns) as Coord;
+            let mut y = cell_height * (idx / columns) as Coord + vp_y;
+            let instance = c.1.as_ref().unwrap().as_pin_ref();
+            instance.listview_layout(Orientation::Horizontal, &mut x);
+            instance.listview_layout(Orientation::Vertical, &mut y);
+        }
+
+        drop(inner);
+        let inner = self.0.inner.borrow();
+        for item in indices_to_init.into_iter().filter_map(|index| inner.instances.get(index)) {
+            item.1.as_ref().unwrap().init();
+        }
+    }
+
+    /// Implementation of the ListView virtualization along the `orientation` axis.
+    ///
+    /// The "main" size and position are along the `orientation` axis, the "cross" size
+    /// is along the other one.
+    #[allow(clippy::too_many_arguments)]
+    fn ensure_updated_virtualized(
+        self: Pin<&Self>,
+        init: impl Fn() -> ItemTreeRc<C>,
+        orientation: Orientation,
+        viewport_cross_size: Pin<&Property<LogicalLength>>,
+        viewport_main_size: Pin<&Property<LogicalLength>>,
+        viewport_main_pos: Pin<&Property<LogicalLength>>,
+        listview_cross_size: LogicalLength,
+        listview_main_size: Pin<&Property<LogicalLength>>,
+        requested_row: Option<usize>,
     ) {
         // Query is_dirty to track model changes
         self.data().project_ref().is_dirty.get();
         self.data().project_ref().is_dirty.set(false);
 
-        let mut vp_width = listview_width;
+        let mut vp_width = listview_cross_size;
         let model = self.model();
         let row_count = model.row_count();
         let zero = LogicalLength::zero();
         if row_count == 0 {
             self.0.inner.borrow_mut().instances.clear();
-            viewport_height.set(zero);
-            viewport_y.set(zero);
-            viewport_width.set(vp_width);
+            viewport_main_size.set(zero);
+            viewport_main_pos.set(zero);
+            viewport_cross_size.set(vp_width);
             return;
         }
 
-        let listview_height = listview_height.get();
-        let mut vp_y = viewport_y.get().min(zero);
+        let listview_height = listview_main_size.get();
+        let mut vp_y = viewport_main_pos.get().min(zero);
+
+        let item_height = |x: Pin<&C>| match orientation {
+            Orientation::Vertical => x.item_geometry(0).height_length(),
+            Orientation::Horizontal => x.item_geometry(0).width_length(),
+        };
 
         // We need some sort of estimation of the element height
-        let cached_item_height = self.data().inner.borrow_mut().cached_item_height;
+        let cached_item_height = self.data().inner.borrow_mut().cached_item_size;
         let element_height = if cached_item_height > zero {
             cached_item_height
         } else {
             let total_height = Cell::new(zero);
             let count = Cell::new(0);
             let get_height_visitor = |x: &ItemTreeRc<C>| {
-                let height = x.as_pin_ref().item_geometry(0).height_length();
+                let height = item_height(x.as_pin_ref());
                 count.set(count.get() + 1);
                 total_height.set(total_height.get() + height);
             };
@@ -1120,20 +1432,67 @@ pub fn ensure_updated_listview(
             inner.offset = row_count - 1;
         }
 
+        // Scroll the requested row into view. If it is instantiated, we know its position.
+        // Otherwise, jump to it: like with `viewport_position_showing`, it will be at the top
+        // of the view if it is before the instantiated rows, and at the bottom if it is after.
+        let mut jump_to_row = None;
+        if let Some(row) = requested_row.filter(|row| *row < row_count) {
+            let instantiated = inner.offset..inner.offset + inner.instances.len();
+            let row_geometry = if instantiated.contains(&row) {
+                inner.instances[..=row - inner.offset]
+                    .iter()
+                    .map(|c| c.1.as_ref().map(|x| item_height(x.as_pin_ref())))
+                    .collect::<Option<Vec<_>>>()
+                    .map(|sizes| {
+                        let (size, before) = sizes.split_last().unwrap();
+                        (before.iter().fold(inner.anchor_pos, |pos, s| pos + *s), *size)
+                    })
+            } else {
+                None
+            };
+            match row_geometry {
+                Some((pos, size)) => {
+                    vp_y =
+                        crate::items::viewport_position_showing(vp_y, pos, size, listview_height)
+                            .min(zero);
+                }
+                None => {
+                    vp_y = -element_height * row as Coord;
+                    jump_to_row = Some((row, row > inner.offset));
+                }
+            }
+        }
+
         let one_and_a_half_screen = listview_height * 3 as Coord / 2 as Coord;
-        let first_item_y = inner.anchor_y;
+        let first_item_y = inner.anchor_pos;
         let last_item_bottom = first_item_y + element_height * inner.instances.len() as Coord;
 
         let mut indices_to_init = Vec::new();
 
-        let (mut new_offset, mut new_offset_y) = if first_item_y > -vp_y + one_and_a_half_screen
+        let (mut new_offset, mut new_offset_y) = if let Some((row, at_bottom)) = jump_to_row {
+            inner.instances.clear();
+            inner.offset = row;
+            if at_bottom {
+                // Instantiate the row first to know its size; the rows before are filled in below
+                let new_instance = init();
+                if let Some(data) = model.row_data(row) {
+                    new_instance.update(row, data);
+                }
+                let size = item_height(new_instance.as_pin_ref());
+                indices_to_init.push(0);
+                inner.instances.push((RepeatedInstanceState::Clean, Some(new_instance)));
+                (row, (listview_height - size).max(zero))
+            } else {
+                (row, zero)
+            }
+        } else if first_item_y > -vp_y + one_and_a_half_screen
             || last_item_bottom + element_height < -vp_y
         {
             // We are jumping more than 1.5 screens, consider this as a random seek.
             inner.instances.clear();
             inner.offset = ((-vp_y / element_height).get().floor() as usize).min(row_count - 1);
             (inner.offset, zero)
-        } else if vp_y < inner.previous_viewport_y {
+        } else if vp_y < inner.previous_viewport_pos {
             // we scrolled down, try to find out the new offset.
             let mut it_y = first_item_y + vp_y;
             let mut new_offset = inner.offset;
@@ -1149,7 +1508,7 @@ pub fn ensure_updated_listview(
                     }
                     c.0 = RepeatedInstanceState::Clean;
                 }
-                let h = c.1.as_ref().unwrap().as_pin_ref().item_geometry(0).height_length();
+                let h = item_height(c.1.as_ref().unwrap().as_pin_ref());
                 if it_y + h > zero || new_offset + 1 >= row_count {
                     break;
                 }
@@ -1169,13 +1528,9 @@ pub fn ensure_updated_listview(
             // inner.instances, if any.
             while new_offset > inner.offset && new_offset_y > zero {
                 new_offset -= 1;
-                new_offset_y -= inner.instances[new_offset - inner.offset]
-                    .1
-                    .as_ref()
-                    .unwrap()
-                    .as_pin_ref()
-                    .item_geometry(0)
-                    .height_length();
+                new_offset_y -= item_height(
+                    inner.instances[new_offset - inner.offset].1.as_ref().unwrap().as_pin_ref(),
+                );
             }
             // If there is still a gap, fill it with new instances before
             let mut new_instances = Vec::new();
@@ -1185,7 +1540,7 @@ pub fn ensure_updated_listview(
                 if let Some(data) = model.row_data(new_offset) {
                     new_instance.update(new_offset, data);
                 }
-                new_offset_y -= new_instance.as_pin_ref().item_geometry(0).height_length();
+                new_offset_y -= item_height(new_instance.as_pin_ref());
                 new_instances.push(new_instance);
             }
             if !new_instances.is_empty() {
@@ -1225,7 +1580,7 @@ pub fn ensure_updated_listview(
                     c.0 = RepeatedInstanceState::Clean;
                 }
                 if let Some(x) = c.1.as_ref() {
-                    vp_width = vp_width.max(x.as_pin_ref().listview_layout(&mut y));
+                    vp_width = vp_width.max(x.as_pin_ref().listview_layout(orientation, &mut y));
                 }
                 idx += 1;
                 if y >= listview_height {
@@ -1239,7 +1594,8 @@ pub fn ensure_updated_listview(
                 if let Some(data) = model.row_data(idx) {
                     new_instance.update(idx, data);
                 }
-                vp_width = vp_width.max(new_instance.as_pin_ref().listview_layout(&mut y));
+                vp_width =
+                    vp_width.max(new_instance.as_pin_ref().listview_layout(orientation, &mut y));
                 indices_to_init.push(inner.instances.len());
                 inner.instances.push((RepeatedInstanceState::Clean, Some(new_instance)));
                 idx += 1;
@@ -1276,13 +1632,13 @@ pub fn ensure_updated_listview(
             }
 
             // Now re-compute some coordinate such a way that the scrollbar are adjusted.
-            inner.cached_item_height = (y - new_offset_y) / inner.instances.len() as Coord;
-            inner.anchor_y = inner.cached_item_height * inner.offset as Coord;
-            viewport_height.set(inner.cached_item_height * row_count as Coord);
-            viewport_width.set(vp_width);
-            let new_viewport_y = -inner.anchor_y + new_offset_y;
-            viewport_y.set(new_viewport_y);
-            inner.previous_viewport_y = new_viewport_y;
+            inner.cached_item_size = (y - new_offset_y) / inner.instances.len() as Coord;
+            inner.anchor_pos = inner.cached_item_size * inner.offset as Coord;
+            viewport_main_size.set(inner.cached_item_size * row_count as Coord);
+            viewport_cross_size.set(vp_width);
+            let new_viewport_y = -inner.anchor_pos + new_offset_y;
+            viewport_main_pos.set(new_viewport_y);
+            inner.previous_viewport_pos = new_viewport_y;
             break;
         }
         drop(inner);
@@ -1309,6 +1665,9 @@ pub fn visit(
         order: TraversalOrder,
         mut visitor: crate::item_tree::ItemVisitorRefMut,
     ) -> crate::item_tree::VisitChildrenResult {
+        if self.0.leaving.visit(order, visitor.borrow_mut()).has_aborted() {
+            return crate::item_tree::VisitChildrenResult::abort(0, 0);
+        }
         // We can't keep self.inner borrowed because the event might modify the model
         let count = self.0.inner.borrow().instances.len() as u32;
         for i in 0..count {
@@ -1364,6 +1723,7 @@ pub struct Conditional<C: RepeatedItemTree> {
     #[pin]
     model: Property<bool>,
     instance: RefCell<Option<ItemTreeRc<C>>>,
+    leaving: LeavingInstances<C>,
 }
 
 impl<C: RepeatedItemTree> Default for Conditional<C> {
@@ -1371,6 +1731,7 @@ fn default() -> Self {
         Self {
             model: Property::new_named(false, "i_slint_core::Conditional::model"),
             instance: RefCell::new(None),
+            leaving: Default::default(),
         }
     }
 }
@@ -1382,12 +1743,14 @@ pub fn ensure_updated(self: Pin<&Self>, init: impl Fn() -> ItemTreeRc<C>) {
         let model = self.project_ref().model.get();
 
         if !model {
-            drop(self.instance.replace(None));
+            let removed = self.instance.replace(None);
+            self.leaving.retire(removed);
         } else if self.instance.borrow().is_none() {
             let i = init();
             self.instance.replace(Some(i.clone()));
             i.init();
         }
+        self.leaving.drop_finished();
     }
 
     /// Set the model binding
@@ -1401,6 +1764,9 @@ pub fn visit(
         order: TraversalOrder,
         mut visitor: crate::item_tree::ItemVisitorRefMut,
     ) -> crate::item_tree::VisitChildrenResult {
+        if self.leaving.visit(order, visitor.borrow_mut()).has_aborted() {
+            return crate::item_tree::VisitChildrenResult::abort(0, 0);
+        }
         // We can't keep self.inner borrowed because the event might modify the model
         let instance = self.instance.borrow().clone();
         if let Some(c) = instance {
@@ -1456,6 +1822,12 @@ fn from(value: &str) -> Self {
     }
 }
 
+impl From<StandardListViewItem> for SharedString {
+    fn from(value: StandardListViewItem) -> Self {
+        value.text
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1552,6 +1924,7 @@ struct TestView {
         changed_rows: RefCell<Vec<(usize, usize)>>,
         added_rows: RefCell<Vec<(usize, usize, usize)>>,
         removed_rows: RefCell<Vec<(usize, usize, usize)>>,
+        moved_rows: RefCell<Vec<(usize, usize, usize, usize)>>,
         reset: RefCell<usize>,
         model: RefCell<Option<std::rc::Weak<dyn Model<Data = i32>>>>,
     }
@@ -1560,6 +1933,7 @@ fn clear(&self) {
             self.changed_rows.borrow_mut().clear();
             self.added_rows.borrow_mut().clear();
             self.removed_rows.borrow_mut().clear();
+            self.moved_rows.borrow_mut().clear();
             *self.reset.borrow_mut() = 0;
         }
         fn row_count(&self) -> usize {
@@ -1582,6 +1956,10 @@ fn row_added(self: Pin<&Self>, index: usize, count: usize) {
         fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
             self.removed_rows.borrow_mut().push((index, count, self.row_count()));
         }
+
+        fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+            self.moved_rows.borrow_mut().push((from, count, to, self.row_count()));
+        }
         fn reset(self: Pin<&Self>) {
             *self.reset.borrow_mut() += 1;
         }
@@ -1634,13 +2012,56 @@ fn test_vecmodel_set_vec() {
         view.clear();
 
         model.swap(1, 2);
-        assert_eq!(&*view.changed_rows.borrow(), &[(1, 8), (2, 8)]);
+        assert!(view.changed_rows.borrow().is_empty());
         assert!(view.added_rows.borrow().is_empty());
         assert!(view.removed_rows.borrow().is_empty());
+        assert_eq!(&*view.moved_rows.borrow(), &[(2, 1, 1, 8)]);
         assert_eq!(*view.reset.borrow(), 0);
         view.clear();
 
         assert_eq!(model.iter().collect::<Vec<_>>(), vec![6, 8, 7, 9, 10, 11, 12, 13]);
+
+        model.swap(5, 0);
+        assert!(view.changed_rows.borrow().is_empty());
+        assert_eq!(&*view.moved_rows.borrow(), &[(5, 1, 0, 8), (1, 1, 5, 8)]);
+        view.clear();
+
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![11, 8, 7, 9, 10, 6, 12, 13]);
+    }
+
+    #[test]
+    fn test_vecmodel_move_row() {
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+
+        let model = Rc::new(VecModel::from(vec![1i32, 2, 3, 4, 5]));
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+        *view.model.borrow_mut() =
+            Some(std::rc::Rc::downgrade(&(model.clone() as Rc<dyn Model<Data = i32>>)));
+
+        model.move_row(0, 3);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![2, 3, 4, 1, 5]);
+        assert_eq!(&*view.moved_rows.borrow(), &[(0, 1, 3, 5)]);
+        assert!(view.added_rows.borrow().is_empty());
+        assert!(view.removed_rows.borrow().is_empty());
+        view.clear();
+
+        model.move_row(4, 1);
+        assert_eq!(model.iter().collect::<Vec<_>>(), vec![2, 5, 3, 4, 1]);
+        assert_eq!(&*view.moved_rows.borrow(), &[(4, 1, 1, 5)]);
+        view.clear();
+
+        model.move_row(2, 2);
+        assert!(view.moved_rows.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_moved_row_index() {
+        // [0, 1, 2, 3, 4, 5] -> move 1..3 to 3 -> [0, 3, 4, 1, 2, 5]
+        let moved = [0, 1, 2, 3, 4, 5].map(|r| moved_row_index(r, 1, 2, 3));
+        assert_eq!(moved, [0, 3, 4, 1, 2, 5]);
+        // [0, 1, 2, 3, 4, 5] -> move 4..6 to 0 -> [4, 5, 0, 1, 2, 3]
+        let moved = [0, 1, 2, 3, 4, 5].map(|r| moved_row_index(r, 4, 2, 0));
+        assert_eq!(moved, [2, 3, 4, 5, 0, 1]);
     }
 
     #[test]
@@ -14,6 +14,7 @@ struct TestView {
     changed_rows: RefCell<Vec<usize>>,
     added_rows: RefCell<Vec<(usize, usize)>>,
     removed_rows: RefCell<Vec<(usize, usize)>>,
+    moved_rows: RefCell<Vec<(usize, usize, usize)>>,
     reset: RefCell<usize>,
 }
 
@@ -23,6 +24,7 @@ fn clear(&self) {
         self.changed_rows.borrow_mut().clear();
         self.added_rows.borrow_mut().clear();
         self.removed_rows.borrow_mut().clear();
+        self.moved_rows.borrow_mut().clear();
     }
 }
 
@@ -39,13 +41,17 @@ fn row_added(self: Pin<&Self>, index: usize, count: usize) {
     fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         self.removed_rows.borrow_mut().push((index, count));
     }
+
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        self.moved_rows.borrow_mut().push((from, count, to));
+    }
     fn reset(self: Pin<&Self>) {
         *self.reset.borrow_mut() += 1;
     }
 }
 
 #[cfg(test)]
-struct ModelChecker<Data: PartialEq + core::fmt::Debug + 'static> {
+pub(super) struct ModelChecker<Data: PartialEq + core::fmt::Debug + 'static> {
     model: Rc<dyn Model<Data = Data>>,
     rows_copy: RefCell<Vec<Data>>,
 }
@@ -66,6 +72,12 @@ fn row_added(self: Pin<&Self>, index: usize, count: usize) {
     fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         self.rows_copy.borrow_mut().drain(index..index + count);
     }
+
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        let mut copy = self.rows_copy.borrow_mut();
+        let moved = copy.drain(from..from + count).collect::<Vec<_>>();
+        copy.splice(to..to, moved);
+    }
     fn reset(self: Pin<&Self>) {
         *self.rows_copy.borrow_mut() = ModelRc::from(self.model.clone()).iter().collect()
     }
@@ -348,6 +360,21 @@ fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         }
     }
 
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        let mut mapping = self.mapping.borrow_mut();
+
+        // The moved rows that pass the filter are contiguous in the mapping before and after the move
+        let start = mapping.partition_point(|i| *i < from);
+        let end = mapping.partition_point(|i| *i < from + count);
+
+        mapping.iter_mut().for_each(|i| *i = moved_row_index(*i, from, count, to));
+        mapping.sort_unstable();
+        let new_start = mapping.partition_point(|i| *i < to);
+
+        drop(mapping);
+        self.notify.row_moved(start, end - start, new_start);
+    }
+
     fn reset(self: Pin<&Self>) {
         self.build_mapping_vec();
         self.notify.reset();
@@ -645,8 +672,8 @@ fn row_changed(self: Pin<&Self>, row: usize) {
         if insertion_index == removed_index {
             self.notify.row_changed(removed_index);
         } else {
-            self.notify.row_removed(removed_index, 1);
-            self.notify.row_added(insertion_index, 1);
+            self.notify.row_moved(removed_index, 1, insertion_index);
+            self.notify.row_changed(insertion_index);
         }
     }
 
@@ -720,6 +747,17 @@ fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         }
     }
 
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        if self.sorted_rows_dirty.get() {
+            return;
+        }
+
+        // The sorted order doesn't change, only the indices of the source rows do
+        for row in self.mapping.borrow_mut().iter_mut() {
+            *row = moved_row_index(*row, from, count, to);
+        }
+    }
+
     fn reset(self: Pin<&Self>) {
         self.sorted_rows_dirty.set(true);
         self.notify.reset();
@@ -1032,14 +1070,13 @@ fn test_sorted_model_changed() {
         assert_eq!(sorted_model.row_data(2).unwrap(), 3);
         assert_eq!(sorted_model.row_data(3).unwrap(), 10);
 
-        // Change the entry with the value 10 to 0 -> new order with remove and insert
+        // Change the entry with the value 10 to 0 -> new order with a move
         wrapped_rc.set_row_data(1, 0);
 
-        assert_eq!(observer.added_rows.borrow().len(), 1);
-        assert!(observer.added_rows.borrow().first().unwrap().eq(&(0, 1)));
-        assert!(observer.changed_rows.borrow().is_empty());
-        assert_eq!(observer.removed_rows.borrow().len(), 1);
-        assert!(observer.removed_rows.borrow().first().unwrap().eq(&(3, 1)));
+        assert!(observer.added_rows.borrow().is_empty());
+        assert!(observer.removed_rows.borrow().is_empty());
+        assert!(observer.moved_rows.borrow().eq(&[(3, 1, 0)]));
+        assert!(observer.changed_rows.borrow().eq(&[0]));
         assert_eq!(*observer.reset.borrow(), 0);
         observer.clear();
 
@@ -1048,6 +1085,59 @@ fn test_sorted_model_changed() {
         assert_eq!(sorted_model.row_data(1).unwrap(), 1);
         assert_eq!(sorted_model.row_data(2).unwrap(), 2);
         assert_eq!(sorted_model.row_data(3).unwrap(), 3);
+
+        // Moving rows in the source model doesn't change the sorted order
+        wrapped_rc.move_row(0, 3);
+        assert!(observer.moved_rows.borrow().is_empty());
+        assert!(observer.changed_rows.borrow().is_empty());
+        assert_eq!(wrapped_rc.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        wrapped_rc.set_row_data(1, 5);
+        assert!(observer.moved_rows.borrow().eq(&[(1, 1, 3)]));
+        assert!(observer.changed_rows.borrow().eq(&[3]));
+        assert_eq!(sorted_model.row_data(0).unwrap(), 0);
+        assert_eq!(sorted_model.row_data(1).unwrap(), 2);
+        assert_eq!(sorted_model.row_data(2).unwrap(), 3);
+        assert_eq!(sorted_model.row_data(3).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_filter_model_moved() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5, 6]));
+        let filter = Rc::new(FilterModel::new(wrapped_rc.clone(), |x| x % 2 == 0));
+
+        let _checker = ModelChecker::new(filter.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        filter.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        // [2, 4, 6] -> [4, 6, 2]
+        wrapped_rc.move_row(1, 5);
+        assert!(observer.moved_rows.borrow().eq(&[(0, 1, 2)]));
+        assert!(observer.added_rows.borrow().is_empty());
+        assert!(observer.removed_rows.borrow().is_empty());
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![4, 6, 2]);
+        observer.clear();
+
+        // Moving a row that is filtered out doesn't notify
+        wrapped_rc.move_row(0, 2);
+        assert!(observer.moved_rows.borrow().is_empty());
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![4, 6, 2]);
+    }
+
+    #[test]
+    fn test_reverse_model_moved() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        let reverse = Rc::new(ReverseModel::new(wrapped_rc.clone()));
+
+        let _checker = ModelChecker::new(reverse.clone());
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        reverse.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        // [4, 3, 2, 1] -> [3, 2, 4, 1]
+        wrapped_rc.move_row(3, 1);
+        assert!(observer.moved_rows.borrow().eq(&[(0, 1, 2)]));
+        assert_eq!(reverse.iter().collect::<Vec<_>>(), vec![3, 2, 4, 1]);
     }
 
     #[test]
@@ -1165,6 +1255,11 @@ fn row_removed(self: Pin<&Self>, index: usize, count: usize) {
         self.notify.row_removed(row_count - index, count);
     }
 
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        let row_count = self.wrapped_model.row_count();
+        self.notify.row_moved(row_count - from - count, count, row_count - to - count);
+    }
+
     fn reset(self: Pin<&Self>) {
         self.notify.reset()
     }
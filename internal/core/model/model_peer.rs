@@ -85,6 +85,24 @@ pub fn row_removed(&self, index: usize, count: usize) {
         }
     }
 
+    /// Notify the peers that `count` rows starting at `from` were moved, so that
+    /// the first of them is now at index `to`.
+    ///
+    /// `to` is expressed in terms of the model after the move. The row count is unchanged.
+    pub fn row_moved(&self, from: usize, count: usize, to: usize) {
+        if count == 0 || from == to {
+            return;
+        }
+        if let Some(inner) = self.inner.get() {
+            inner.tracked_rows.borrow_mut().clear();
+            inner.model_row_data_dirty_property.mark_dirty();
+            inner.as_ref().project_ref().peers.for_each(|p| {
+                // Safety: The peers contain a list of pinned ModelChangedListener
+                unsafe { Pin::new_unchecked(&**p) }.row_moved(from, count, to)
+            })
+        }
+    }
+
     /// Notify the peer that the model has been changed in some way and
     /// everything needs to be reloaded
     pub fn reset(&self) {
@@ -128,6 +146,13 @@ pub trait ModelChangeListener {
     fn row_changed(self: Pin<&Self>, row: usize);
     fn row_added(self: Pin<&Self>, index: usize, count: usize);
     fn row_removed(self: Pin<&Self>, index: usize, count: usize);
+    /// `count` rows starting at `from` were moved so that the first of them is now at `to`.
+    ///
+    /// The default implementation reports the move as a removal followed by an insertion.
+    fn row_moved(self: Pin<&Self>, from: usize, count: usize, to: usize) {
+        self.row_removed(from, count);
+        self.row_added(to, count);
+    }
     fn reset(self: Pin<&Self>);
 }
 
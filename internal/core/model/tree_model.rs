@@ -0,0 +1,533 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the hierarchical model and the adapter that flattens it into a list.
+
+use super::*;
+use alloc::collections::BTreeSet;
+use alloc::rc::Weak;
+
+/// A TreeModel provides hierarchical data, such as the content of a file system or an outline.
+///
+/// Nodes are addressed by their path: the list of child indices from the top level down to the node.
+/// The empty path refers to the invisible root, whose children are the top level nodes.
+///
+/// A TreeModel can't be used directly with a `for` repeater, wrap it in a [`FlattenedTreeModel`]
+/// which exposes the visible (expanded) nodes as a flat list.
+///
+/// If the tree can be changed, the type implementing the TreeModel trait should hold a
+/// [`TreeModelNotify`] and return it from [`TreeModel::tree_tracker`].
+///
+/// ## Example
+///
+/// ```
+/// # use i_slint_core::model::{TreeModel, FlattenedTreeModel, Model};
+/// # use i_slint_core::SharedString;
+/// struct Outline;
+///
+/// impl TreeModel for Outline {
+///     type Data = SharedString;
+///
+///     fn child_count(&self, parent: &[usize]) -> usize {
+///         match parent.len() {
+///             0 => 2,
+///             1 => 3,
+///             _ => 0,
+///         }
+///     }
+///
+///     fn data(&self, path: &[usize]) -> Option<Self::Data> {
+///         Some(i_slint_core::format!("{path:?}"))
+///     }
+/// }
+///
+/// let flattened = FlattenedTreeModel::new(Outline);
+/// assert_eq!(flattened.row_count(), 2);
+/// flattened.set_expanded(0, true);
+/// assert_eq!(flattened.row_count(), 5);
+/// assert_eq!(flattened.row_data(1).unwrap().data, "[0, 0]");
+/// assert_eq!(flattened.row_data(1).unwrap().level, 1);
+/// ```
+pub trait TreeModel {
+    /// The model data: A model is a set of nodes and each node has this data
+    type Data;
+
+    /// The amount of children of the node at `parent`
+    fn child_count(&self, parent: &[usize]) -> usize;
+
+    /// Returns the data of the node at `path`
+    fn data(&self, path: &[usize]) -> Option<Self::Data>;
+
+    /// Returns true if the node at `path` has children and can be expanded.
+    ///
+    /// The default implementation returns whether [`Self::child_count`] is not zero.
+    /// Models that load their children lazily should re-implement it so the children
+    /// don't need to be loaded just to decide whether to show an expand indicator.
+    fn has_children(&self, path: &[usize]) -> bool {
+        self.child_count(path) > 0
+    }
+
+    /// Called before the node at `path` is expanded.
+    ///
+    /// Models that load their children lazily can load them here. Children that only become
+    /// available later must be reported with [`TreeModelNotify::children_reset`].
+    fn fetch_children(&self, _path: &[usize]) {}
+
+    /// The implementation should return a reference to its [`TreeModelNotify`] field.
+    ///
+    /// The default implementation returns a tracker for a tree that never changes.
+    fn tree_tracker(&self) -> &dyn TreeModelTracker {
+        &()
+    }
+}
+
+impl<M: TreeModel> TreeModel for Rc<M> {
+    type Data = M::Data;
+
+    fn child_count(&self, parent: &[usize]) -> usize {
+        (**self).child_count(parent)
+    }
+
+    fn data(&self, path: &[usize]) -> Option<Self::Data> {
+        (**self).data(path)
+    }
+
+    fn has_children(&self, path: &[usize]) -> bool {
+        (**self).has_children(path)
+    }
+
+    fn fetch_children(&self, path: &[usize]) {
+        (**self).fetch_children(path)
+    }
+
+    fn tree_tracker(&self) -> &dyn TreeModelTracker {
+        (**self).tree_tracker()
+    }
+}
+
+/// Receives the change notifications of a [`TreeModel`]
+pub trait TreeModelListener {
+    /// The data of the node at `path` changed
+    fn data_changed(&self, path: &[usize]);
+    /// The children of the node at `parent` were added, removed, or replaced
+    fn children_reset(&self, parent: &[usize]);
+}
+
+/// This trait is used by views to get notified of changes to a [`TreeModel`].
+/// It is supplied via [`TreeModel::tree_tracker`].
+pub trait TreeModelTracker {
+    /// Attach a listener. The listener is detached when it is dropped.
+    fn attach_listener(&self, listener: Weak<dyn TreeModelListener>);
+}
+
+impl TreeModelTracker for () {
+    fn attach_listener(&self, _listener: Weak<dyn TreeModelListener>) {}
+}
+
+/// Dispatch notifications from a [`TreeModel`] to its views.
+#[derive(Default)]
+pub struct TreeModelNotify {
+    listeners: RefCell<Vec<Weak<dyn TreeModelListener>>>,
+}
+
+impl TreeModelNotify {
+    fn for_each_listener(&self, f: impl Fn(&dyn TreeModelListener)) {
+        self.listeners.borrow_mut().retain(|l| l.strong_count() > 0);
+        let listeners = self.listeners.borrow().clone();
+        for listener in listeners.iter().filter_map(|l| l.upgrade()) {
+            f(&*listener)
+        }
+    }
+
+    /// Notify the views that the data of the node at `path` changed
+    pub fn data_changed(&self, path: &[usize]) {
+        self.for_each_listener(|l| l.data_changed(path))
+    }
+
+    /// Notify the views that the children of the node at `parent` were added, removed, or replaced.
+    /// Use the empty path for the top level nodes.
+    pub fn children_reset(&self, parent: &[usize]) {
+        self.for_each_listener(|l| l.children_reset(parent))
+    }
+}
+
+impl TreeModelTracker for TreeModelNotify {
+    fn attach_listener(&self, listener: Weak<dyn TreeModelListener>) {
+        self.listeners.borrow_mut().push(listener)
+    }
+}
+
+/// A row of a [`FlattenedTreeModel`]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FlattenedTreeRow<T> {
+    /// The data of the node, as returned by [`TreeModel::data`]
+    pub data: T,
+    /// The depth of the node in the tree. Top level nodes have the level 0.
+    pub level: usize,
+    /// Whether the node has children and can be expanded
+    pub has_children: bool,
+    /// Whether the children of the node are currently shown
+    pub expanded: bool,
+}
+
+impl<T: Into<SharedString>> From<FlattenedTreeRow<T>> for TreeViewItem {
+    fn from(row: FlattenedTreeRow<T>) -> Self {
+        TreeViewItem {
+            text: row.data.into(),
+            level: row.level as i32,
+            has_children: row.has_children,
+            expanded: row.expanded,
+        }
+    }
+}
+
+struct VisibleNode {
+    path: Vec<usize>,
+    has_children: bool,
+}
+
+struct FlattenedTreeModelInner<M> {
+    tree: M,
+    /// The visible nodes, in display order. This is the depth-first order of the tree,
+    /// so the rows are sorted by their path.
+    rows: RefCell<Vec<VisibleNode>>,
+    /// The paths of the expanded nodes, including the ones hidden in a collapsed parent
+    expanded: RefCell<BTreeSet<Vec<usize>>>,
+    notify: ModelNotify,
+}
+
+impl<M: TreeModel> FlattenedTreeModelInner<M> {
+    /// Appends the visible descendants of `parent` to `rows`
+    fn collect_children(&self, parent: &[usize], rows: &mut Vec<VisibleNode>) {
+        let mut path = parent.to_vec();
+        path.push(0);
+        for i in 0..self.tree.child_count(parent) {
+            *path.last_mut().unwrap() = i;
+            let has_children = self.tree.has_children(&path);
+            rows.push(VisibleNode { path: path.clone(), has_children });
+            if has_children && self.expanded.borrow().contains(&path) {
+                self.collect_children(&path, rows);
+            }
+        }
+    }
+
+    fn row_of(&self, path: &[usize]) -> Option<usize> {
+        self.rows.borrow().binary_search_by(|n| n.path.as_slice().cmp(path)).ok()
+    }
+
+    /// Returns the number of visible descendants of the node at `row`
+    fn descendant_count(&self, row: usize) -> usize {
+        let rows = self.rows.borrow();
+        let path = &rows[row].path;
+        rows[row + 1..].iter().take_while(|n| n.path.starts_with(path)).count()
+    }
+}
+
+impl<M: TreeModel> TreeModelListener for FlattenedTreeModelInner<M> {
+    fn data_changed(&self, path: &[usize]) {
+        if let Some(row) = self.row_of(path) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn children_reset(&self, parent: &[usize]) {
+        // The indices of the children changed, so their expansion state is no longer valid
+        self.expanded.borrow_mut().retain(|p| p.len() <= parent.len() || !p.starts_with(parent));
+
+        if parent.is_empty() {
+            let mut rows = Vec::new();
+            self.collect_children(&[], &mut rows);
+            *self.rows.borrow_mut() = rows;
+            self.notify.reset();
+            return;
+        }
+
+        let Some(row) = self.row_of(parent) else { return };
+        let has_children = self.tree.has_children(parent);
+        let removed = self.descendant_count(row);
+        let mut added = Vec::new();
+        if has_children && self.expanded.borrow().contains(parent) {
+            self.collect_children(parent, &mut added);
+        }
+        let added_count = added.len();
+        {
+            let mut rows = self.rows.borrow_mut();
+            rows[row].has_children = has_children;
+            rows.splice(row + 1..row + 1 + removed, added);
+        }
+        self.notify.row_changed(row);
+        if removed > 0 {
+            self.notify.row_removed(row + 1, removed);
+        }
+        if added_count > 0 {
+            self.notify.row_added(row + 1, added_count);
+        }
+    }
+}
+
+/// Provides the visible nodes of a [`TreeModel`] as a flat list, so that they can be shown
+/// with a `for` repeater, a `ListView`, or a `TreeView`.
+///
+/// The `FlattenedTreeModel` keeps track of which nodes are expanded. Only the children of the
+/// expanded nodes are part of the list, and they are only queried from the tree when their
+/// parent gets expanded, which allows the tree to load them lazily.
+///
+/// Each row is a [`FlattenedTreeRow`], which can be converted to the [`TreeViewItem`] used by the
+/// `TreeView` widget with [`ModelExt::map`].
+///
+/// ## Example
+///
+/// ```
+/// # use i_slint_core::model::{TreeModel, FlattenedTreeModel, Model, ModelExt};
+/// # use i_slint_core::model::TreeViewItem;
+/// # use i_slint_core::SharedString;
+/// # use std::rc::Rc;
+/// # struct Files;
+/// # impl TreeModel for Files {
+/// #     type Data = SharedString;
+/// #     fn child_count(&self, parent: &[usize]) -> usize { if parent.len() < 2 { 2 } else { 0 } }
+/// #     fn data(&self, path: &[usize]) -> Option<SharedString> { Some(i_slint_core::format!("{path:?}")) }
+/// # }
+/// let files = Rc::new(FlattenedTreeModel::new(Files));
+/// let tree_view_model = files.clone().map(TreeViewItem::from);
+/// // Pass `tree_view_model` to the `model` property of the `TreeView` and call
+/// // `files.set_expanded(row, expanded)` from its `expanded-changed` callback.
+/// files.set_expanded(0, true);
+/// assert_eq!(tree_view_model.row_count(), 4);
+/// assert!(tree_view_model.row_data(0).unwrap().expanded);
+/// ```
+pub struct FlattenedTreeModel<M: TreeModel + 'static>(Rc<FlattenedTreeModelInner<M>>);
+
+impl<M: TreeModel + 'static> FlattenedTreeModel<M> {
+    /// Creates a new FlattenedTreeModel showing the top level nodes of `tree`.
+    pub fn new(tree: M) -> Self {
+        let inner = Rc::new(FlattenedTreeModelInner {
+            tree,
+            rows: Default::default(),
+            expanded: Default::default(),
+            notify: Default::default(),
+        });
+        let mut rows = Vec::new();
+        inner.collect_children(&[], &mut rows);
+        *inner.rows.borrow_mut() = rows;
+        let weak: Weak<dyn TreeModelListener> = Rc::downgrade(&inner) as _;
+        inner.tree.tree_tracker().attach_listener(weak);
+        Self(inner)
+    }
+
+    /// Returns a reference to the tree model
+    pub fn source_model(&self) -> &M {
+        &self.0.tree
+    }
+
+    /// Returns the path of the node shown at `row`
+    pub fn row_path(&self, row: usize) -> Option<Vec<usize>> {
+        self.0.rows.borrow().get(row).map(|n| n.path.clone())
+    }
+
+    /// Returns the row at which the node at `path` is shown, or None if it's not visible
+    pub fn row_of(&self, path: &[usize]) -> Option<usize> {
+        self.0.row_of(path)
+    }
+
+    /// Returns whether the node shown at `row` is expanded
+    pub fn is_expanded(&self, row: usize) -> bool {
+        self.0.rows.borrow().get(row).is_some_and(|n| self.0.expanded.borrow().contains(&n.path))
+    }
+
+    /// Expands or collapses the node shown at `row`.
+    ///
+    /// When expanding, [`TreeModel::fetch_children`] is called before the children are queried.
+    /// Nodes without children can't be expanded.
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        let inner = &*self.0;
+        let Some((path, has_children)) =
+            inner.rows.borrow().get(row).map(|n| (n.path.clone(), n.has_children))
+        else {
+            return;
+        };
+        if !has_children || inner.expanded.borrow().contains(&path) == expanded {
+            return;
+        }
+
+        if expanded {
+            inner.tree.fetch_children(&path);
+            inner.expanded.borrow_mut().insert(path.clone());
+            let mut added = Vec::new();
+            inner.collect_children(&path, &mut added);
+            let count = added.len();
+            inner.rows.borrow_mut().splice(row + 1..row + 1, added);
+            inner.notify.row_changed(row);
+            if count > 0 {
+                inner.notify.row_added(row + 1, count);
+            }
+        } else {
+            // The expansion state of the descendants is kept for when the node is expanded again
+            inner.expanded.borrow_mut().remove(&path);
+            let count = inner.descendant_count(row);
+            inner.rows.borrow_mut().drain(row + 1..row + 1 + count);
+            inner.notify.row_changed(row);
+            if count > 0 {
+                inner.notify.row_removed(row + 1, count);
+            }
+        }
+    }
+}
+
+impl<M: TreeModel + 'static> Model for FlattenedTreeModel<M> {
+    type Data = FlattenedTreeRow<M::Data>;
+
+    fn row_count(&self) -> usize {
+        self.0.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let rows = self.0.rows.borrow();
+        let node = rows.get(row)?;
+        Some(FlattenedTreeRow {
+            data: self.0.tree.data(&node.path)?,
+            level: node.path.len() - 1,
+            has_children: node.has_children,
+            expanded: self.0.expanded.borrow().contains(&node.path),
+        })
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A tree where every node has `width` children, up to `depth` levels
+    #[derive(Default)]
+    struct TestTree {
+        width: Cell<usize>,
+        depth: usize,
+        fetched: RefCell<Vec<Vec<usize>>>,
+        notify: TreeModelNotify,
+    }
+
+    impl TreeModel for TestTree {
+        type Data = SharedString;
+
+        fn child_count(&self, parent: &[usize]) -> usize {
+            if parent.len() < self.depth {
+                self.width.get()
+            } else {
+                0
+            }
+        }
+
+        fn data(&self, path: &[usize]) -> Option<Self::Data> {
+            Some(crate::format!("{path:?}"))
+        }
+
+        fn fetch_children(&self, path: &[usize]) {
+            self.fetched.borrow_mut().push(path.to_vec());
+        }
+
+        fn tree_tracker(&self) -> &dyn TreeModelTracker {
+            &self.notify
+        }
+    }
+
+    fn texts<M: TreeModel<Data = SharedString> + 'static>(
+        model: &FlattenedTreeModel<M>,
+    ) -> Vec<alloc::string::String> {
+        model.iter().map(|r| alloc::format!("{}{}", " ".repeat(r.level), r.data)).collect()
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        let tree = Rc::new(TestTree { width: Cell::new(2), depth: 2, ..Default::default() });
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let _checker = super::adapters::ModelChecker::new(model.clone());
+
+        assert_eq!(texts(&model), ["[0]", "[1]"]);
+        assert!(model.row_data(0).unwrap().has_children);
+        assert!(!model.is_expanded(0));
+
+        model.set_expanded(1, true);
+        assert_eq!(texts(&model), ["[0]", "[1]", " [1, 0]", " [1, 1]"]);
+        assert_eq!(&*tree.fetched.borrow(), &[vec![1]]);
+        assert!(model.is_expanded(1));
+        assert!(!model.row_data(2).unwrap().has_children);
+
+        model.set_expanded(0, true);
+        assert_eq!(texts(&model), ["[0]", " [0, 0]", " [0, 1]", "[1]", " [1, 0]", " [1, 1]"]);
+        assert_eq!(model.row_of(&[1, 0]), Some(4));
+        assert_eq!(model.row_path(2), Some(vec![0, 1]));
+
+        // Leaves can't be expanded
+        model.set_expanded(2, true);
+        assert_eq!(model.row_count(), 6);
+
+        model.set_expanded(0, false);
+        assert_eq!(texts(&model), ["[0]", "[1]", " [1, 0]", " [1, 1]"]);
+    }
+
+    #[test]
+    fn test_nested_expansion_is_kept() {
+        let tree = Rc::new(TestTree { width: Cell::new(2), depth: 3, ..Default::default() });
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let _checker = super::adapters::ModelChecker::new(model.clone());
+
+        model.set_expanded(0, true);
+        model.set_expanded(2, true);
+        assert_eq!(
+            texts(&model),
+            ["[0]", " [0, 0]", " [0, 1]", "  [0, 1, 0]", "  [0, 1, 1]", "[1]"]
+        );
+
+        model.set_expanded(0, false);
+        assert_eq!(texts(&model), ["[0]", "[1]"]);
+
+        model.set_expanded(0, true);
+        assert_eq!(
+            texts(&model),
+            ["[0]", " [0, 0]", " [0, 1]", "  [0, 1, 0]", "  [0, 1, 1]", "[1]"]
+        );
+    }
+
+    #[test]
+    fn test_children_reset() {
+        let tree = Rc::new(TestTree { width: Cell::new(2), depth: 3, ..Default::default() });
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let _checker = super::adapters::ModelChecker::new(model.clone());
+
+        model.set_expanded(0, true);
+        model.set_expanded(1, true);
+        assert_eq!(
+            texts(&model),
+            ["[0]", " [0, 0]", "  [0, 0, 0]", "  [0, 0, 1]", " [0, 1]", "[1]"]
+        );
+
+        tree.width.set(1);
+        tree.notify.children_reset(&[0]);
+        // The expansion state of the children was reset
+        assert_eq!(texts(&model), ["[0]", " [0, 0]", "[1]"]);
+
+        // Children of a collapsed node don't change the visible rows
+        tree.notify.children_reset(&[0, 0]);
+        assert_eq!(texts(&model), ["[0]", " [0, 0]", "[1]"]);
+        assert!(!model.is_expanded(1));
+
+        // Nodes that aren't visible are ignored
+        tree.notify.children_reset(&[1, 0]);
+        tree.notify.data_changed(&[1, 0]);
+        assert_eq!(texts(&model), ["[0]", " [0, 0]", "[1]"]);
+        assert_eq!(model.row_of(&[1, 0]), None);
+        assert_eq!(model.row_of(&[1]), Some(2));
+
+        tree.notify.children_reset(&[]);
+        assert_eq!(texts(&model), ["[0]"]);
+        assert!(!model.is_expanded(0));
+    }
+}
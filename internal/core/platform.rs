@@ -281,6 +281,7 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 // reexport key enum to the public api
 pub use crate::input::key_codes::Key;
 pub use crate::input::PointerEventButton;
+pub use crate::items::{KeyLocation, PhysicalKey};
 
 /// A event that describes user input or windowing system events.
 ///
@@ -353,6 +354,40 @@ pub enum WindowEvent {
         /// ```
         text: SharedString,
     },
+    /// A key was pressed. Like [`WindowEvent::KeyPressed`], but also carries the physical key
+    /// and its location, for backends that know about them.
+    ///
+    /// The winit backend reports the physical keys on all platforms. The Qt backend only reports them on Linux,
+    /// and uses [`PhysicalKey::Unidentified`] elsewhere.
+    PhysicalKeyPressed {
+        /// The unicode representation of the key pressed.
+        text: SharedString,
+        /// The physical key, independent of the keyboard layout.
+        /// See [`KeyEvent::physical_key`](crate::items::KeyEvent::physical_key).
+        physical_key: PhysicalKey,
+        /// The location of the key on the keyboard.
+        location: KeyLocation,
+    },
+    /// A key press was auto-repeated. Like [`WindowEvent::KeyPressRepeated`], but also carries
+    /// the physical key and its location.
+    PhysicalKeyPressRepeated {
+        /// The unicode representation of the key pressed.
+        text: SharedString,
+        /// The physical key, independent of the keyboard layout.
+        physical_key: PhysicalKey,
+        /// The location of the key on the keyboard.
+        location: KeyLocation,
+    },
+    /// A key was released. Like [`WindowEvent::KeyReleased`], but also carries the physical key
+    /// and its location.
+    PhysicalKeyReleased {
+        /// The unicode representation of the key released.
+        text: SharedString,
+        /// The physical key, independent of the keyboard layout.
+        physical_key: PhysicalKey,
+        /// The location of the key on the keyboard.
+        location: KeyLocation,
+    },
     /// The window's scale factor has changed. This can happen for example when the display's resolution
     /// changes, the user selects a new scale factor in the system settings, or the window is moved to a
     /// different screen.
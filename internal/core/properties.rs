@@ -292,7 +292,8 @@ struct BindingVTable {
 ///
 /// # Safety
 ///
-/// IS_TWO_WAY_BINDING cannot be true if Self is not a TwoWayBinding
+/// IS_TWO_WAY_BINDING cannot be true if Self is not a TwoWayBinding, and
+/// IS_ANIMATED_VALUE_BINDING cannot be true if Self is not an AnimatedValueBinding
 unsafe trait BindingCallable {
     /// This function is called by the property to evaluate the binding and produce a new value. The
     /// previous property value is provided in the value parameter.
@@ -320,6 +321,9 @@ unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHold
 
     /// Set to true if and only if Self is a TwoWayBinding<T>
     const IS_TWO_WAY_BINDING: bool = false;
+
+    /// Set to true if and only if Self is an `AnimatedValueBinding<T>`
+    const IS_ANIMATED_VALUE_BINDING: bool = false;
 }
 
 unsafe impl<F: Fn(*mut ()) -> BindingResult> BindingCallable for F {
@@ -394,6 +398,8 @@ struct BindingHolder<B = ()> {
     dirty: Cell<bool>,
     /// Specify that B is a `TwoWayBinding<T>`
     is_two_way_binding: bool,
+    /// Specify that B is an `AnimatedValueBinding<T>`
+    is_animated_value_binding: bool,
     pinned: PhantomPinned,
     #[cfg(slint_debug_property)]
     pub debug_name: String,
@@ -473,6 +479,7 @@ impl<B: BindingCallable> HasBindingVTable for B {
         vtable: <B as HasBindingVTable>::VT,
         dirty: Cell::new(true), // starts dirty so it evaluates the property when used
         is_two_way_binding: B::IS_TWO_WAY_BINDING,
+        is_animated_value_binding: B::IS_ANIMATED_VALUE_BINDING,
         pinned: PhantomPinned,
         #[cfg(slint_debug_property)]
         debug_name: Default::default(),
@@ -1005,36 +1012,51 @@ struct Component {
     assert_eq!(g(&compo.area), 8 * 8 * 2);
 }
 
-impl<T: PartialEq + Clone + 'static> Property<T> {
-    /// Link two property such that any change to one property is affecting the other property as if they
-    /// where, in fact, a single property.
-    /// The value or binding of prop2 is kept.
-    pub fn link_two_way(prop1: Pin<&Self>, prop2: Pin<&Self>) {
-        struct TwoWayBinding<T> {
-            common_property: Pin<Rc<Property<T>>>,
-        }
-        unsafe impl<T: PartialEq + Clone + 'static> BindingCallable for TwoWayBinding<T> {
-            unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
-                *(value as *mut T) = self.common_property.as_ref().get();
-                BindingResult::KeepBinding
-            }
+struct TwoWayBinding<T> {
+    common_property: Pin<Rc<Property<T>>>,
+}
+unsafe impl<T: PartialEq + Clone + 'static> BindingCallable for TwoWayBinding<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        *(value as *mut T) = self.common_property.as_ref().get();
+        BindingResult::KeepBinding
+    }
 
-            unsafe fn intercept_set(self: Pin<&Self>, value: *const ()) -> bool {
-                self.common_property.as_ref().set((*(value as *const T)).clone());
-                true
-            }
+    unsafe fn intercept_set(self: Pin<&Self>, value: *const ()) -> bool {
+        self.common_property.as_ref().set((*(value as *const T)).clone());
+        true
+    }
 
-            unsafe fn intercept_set_binding(
-                self: Pin<&Self>,
-                new_binding: *mut BindingHolder,
-            ) -> bool {
-                self.common_property.handle.set_binding_impl(new_binding);
-                true
-            }
+    unsafe fn intercept_set_binding(self: Pin<&Self>, new_binding: *mut BindingHolder) -> bool {
+        self.common_property.handle.set_binding_impl(new_binding);
+        true
+    }
+
+    const IS_TWO_WAY_BINDING: bool = true;
+}
 
-            const IS_TWO_WAY_BINDING: bool = true;
+impl<T: PartialEq + Clone + 'static> Property<T> {
+    /// Returns true if the property has a binding that would be removed by [`Self::set()`].
+    /// Setting a property with a two-way binding sets the linked properties instead, so it's only
+    /// reported if the linked properties have such a binding.
+    pub(crate) fn has_binding(&self) -> bool {
+        let handle_val = self.handle.handle.get();
+        if handle_val & 0b10 != 0b10 {
+            return false;
         }
+        // Safety: the handle is a pointer to a binding
+        let holder = unsafe { &*((handle_val & !0b11) as *const BindingHolder) };
+        if !holder.is_two_way_binding {
+            return true;
+        }
+        // Safety: the handle is a pointer to a binding whose B is a TwoWayBinding<T>
+        let holder = unsafe { &*((handle_val & !0b11) as *const BindingHolder<TwoWayBinding<T>>) };
+        holder.binding.common_property.has_binding()
+    }
 
+    /// Link two property such that any change to one property is affecting the other property as if they
+    /// where, in fact, a single property.
+    /// The value or binding of prop2 is kept.
+    pub fn link_two_way(prop1: Pin<&Self>, prop2: Pin<&Self>) {
         #[cfg(slint_debug_property)]
         let debug_name = format!("<{}<=>{}>", prop1.debug_name.borrow(), prop2.debug_name.borrow());
 
@@ -1134,6 +1156,22 @@ fn property_two_ways_test() {
     assert_eq!(depends.as_ref().get(), 22 + 8);
 }
 
+#[test]
+fn property_has_binding() {
+    let p1 = Rc::pin(Property::new(42));
+    let p2 = Rc::pin(Property::new(88));
+    assert!(!p1.has_binding());
+    Property::link_two_way(p1.as_ref(), p2.as_ref());
+    assert!(!p1.has_binding());
+    assert!(!p2.has_binding());
+    p2.as_ref().set_binding(|| 5);
+    assert!(p1.has_binding());
+    assert!(p2.has_binding());
+    p1.as_ref().set(3);
+    assert!(!p1.has_binding());
+    assert!(!p2.has_binding());
+}
+
 #[test]
 fn property_two_ways_test_binding() {
     let p1 = Rc::pin(Property::new(42));
@@ -1393,6 +1431,7 @@ fn default() -> Self {
             vtable: VT,
             dirty: Cell::new(true), // starts dirty so it evaluates the property when used
             is_two_way_binding: false,
+            is_animated_value_binding: false,
             pinned: PhantomPinned,
             binding: (),
             #[cfg(slint_debug_property)]
@@ -1521,6 +1560,7 @@ impl<B: PropertyDirtyHandler> HasBindingVTable for B {
             vtable: <DirtyHandler as HasBindingVTable>::VT,
             dirty: Cell::new(true), // starts dirty so it evaluates the property when used
             is_two_way_binding: false,
+            is_animated_value_binding: false,
             pinned: PhantomPinned,
             binding: handler,
             #[cfg(slint_debug_property)]
@@ -126,6 +126,7 @@ impl<T: PartialEq, EF: Fn(&Data) -> T, NF: Fn(&Data, &T), Data> HasBindingVTable
             vtable: <ChangeTrackerInner<T, EF, NF, Data> as HasBindingVTable>::VT,
             dirty: Cell::new(false),
             is_two_way_binding: false,
+            is_animated_value_binding: false,
             pinned: PhantomPinned,
             binding: inner,
             #[cfg(slint_debug_property)]
@@ -165,31 +165,32 @@ pub extern "C" fn slint_property_set_constant(handle: &PropertyHandleOpaque) {
     core::ptr::drop_in_place(handle);
 }
 
+/// Safety: `keyframes` must point to `keyframe_count` valid keyframes (or be null if the count is 0)
+unsafe fn c_keyframes<T: Clone>(
+    keyframes: *const Keyframe<T>,
+    keyframe_count: usize,
+) -> alloc::vec::Vec<Keyframe<T>> {
+    if keyframes.is_null() || keyframe_count == 0 {
+        return alloc::vec::Vec::new();
+    }
+    core::slice::from_raw_parts(keyframes, keyframe_count).to_vec()
+}
+
 fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     handle: &PropertyHandleOpaque,
     from: T,
     to: T,
     animation_data: &PropertyAnimation,
+    keyframes: alloc::vec::Vec<Keyframe<T>>,
 ) {
-    let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
+    let d = properties_animations::PropertyValueAnimationData::with_keyframes(
         from,
         to,
         animation_data.clone(),
-    ));
-    // Safety: The BindingCallable is for type T
-    unsafe {
-        handle.0.set_binding(move |val: *mut ()| {
-            let (value, finished) = d.borrow_mut().compute_interpolated_value();
-            *(val as *mut T) = value;
-            if finished {
-                BindingResult::RemoveBinding
-            } else {
-                crate::animations::CURRENT_ANIMATION_DRIVER
-                    .with(|driver| driver.set_has_active_animations());
-                BindingResult::KeepBinding
-            }
-        })
-    };
+        keyframes,
+    );
+    // Safety: The AnimatedValueBinding is for type T
+    unsafe { handle.0.set_animated_value_binding(d) };
     handle.0.mark_dirty();
 }
 
@@ -200,8 +201,10 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: i32,
     to: i32,
     animation_data: &PropertyAnimation,
+    keyframes: *const Keyframe<i32>,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from, to, animation_data)
+    c_set_animated_value(handle, from, to, animation_data, c_keyframes(keyframes, keyframe_count))
 }
 
 /// Internal function to set up a property animation to the specified target value for a float property.
@@ -211,8 +214,10 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: f32,
     to: f32,
     animation_data: &PropertyAnimation,
+    keyframes: *const Keyframe<f32>,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from, to, animation_data)
+    c_set_animated_value(handle, from, to, animation_data, c_keyframes(keyframes, keyframe_count))
 }
 
 /// Internal function to set up a property animation to the specified target value for a color property.
@@ -222,8 +227,10 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: Color,
     to: Color,
     animation_data: &PropertyAnimation,
+    keyframes: *const Keyframe<Color>,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from, to, animation_data);
+    c_set_animated_value(handle, from, to, animation_data, c_keyframes(keyframes, keyframe_count));
 }
 
 /// Internal function to set up a property animation to the specified target value for a brush property.
@@ -233,8 +240,16 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: &Brush,
     to: &Brush,
     animation_data: &PropertyAnimation,
+    keyframes: *const Keyframe<Brush>,
+    keyframe_count: usize,
 ) {
-    c_set_animated_value(handle, from.clone(), to.clone(), animation_data);
+    c_set_animated_value(
+        handle,
+        from.clone(),
+        to.clone(),
+        animation_data,
+        c_keyframes(keyframes, keyframe_count),
+    );
 }
 
 unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
@@ -243,8 +258,14 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: alloc::vec::Vec<Keyframe<T>>,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut *const Keyframe<T>,
+            keyframe_count: &mut usize,
+        ) -> PropertyAnimation,
     >,
 ) {
     let binding = core::mem::transmute::<
@@ -263,20 +284,33 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
                 | 0b10,
         ),
     };
-    let animation_data = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-        T::default(),
-        T::default(),
-        animation_data.cloned().unwrap_or_default(),
-    ));
+    let animation_data =
+        RefCell::new(properties_animations::PropertyValueAnimationData::with_keyframes(
+            T::default(),
+            T::default(),
+            animation_data.cloned().unwrap_or_default(),
+            keyframes,
+        ));
     if let Some(transition_data) = transition_data {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding,
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
             animation_data,
-            compute_animation_details: move || -> properties_animations::AnimationDetail {
+            compute_animation_details: move || -> properties_animations::AnimationDetail<T> {
                 let mut start_instant = 0;
-                let anim = transition_data(user_data, &mut start_instant);
-                Some((anim, crate::animations::Instant(start_instant)))
+                let mut keyframes = core::ptr::null();
+                let mut keyframe_count = 0;
+                let anim = transition_data(
+                    user_data,
+                    &mut start_instant,
+                    &mut keyframes,
+                    &mut keyframe_count,
+                );
+                Some((
+                    anim,
+                    crate::animations::Instant(start_instant),
+                    c_keyframes(keyframes, keyframe_count),
+                ))
             },
         });
     } else {
@@ -284,7 +318,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
             original_binding,
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
             animation_data,
-            compute_animation_details: || -> properties_animations::AnimationDetail { None },
+            compute_animation_details: || -> properties_animations::AnimationDetail<T> { None },
         });
     }
     handle.0.mark_dirty();
@@ -298,8 +332,15 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: *const Keyframe<i32>,
+    keyframe_count: usize,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut *const Keyframe<i32>,
+            keyframe_count: &mut usize,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -308,6 +349,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframes, keyframe_count),
         transition_data,
     );
 }
@@ -320,8 +362,15 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: *const Keyframe<f32>,
+    keyframe_count: usize,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut *const Keyframe<f32>,
+            keyframe_count: &mut usize,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -330,6 +379,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframes, keyframe_count),
         transition_data,
     );
 }
@@ -342,8 +392,15 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: *const Keyframe<Color>,
+    keyframe_count: usize,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut *const Keyframe<Color>,
+            keyframe_count: &mut usize,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -352,6 +409,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframes, keyframe_count),
         transition_data,
     );
 }
@@ -364,8 +422,15 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: *const Keyframe<Brush>,
+    keyframe_count: usize,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut *const Keyframe<Brush>,
+            keyframe_count: &mut usize,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -374,6 +439,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        c_keyframes(keyframes, keyframe_count),
         transition_data,
     );
 }
@@ -536,6 +602,7 @@ unsafe fn evaluate(_self: *mut BindingHolder, _value: *mut ()) -> BindingResult
         vtable: VT,
         dirty: Cell::new(false),
         is_two_way_binding: false,
+        is_animated_value_binding: false,
         pinned: PhantomPinned,
         binding: inner,
         #[cfg(slint_debug_property)]
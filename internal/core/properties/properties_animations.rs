@@ -3,12 +3,26 @@
 
 use super::*;
 use crate::{
+    animations::{EasingCurve, Instant, Spring},
     items::{AnimationDirection, PropertyAnimation},
     lengths::LogicalLength,
 };
+use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
+/// An intermediate stop of a keyframe animation.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyframe<T> {
+    /// The position of this keyframe in the animation, between 0 and 1
+    pub offset: f32,
+    /// The easing curve used to interpolate from the previous keyframe to this one
+    pub easing: EasingCurve,
+    /// The value of the property at this keyframe
+    pub value: T,
+}
+
 enum AnimationState {
     Delaying,
     Animating { current_iteration: u64 },
@@ -19,21 +33,104 @@ pub(super) struct PropertyValueAnimationData<T> {
     from_value: T,
     to_value: T,
     details: PropertyAnimation,
+    /// Sorted by offset
+    keyframes: Vec<Keyframe<T>>,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// The velocity (in units of the value per second, towards `to_value`) at the start of a
+    /// spring animation
+    initial_velocity: f32,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
+        Self::with_keyframes(from_value, to_value, details, Vec::new())
+    }
+
+    pub fn with_keyframes(
+        from_value: T,
+        to_value: T,
+        details: PropertyAnimation,
+        keyframes: Vec<Keyframe<T>>,
+    ) -> Self {
         let start_time = crate::animations::current_tick();
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self {
+            from_value,
+            to_value,
+            details,
+            keyframes,
+            start_time,
+            state: AnimationState::Delaying,
+            initial_velocity: 0.,
+        }
+    }
+
+    /// Returns the spring if this animation is driven by a spring rather than by a duration
+    fn spring(&self) -> Option<Spring> {
+        match self.details.easing {
+            EasingCurve::Spring(params) if self.keyframes.is_empty() => Spring::new(params),
+            _ => None,
+        }
+    }
+
+    /// The initial velocity of the spring, in progress (from 0 to 1) per second
+    fn initial_progress_velocity(&self) -> f32 {
+        match self.from_value.distance(&self.to_value) {
+            Some(distance) if distance.abs() > f32::EPSILON => self.initial_velocity / distance,
+            _ => 0.,
+        }
+    }
+
+    /// Returns the current velocity of a spring animation, in units of the value per second.
+    /// Returns 0 for other animations.
+    fn velocity(&self) -> f32 {
+        let Some(spring) = self.spring() else { return 0. };
+        match self.state {
+            AnimationState::Delaying if self.details.delay <= 0 => self.initial_velocity,
+            AnimationState::Animating { .. } => {
+                let elapsed =
+                    crate::animations::current_tick().duration_since(self.start_time).as_secs_f32();
+                let distance = self.from_value.distance(&self.to_value).unwrap_or_default();
+                spring
+                    .state_at(self.initial_progress_velocity(), elapsed)
+                    .map_or(0., |(_, velocity)| velocity * distance)
+            }
+            _ => 0.,
+        }
+    }
+
+    /// Returns the value at the given progress (between 0 and 1), going through the keyframes.
+    fn value_at(&self, progress: f32) -> T {
+        let mut previous_offset = 0.;
+        let mut previous_value = &self.from_value;
+        for keyframe in &self.keyframes {
+            if progress < keyframe.offset {
+                let t = (progress - previous_offset) / (keyframe.offset - previous_offset);
+                let t = crate::animations::easing_curve(&keyframe.easing, t);
+                return previous_value.interpolate(&keyframe.value, t);
+            }
+            previous_offset = keyframe.offset;
+            previous_value = &keyframe.value;
+        }
+        if previous_offset >= 1. {
+            // The last keyframe is held until the end of the animation
+            return previous_value.clone();
+        }
+        let t = (progress - previous_offset) / (1. - previous_offset);
+        let t = crate::animations::easing_curve(&self.details.easing, t);
+        previous_value.interpolate(&self.to_value, t)
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
         let new_tick = crate::animations::current_tick();
         let mut time_progress = new_tick.duration_since(self.start_time).as_millis() as u64;
+        let spring = self.spring();
         let reversed = |iteration: u64| -> bool {
+            if spring.is_some() {
+                // the direction doesn't apply to springs
+                return false;
+            }
             match self.details.direction {
                 AnimationDirection::Normal => false,
                 AnimationDirection::Reverse => true,
@@ -67,6 +164,20 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 }
             }
             AnimationState::Animating { mut current_iteration } => {
+                if let Some(spring) = spring {
+                    // The duration and iteration count don't apply: the spring runs until it is at rest
+                    let elapsed = time_progress as f32 / 1000.;
+                    return match spring.state_at(self.initial_progress_velocity(), elapsed) {
+                        Some((progress, _)) => {
+                            (self.from_value.interpolate(&self.to_value, progress), false)
+                        }
+                        None => {
+                            self.state = AnimationState::Done { iteration_count: 0 };
+                            self.compute_interpolated_value()
+                        }
+                    };
+                }
+
                 if self.details.duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done { iteration_count: 0 };
                     return self.compute_interpolated_value();
@@ -95,10 +206,7 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                             progress
                         }
                     };
-                    let t = crate::animations::easing_curve(&self.details.easing, progress);
-                    let val = self.from_value.interpolate(&self.to_value, t);
-
-                    (val, false)
+                    (self.value_at(progress), false)
                 } else {
                     self.state =
                         AnimationState::Done { iteration_count: current_iteration.max(1) - 1 };
@@ -116,11 +224,58 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
     }
 
     fn reset(&mut self) {
+        // Keep the momentum of a running spring for the next animation
+        self.initial_velocity = self.velocity();
         self.state = AnimationState::Delaying;
         self.start_time = crate::animations::current_tick();
     }
 }
 
+/// The binding set on a property by [`Property::set_animated_value`]
+pub(super) struct AnimatedValueBinding<T>(RefCell<PropertyValueAnimationData<T>>);
+
+unsafe impl<T: InterpolatedPropertyValue + Clone> BindingCallable for AnimatedValueBinding<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        let (val, finished) = self.0.borrow_mut().compute_interpolated_value();
+        *(value as *mut T) = val;
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.set_has_active_animations());
+            BindingResult::KeepBinding
+        }
+    }
+
+    const IS_ANIMATED_VALUE_BINDING: bool = true;
+}
+
+impl PropertyHandle {
+    /// Set an `AnimatedValueBinding` running the given animation. If the previous binding
+    /// was also animating a value, its velocity is carried over.
+    ///
+    /// Safety: T must be the type of the property
+    pub(super) unsafe fn set_animated_value_binding<T: InterpolatedPropertyValue + Clone>(
+        &self,
+        mut animation_data: PropertyValueAnimationData<T>,
+        #[cfg(slint_debug_property)] debug_name: &str,
+    ) {
+        animation_data.initial_velocity = self.access(|binding| {
+            binding.filter(|b| b.is_animated_value_binding).map_or(0., |b| {
+                // Safety: the binding is an AnimatedValueBinding, and its type is the one of the property
+                let b = &*(&*b as *const BindingHolder
+                    as *const BindingHolder<AnimatedValueBinding<T>>);
+                b.binding.0.borrow().velocity()
+            })
+        });
+        self.set_binding(
+            AnimatedValueBinding(RefCell::new(animation_data)),
+            #[cfg(slint_debug_property)]
+            debug_name,
+        );
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub(super) enum AnimatedBindingState {
     Animating,
@@ -135,9 +290,10 @@ pub(super) struct AnimatedBindingCallable<T, A> {
     pub(super) compute_animation_details: A,
 }
 
-pub(super) type AnimationDetail = Option<(PropertyAnimation, crate::animations::Instant)>;
+/// The animation, its start time, and its keyframes (sorted by offset)
+pub(super) type AnimationDetail<T> = Option<(PropertyAnimation, Instant, Vec<Keyframe<T>>)>;
 
-unsafe impl<T: InterpolatedPropertyValue + Clone, A: Fn() -> AnimationDetail> BindingCallable
+unsafe impl<T: InterpolatedPropertyValue + Clone, A: Fn() -> AnimationDetail<T>> BindingCallable
     for AnimatedBindingCallable<T, A>
 {
     unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
@@ -167,9 +323,10 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                 // animation_data.details.iteration_count = 1.;
                 animation_data.from_value = value.clone();
                 self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
-                if let Some((details, start_time)) = (self.compute_animation_details)() {
+                if let Some((details, start_time, keyframes)) = (self.compute_animation_details)() {
                     animation_data.start_time = start_time;
                     animation_data.details = details;
+                    animation_data.keyframes = keyframes;
                 }
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
@@ -204,24 +361,43 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns the signed distance from self to the target value, for the types that can be
+    /// measured along a single axis. This is used to preserve the velocity of spring animations.
+    /// The types that return None, like colors, restart their spring animations from rest.
+    fn distance(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as i32
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as Self
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
@@ -229,12 +405,20 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).round().clamp(0., 255.)
             as u8
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+
+    fn distance(&self, target_value: &Self) -> Option<f32> {
+        self.get().distance(&target_value.get())
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -245,26 +429,28 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
+        self.set_animated_value_with_keyframes(value, animation_data, Vec::new())
+    }
+
+    /// Same as [`Self::set_animated_value`], but the animation goes through the given keyframes
+    /// before reaching the value. The keyframes must be sorted by offset.
+    pub fn set_animated_value_with_keyframes(
+        &self,
+        value: T,
+        animation_data: PropertyAnimation,
+        keyframes: Vec<Keyframe<T>>,
+    ) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
+        let d = properties_animations::PropertyValueAnimationData::with_keyframes(
             self.get_internal(),
             value,
             animation_data,
-        ));
-        // Safety: the BindingCallable will cast its argument to T
+            keyframes,
+        );
+        // Safety: the AnimatedValueBinding is for type T
         unsafe {
-            self.handle.set_binding(
-                move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                    *(val as *mut T) = value;
-                    if finished {
-                        BindingResult::RemoveBinding
-                    } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
-                        BindingResult::KeepBinding
-                    }
-                },
+            self.handle.set_animated_value_binding(
+                d,
                 #[cfg(slint_debug_property)]
                 self.debug_name.borrow().as_str(),
             );
@@ -281,6 +467,17 @@ pub fn set_animated_binding(
         &self,
         binding: impl Binding<T> + 'static,
         animation_data: PropertyAnimation,
+    ) {
+        self.set_animated_binding_with_keyframes(binding, animation_data, Vec::new())
+    }
+
+    /// Set a binding to this property, animated through the given keyframes each time
+    /// the value changes. The keyframes must be sorted by offset.
+    pub fn set_animated_binding_with_keyframes(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: PropertyAnimation,
+        keyframes: Vec<Keyframe<T>>,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -294,12 +491,15 @@ pub fn set_animated_binding(
                 ),
             },
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
-            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
-                T::default(),
-                T::default(),
-                animation_data,
-            )),
-            compute_animation_details: || -> properties_animations::AnimationDetail { None },
+            animation_data: RefCell::new(
+                properties_animations::PropertyValueAnimationData::with_keyframes(
+                    T::default(),
+                    T::default(),
+                    animation_data,
+                    keyframes,
+                ),
+            ),
+            compute_animation_details: || -> properties_animations::AnimationDetail<T> { None },
         };
 
         // Safety: the `AnimatedBindingCallable`'s type match the property type
@@ -316,13 +516,12 @@ pub fn set_animated_binding(
         );
     }
 
-    /// Set a binding to this property, providing a callback for the transition animation
-    ///
+    /// Set a binding to this property, providing a callback for the transition animation.
+    /// The callback returns the animation, its start time, and its keyframes sorted by offset.
     pub fn set_animated_binding_for_transition(
         &self,
         binding: impl Binding<T> + 'static,
-        compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant)
-            + 'static,
+        compute_animation_details: impl Fn() -> (PropertyAnimation, Instant, Vec<Keyframe<T>>) + 'static,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -1036,4 +1235,165 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    #[test]
+    fn properties_test_keyframes_triggered_by_set() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+        let keyframes = std::vec![
+            Keyframe { offset: 0.25, easing: EasingCurve::Linear, value: 500 },
+            Keyframe { offset: 0.5, easing: EasingCurve::Linear, value: 0 },
+        ];
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value_with_keyframes(200, animation_details, keyframes);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 8));
+        assert_eq!(get_prop_value(&compo.width), 300);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 8));
+        assert_eq!(get_prop_value(&compo.width), 250);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 100);
+        assert_eq!(get_prop_value(&compo.width_times_two), 200);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn properties_test_keyframes_triggered_by_binding() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+        let keyframes = std::vec![
+            Keyframe { offset: 0., easing: EasingCurve::Linear, value: 0 },
+            Keyframe { offset: 1., easing: EasingCurve::Linear, value: 1000 },
+        ];
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding_with_keyframes(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+            keyframes,
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 500);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION - DURATION / 100));
+        assert_eq!(get_prop_value(&compo.width), 990);
+
+        // Once the animation is over, the property gets the value of the binding
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn properties_test_spring_animation() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            easing: EasingCurve::Spring([100., 10., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(1000, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(100))
+        });
+        assert_eq!(get_prop_value(&compo.width), 340);
+
+        // An under damped spring overshoots the target
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(350))
+        });
+        assert!(get_prop_value(&compo.width) > 1000);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_secs(3))
+        });
+        assert_eq!(get_prop_value(&compo.width), 1000);
+
+        // the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn properties_test_spring_animation_keeps_velocity() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            easing: EasingCurve::Spring([100., 10., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(1000, animation_details.clone());
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(100))
+        });
+        assert_eq!(get_prop_value(&compo.width), 340);
+
+        // Retarget while the spring is moving at about 5300 per second
+        compo.width.set_animated_value(2000, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 340);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(116))
+        });
+        // Starting from rest, the value would only have reached 360
+        assert!(get_prop_value(&compo.width) > 400);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_secs(3))
+        });
+        assert_eq!(get_prop_value(&compo.width), 2000);
+    }
 }
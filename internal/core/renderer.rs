@@ -7,9 +7,11 @@
 
 use crate::api::PlatformError;
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use crate::item_rendering::RenderText;
 use crate::item_tree::ItemTreeRef;
-use crate::items::TextWrap;
+use crate::items::{ItemRc, TextWrap};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
+use crate::styled_text::StyledText;
 use crate::window::WindowAdapter;
 
 /// This trait represents a Renderer that can render a slint scene.
@@ -37,6 +39,19 @@ fn text_size(
         text_wrap: TextWrap,
     ) -> LogicalSize;
 
+    /// Returns the size of the given styled text in logical pixels, like [`Self::text_size`].
+    /// The spans of the text that change the font are measured with their own font.
+    fn styled_text_size(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        text: &StyledText,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        self.text_size(font_request, &text.text, max_width, scale_factor, text_wrap)
+    }
+
     /// Returns the metrics of the given font.
     fn font_metrics(
         &self,
@@ -67,6 +82,32 @@ fn text_input_cursor_rect_for_byte_offset(
         scale_factor: ScaleFactor,
     ) -> LogicalRect;
 
+    /// Returns the (UTF-8) byte offset in the text of the `text` item, which is the closest to the given position.
+    /// This is used for selecting text with the mouse in a selectable `Text` element.
+    fn text_byte_offset_for_position(
+        &self,
+        _text: Pin<&dyn RenderText>,
+        _self_rc: &ItemRc,
+        _pos: LogicalPoint,
+        _scale_factor: ScaleFactor,
+    ) -> usize {
+        0
+    }
+
+    /// Returns the (UTF-8) byte offset in the plain text of `styled_text`, which is rendered for the `text` item,
+    /// of the glyph at the given position. Returns None if there is no glyph at that position.
+    /// This is used for hit-testing links.
+    fn styled_text_byte_offset_for_position(
+        &self,
+        _text: Pin<&dyn RenderText>,
+        _self_rc: &ItemRc,
+        _styled_text: &StyledText,
+        _pos: LogicalPoint,
+        _scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        None
+    }
+
     /// Clear the caches for the items that are being removed
     fn free_graphics_resources(
         &self,
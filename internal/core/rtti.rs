@@ -10,6 +10,7 @@
 
 pub type FieldOffset<T, U> = const_field_offset::FieldOffset<T, U, const_field_offset::AllowPin>;
 use crate::items::PropertyAnimation;
+use crate::properties::Keyframe;
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -59,39 +60,60 @@ macro_rules! declare_ValueType_2 {
 i_slint_common::for_each_enums!(declare_ValueType_2);
 
 /// What kind of animation is on a binding
-pub enum AnimatedBindingKind {
+pub enum AnimatedBindingKind<Value> {
     /// No animation is on the binding
     NotAnimated,
     /// Single animation
     Animation(PropertyAnimation),
-    /// Transition
-    Transition(Box<dyn Fn() -> (PropertyAnimation, crate::animations::Instant)>),
+    /// Animation going through intermediate keyframes
+    Keyframes(PropertyAnimation, Vec<Keyframe<Value>>),
+    /// Transition: the callback returns the animation, its start time, and its keyframes
+    Transition(
+        Box<dyn Fn() -> (PropertyAnimation, crate::animations::Instant, Vec<Keyframe<Value>>)>,
+    ),
 }
 
-impl AnimatedBindingKind {
-    /// return a PropertyAnimation if self contains AnimatedBindingKind::Animation
-    pub fn as_animation(self) -> Option<PropertyAnimation> {
+impl<Value> AnimatedBindingKind<Value> {
+    /// return a PropertyAnimation and its keyframes if self contains AnimatedBindingKind::Animation
+    /// or AnimatedBindingKind::Keyframes
+    pub fn as_animation(self) -> Option<(PropertyAnimation, Vec<Keyframe<Value>>)> {
         match self {
             AnimatedBindingKind::NotAnimated => None,
-            AnimatedBindingKind::Animation(a) => Some(a),
+            AnimatedBindingKind::Animation(a) => Some((a, Vec::new())),
+            AnimatedBindingKind::Keyframes(a, keyframes) => Some((a, keyframes)),
             AnimatedBindingKind::Transition(_) => None,
         }
     }
 }
 
+fn convert_keyframes<T, Value: TryInto<T>>(
+    keyframes: Vec<Keyframe<Value>>,
+) -> Result<Vec<Keyframe<T>>, ()> {
+    keyframes
+        .into_iter()
+        .map(|kf| {
+            Ok(Keyframe {
+                offset: kf.offset,
+                easing: kf.easing,
+                value: kf.value.try_into().map_err(|_| ())?,
+            })
+        })
+        .collect()
+}
+
 pub trait PropertyInfo<Item, Value> {
     fn get(&self, item: Pin<&Item>) -> Result<Value, ()>;
     fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<(PropertyAnimation, Vec<Keyframe<Value>>)>,
     ) -> Result<(), ()>;
     fn set_binding(
         &self,
         item: Pin<&Item>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) -> Result<(), ()>;
 
     /// The offset of the property in the item.
@@ -128,7 +150,7 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<(PropertyAnimation, Vec<Keyframe<Value>>)>,
     ) -> Result<(), ()> {
         if animation.is_some() {
             Err(())
@@ -141,7 +163,7 @@ fn set_binding(
         &self,
         item: Pin<&Item>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) -> Result<(), ()> {
         if !matches!(animation, AnimatedBindingKind::NotAnimated) {
             Err(())
@@ -184,10 +206,14 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<(PropertyAnimation, Vec<Keyframe<Value>>)>,
     ) -> Result<(), ()> {
-        if let Some(animation) = animation {
-            self.apply_pin(item).set_animated_value(value.try_into().map_err(|_| ())?, animation);
+        if let Some((animation, keyframes)) = animation {
+            self.apply_pin(item).set_animated_value_with_keyframes(
+                value.try_into().map_err(|_| ())?,
+                animation,
+                convert_keyframes(keyframes)?,
+            );
             Ok(())
         } else {
             self.0.set(item, value, None)
@@ -197,13 +223,13 @@ fn set_binding(
         &self,
         item: Pin<&Item>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) -> Result<(), ()> {
         // Put in a function that does not depends on Item to avoid code bloat
         fn set_binding_impl<T, Value>(
             p: Pin<&crate::Property<T>>,
             binding: Box<dyn Fn() -> Value>,
-            animation: AnimatedBindingKind,
+            animation: AnimatedBindingKind<Value>,
         ) -> Result<(), ()>
         where
             T: Clone + TryInto<Value> + crate::properties::InterpolatedPropertyValue + 'static,
@@ -228,6 +254,19 @@ fn set_binding_impl<T, Value>(
                     );
                     Ok(())
                 }
+                AnimatedBindingKind::Keyframes(animation, keyframes) => {
+                    p.set_animated_binding_with_keyframes(
+                        move || {
+                            binding()
+                                .try_into()
+                                .map_err(|_| ())
+                                .expect("binding was of the wrong type")
+                        },
+                        animation,
+                        convert_keyframes(keyframes)?,
+                    );
+                    Ok(())
+                }
                 AnimatedBindingKind::Transition(tr) => {
                     p.set_animated_binding_for_transition(
                         move || {
@@ -236,7 +275,12 @@ fn set_binding_impl<T, Value>(
                                 .map_err(|_| ())
                                 .expect("binding was of the wrong type")
                         },
-                        tr,
+                        move || {
+                            let (animation, start_time, keyframes) = tr();
+                            let keyframes = convert_keyframes(keyframes)
+                                .expect("keyframes were of the wrong type");
+                            (animation, start_time, keyframes)
+                        },
                     );
                     Ok(())
                 }
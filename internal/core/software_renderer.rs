@@ -21,15 +21,18 @@
 use crate::graphics::{BorderRadius, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer};
 use crate::item_rendering::{
     CachedRenderingData, DirtyRegion, PartialRenderingState, RenderBorderRectangle, RenderImage,
-    RenderRectangle,
+    RenderRectangle, TextDecoration,
 };
-use crate::items::{FillRule, ItemRc, TextOverflow, TextWrap};
+use crate::items::{FillRule, ItemRc, TextDecorationStyle, TextOverflow, TextWrap};
 use crate::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
 };
 use crate::renderer::RendererSealed;
-use crate::textlayout::{AbstractFont, FontMetrics, TextParagraphLayout};
+use crate::styled_text::StyledText;
+use crate::textlayout::{
+    AbstractFont, DecorationMetrics, FontMetrics, StyledTextParagraphLayout, TextParagraphLayout,
+};
 use crate::window::{WindowAdapter, WindowInner};
 use crate::{Brush, Color, ImageInner, StaticTextures};
 use alloc::rc::{Rc, Weak};
@@ -717,6 +720,17 @@ fn text_size(
         fonts::text_size(font_request, text, max_width, scale_factor, text_wrap)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        text: &StyledText,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        fonts::styled_text_size(font_request, text, max_width, scale_factor, text_wrap)
+    }
+
     fn font_metrics(
         &self,
         font_request: crate::graphics::FontRequest,
@@ -786,6 +800,93 @@ fn text_input_byte_offset_for_position(
         }
     }
 
+    fn styled_text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn crate::item_rendering::RenderText>,
+        self_rc: &ItemRc,
+        styled_text: &StyledText,
+        pos: LogicalPoint,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let font_request = text.font_request(self_rc);
+        let fonts = fonts::match_styled_text_fonts(styled_text, &font_request, scale_factor);
+        let spans = fonts::styled_text_span_layouts(styled_text, &fonts, scale_factor);
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+        let max_size = (text.target_size().cast() * scale_factor).cast();
+        let paragraph = StyledTextParagraphLayout {
+            string: &styled_text.text,
+            spans: &spans,
+            max_width: max_size.width_length(),
+            max_height: max_size.height_length(),
+            horizontal_alignment,
+            vertical_alignment,
+            wrap: text.wrap(),
+            overflow: text.overflow(),
+        };
+
+        let pos: PhysicalPoint = (pos.cast() * scale_factor)
+            .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+            .cast();
+        paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+    }
+
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn crate::item_rendering::RenderText>,
+        self_rc: &ItemRc,
+        pos: LogicalPoint,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let string = text.text();
+        let font_request = text.font_request(self_rc);
+        let font = fonts::match_font(&font_request, scale_factor);
+
+        let size = (text.target_size().cast() * scale_factor).cast();
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+        let pos = (pos.cast() * scale_factor)
+            .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+            .cast();
+
+        match font {
+            fonts::Font::PixelFont(pf) => {
+                let layout = fonts::text_layout_for_font(&pf, &font_request, scale_factor);
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout,
+                    max_width: size.width_length(),
+                    max_height: size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+            }
+            #[cfg(feature = "software-renderer-systemfonts")]
+            fonts::Font::VectorFont(vf) => {
+                let layout = fonts::text_layout_for_font(&vf, &font_request, scale_factor);
+
+                let paragraph = TextParagraphLayout {
+                    string: &string,
+                    layout,
+                    max_width: size.width_length(),
+                    max_height: size.height_length(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    wrap: text.wrap(),
+                    overflow: text.overflow(),
+                    single_line: false,
+                };
+
+                paragraph.byte_offset_for_position((pos.x_length(), pos.y_length()))
+            }
+        }
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -1834,12 +1935,15 @@ fn draw_text_paragraph<Font>(
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
         selection: Option<SelectionInfo>,
+        decoration: TextDecoration,
     ) where
         Font: AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength> + GlyphRenderer,
     {
         paragraph
             .layout_lines::<()>(
                 |glyphs, line_x, line_y, _, sel| {
+                    // The horizontal extent of the glyphs in the line, for the decoration lines
+                    let mut extent: Option<(PhysicalLength, PhysicalLength)> = None;
                     let baseline_y = line_y + paragraph.layout.font.ascent();
                     if let (Some(sel), Some(selection)) = (sel, &selection) {
                         let geometry = euclid::rect(
@@ -1848,148 +1952,340 @@ fn draw_text_paragraph<Font>(
                             (sel.end - sel.start).get(),
                             paragraph.layout.font.height().get(),
                         );
-                        if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
-                            let geometry =
-                                clipped_src.translate(offset.cast()).transformed(self.rotation);
-                            let args = target_pixel_buffer::DrawRectangleArgs::from_rect(
-                                geometry.cast(),
-                                selection.selection_background.into(),
-                            );
-                            self.processor.process_rectangle(&args, geometry);
-                        }
+                        self.draw_text_rectangle(
+                            geometry,
+                            selection.selection_background,
+                            physical_clip,
+                            offset,
+                        );
                     }
-                    let scale_delta = paragraph.layout.font.scale_delta();
                     for positioned_glyph in glyphs {
-                        let Some(glyph) =
-                            paragraph.layout.font.render_glyph(positioned_glyph.glyph_id)
-                        else {
-                            continue;
-                        };
-
-                        let gl_x = PhysicalLength::new((-glyph.x).truncate() as i16);
-                        let gl_y = PhysicalLength::new(glyph.y.truncate() as i16);
-                        let target_rect = PhysicalRect::new(
-                            PhysicalPoint::from_lengths(
-                                line_x + positioned_glyph.x - gl_x,
-                                baseline_y - gl_y - glyph.height,
-                            ),
-                            glyph.size(),
-                        )
-                        .cast();
-
                         let color = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
                                 s.selection_color
                             }
                             _ => color,
                         };
+                        self.draw_glyph(
+                            paragraph.layout.font,
+                            positioned_glyph.glyph_id,
+                            PhysicalPoint::from_lengths(line_x + positioned_glyph.x, baseline_y),
+                            color,
+                            physical_clip,
+                            offset,
+                        );
+                        let glyph_end = positioned_glyph.x + positioned_glyph.advance;
+                        extent = Some(extent.map_or((positioned_glyph.x, glyph_end), |(s, e)| {
+                            (s.min(positioned_glyph.x), e.max(glyph_end))
+                        }));
+                    }
+                    if let Some((start, end)) = extent.filter(|_| !decoration.is_empty()) {
+                        self.draw_text_decoration(
+                            paragraph.layout.font,
+                            &decoration,
+                            (line_x + start)..(line_x + end),
+                            baseline_y,
+                            color,
+                            physical_clip,
+                            offset,
+                        );
+                    }
+                    core::ops::ControlFlow::Continue(())
+                },
+                selection.as_ref().map(|s| s.selection.clone()),
+            )
+            .ok();
+    }
 
-                        let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
-                            continue;
-                        };
+    fn draw_styled_text(
+        &mut self,
+        text: Pin<&dyn crate::item_rendering::RenderText>,
+        styled_text: &StyledText,
+        font_request: &crate::graphics::FontRequest,
+        max_size: PhysicalSize,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) {
+        let default_color = self.alpha_color(text.color().color());
+        let fonts = fonts::match_styled_text_fonts(styled_text, font_request, self.scale_factor);
+        let spans = fonts::styled_text_span_layouts(styled_text, &fonts, self.scale_factor);
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+        let paragraph = StyledTextParagraphLayout {
+            string: &styled_text.text,
+            spans: &spans,
+            max_width: max_size.width_length(),
+            max_height: max_size.height_length(),
+            horizontal_alignment,
+            vertical_alignment,
+            wrap: text.wrap(),
+            overflow: text.overflow(),
+        };
 
-                        let data = match &glyph.alpha_map {
-                            fonts::GlyphAlphaMap::Static(data) => {
-                                if glyph.sdf {
-                                    let geometry = clipped_target.translate(offset).round();
-                                    let origin =
-                                        (geometry.origin - offset.round()).round().cast::<i16>();
-                                    let off_x = origin.x - target_rect.origin.x as i16;
-                                    let off_y = origin.y - target_rect.origin.y as i16;
-                                    let pixel_stride = glyph.pixel_stride;
-                                    let mut geometry = geometry.cast();
-                                    if geometry.size.width > glyph.width.get() - off_x {
-                                        geometry.size.width = glyph.width.get() - off_x
-                                    }
-                                    if geometry.size.height > glyph.height.get() - off_y {
-                                        geometry.size.height = glyph.height.get() - off_y
-                                    }
-                                    let source_size = geometry.size;
-                                    if source_size.is_empty() {
-                                        continue;
-                                    }
-
-                                    let delta32 = Fixed::<i32, 8>::from_fixed(scale_delta);
-                                    let normalize = |x: Fixed<i32, 8>| {
-                                        if x < Fixed::from_integer(0) {
-                                            x + Fixed::from_integer(1)
-                                        } else {
-                                            x
-                                        }
-                                    };
-                                    let fract_x = normalize(
-                                        (-glyph.x) - Fixed::from_integer(gl_x.get() as _),
-                                    );
-                                    let off_x = delta32 * off_x as i32 + fract_x;
-                                    let fract_y =
-                                        normalize(glyph.y - Fixed::from_integer(gl_y.get() as _));
-                                    let off_y = delta32 * off_y as i32 + fract_y;
-                                    let texture = SceneTexture {
-                                        data,
-                                        pixel_stride,
-                                        format: TexturePixelFormat::SignedDistanceField,
-                                        extra: SceneTextureExtra {
-                                            colorize: color,
-                                            // color already is mixed with global alpha
-                                            alpha: color.alpha(),
-                                            rotation: self.rotation.orientation,
-                                            dx: scale_delta,
-                                            dy: scale_delta,
-                                            off_x: Fixed::try_from_fixed(off_x).unwrap(),
-                                            off_y: Fixed::try_from_fixed(off_y).unwrap(),
-                                        },
-                                    };
-                                    self.processor.process_scene_texture(
-                                        geometry.transformed(self.rotation),
-                                        texture,
-                                    );
-                                    continue;
-                                };
-
-                                target_pixel_buffer::TextureDataContainer::Static(
-                                    target_pixel_buffer::TextureData::new(
-                                        data,
-                                        TexturePixelFormat::AlphaMap,
-                                        glyph.pixel_stride as usize,
-                                        euclid::size2(glyph.width.get(), glyph.height.get()).cast(),
-                                    ),
-                                )
-                            }
-                            fonts::GlyphAlphaMap::Shared(data) => {
-                                let source_rect = euclid::rect(0, 0, glyph.width.0, glyph.height.0);
-                                target_pixel_buffer::TextureDataContainer::Shared {
-                                    buffer: SharedBufferData::AlphaMap {
-                                        data: data.clone(),
-                                        width: glyph.pixel_stride,
-                                    },
-                                    source_rect,
-                                }
+        let decoration = text.decoration();
+
+        paragraph
+            .layout_lines::<()>(|glyphs, line, _| {
+                let baseline_y = line.y + line.ascent;
+                // The glyphs of the span being drawn: (span index, start x, end x)
+                let mut run: Option<(usize, PhysicalLength, PhysicalLength)> = None;
+                let draw_decorations = |this: &mut Self,
+                                        (span_index, start, end): (
+                    usize,
+                    PhysicalLength,
+                    PhysicalLength,
+                )| {
+                    let font = &fonts[span_index].1;
+                    let style = &styled_text.spans[span_index].style;
+                    let color = style.color.map_or(default_color, |color| this.alpha_color(color));
+                    // The underline of the element replaces the one of links and `<u>`
+                    if style.underline && !decoration.underline {
+                        let metrics = decoration_metrics(font);
+                        this.draw_text_rectangle(
+                            euclid::rect(
+                                (line.x + start).get(),
+                                (baseline_y - metrics.underline_position).get(),
+                                (end - start).get(),
+                                metrics.underline_thickness.get(),
+                            ),
+                            color,
+                            physical_clip,
+                            offset,
+                        );
+                    }
+                    if !decoration.is_empty() {
+                        this.draw_text_decoration(
+                            font,
+                            &decoration,
+                            (line.x + start)..(line.x + end),
+                            baseline_y,
+                            color,
+                            physical_clip,
+                            offset,
+                        );
+                    }
+                };
+                for (span_index, positioned_glyph) in glyphs {
+                    let style = &styled_text.spans[span_index].style;
+                    let color = style.color.map_or(default_color, |color| self.alpha_color(color));
+                    self.draw_glyph(
+                        &fonts[span_index].1,
+                        positioned_glyph.glyph_id,
+                        PhysicalPoint::from_lengths(line.x + positioned_glyph.x, baseline_y),
+                        color,
+                        physical_clip,
+                        offset,
+                    );
+                    let glyph_end = positioned_glyph.x + positioned_glyph.advance;
+                    match &mut run {
+                        Some((index, _, end)) if *index == span_index => *end = glyph_end,
+                        _ => {
+                            if let Some(previous) = run.take() {
+                                draw_decorations(self, previous);
                             }
-                        };
-                        let clipped_target =
-                            clipped_target.translate(offset).round().transformed(self.rotation);
-                        let target_rect =
-                            target_rect.translate(offset).round().transformed(self.rotation);
-                        let t = target_pixel_buffer::DrawTextureArgs {
-                            data,
-                            colorize: Some(color),
+                            run = Some((span_index, positioned_glyph.x, glyph_end));
+                        }
+                    }
+                }
+                if let Some(previous) = run {
+                    draw_decorations(self, previous);
+                }
+                core::ops::ControlFlow::Continue(())
+            })
+            .ok();
+    }
+
+    /// Draws a single glyph, where `origin` is the position of the glyph on the baseline.
+    fn draw_glyph<Font: GlyphRenderer>(
+        &mut self,
+        font: &Font,
+        glyph_id: core::num::NonZeroU16,
+        origin: PhysicalPoint,
+        color: Color,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) {
+        let Some(glyph) = font.render_glyph(glyph_id) else {
+            return;
+        };
+        let scale_delta = font.scale_delta();
+
+        let gl_x = PhysicalLength::new((-glyph.x).truncate() as i16);
+        let gl_y = PhysicalLength::new(glyph.y.truncate() as i16);
+        let target_rect = PhysicalRect::new(
+            PhysicalPoint::from_lengths(
+                origin.x_length() - gl_x,
+                origin.y_length() - gl_y - glyph.height,
+            ),
+            glyph.size(),
+        )
+        .cast();
+
+        let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
+            return;
+        };
+
+        let data = match &glyph.alpha_map {
+            fonts::GlyphAlphaMap::Static(data) => {
+                if glyph.sdf {
+                    let geometry = clipped_target.translate(offset).round();
+                    let origin = (geometry.origin - offset.round()).round().cast::<i16>();
+                    let off_x = origin.x - target_rect.origin.x as i16;
+                    let off_y = origin.y - target_rect.origin.y as i16;
+                    let pixel_stride = glyph.pixel_stride;
+                    let mut geometry = geometry.cast();
+                    if geometry.size.width > glyph.width.get() - off_x {
+                        geometry.size.width = glyph.width.get() - off_x
+                    }
+                    if geometry.size.height > glyph.height.get() - off_y {
+                        geometry.size.height = glyph.height.get() - off_y
+                    }
+                    let source_size = geometry.size;
+                    if source_size.is_empty() {
+                        return;
+                    }
+
+                    let delta32 = Fixed::<i32, 8>::from_fixed(scale_delta);
+                    let normalize = |x: Fixed<i32, 8>| {
+                        if x < Fixed::from_integer(0) {
+                            x + Fixed::from_integer(1)
+                        } else {
+                            x
+                        }
+                    };
+                    let fract_x = normalize((-glyph.x) - Fixed::from_integer(gl_x.get() as _));
+                    let off_x = delta32 * off_x as i32 + fract_x;
+                    let fract_y = normalize(glyph.y - Fixed::from_integer(gl_y.get() as _));
+                    let off_y = delta32 * off_y as i32 + fract_y;
+                    let texture = SceneTexture {
+                        data,
+                        pixel_stride,
+                        format: TexturePixelFormat::SignedDistanceField,
+                        extra: SceneTextureExtra {
+                            colorize: color,
                             // color already is mixed with global alpha
                             alpha: color.alpha(),
-                            dst_x: target_rect.origin.x as _,
-                            dst_y: target_rect.origin.y as _,
-                            dst_width: target_rect.size.width as _,
-                            dst_height: target_rect.size.height as _,
                             rotation: self.rotation.orientation,
-                            tiling: None,
-                        };
+                            dx: scale_delta,
+                            dy: scale_delta,
+                            off_x: Fixed::try_from_fixed(off_x).unwrap(),
+                            off_y: Fixed::try_from_fixed(off_y).unwrap(),
+                        },
+                    };
+                    self.processor
+                        .process_scene_texture(geometry.transformed(self.rotation), texture);
+                    return;
+                };
+
+                target_pixel_buffer::TextureDataContainer::Static(
+                    target_pixel_buffer::TextureData::new(
+                        data,
+                        TexturePixelFormat::AlphaMap,
+                        glyph.pixel_stride as usize,
+                        euclid::size2(glyph.width.get(), glyph.height.get()).cast(),
+                    ),
+                )
+            }
+            fonts::GlyphAlphaMap::Shared(data) => {
+                let source_rect = euclid::rect(0, 0, glyph.width.0, glyph.height.0);
+                target_pixel_buffer::TextureDataContainer::Shared {
+                    buffer: SharedBufferData::AlphaMap {
+                        data: data.clone(),
+                        width: glyph.pixel_stride,
+                    },
+                    source_rect,
+                }
+            }
+        };
+        let clipped_target = clipped_target.translate(offset).round().transformed(self.rotation);
+        let target_rect = target_rect.translate(offset).round().transformed(self.rotation);
+        let t = target_pixel_buffer::DrawTextureArgs {
+            data,
+            colorize: Some(color),
+            // color already is mixed with global alpha
+            alpha: color.alpha(),
+            dst_x: target_rect.origin.x as _,
+            dst_y: target_rect.origin.y as _,
+            dst_width: target_rect.size.width as _,
+            dst_height: target_rect.size.height as _,
+            rotation: self.rotation.orientation,
+            tiling: None,
+        };
+
+        self.processor.process_target_texture(&t, clipped_target.cast());
+    }
 
-                        self.processor.process_target_texture(&t, clipped_target.cast());
+    /// Draws the underline, overline and strikethrough of a line of text, spanning `x_range`
+    /// horizontally. `color` is the text color, used if the decoration doesn't specify one.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_text_decoration<Font: FontMetrics<PhysicalLength>>(
+        &mut self,
+        font: &Font,
+        decoration: &TextDecoration,
+        x_range: core::ops::Range<PhysicalLength>,
+        baseline_y: PhysicalLength,
+        color: Color,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) {
+        let metrics = decoration_metrics(font);
+        let color =
+            if decoration.color.alpha() == 0 { color } else { self.alpha_color(decoration.color) };
+        let lines = [
+            (decoration.underline, metrics.underline_position, metrics.underline_thickness),
+            (decoration.overline, font.ascent(), metrics.underline_thickness),
+            (
+                decoration.strikethrough,
+                metrics.strikethrough_position,
+                metrics.strikethrough_thickness,
+            ),
+        ];
+        for (_, position, thickness) in lines.into_iter().filter(|line| line.0) {
+            let y = baseline_y - position;
+            match decoration.style {
+                TextDecorationStyle::Solid => self.draw_text_rectangle(
+                    euclid::rect(
+                        x_range.start.get(),
+                        y.get(),
+                        (x_range.end - x_range.start).get(),
+                        thickness.get(),
+                    ),
+                    color,
+                    physical_clip,
+                    offset,
+                ),
+                TextDecorationStyle::Wavy => {
+                    // A triangle wave around the line, drawn one pixel column at a time.
+                    let t = thickness.get();
+                    let period = 4 * t;
+                    for x in x_range.start.get()..x_range.end.get() {
+                        let phase = (x - x_range.start.get()) % period;
+                        let dy = if phase < 2 * t { phase } else { period - phase };
+                        self.draw_text_rectangle(
+                            euclid::rect(x, y.get() - t + dy, 1, t),
+                            color,
+                            physical_clip,
+                            offset,
+                        );
                     }
-                    core::ops::ControlFlow::Continue(())
-                },
-                selection.as_ref().map(|s| s.selection.clone()),
-            )
-            .ok();
+                }
+            }
+        }
+    }
+
+    /// Fills a rectangle in a text, such as the background of the selection or an underline.
+    fn draw_text_rectangle(
+        &mut self,
+        geometry: PhysicalRect,
+        color: Color,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) {
+        if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
+            let geometry = clipped_src.translate(offset.cast()).transformed(self.rotation);
+            let args =
+                target_pixel_buffer::DrawRectangleArgs::from_rect(geometry.cast(), color.into());
+            self.processor.process_rectangle(&args, geometry);
+        }
     }
 
     /// Returns the color, mixed with the current_state's alpha
@@ -2007,6 +2303,22 @@ fn alpha_color(&self, color: Color) -> Color {
     }
 }
 
+/// Returns the placement of the decoration lines of the font, or a reasonable approximation if the
+/// font doesn't provide them.
+fn decoration_metrics<Font: FontMetrics<PhysicalLength>>(
+    font: &Font,
+) -> DecorationMetrics<PhysicalLength> {
+    font.decoration_metrics().unwrap_or_else(|| {
+        let thickness = (font.height() / 14).max(PhysicalLength::new(1));
+        DecorationMetrics {
+            underline_position: -(-font.descent() / 3).max(PhysicalLength::new(1)),
+            underline_thickness: thickness,
+            strikethrough_position: (font.x_height() + thickness) / 2,
+            strikethrough_thickness: thickness,
+        }
+    })
+}
+
 fn alpha_color(color: Color, alpha: u8) -> Color {
     if alpha < 255 {
         Color::from_argb_u8(
@@ -2179,7 +2491,13 @@ fn draw_text(
         _cache: &CachedRenderingData,
     ) {
         let string = text.text();
-        if string.trim().is_empty() {
+        let styled_text = text.styled_text();
+        if styled_text
+            .as_ref()
+            .map_or(string.as_str(), |styled| styled.text.as_str())
+            .trim()
+            .is_empty()
+        {
             return;
         }
         let geom = LogicalRect::from(size);
@@ -2203,6 +2521,25 @@ fn draw_text(
         };
         let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
 
+        if let Some(styled_text) = styled_text {
+            self.draw_styled_text(
+                text,
+                &styled_text,
+                &font_request,
+                max_size,
+                physical_clip,
+                offset,
+            );
+            return;
+        }
+
+        let selection = text.selection().map(|selection| SelectionInfo {
+            selection_background: self.alpha_color(selection.background),
+            selection_color: self.alpha_color(selection.foreground),
+            selection: selection.range,
+        });
+
+        let decoration = text.decoration();
         let font = fonts::match_font(&font_request, self.scale_factor);
 
         match font {
@@ -2222,7 +2559,14 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    decoration,
+                );
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -2241,7 +2585,14 @@ fn draw_text(
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    decoration,
+                );
             }
         }
     }
@@ -2282,6 +2633,7 @@ fn draw_text_input(
                 selection_color: self.alpha_color(text_input.selection_foreground_color()),
                 selection: text_visual_representation.selection_range.clone(),
             });
+        let decoration = text_input.decoration();
 
         let cursor_pos_and_height = match font {
             fonts::Font::PixelFont(pf) => {
@@ -2297,7 +2649,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    decoration,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -2317,7 +2676,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    selection,
+                    decoration,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -2574,7 +2940,14 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    clip,
+                    Default::default(),
+                    color,
+                    None,
+                    Default::default(),
+                );
             }
             #[cfg(feature = "software-renderer-systemfonts")]
             fonts::Font::VectorFont(vf) => {
@@ -2592,7 +2965,14 @@ fn draw_string(&mut self, string: &str, color: Color) {
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, clip, Default::default(), color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    clip,
+                    Default::default(),
+                    color,
+                    None,
+                    Default::default(),
+                );
             }
         }
     }
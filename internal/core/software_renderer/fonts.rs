@@ -7,9 +7,12 @@
 
 use super::{Fixed, PhysicalLength, PhysicalSize};
 use crate::graphics::{BitmapFont, FontRequest};
-use crate::items::TextWrap;
+use crate::items::{TextOverflow, TextWrap};
 use crate::lengths::{LogicalLength, LogicalSize, ScaleFactor};
-use crate::textlayout::{FontMetrics, TextLayout};
+use crate::styled_text::StyledText;
+use crate::textlayout::{
+    FontMetrics, Glyph, StyledTextParagraphLayout, StyledTextSpanLayout, TextLayout, TextShaper,
+};
 use crate::Coord;
 
 crate::thread_local! {
@@ -101,6 +104,64 @@ fn cap_height(&self) -> PhysicalLength {
             Font::VectorFont(vector_font) => vector_font.cap_height(),
         }
     }
+    fn decoration_metrics(&self) -> Option<crate::textlayout::DecorationMetrics<PhysicalLength>> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.decoration_metrics(),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.decoration_metrics(),
+        }
+    }
+}
+
+impl crate::textlayout::TextShaper for Font {
+    type LengthPrimitive = i16;
+    type Length = PhysicalLength;
+
+    fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.shape_text(text, glyphs),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.shape_text(text, glyphs),
+        }
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.glyph_for_char(ch),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.glyph_for_char(ch),
+        }
+    }
+
+    fn max_lines(&self, max_height: PhysicalLength) -> usize {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.max_lines(max_height),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.max_lines(max_height),
+        }
+    }
+}
+
+impl GlyphRenderer for Font {
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<RenderableGlyph> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.render_glyph(glyph_id),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.render_glyph(glyph_id),
+        }
+    }
+
+    fn scale_delta(&self) -> Fixed<u16, 8> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.scale_delta(),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.scale_delta(),
+        }
+    }
 }
 
 pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
@@ -173,10 +234,45 @@ pub fn text_layout_for_font<'a, Font>(
 where
     Font: crate::textlayout::AbstractFont + crate::textlayout::TextShaper<Length = PhysicalLength>,
 {
-    let letter_spacing =
-        font_request.letter_spacing.map(|spacing| (spacing.cast() * scale_factor).cast());
+    let to_physical = |length: LogicalLength| (length.cast() * scale_factor).cast();
+
+    TextLayout {
+        font,
+        letter_spacing: font_request.letter_spacing.map(to_physical),
+        line_height: font_request.line_height.map(to_physical),
+        paragraph_spacing: font_request.paragraph_spacing.map(to_physical),
+    }
+}
+
+/// Returns the font request and the matching font for each span of the styled text.
+pub fn match_styled_text_fonts(
+    text: &StyledText,
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+) -> Vec<(FontRequest, Font)> {
+    text.spans
+        .iter()
+        .map(|span| {
+            let request = span.style.font_request(font_request);
+            let font = match_font(&request, scale_factor);
+            (request, font)
+        })
+        .collect()
+}
 
-    TextLayout { font, letter_spacing }
+pub fn styled_text_span_layouts<'a>(
+    text: &StyledText,
+    fonts: &'a [(FontRequest, Font)],
+    scale_factor: ScaleFactor,
+) -> Vec<StyledTextSpanLayout<'a, Font>> {
+    text.spans
+        .iter()
+        .zip(fonts)
+        .map(|(span, (request, font))| StyledTextSpanLayout {
+            byte_range: span.range.clone(),
+            layout: text_layout_for_font(font, request, scale_factor),
+        })
+        .collect()
 }
 
 pub fn register_bitmap_font(font_data: &'static BitmapFont) {
@@ -214,6 +310,32 @@ pub fn text_size(
     (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
 }
 
+pub fn styled_text_size(
+    font_request: FontRequest,
+    text: &StyledText,
+    max_width: Option<LogicalLength>,
+    scale_factor: ScaleFactor,
+    text_wrap: TextWrap,
+) -> LogicalSize {
+    let fonts = match_styled_text_fonts(text, &font_request, scale_factor);
+    let spans = styled_text_span_layouts(text, &fonts, scale_factor);
+    let paragraph = StyledTextParagraphLayout {
+        string: &text.text,
+        spans: &spans,
+        max_width: max_width.map_or(PhysicalLength::new(i16::MAX), |max_width| {
+            (max_width.cast() * scale_factor).cast()
+        }),
+        max_height: PhysicalLength::new(i16::MAX),
+        horizontal_alignment: Default::default(),
+        vertical_alignment: Default::default(),
+        wrap: if max_width.is_some() { text_wrap } else { TextWrap::NoWrap },
+        overflow: TextOverflow::Clip,
+    };
+    let (longest_line_width, height) = paragraph.text_size();
+
+    (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
+}
+
 pub fn font_metrics(
     font_request: FontRequest,
     scale_factor: ScaleFactor,
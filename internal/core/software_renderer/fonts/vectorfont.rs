@@ -6,7 +6,7 @@
 use crate::lengths::PhysicalPx;
 use crate::software_renderer::fixed::Fixed;
 use crate::software_renderer::PhysicalLength;
-use crate::textlayout::{Glyph, TextShaper};
+use crate::textlayout::{DecorationMetrics, Glyph, TextShaper};
 use i_slint_common::sharedfontdb::{self, fontdb};
 
 use super::RenderableGlyph;
@@ -55,6 +55,7 @@ pub struct VectorFont {
     pixel_size: PhysicalLength,
     x_height: PhysicalLength,
     cap_height: PhysicalLength,
+    decoration_metrics: Option<DecorationMetrics<PhysicalLength>>,
 }
 
 impl VectorFont {
@@ -76,6 +77,31 @@ pub fn new(
                         FontLength::new(face.capital_height().unwrap_or_default() as _);
                     let units_per_em = face.units_per_em();
                     let scale = FontScaleFactor::new(pixel_size.get() as f32 / units_per_em as f32);
+                    let scale_line = |position: i16, thickness: i16| {
+                        let scaled = |v: i16| (v as f32 * scale.get()).round() as i16;
+                        (
+                            PhysicalLength::new(scaled(position)),
+                            PhysicalLength::new(scaled(thickness).max(1)),
+                        )
+                    };
+                    let decoration_metrics = face
+                        .underline_metrics()
+                        .zip(face.strikeout_metrics())
+                        .filter(|(underline, strikeout)| {
+                            underline.thickness > 0 && strikeout.thickness > 0
+                        })
+                        .map(|(underline, strikeout)| {
+                            let (underline_position, underline_thickness) =
+                                scale_line(underline.position, underline.thickness);
+                            let (strikethrough_position, strikethrough_thickness) =
+                                scale_line(strikeout.position, strikeout.thickness);
+                            DecorationMetrics {
+                                underline_position,
+                                underline_thickness,
+                                strikethrough_position,
+                                strikethrough_thickness,
+                            }
+                        });
                     Self {
                         id,
                         fontdue_font,
@@ -86,6 +112,7 @@ pub fn new(
                         pixel_size,
                         x_height: (x_height.cast() * scale).cast(),
                         cap_height: (cap_height.cast() * scale).cast(),
+                        decoration_metrics,
                     }
                 })
                 .unwrap()
@@ -189,6 +216,10 @@ fn x_height(&self) -> PhysicalLength {
     fn cap_height(&self) -> PhysicalLength {
         self.cap_height
     }
+
+    fn decoration_metrics(&self) -> Option<DecorationMetrics<PhysicalLength>> {
+        self.decoration_metrics
+    }
 }
 
 impl super::GlyphRenderer for VectorFont {
@@ -0,0 +1,330 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the representation of styled text, as displayed by the `Text` element when
+its `text-format` is set to `markup`.
+
+The markup is a small subset of HTML:
+
+ * `<b>...</b>`: bold text
+ * `<i>...</i>`: italic text
+ * `<u>...</u>`: underlined text
+ * `<a href="...">...</a>`: a link, rendered underlined. Clicking on it invokes the `link-clicked` callback.
+ * `<span color="#rrggbb" font-size="20px" font-weight="700">...</span>`: all attributes are optional.
+   Colors are given in the `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa` notation.
+ * `<br>`: a line break
+ * The entities `&lt;`, `&gt;`, `&amp;`, `&quot;`, `&apos;` as well as numeric character references.
+
+Anything that cannot be parsed as one of these tags is kept as literal text.
+*/
+
+use crate::graphics::{Color, FontRequest};
+use crate::lengths::LogicalLength;
+use crate::{Coord, SharedString};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The style applied to a span of text. Fields that are `None` inherit the value from the `Text` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    /// The color of the text in this span.
+    pub color: Option<Color>,
+    /// The font weight of the text in this span.
+    pub font_weight: Option<i32>,
+    /// Whether to select an italic face for this span.
+    pub font_italic: Option<bool>,
+    /// The font size of the text in this span.
+    pub font_size: Option<LogicalLength>,
+    /// Whether the text in this span is underlined.
+    pub underline: bool,
+    /// The target of the link, if this span is part of a link.
+    pub link: Option<SharedString>,
+}
+
+impl SpanStyle {
+    /// Returns the font request to use for this span, based on the font request of the `Text` element.
+    pub fn font_request(&self, base: &FontRequest) -> FontRequest {
+        let mut request = base.clone();
+        if let Some(weight) = self.font_weight {
+            request.weight = Some(weight);
+        }
+        if let Some(italic) = self.font_italic {
+            request.italic = italic;
+        }
+        if let Some(size) = self.font_size {
+            request.pixel_size = Some(size);
+        }
+        request
+    }
+}
+
+/// A range of the text, in UTF-8 bytes, with its style.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledSpan {
+    /// The range in the [`StyledText::text`].
+    pub range: Range<usize>,
+    /// The style of the text.
+    pub style: SpanStyle,
+}
+
+/// Text along with the style of its spans. The spans are sorted, contiguous, and cover the entire text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledText {
+    /// The text, with all the markup removed.
+    pub text: String,
+    /// The spans of the text.
+    pub spans: Vec<StyledSpan>,
+}
+
+impl StyledText {
+    /// Parses the markup described in the [module documentation](self).
+    pub fn parse_markup(markup: &str) -> Self {
+        let mut result = StyledText::default();
+        // Stack of open tags with the style that was in effect before they were opened.
+        let mut stack: Vec<(&str, SpanStyle)> = Vec::new();
+        let mut style = SpanStyle::default();
+        let mut rest = markup;
+
+        while !rest.is_empty() {
+            let special = rest.find(['<', '&']).unwrap_or(rest.len());
+            result.push_str(&rest[..special], &style);
+            rest = &rest[special..];
+            if rest.is_empty() {
+                break;
+            }
+
+            if rest.starts_with('&') {
+                if let Some((ch, len)) = parse_entity(rest) {
+                    let mut buf = [0; 4];
+                    result.push_str(ch.encode_utf8(&mut buf), &style);
+                    rest = &rest[len..];
+                } else {
+                    result.push_str("&", &style);
+                    rest = &rest[1..];
+                }
+                continue;
+            }
+
+            let Some(tag_end) = rest.find('>') else {
+                result.push_str(rest, &style);
+                break;
+            };
+            let tag = &rest[1..tag_end];
+
+            if let Some(closing) = tag.strip_prefix('/') {
+                let closing = closing.trim();
+                if let Some(pos) = stack.iter().rposition(|(name, _)| *name == closing) {
+                    style = stack[pos].1.clone();
+                    stack.truncate(pos);
+                    rest = &rest[tag_end + 1..];
+                    continue;
+                }
+            } else if let Some((name, attributes)) = parse_tag(tag) {
+                let mut new_style = style.clone();
+                let recognized = match name {
+                    "br" => {
+                        result.push_str("\n", &style);
+                        rest = &rest[tag_end + 1..];
+                        continue;
+                    }
+                    "b" => {
+                        new_style.font_weight = Some(700);
+                        attributes.is_empty()
+                    }
+                    "i" => {
+                        new_style.font_italic = Some(true);
+                        attributes.is_empty()
+                    }
+                    "u" => {
+                        new_style.underline = true;
+                        attributes.is_empty()
+                    }
+                    "a" => attributes.iter().all(|(attr, value)| match *attr {
+                        "href" => {
+                            new_style.link = Some(unescape(value).into());
+                            new_style.underline = true;
+                            true
+                        }
+                        _ => false,
+                    }),
+                    "span" => attributes.iter().all(|(attr, value)| match *attr {
+                        "color" => parse_color(value).map(|c| new_style.color = Some(c)).is_some(),
+                        "font-size" => value
+                            .trim_end_matches("px")
+                            .parse::<Coord>()
+                            .ok()
+                            .filter(|size| *size > 0 as Coord)
+                            .map(|size| new_style.font_size = Some(LogicalLength::new(size)))
+                            .is_some(),
+                        "font-weight" => value
+                            .parse::<i32>()
+                            .map(|weight| new_style.font_weight = Some(weight))
+                            .is_ok(),
+                        _ => false,
+                    }),
+                    _ => false,
+                };
+                if recognized {
+                    stack.push((name, core::mem::replace(&mut style, new_style)));
+                    rest = &rest[tag_end + 1..];
+                    continue;
+                }
+            }
+
+            // Not a tag we know: keep the '<' as text and continue after it
+            result.push_str("<", &style);
+            rest = &rest[1..];
+        }
+
+        result
+    }
+
+    /// Creates a styled text with a single unstyled span.
+    pub fn from_plain_text(text: &str) -> Self {
+        let mut result = Self::default();
+        result.push_str(text, &SpanStyle::default());
+        result
+    }
+
+    /// Returns the link target of the span containing the given byte offset, if any.
+    pub fn link_at(&self, byte_offset: usize) -> Option<&SharedString> {
+        self.span_at(byte_offset).and_then(|span| span.style.link.as_ref())
+    }
+
+    /// Returns the span containing the given byte offset.
+    pub fn span_at(&self, byte_offset: usize) -> Option<&StyledSpan> {
+        let index = self.spans.partition_point(|span| span.range.end <= byte_offset);
+        self.spans.get(index).filter(|span| span.range.start <= byte_offset)
+    }
+
+    fn push_str(&mut self, text: &str, style: &SpanStyle) {
+        if text.is_empty() {
+            return;
+        }
+        let start = self.text.len();
+        self.text.push_str(text);
+        let end = self.text.len();
+        match self.spans.last_mut() {
+            Some(last) if last.style == *style => last.range.end = end,
+            _ => self.spans.push(StyledSpan { range: start..end, style: style.clone() }),
+        }
+    }
+}
+
+/// Parses `name attr="value" attr2='value'` (optionally followed by a `/`) into the tag name and its attributes.
+fn parse_tag(tag: &str) -> Option<(&str, Vec<(&str, &str)>)> {
+    let tag = tag.strip_suffix('/').unwrap_or(tag).trim_end();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = &tag[..name_end];
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let mut attributes = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let (attr, after) = rest.split_once('=')?;
+        let attr = attr.trim();
+        let after = after.trim_start();
+        let quote = after.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let (value, after) = after[1..].split_once(quote)?;
+        attributes.push((attr, value));
+        rest = after.trim_start();
+    }
+    Some((name, attributes))
+}
+
+/// Parses the entity at the start of `text` and returns the character and the length of the entity in bytes.
+fn parse_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.bytes().take(12).position(|b| b == b';')?;
+    let ch = match &text[1..end] {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        numeric => {
+            let numeric = numeric.strip_prefix('#')?;
+            let code = match numeric.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => numeric.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((ch, end + 1))
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('&') {
+        result.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if let Some((ch, len)) = parse_entity(rest) {
+            result.push(ch);
+            rest = &rest[len..];
+        } else {
+            result.push('&');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap();
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+    let (r, g, b, a) = match hex.len() {
+        3 => (digit(0) * 0x11, digit(1) * 0x11, digit(2) * 0x11, 0xff),
+        4 => (digit(0) * 0x11, digit(1) * 0x11, digit(2) * 0x11, digit(3) * 0x11),
+        6 => (byte(0), byte(2), byte(4), 0xff),
+        8 => (byte(0), byte(2), byte(4), byte(6)),
+        _ => return None,
+    };
+    Some(Color::from_argb_u8(a, r, g, b))
+}
+
+#[test]
+fn test_parse_markup() {
+    let styled = StyledText::parse_markup("Hello <b>bold</b> &amp; <i>it<u>al</u></i>");
+    assert_eq!(styled.text, "Hello bold & ital");
+    let ranges: Vec<_> = styled.spans.iter().map(|s| s.range.clone()).collect();
+    assert_eq!(ranges, [0..6, 6..10, 10..13, 13..15, 15..17]);
+    assert_eq!(styled.spans[1].style.font_weight, Some(700));
+    assert_eq!(styled.spans[2].style, SpanStyle::default());
+    assert_eq!(styled.spans[3].style.font_italic, Some(true));
+    assert!(!styled.spans[3].style.underline);
+    assert!(styled.spans[4].style.underline);
+    assert_eq!(styled.spans[4].style.font_italic, Some(true));
+
+    let styled = StyledText::parse_markup(
+        "<span color=\"#f00\" font-size='20px'>red</span><br/><a href=\"https://slint.dev/?a=1&amp;b=2\">link</a>",
+    );
+    assert_eq!(styled.text, "red\nlink");
+    assert_eq!(styled.spans[0].style.color, Some(Color::from_rgb_u8(0xff, 0, 0)));
+    assert_eq!(styled.spans[0].style.font_size, Some(LogicalLength::new(20 as Coord)));
+    assert_eq!(styled.link_at(0), None);
+    assert_eq!(styled.link_at(5).map(|s| s.as_str()), Some("https://slint.dev/?a=1&b=2"));
+    assert_eq!(styled.link_at(8), None);
+}
+
+#[test]
+fn test_parse_invalid_markup() {
+    let styled = StyledText::parse_markup("a < b, <c>, </b> &foo; <span size=\"3\">x</span> <b");
+    assert_eq!(styled.text, "a < b, <c>, </b> &foo; <span size=\"3\">x</span> <b");
+    assert_eq!(styled.spans.len(), 1);
+
+    let styled = StyledText::parse_markup("<b>unclosed &#x41;&#66;");
+    assert_eq!(styled.text, "unclosed AB");
+    assert_eq!(styled.spans.len(), 1);
+    assert_eq!(styled.spans[0].style.font_weight, Some(700));
+
+    assert_eq!(StyledText::parse_markup("").spans, []);
+}
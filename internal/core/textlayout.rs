@@ -41,7 +41,7 @@
 mod glyphclusters;
 mod shaping;
 use shaping::ShapeBuffer;
-pub use shaping::{AbstractFont, FontMetrics, Glyph, TextShaper};
+pub use shaping::{AbstractFont, DecorationMetrics, FontMetrics, Glyph, TextShaper};
 
 mod linebreaker;
 pub use linebreaker::TextLine;
@@ -51,9 +51,90 @@
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
+    /// The distance between the tops of two consecutive lines. If None, the height of the font is used.
+    pub line_height: Option<<Font as TextShaper>::Length>,
+    /// The additional space after each line that ends with a line break.
+    pub paragraph_spacing: Option<<Font as TextShaper>::Length>,
 }
 
 impl<Font: AbstractFont> TextLayout<'_, Font> {
+    /// Returns the distance between the tops of two consecutive lines.
+    pub fn line_height(&self) -> Font::Length {
+        self.line_height.unwrap_or_else(|| self.font.height())
+    }
+
+    /// Returns the offset of the glyphs from the top of the line, when the line height is larger
+    /// (or smaller) than the height of the font. The extra space is split evenly above and below the glyphs.
+    pub fn half_leading(&self) -> Font::Length {
+        let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+        (self.line_height() - self.font.height()) / two
+    }
+
+    /// Returns the distance from the top of the line to the top of the next line.
+    fn line_advance(&self, line: &TextLine<Font::Length>, text: &str) -> Font::Length {
+        match self.paragraph_spacing {
+            Some(spacing) if line.ends_paragraph(text) => self.line_height() + spacing,
+            _ => self.line_height(),
+        }
+    }
+
+    /// Returns the height of the given lines, which excludes the paragraph spacing after the last line.
+    fn lines_height<'l>(
+        &self,
+        lines: impl Iterator<Item = &'l TextLine<Font::Length>>,
+        text: &str,
+    ) -> Font::Length
+    where
+        Font::Length: 'l,
+    {
+        let mut lines = lines.peekable();
+        let mut height = Font::Length::zero();
+        while let Some(line) = lines.next() {
+            height += if lines.peek().is_some() {
+                self.line_advance(line, text)
+            } else {
+                self.line_height()
+            };
+        }
+        height
+    }
+
+    /// Returns how many lines of the text fit into the given height.
+    fn max_lines(
+        &self,
+        max_height: Font::Length,
+        text: &str,
+        shape_buffer: &ShapeBuffer<Font::Length>,
+        max_width: Option<Font::Length>,
+        text_wrap: TextWrap,
+    ) -> usize {
+        if self.paragraph_spacing.is_some() {
+            // The lines that end a paragraph take more space, so the lines must be known
+            let mut lines = 0;
+            let mut height = Font::Length::zero();
+            for line in TextLineBreaker::<Font>::new(text, shape_buffer, max_width, None, text_wrap)
+            {
+                if height + self.line_height() > max_height {
+                    break;
+                }
+                lines += 1;
+                height += self.line_advance(&line, text);
+            }
+            return lines;
+        }
+        let Some(line_height) = self.line_height.filter(|height| *height > Font::Length::zero())
+        else {
+            return self.font.max_lines(max_height);
+        };
+        let mut lines = 0;
+        let mut height = line_height;
+        while height <= max_height {
+            lines += 1;
+            height += line_height;
+        }
+        lines
+    }
+
     // Measures the size of the given text when rendered with the specified font and optionally constrained
     // by the provided `max_width`.
     // Returns a tuple of the width of the longest line as well as height of all lines.
@@ -66,16 +147,14 @@ pub fn text_size(
     where
         Font::Length: core::fmt::Debug,
     {
-        let mut max_line_width = Font::Length::zero();
-        let mut line_count: i16 = 0;
         let shape_buffer = ShapeBuffer::new(self, text);
+        let lines = TextLineBreaker::<Font>::new(text, &shape_buffer, max_width, None, text_wrap)
+            .collect::<Vec<_>>();
+        let max_line_width = lines.iter().fold(Font::Length::zero(), |width, line| {
+            euclid::approxord::max(width, line.text_width)
+        });
 
-        for line in TextLineBreaker::<Font>::new(text, &shape_buffer, max_width, None, text_wrap) {
-            max_line_width = euclid::approxord::max(max_line_width, line.text_width);
-            line_count += 1;
-        }
-
-        (max_line_width, self.font.height() * line_count.into())
+        (max_line_width, self.lines_height(lines.iter(), text))
     }
 }
 
@@ -102,6 +181,7 @@ pub struct TextParagraphLayout<'a, Font: AbstractFont> {
 impl<Font: AbstractFont> TextParagraphLayout<'_, Font> {
     /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
     /// The signature of the `layout_line` function is: `(glyph_iterator, line_x, line_y, text_line, selection)`.
+    /// `line_y` is the top of the glyphs, which is below the top of the line if the line height is larger than the font.
     /// Returns the baseline y coordinate as Ok, or the break value if `line_callback` returns `core::ops::ControlFlow::Break`.
     pub fn layout_lines<R>(
         &self,
@@ -126,12 +206,17 @@ pub fn layout_lines<R>(
 
         let shape_buffer = ShapeBuffer::new(&self.layout, self.string);
 
+        let max_width = if wrap { Some(self.max_width) } else { None };
+        let max_lines = elide.then(|| {
+            self.layout.max_lines(self.max_height, self.string, &shape_buffer, max_width, self.wrap)
+        });
+
         let new_line_break_iter = || {
             TextLineBreaker::<Font>::new(
                 self.string,
                 &shape_buffer,
-                if wrap { Some(self.max_width) } else { None },
-                if elide { Some(self.layout.font.max_lines(self.max_height)) } else { None },
+                max_width,
+                max_lines,
                 self.wrap,
             )
         };
@@ -139,10 +224,10 @@ pub fn layout_lines<R>(
 
         let mut text_height = || {
             if self.single_line {
-                self.layout.font.height()
+                self.layout.line_height()
             } else {
-                text_lines = Some(new_line_break_iter().collect::<Vec<_>>());
-                self.layout.font.height() * (text_lines.as_ref().unwrap().len() as i16).into()
+                let lines = text_lines.insert(new_line_break_iter().collect::<Vec<_>>());
+                self.layout.lines_height(lines.iter(), self.string)
             }
         };
 
@@ -155,13 +240,15 @@ pub fn layout_lines<R>(
         };
 
         let mut y = baseline_y;
+        let half_leading = self.layout.half_leading();
 
         let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
             let elide_long_line =
                 elide && (self.single_line || !wrap) && line.text_width > self.max_width;
             let elide_last_line = elide
                 && line.glyph_range.end < glyphs.len()
-                && y + self.layout.font.height() * two > self.max_height;
+                && y + self.layout.line_advance(line, self.string) + self.layout.line_height()
+                    > self.max_height;
 
             let text_width = || {
                 if elide_long_line || elide_last_line {
@@ -244,11 +331,11 @@ pub fn layout_lines<R>(
             });
 
             if let core::ops::ControlFlow::Break(break_val) =
-                line_callback(&mut positioned_glyph_it, x, y, line, selection)
+                line_callback(&mut positioned_glyph_it, x, y + half_leading, line, selection)
             {
                 return core::ops::ControlFlow::Break(break_val);
             }
-            y += self.layout.font.height();
+            y += self.layout.line_advance(line, self.string);
 
             core::ops::ControlFlow::Continue(())
         };
@@ -313,9 +400,11 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
         let mut byte_offset = 0;
         let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
 
+        let line_bottom = self.layout.line_height() - self.layout.half_leading();
+
         match self.layout_lines(
             |glyphs, line_x, line_y, line, _| {
-                if pos_y >= line_y + self.layout.font.height() {
+                if pos_y >= line_y + line_bottom {
                     byte_offset = line.byte_range.end;
                     return core::ops::ControlFlow::Continue(());
                 }
@@ -348,6 +437,338 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
     }
 }
 
+/// A span of text, by byte range, that is shaped and measured with its own font.
+pub struct StyledTextSpanLayout<'a, Font: AbstractFont> {
+    pub byte_range: core::ops::Range<usize>,
+    pub layout: TextLayout<'a, Font>,
+}
+
+/// The position of a line laid out by [`StyledTextParagraphLayout::layout_lines`].
+#[derive(Clone, Copy, Debug)]
+pub struct StyledLinePosition<Length> {
+    /// The x coordinate of the start of the line
+    pub x: Length,
+    /// The y coordinate of the top of the line
+    pub y: Length,
+    /// The largest ascent of the fonts used in the line. The baseline is at `y + ascent`.
+    pub ascent: Length,
+    /// The height of the line, which is the height of the tallest font used in the line.
+    pub height: Length,
+    /// The byte offset of the character that is replaced by an ellipsis, when the line is elided.
+    /// The ellipsis is the last glyph of the line.
+    pub ellipsis_byte_offset: Option<usize>,
+}
+
+/// Like [`TextParagraphLayout`], but for text where each span is shaped with a different font.
+/// The spans must be sorted and cover the entire string. Lines are as tall as the tallest font used in them,
+/// unless the layout of the first span has a line height.
+/// When eliding, the ellipsis is shaped with the font of the span of the character it replaces.
+pub struct StyledTextParagraphLayout<'a, Font: AbstractFont> {
+    pub string: &'a str,
+    pub spans: &'a [StyledTextSpanLayout<'a, Font>],
+    pub max_width: Font::Length,
+    pub max_height: Font::Length,
+    pub horizontal_alignment: TextHorizontalAlignment,
+    pub vertical_alignment: TextVerticalAlignment,
+    pub wrap: TextWrap,
+    pub overflow: TextOverflow,
+}
+
+impl<Font: AbstractFont> StyledTextParagraphLayout<'_, Font> {
+    /// Shapes all the spans and returns the shape buffer along with the index of the span of each text run.
+    fn shape(&self) -> (ShapeBuffer<Font::Length>, Vec<usize>) {
+        let mut shape_buffer = ShapeBuffer { glyphs: Vec::new(), text_runs: Vec::new() };
+        let mut run_spans = Vec::new();
+        for (index, span) in self.spans.iter().enumerate() {
+            shape_buffer.push_runs(&span.layout, self.string, span.byte_range.clone());
+            run_spans.resize(shape_buffer.text_runs.len(), index);
+        }
+        (shape_buffer, run_spans)
+    }
+
+    fn lines(&self, shape_buffer: &ShapeBuffer<Font::Length>) -> Vec<TextLine<Font::Length>> {
+        let wrap = self.wrap != TextWrap::NoWrap;
+        TextLineBreaker::<Font>::new(
+            self.string,
+            shape_buffer,
+            if wrap { Some(self.max_width) } else { None },
+            None,
+            self.wrap,
+        )
+        .collect()
+    }
+
+    /// Returns the ascent and the height of the line, using the fonts of all the spans it contains.
+    fn line_metrics(
+        &self,
+        line: &TextLine<Font::Length>,
+        shape_buffer: &ShapeBuffer<Font::Length>,
+        run_spans: &[usize],
+    ) -> (Font::Length, Font::Length) {
+        let mut fonts = shape_buffer
+            .text_runs
+            .iter()
+            .zip(run_spans)
+            .filter(|(run, _)| {
+                run.glyph_range.start < line.glyph_range.end
+                    && line.glyph_range.start < run.glyph_range.end
+            })
+            .map(|(_, span_index)| self.spans[*span_index].layout.font)
+            .peekable();
+        if fonts.peek().is_none() {
+            // Empty lines use the font of the span they're in.
+            let span_index = self
+                .spans
+                .partition_point(|span| span.byte_range.end <= line.byte_range.start)
+                .min(self.spans.len().saturating_sub(1));
+            let Some(span) = self.spans.get(span_index) else {
+                return (Font::Length::zero(), Font::Length::zero());
+            };
+            return self.apply_line_height(span.layout.font.ascent(), span.layout.font.height());
+        }
+        let (ascent, descent) =
+            fonts.fold((Font::Length::zero(), Font::Length::zero()), |(ascent, descent), font| {
+                (
+                    euclid::approxord::max(ascent, font.ascent()),
+                    euclid::approxord::min(descent, font.descent()),
+                )
+            });
+        self.apply_line_height(ascent, ascent - descent)
+    }
+
+    /// Applies the line height of the first span, which is the one of the `Text` element, to the ascent and the
+    /// height of a line.
+    fn apply_line_height(
+        &self,
+        ascent: Font::Length,
+        height: Font::Length,
+    ) -> (Font::Length, Font::Length) {
+        match self.spans.first().and_then(|span| span.layout.line_height) {
+            Some(line_height) => {
+                let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+                (ascent + (line_height - height) / two, line_height)
+            }
+            None => (ascent, height),
+        }
+    }
+
+    /// Returns the sum of the paragraph spacing between the lines.
+    fn paragraph_spacing_height(&self, lines: &[TextLine<Font::Length>]) -> Font::Length {
+        let lines = &lines[..lines.len().saturating_sub(1)];
+        lines.iter().fold(Font::Length::zero(), |spacing, line| spacing + self.spacing_after(line))
+    }
+
+    /// Returns the paragraph spacing to add after the line.
+    fn spacing_after(&self, line: &TextLine<Font::Length>) -> Font::Length {
+        match self.spans.first().and_then(|span| span.layout.paragraph_spacing) {
+            Some(spacing) if line.ends_paragraph(self.string) => spacing,
+            _ => Font::Length::zero(),
+        }
+    }
+
+    /// Returns the width of the longest line and the height of all lines.
+    pub fn text_size(&self) -> (Font::Length, Font::Length) {
+        let (shape_buffer, run_spans) = self.shape();
+        let lines = self.lines(&shape_buffer);
+        let spacing = self.paragraph_spacing_height(&lines);
+        lines.iter().fold((Font::Length::zero(), spacing), |(width, height), line| {
+            let (_, line_height) = self.line_metrics(line, &shape_buffer, &run_spans);
+            (euclid::approxord::max(width, line.text_width), height + line_height)
+        })
+    }
+
+    /// Returns where the line is elided, if it doesn't fit in the width or if `elide_end` is set because lines
+    /// follow that are not shown. That's the index of the glyph replaced by the ellipsis, the byte offset of its
+    /// character, and the ellipsis glyph of the font of its span.
+    fn ellipsis_position(
+        &self,
+        line: &TextLine<Font::Length>,
+        shape_buffer: &ShapeBuffer<Font::Length>,
+        run_spans: &[usize],
+        elide_end: bool,
+    ) -> Option<(usize, usize, Option<Glyph<Font::Length>>)> {
+        if !elide_end && line.text_width <= self.max_width {
+            return None;
+        }
+        let mut run_index = shape_buffer
+            .text_runs
+            .partition_point(|run| run.glyph_range.end <= line.glyph_range.start);
+        let mut glyph_x = Font::Length::zero();
+        for index in line.glyph_range.clone() {
+            while shape_buffer.text_runs[run_index].glyph_range.end <= index {
+                run_index += 1;
+            }
+            let run = &shape_buffer.text_runs[run_index];
+            let glyph = &shape_buffer.glyphs[index];
+            let ellipsis = self.spans[run_spans[run_index]]
+                .layout
+                .font
+                .glyph_for_char('…')
+                .filter(|glyph| glyph.glyph_id.is_some());
+            let ellipsis_width = ellipsis.as_ref().map_or(Font::Length::zero(), |g| g.advance);
+            if glyph_x + glyph.advance + ellipsis_width > self.max_width
+                || (elide_end && index == line.glyph_range.end - 1)
+            {
+                return Some((index, run.byte_range.start + glyph.text_byte_offset, ellipsis));
+            }
+            glyph_x += glyph.advance;
+        }
+        None
+    }
+
+    /// Layout the string in lines, and call the `line_callback` with the glyphs of each line, along
+    /// with the index of the span they belong to. The `text_byte_offset` of the positioned glyphs is
+    /// relative to the start of the string.
+    /// Returns the break value if `line_callback` returns `core::ops::ControlFlow::Break`.
+    pub fn layout_lines<R>(
+        &self,
+        mut line_callback: impl FnMut(
+            &mut dyn Iterator<Item = (usize, PositionedGlyph<Font::Length>)>,
+            StyledLinePosition<Font::Length>,
+            &TextLine<Font::Length>,
+        ) -> core::ops::ControlFlow<R>,
+    ) -> Result<(), R> {
+        let (shape_buffer, run_spans) = self.shape();
+        let mut lines = self.lines(&shape_buffer);
+        let mut metrics = lines
+            .iter()
+            .map(|line| self.line_metrics(line, &shape_buffer, &run_spans))
+            .collect::<Vec<_>>();
+
+        let elide = self.overflow == TextOverflow::Elide;
+        let wrap = self.wrap != TextWrap::NoWrap;
+        // When eliding wrapped text, only the lines that fit are shown, and the last one ends with an ellipsis
+        let mut elide_last_line = false;
+        if elide && wrap {
+            let mut height = Font::Length::zero();
+            let visible_lines = lines
+                .iter()
+                .zip(&metrics)
+                .take_while(|(line, (_, line_height))| {
+                    let fits = height + *line_height <= self.max_height;
+                    height += *line_height + self.spacing_after(line);
+                    fits
+                })
+                .count()
+                .max(1);
+            elide_last_line = visible_lines < lines.len();
+            lines.truncate(visible_lines);
+            metrics.truncate(visible_lines);
+        }
+
+        let text_height = metrics
+            .iter()
+            .fold(self.paragraph_spacing_height(&lines), |text_height, (_, line_height)| {
+                text_height + *line_height
+            });
+
+        let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+
+        let mut y = match self.vertical_alignment {
+            TextVerticalAlignment::Top => Font::Length::zero(),
+            TextVerticalAlignment::Center => self.max_height / two - text_height / two,
+            TextVerticalAlignment::Bottom => self.max_height - text_height,
+        };
+
+        let line_count = lines.len();
+        for (line_index, (line, (ascent, height))) in lines.iter().zip(metrics).enumerate() {
+            let ellipsis = if elide {
+                let elide_end = elide_last_line && line_index == line_count - 1;
+                self.ellipsis_position(line, &shape_buffer, &run_spans, elide_end)
+            } else {
+                None
+            };
+            let ellipsis_byte_offset = ellipsis.as_ref().map(|(_, byte_offset, _)| *byte_offset);
+
+            let text_width = match &ellipsis {
+                Some((ellipsis_index, _, ellipsis_glyph)) => {
+                    shape_buffer.glyphs[line.glyph_range.start..*ellipsis_index].iter().fold(
+                        ellipsis_glyph.as_ref().map_or(Font::Length::zero(), |g| g.advance),
+                        |width, glyph| width + glyph.advance,
+                    )
+                }
+                None => euclid::approxord::min(self.max_width, line.text_width),
+            };
+            let x = match self.horizontal_alignment {
+                TextHorizontalAlignment::Left => Font::Length::zero(),
+                TextHorizontalAlignment::Center => self.max_width / two - text_width / two,
+                TextHorizontalAlignment::Right => self.max_width - text_width,
+            };
+
+            let mut run_index = shape_buffer
+                .text_runs
+                .partition_point(|run| run.glyph_range.end <= line.glyph_range.start);
+            let mut glyph_x = Font::Length::zero();
+            let glyph_range = match &ellipsis {
+                Some((ellipsis_index, ..)) => line.glyph_range.start..*ellipsis_index + 1,
+                None => line.glyph_range.clone(),
+            };
+            let mut positioned_glyph_it = glyph_range.filter_map(|index| {
+                if glyph_x > self.max_width {
+                    return None;
+                }
+                while shape_buffer.text_runs[run_index].glyph_range.end <= index {
+                    run_index += 1;
+                }
+                let run = &shape_buffer.text_runs[run_index];
+                let text_byte_offset =
+                    run.byte_range.start + shape_buffer.glyphs[index].text_byte_offset;
+                let glyph = match &ellipsis {
+                    Some((ellipsis_index, _, ellipsis_glyph)) if *ellipsis_index == index => {
+                        ellipsis_glyph.as_ref()?
+                    }
+                    _ => &shape_buffer.glyphs[index],
+                };
+                let x = glyph_x;
+                glyph_x += glyph.advance;
+
+                glyph.glyph_id.map(|glyph_id| {
+                    (
+                        run_spans[run_index],
+                        PositionedGlyph {
+                            x,
+                            y: Font::Length::zero(),
+                            advance: glyph.advance,
+                            glyph_id,
+                            text_byte_offset,
+                        },
+                    )
+                })
+            });
+
+            if let core::ops::ControlFlow::Break(break_val) = line_callback(
+                &mut positioned_glyph_it,
+                StyledLinePosition { x, y, ascent, height, ellipsis_byte_offset },
+                line,
+            ) {
+                return Err(break_val);
+            }
+            y += height + self.spacing_after(line);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the byte offset of the glyph at the given position, or None if there is no glyph there.
+    pub fn byte_offset_for_position(
+        &self,
+        (pos_x, pos_y): (Font::Length, Font::Length),
+    ) -> Option<usize> {
+        match self.layout_lines(|glyphs, line, _| {
+            if pos_y < line.y || pos_y >= line.y + line.height {
+                return core::ops::ControlFlow::Continue(());
+            }
+            let glyph = glyphs.find(|(_, glyph)| {
+                pos_x >= line.x + glyph.x && pos_x < line.x + glyph.x + glyph.advance
+            });
+            core::ops::ControlFlow::Break(glyph.map(|(_, glyph)| glyph.text_byte_offset))
+        }) {
+            Ok(()) => None,
+            Err(byte_offset) => byte_offset,
+        }
+    }
+}
+
 #[test]
 fn test_no_linebreak_opportunity_at_eot() {
     let mut it = LineBreakIterator::new("Hello World");
@@ -431,7 +852,12 @@ fn test_elision() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -473,7 +899,12 @@ fn test_exact_fit() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 4. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -515,7 +946,12 @@ fn test_no_line_separators_characters_rendered() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -560,7 +996,12 @@ fn test_cursor_position() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -600,7 +1041,12 @@ fn test_cursor_position_with_newline() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -613,6 +1059,90 @@ fn test_cursor_position_with_newline() {
     assert_eq!(paragraph.cursor_pos_for_byte_offset(5), (5. * 10., 0.));
 }
 
+#[test]
+fn test_line_height_and_paragraph_spacing() {
+    let font = FixedTestFont;
+    let text = "Hello\nWorld foo";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: Some(20.),
+            paragraph_spacing: Some(5.),
+        },
+        max_width: 6. * 10.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+
+    // The paragraph spacing only applies after the explicit line break, not after the wrapped "World".
+    assert_eq!(paragraph.layout.text_size(text, Some(6. * 10.), TextWrap::WordWrap), (50., 65.));
+
+    let mut line_ys = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |_, _, line_y, _, _| {
+                line_ys.push(line_y);
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert_eq!(line_ys, [5., 30., 50.]);
+
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(6), (0., 30.));
+    assert_eq!(paragraph.byte_offset_for_position((0., 27.)), 6);
+    assert_eq!(paragraph.byte_offset_for_position((0., 47.)), 12);
+}
+
+#[test]
+fn test_elision_with_paragraph_spacing() {
+    let font = FixedTestFont;
+    let text = "Hello\nWorld foo";
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: Some(20.),
+            paragraph_spacing: Some(5.),
+        },
+        max_width: 6. * 10.,
+        max_height: 60.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Elide,
+        single_line: false,
+    };
+
+    // Three lines would fit in 60 without the paragraph spacing after "Hello"
+    let mut lines = Vec::new();
+    paragraph
+        .layout_lines::<()>(
+            |glyphs, _, _, _, _| {
+                lines.push(
+                    glyphs
+                        .map(|positioned_glyph| {
+                            char::from_u32(positioned_glyph.glyph_id.get() as u32).unwrap()
+                        })
+                        .collect::<std::string::String>(),
+                );
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        )
+        .unwrap();
+    assert_eq!(lines, ["Hello", "World…"]);
+}
+
 #[test]
 fn byte_offset_for_empty_line() {
     let font = FixedTestFont;
@@ -620,7 +1150,12 @@ fn byte_offset_for_empty_line() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -642,7 +1177,12 @@ fn test_byte_offset() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -699,3 +1239,145 @@ fn test_byte_offset() {
     assert_eq!(paragraph.byte_offset_for_position((45., 10.)), end_offset);
     assert_eq!(paragraph.byte_offset_for_position((0., 20.)), end_offset);
 }
+
+#[test]
+fn test_styled_layout() {
+    let font = FixedTestFont;
+    let text = "Hello World";
+    let spans = [
+        StyledTextSpanLayout {
+            byte_range: 0..6,
+            layout: TextLayout {
+                font: &font,
+                letter_spacing: None,
+                line_height: None,
+                paragraph_spacing: None,
+            },
+        },
+        StyledTextSpanLayout {
+            byte_range: 6..11,
+            layout: TextLayout {
+                font: &font,
+                letter_spacing: Some(2.),
+                line_height: None,
+                paragraph_spacing: None,
+            },
+        },
+    ];
+    let paragraph = StyledTextParagraphLayout {
+        string: text,
+        spans: &spans,
+        max_width: 100.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+        overflow: TextOverflow::Clip,
+    };
+
+    let mut lines = Vec::new();
+    paragraph
+        .layout_lines::<()>(|glyphs, line, _| {
+            lines.push((
+                line.y,
+                glyphs
+                    .map(|(span, glyph)| (span, glyph.x, glyph.text_byte_offset))
+                    .collect::<Vec<_>>(),
+            ));
+            core::ops::ControlFlow::Continue(())
+        })
+        .unwrap();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].0, 0.);
+    assert_eq!(lines[0].1[..2], [(0, 0., 0), (0, 10., 1)]);
+    assert_eq!(lines[1].0, 10.);
+    assert_eq!(lines[1].1, [(1, 0., 6), (1, 12., 7), (1, 24., 8), (1, 36., 9), (1, 48., 10)]);
+
+    assert_eq!(paragraph.text_size(), (60., 20.));
+    assert_eq!(paragraph.byte_offset_for_position((5., 5.)), Some(0));
+    assert_eq!(paragraph.byte_offset_for_position((13., 15.)), Some(7));
+    assert_eq!(paragraph.byte_offset_for_position((65., 15.)), None);
+    assert_eq!(paragraph.byte_offset_for_position((5., 25.)), None);
+}
+
+#[test]
+fn test_styled_layout_elide() {
+    let font = FixedTestFont;
+    let text = "Hello World";
+    let spans = [
+        StyledTextSpanLayout {
+            byte_range: 0..6,
+            layout: TextLayout {
+                font: &font,
+                letter_spacing: None,
+                line_height: None,
+                paragraph_spacing: None,
+            },
+        },
+        StyledTextSpanLayout {
+            byte_range: 6..11,
+            layout: TextLayout {
+                font: &font,
+                letter_spacing: None,
+                line_height: None,
+                paragraph_spacing: None,
+            },
+        },
+    ];
+    let layout_lines = |wrap, max_height| {
+        let paragraph = StyledTextParagraphLayout {
+            string: text,
+            spans: &spans,
+            max_width: 50.,
+            max_height,
+            horizontal_alignment: TextHorizontalAlignment::Left,
+            vertical_alignment: TextVerticalAlignment::Top,
+            wrap,
+            overflow: TextOverflow::Elide,
+        };
+        let mut lines = Vec::new();
+        paragraph
+            .layout_lines::<()>(|glyphs, line, _| {
+                lines.push((
+                    line.ellipsis_byte_offset,
+                    glyphs
+                        .map(|(span, glyph)| (span, glyph.glyph_id.get(), glyph.text_byte_offset))
+                        .collect::<Vec<_>>(),
+                ));
+                core::ops::ControlFlow::Continue(())
+            })
+            .unwrap();
+        lines
+    };
+
+    let ellipsis = '…' as u16;
+
+    // A long line is cut to fit the ellipsis
+    let lines = layout_lines(TextWrap::NoWrap, 100.);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].0, Some(4));
+    assert_eq!(
+        lines[0].1,
+        [
+            (0, 'H' as u16, 0),
+            (0, 'e' as u16, 1),
+            (0, 'l' as u16, 2),
+            (0, 'l' as u16, 3),
+            (0, ellipsis, 4)
+        ]
+    );
+
+    // Only the lines that fit are shown, and the last one ends with an ellipsis
+    let lines = layout_lines(TextWrap::WordWrap, 15.);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].0, Some(4));
+    assert_eq!(lines[0].1.last(), Some(&(0, ellipsis, 4)));
+
+    // Everything fits
+    let lines = layout_lines(TextWrap::WordWrap, 100.);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].0, None);
+    assert_eq!(lines[1].0, None);
+    assert_eq!(lines[1].1.len(), 5);
+}
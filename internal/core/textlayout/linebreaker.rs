@@ -40,6 +40,13 @@ pub fn width_including_trailing_whitespace(&self) -> Length {
     pub fn is_empty(&self) -> bool {
         self.byte_range.is_empty()
     }
+
+    /// Returns true if the line is ended by a line break, so that it's the last line of a paragraph
+    /// that is followed by another one.
+    pub fn ends_paragraph(&self, paragraph: &str) -> bool {
+        paragraph[self.byte_range.end..self.byte_range.end + self.trailing_whitespace_bytes]
+            .contains(['\n', '\r', '\u{2029}'])
+    }
 }
 
 impl<Length: Clone + Copy + Default + core::ops::AddAssign> TextLine<Length> {
@@ -199,7 +206,15 @@ fn next(&mut self) -> Option<Self::Item> {
 fn test_empty_line_break() {
     let font = FixedTestFont;
     let text = "";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -217,7 +232,15 @@ fn test_basic_line_break_char_wrap() {
     // The available width is half-way into the next word
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -235,7 +258,15 @@ fn test_basic_line_break_char_wrap() {
 fn test_basic_line_break() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -253,7 +284,15 @@ fn test_basic_line_break() {
 fn test_basic_line_break_max_lines() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -270,7 +309,15 @@ fn test_basic_line_break_max_lines() {
 fn test_linebreak_trailing_space() {
     let font = FixedTestFont;
     let text = "Hello              ";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -287,7 +334,15 @@ fn test_linebreak_trailing_space() {
 fn test_forced_break() {
     let font = FixedTestFont;
     let text = "Hello\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines =
         TextLineBreaker::<FixedTestFont>::new(text, &shape_buffer, None, None, TextWrap::WordWrap)
             .collect::<std::vec::Vec<_>>();
@@ -300,7 +355,15 @@ fn test_forced_break() {
 fn test_forced_break_multi() {
     let font = FixedTestFont;
     let text = "Hello\n\n\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines =
         TextLineBreaker::<FixedTestFont>::new(text, &shape_buffer, None, None, TextWrap::WordWrap)
             .collect::<std::vec::Vec<_>>();
@@ -315,7 +378,15 @@ fn test_forced_break_multi() {
 fn test_forced_break_multi_char_wrap() {
     let font = FixedTestFont;
     let text = "Hello\n\n\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -337,7 +408,15 @@ fn test_forced_break_multi_char_wrap() {
 fn test_forced_break_max_lines() {
     let font = FixedTestFont;
     let text = "Hello\n\n\nWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -355,7 +434,15 @@ fn test_forced_break_max_lines() {
 fn test_nbsp_break() {
     let font = FixedTestFont;
     let text = "Ok Hello\u{00a0}World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -373,7 +460,15 @@ fn test_nbsp_break() {
 fn test_single_line_multi_break_opportunity() {
     let font = FixedTestFont;
     let text = "a b c";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines =
         TextLineBreaker::<FixedTestFont>::new(text, &shape_buffer, None, None, TextWrap::WordWrap)
             .collect::<std::vec::Vec<_>>();
@@ -385,7 +480,15 @@ fn test_single_line_multi_break_opportunity() {
 fn test_basic_line_break_anywhere_fallback() {
     let font = FixedTestFont;
     let text = "HelloWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -403,7 +506,15 @@ fn test_basic_line_break_anywhere_fallback() {
 fn test_basic_line_break_anywhere_fallback_multi_line() {
     let font = FixedTestFont;
     let text = "HelloWorld\nHelloWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -423,7 +534,15 @@ fn test_basic_line_break_anywhere_fallback_multi_line() {
 fn test_basic_line_break_anywhere_fallback_multi_line_char_wrap() {
     let font = FixedTestFont;
     let text = "HelloWorld\nHelloWorld";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -443,7 +562,15 @@ fn test_basic_line_break_anywhere_fallback_multi_line_char_wrap() {
 fn test_basic_line_break_anywhere_fallback_multi_line_v2() {
     let font = FixedTestFont;
     let text = "HelloW orldHellow";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -463,7 +590,15 @@ fn test_basic_line_break_anywhere_fallback_multi_line_v2() {
 fn test_basic_line_break_anywhere_fallback_max_lines() {
     let font = FixedTestFont;
     let text = "HelloW orldHellow";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -483,7 +618,15 @@ fn test_basic_line_break_space() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "H W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -502,7 +645,15 @@ fn test_basic_line_break_space_char_wrap() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "H W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -521,7 +672,15 @@ fn test_basic_line_break_space_v2() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "B B W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -540,7 +699,15 @@ fn test_basic_line_break_space_v3() {
     // The available width is half-way into the trailing "W"
     let font = FixedTestFont;
     let text = "H   W";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -559,7 +726,15 @@ fn test_basic_line_break_space_v4() {
     // The available width is half-way into the trailing space
     let font = FixedTestFont;
     let text = "H W  H  ";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -576,7 +751,15 @@ fn test_basic_line_break_space_v4() {
 fn test_line_width_with_whitespace() {
     let font = FixedTestFont;
     let text = "Hello World";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -593,7 +776,15 @@ fn test_line_width_with_whitespace() {
 fn zero_width() {
     let font = FixedTestFont;
     let text = "He\nHe o";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -610,7 +801,15 @@ fn zero_width() {
 fn zero_width_char_wrap() {
     let font = FixedTestFont;
     let text = "He\nHe o";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
@@ -627,7 +826,15 @@ fn zero_width_char_wrap() {
 fn char_wrap_sentences() {
     let font = FixedTestFont;
     let text = "Hello world\nHow are you?";
-    let shape_buffer = ShapeBuffer::new(&TextLayout { font: &font, letter_spacing: None }, text);
+    let shape_buffer = ShapeBuffer::new(
+        &TextLayout {
+            font: &font,
+            letter_spacing: None,
+            line_height: None,
+            paragraph_spacing: None,
+        },
+        text,
+    );
     let lines = TextLineBreaker::<FixedTestFont>::new(
         text,
         &shape_buffer,
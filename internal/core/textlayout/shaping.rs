@@ -75,6 +75,21 @@ fn height(&self) -> Length {
     fn descent(&self) -> Length;
     fn x_height(&self) -> Length;
     fn cap_height(&self) -> Length;
+    /// Returns the position and thickness of the underline and strikethrough as specified by
+    /// the font, or None if the font doesn't provide them.
+    fn decoration_metrics(&self) -> Option<DecorationMetrics<Length>> {
+        None
+    }
+}
+
+/// The placement of the lines drawn by text decorations. Positions are the top edge of the line,
+/// relative to the baseline (positive values are above the baseline).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DecorationMetrics<Length> {
+    pub underline_position: Length,
+    pub underline_thickness: Length,
+    pub strikethrough_position: Length,
+    pub strikethrough_thickness: Length,
 }
 
 pub trait AbstractFont: TextShaper + FontMetrics<<Self as TextShaper>::Length> {}
@@ -177,12 +192,25 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
         Font: AbstractFont<Length = Length>,
         Length: Copy + core::ops::AddAssign,
     {
-        let mut glyphs = Vec::new();
-        let text_runs = ShapeBoundaries::new(text)
-            .scan(0, |run_start, run_end| {
+        let mut shape_buffer = Self { glyphs: Vec::new(), text_runs: Vec::new() };
+        shape_buffer.push_runs(layout, text, 0..text.len());
+        shape_buffer
+    }
+
+    /// Shapes the given byte range of `text` with the font of the given layout, and appends the
+    /// resulting glyphs and text runs. This is used to shape text where different spans use different fonts.
+    pub fn push_runs<Font>(&mut self, layout: &TextLayout<Font>, text: &str, range: Range<usize>)
+    where
+        Font: AbstractFont<Length = Length>,
+        Length: Copy + core::ops::AddAssign,
+    {
+        let glyphs = &mut self.glyphs;
+        let text_runs = ShapeBoundaries::new(&text[range.clone()])
+            .map(|run_end| range.start + run_end)
+            .scan(range.start, |run_start, run_end| {
                 let glyphs_start = glyphs.len();
 
-                layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
+                layout.font.shape_text(&text[*run_start..run_end], &mut *glyphs);
 
                 if let Some(letter_spacing) = layout.letter_spacing {
                     if glyphs.len() > glyphs_start {
@@ -207,10 +235,9 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
                 *run_start = run_end;
 
                 Some(run)
-            })
-            .collect();
+            });
 
-        Self { glyphs, text_runs }
+        self.text_runs.extend(text_runs);
     }
 }
 
@@ -380,7 +407,12 @@ fn test_letter_spacing() {
             shaped_glyphs.iter().map(|g| g.advance).collect::<Vec<_>>()
         };
 
-        let layout = TextLayout { font: &face, letter_spacing: Some(20.) };
+        let layout = TextLayout {
+            font: &face,
+            letter_spacing: Some(20.),
+            line_height: None,
+            paragraph_spacing: None,
+        };
         let buffer = ShapeBuffer::new(&layout, text);
 
         assert_eq!(buffer.glyphs.len(), advances.len());
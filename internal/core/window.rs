@@ -188,6 +188,15 @@ fn create_popup(&self, _geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>>
         None
     }
 
+    /// Called on the window of a `SecondaryWindow` before it is shown, when it should be kept on top of
+    /// the `parent` window it was opened from.
+    ///
+    /// When `modal` is true, the windowing system may also block the input to the parent window,
+    /// although [`WindowInner`] already ignores that input while a modal window is shown.
+    ///
+    /// Note: The winit backend only supports this on Windows and macOS.
+    fn set_transient_parent(&self, _parent: &Rc<dyn WindowAdapter>, _modal: bool) {}
+
     /// Set the mouse cursor
     // TODO: Make the enum public and make public
     fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
@@ -415,6 +424,15 @@ pub struct PopupWindow {
     is_menu: bool,
 }
 
+/// A top-level window opened from a `SecondaryWindow` element, see [`WindowInner::show_secondary_window`].
+struct SecondaryWindow {
+    id: NonZeroU32,
+    window_adapter: Rc<dyn WindowAdapter>,
+    /// Keep the component alive as long as the window, even after it was closed by the user.
+    _component: ItemTreeRc,
+    modal: bool,
+}
+
 #[pin_project::pin_project]
 struct WindowPinnedFields {
     #[pin]
@@ -456,10 +474,16 @@ pub struct WindowInner {
 
     /// Stack of currently active popups
     active_popups: RefCell<Vec<PopupWindow>>,
+    /// Top-level windows opened from this window
+    secondary_windows: RefCell<Vec<SecondaryWindow>>,
+    /// When this is the window of a `SecondaryWindow`: the window it was opened from, and its ID there
+    secondary_window_parent: RefCell<Option<(Weak<dyn WindowAdapter>, NonZeroU32)>>,
     next_popup_id: Cell<NonZeroU32>,
     had_popup_on_press: Cell<bool>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
+    /// The menubar whose items' shortcuts are handled by the window, when there is no native menubar
+    menubar_shortcuts: RefCell<Option<Rc<vtable::VBox<MenuVTable>>>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -468,6 +492,9 @@ fn drop(&mut self) {
         if let Some(existing_blinker) = self.cursor_blinker.borrow().upgrade() {
             existing_blinker.stop();
         }
+        for window in self.secondary_windows.take() {
+            let _ = window.window_adapter.window().hide();
+        }
     }
 }
 
@@ -514,10 +541,13 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             last_ime_text: Default::default(),
             cursor_blinker: Default::default(),
             active_popups: Default::default(),
+            secondary_windows: Default::default(),
+            secondary_window_parent: Default::default(),
             next_popup_id: Cell::new(NonZeroU32::MIN),
             had_popup_on_press: Default::default(),
             close_requested: Default::default(),
             click_state: ClickState::default(),
+            menubar_shortcuts: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -582,6 +612,15 @@ pub fn active_popups(&self) -> core::cell::Ref<'_, [PopupWindow]> {
     pub fn process_mouse_input(&self, mut event: MouseEvent) {
         crate::animations::update_animations();
 
+        if let Some(modal_window) = self.visible_modal_window() {
+            if matches!(event, MouseEvent::Pressed { .. }) {
+                let _ = modal_window.internal(crate::InternalToken).map(|x| x.bring_to_front());
+            }
+            if !matches!(event, MouseEvent::Exit) {
+                return;
+            }
+        }
+
         // handle multiple press release
         event = self.click_state.check_repeat(event, self.ctx.platform().click_interval());
 
@@ -758,6 +797,10 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
 
         event.modifiers = self.modifiers.get().into();
 
+        if self.visible_modal_window().is_some() {
+            return;
+        }
+
         let mut item = self.focus_item.borrow().clone().upgrade();
 
         if item.as_ref().is_some_and(|i| !i.is_visible()) {
@@ -776,6 +819,25 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
             item = focus_item.parent_item(ParentItemTraversalMode::StopAtPopups);
         }
 
+        // Give the menubar and the Shortcut elements a chance to handle the key. When a popup
+        // is open, only the Shortcut elements of that popup are considered.
+        let active_popup = self.active_popups.borrow().last().map(|p| p.component.clone());
+        let handled = match active_popup {
+            Some(popup) => crate::items::Shortcut::activate_matching(&popup, &event),
+            None => {
+                let menubar = self.menubar_shortcuts.borrow().clone();
+                menubar.is_some_and(|menubar| {
+                    crate::menus::activate_shortcut(menubar.borrow(), &event)
+                }) || self
+                    .try_component()
+                    .is_some_and(|c| crate::items::Shortcut::activate_matching(&c, &event))
+            }
+        };
+        if handled {
+            crate::properties::ChangeTracker::run_change_handlers();
+            return;
+        }
+
         // Make Tab/Backtab handle keyboard focus
         let extra_mod = event.modifiers.control || event.modifiers.meta || event.modifiers.alt;
         if event.text.starts_with(key_codes::Tab)
@@ -959,6 +1021,9 @@ pub fn focus_next_item(&self) {
             });
         let end_item =
             self.move_focus(start_item.clone(), next_focus_item, FocusReason::TabNavigation);
+        if let Some(end_item) = &end_item {
+            crate::items::scroll_item_into_view(end_item);
+        }
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
             window_adapter.handle_focus_change(Some(start_item), end_item);
@@ -982,6 +1047,9 @@ pub fn focus_previous_item(&self) {
         );
         let end_item =
             self.move_focus(start_item.clone(), previous_focus_item, FocusReason::TabNavigation);
+        if let Some(end_item) = &end_item {
+            crate::items::scroll_item_into_view(end_item);
+        }
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
             window_adapter.handle_focus_change(Some(start_item), end_item);
@@ -1093,6 +1161,11 @@ pub fn show(&self) -> Result<(), PlatformError> {
     /// De-registers the window with the windowing system.
     pub fn hide(&self) -> Result<(), PlatformError> {
         let result = self.window_adapter().set_visible(false);
+        if let Some((parent, id)) = self.secondary_window_parent.take() {
+            if let Some(parent) = parent.upgrade() {
+                WindowInner::from_pub(parent.window()).forget_secondary_window(id);
+            }
+        }
         let was_visible = self.strong_component_ref.borrow_mut().take().is_some();
         if was_visible {
             let mut count = self.ctx.0.window_count.borrow_mut();
@@ -1126,6 +1199,12 @@ pub fn setup_menubar(&self, menubar: vtable::VBox<MenuVTable>) {
         }
     }
 
+    /// Register a menubar that is not shown natively, so that the window handles the keyboard
+    /// shortcuts of its items.
+    pub fn setup_menubar_shortcuts(&self, menubar: vtable::VBox<MenuVTable>) {
+        self.menubar_shortcuts.replace(Some(Rc::new(menubar)));
+    }
+
     /// Show a popup at the given position relative to the `parent_item` and returns its ID.
     /// The returned ID will always be non-zero.
     /// `is_menu` specifies whether the popup is a popup menu.
@@ -1320,6 +1399,83 @@ pub fn close_top_popup(&self) {
         }
     }
 
+    /// Show the component of a `SecondaryWindow` in a new top-level window.
+    ///
+    /// The component must have a `WindowItem` as root, and is kept alive until
+    /// [`Self::close_secondary_window`] is called or this window is destroyed.
+    /// Returns None if no window could be created.
+    pub fn show_secondary_window(
+        &self,
+        component: &ItemTreeRc,
+        transient: bool,
+        modal: bool,
+    ) -> Option<NonZeroU32> {
+        let window_adapter = match self.ctx.platform().create_window_adapter() {
+            Ok(window_adapter) => window_adapter,
+            Err(err) => {
+                crate::debug_log!("Error creating the window of a SecondaryWindow: {err}");
+                return None;
+            }
+        };
+        if transient {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_transient_parent(&self.window_adapter(), modal);
+            }
+        }
+
+        let id = self.next_popup_id.get();
+        self.next_popup_id.set(self.next_popup_id.get().checked_add(1).unwrap());
+
+        let secondary_window_inner = WindowInner::from_pub(window_adapter.window());
+        secondary_window_inner.set_component(component);
+        if let Err(err) = window_adapter.window().show() {
+            crate::debug_log!("Error showing the window of a SecondaryWindow: {err}");
+            return None;
+        }
+        secondary_window_inner
+            .secondary_window_parent
+            .replace(Some((self.window_adapter_weak.clone(), id)));
+
+        self.secondary_windows.borrow_mut().push(SecondaryWindow {
+            id,
+            window_adapter,
+            _component: component.clone(),
+            modal,
+        });
+        Some(id)
+    }
+
+    /// Hides and releases the window created by [`Self::show_secondary_window`] with the given ID.
+    pub fn close_secondary_window(&self, id: NonZeroU32) {
+        let mut secondary_windows = self.secondary_windows.borrow_mut();
+        let Some(index) = secondary_windows.iter().position(|w| w.id == id) else { return };
+        let window = secondary_windows.remove(index);
+        drop(secondary_windows);
+        let window_inner = WindowInner::from_pub(window.window_adapter.window());
+        window_inner.secondary_window_parent.take();
+        let _ = window_inner.hide();
+    }
+
+    /// Called when the window of a `SecondaryWindow` got hidden, for example because the user closed it.
+    fn forget_secondary_window(&self, id: NonZeroU32) {
+        let mut secondary_windows = self.secondary_windows.borrow_mut();
+        let Some(index) = secondary_windows.iter().position(|w| w.id == id) else { return };
+        let window = secondary_windows.remove(index);
+        drop(secondary_windows);
+        // The window adapter may still be in use by the code that hid it
+        crate::timers::Timer::single_shot(Default::default(), move || drop(window));
+    }
+
+    /// Returns the modal secondary window that is currently shown, if any.
+    /// The input to this window is blocked while it is shown.
+    fn visible_modal_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        self.secondary_windows
+            .borrow()
+            .iter()
+            .find(|w| w.modal && w.window_adapter.window().is_visible())
+            .map(|w| w.window_adapter.clone())
+    }
+
     /// Returns the scale factor set on the window, as provided by the windowing system.
     pub fn scale_factor(&self) -> f32 {
         self.pinned_fields.as_ref().project_ref().scale_factor.get()
@@ -1640,6 +1796,30 @@ pub enum GraphicsAPI {
         WindowInner::from_pub(window_adapter.window()).close_popup(popup_id);
     }
 
+    /// Show the component in a new top-level window. Returns 0 if no window could be created.
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_windowrc_show_secondary_window(
+        handle: *const WindowAdapterRcOpaque,
+        component: &ItemTreeRc,
+        transient: bool,
+        modal: bool,
+    ) -> u32 {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window())
+            .show_secondary_window(component, transient, modal)
+            .map_or(0, NonZeroU32::get)
+    }
+
+    /// Close the secondary window by the given ID.
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_windowrc_close_secondary_window(
+        handle: *const WindowAdapterRcOpaque,
+        id: NonZeroU32,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).close_secondary_window(id);
+    }
+
     /// C binding to the set_rendering_notifier() API of Window
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_windowrc_set_rendering_notifier(
@@ -1832,6 +2012,18 @@ fn call(&self) -> CloseRequestResponse {
             .map(|x| x.setup_menubar(vtable::VBox::from_raw(vtable, menu_instance.cast())));
     }
 
+    /// Register a menubar whose shortcuts are handled by the window
+    #[unsafe(no_mangle)]
+    pub unsafe extern "C" fn slint_windowrc_setup_menubar_shortcuts(
+        handle: *const WindowAdapterRcOpaque,
+        vtable: NonNull<MenuVTable>,
+        menu_instance: NonNull<c_void>,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window())
+            .setup_menubar_shortcuts(vtable::VBox::from_raw(vtable, menu_instance.cast()));
+    }
+
     /// Return the default-font-size property of the WindowItem
     #[unsafe(no_mangle)]
     pub unsafe extern "C" fn slint_windowrc_default_font_size(
@@ -1848,12 +2040,16 @@ fn call(&self) -> CloseRequestResponse {
         event_type: crate::input::KeyEventType,
         text: &SharedString,
         repeat: bool,
+        physical_key: crate::input::PhysicalKey,
+        location: crate::input::KeyLocation,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
         window_adapter.window().0.process_key_input(crate::items::KeyEvent {
             text: text.clone(),
             repeat,
             event_type,
+            physical_key,
+            location,
             ..Default::default()
         });
     }
@@ -24,12 +24,12 @@
 use i_slint_core::items::{
     AccessibleRole, ItemRef, ItemVTable, PopupClosePolicy, PropertyAnimation,
 };
-use i_slint_core::layout::{BoxLayoutCellData, LayoutInfo, Orientation};
+use i_slint_core::layout::{BoxLayoutCellData, FlexLayoutCellData, LayoutInfo, Orientation};
 use i_slint_core::lengths::{LogicalLength, LogicalRect};
 use i_slint_core::menus::MenuFromItemTree;
 use i_slint_core::model::{ModelRc, RepeatedItemTree, Repeater};
 use i_slint_core::platform::PlatformError;
-use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue};
+use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue, Keyframe};
 use i_slint_core::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use i_slint_core::slice::Slice;
 use i_slint_core::timers::Timer;
@@ -141,36 +141,74 @@ fn init(&self) {
         self.run_setup_code();
     }
 
-    fn listview_layout(self: Pin<&Self>, offset_y: &mut LogicalLength) -> LogicalLength {
+    fn listview_layout(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        offset: &mut LogicalLength,
+    ) -> LogicalLength {
         generativity::make_guard!(guard);
         let s = self.unerase(guard);
 
         let geom = s.description.original.root_element.borrow().geometry_props.clone().unwrap();
+        let (pos, size, cross_orientation) = match orientation {
+            Orientation::Vertical => (&geom.y, &geom.height, Orientation::Horizontal),
+            Orientation::Horizontal => (&geom.x, &geom.width, Orientation::Vertical),
+        };
 
         crate::eval::store_property(
             s.borrow_instance(),
-            &geom.y.element(),
-            geom.y.name(),
-            Value::Number(offset_y.get() as f64),
+            &pos.element(),
+            pos.name(),
+            Value::Number(offset.get() as f64),
         )
-        .expect("cannot set y");
+        .expect("cannot set position");
 
-        let h: LogicalLength = crate::eval::load_property(
-            s.borrow_instance(),
-            &geom.height.element(),
-            geom.height.name(),
-        )
-        .expect("missing height")
-        .try_into()
-        .expect("height not the right type");
+        let size: LogicalLength =
+            crate::eval::load_property(s.borrow_instance(), &size.element(), size.name())
+                .expect("missing size")
+                .try_into()
+                .expect("size not the right type");
 
-        *offset_y += h;
-        LogicalLength::new(self.borrow().as_ref().layout_info(Orientation::Horizontal).min)
+        *offset += size;
+        LogicalLength::new(self.borrow().as_ref().layout_info(cross_orientation).min)
     }
 
     fn box_layout_data(self: Pin<&Self>, o: Orientation) -> BoxLayoutCellData {
         BoxLayoutCellData { constraint: self.borrow().as_ref().layout_info(o) }
     }
+
+    fn flex_layout_data(self: Pin<&Self>, o: Orientation) -> FlexLayoutCellData {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let factors = s
+            .description
+            .original
+            .parent_element
+            .upgrade()
+            .and_then(|p| p.borrow().repeated.as_ref().and_then(|r| r.flex_factors.clone()));
+        let load = |nr: &NamedReference| -> Option<f32> {
+            eval::load_property(s.borrow_instance(), &nr.element(), nr.name()).ok()?.try_into().ok()
+        };
+        let mut data = FlexLayoutCellData {
+            constraint: self.borrow().as_ref().layout_info(o),
+            ..Default::default()
+        };
+        if let Some(factors) = factors {
+            data.grow = load(&factors.grow).unwrap_or(data.grow);
+            data.shrink = load(&factors.shrink).unwrap_or(data.shrink);
+        }
+        data
+    }
+
+    fn start_exit(&self) -> bool {
+        self.call_exit_transition_function(|exit| &exit.start).is_some()
+    }
+
+    fn is_exit_done(&self) -> bool {
+        self.call_exit_transition_function(|exit| &exit.is_done)
+            .and_then(|done| done.try_into().ok())
+            .unwrap_or(true)
+    }
 }
 
 impl ItemTree for ErasedItemTreeBox {
@@ -289,6 +327,8 @@ pub(crate) struct ComponentExtraData {
     pub(crate) globals: OnceCell<crate::global_component::GlobalStorage>,
     pub(crate) self_weak: OnceCell<ErasedItemTreeBoxWeak>,
     pub(crate) embedding_position: OnceCell<(ItemTreeWeak, u32)>,
+    /// The instances of the `SecondaryWindow`s, by element ID, created when first shown
+    pub(crate) secondary_windows: std::cell::RefCell<HashMap<SmolStr, DynamicComponentVRc>>,
 }
 
 struct ErasedRepeaterWithinComponent<'id>(RepeaterWithinItemTree<'id, 'static>);
@@ -781,17 +821,56 @@ fn ensure_repeater_updated<'id>(
     {
         let assume_property_logical_length =
             |prop| unsafe { Pin::new_unchecked(&*(prop as *const Property<LogicalLength>)) };
-        let get_prop = |nr: &NamedReference| -> LogicalLength {
-            eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap().try_into().unwrap()
+        let get_prop_value = |nr: &NamedReference| {
+            eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap()
         };
-        repeater.ensure_updated_listview(
-            init,
-            assume_property_logical_length(get_property_ptr(&lv.viewport_width, instance_ref)),
-            assume_property_logical_length(get_property_ptr(&lv.viewport_height, instance_ref)),
-            assume_property_logical_length(get_property_ptr(&lv.viewport_y, instance_ref)),
-            get_prop(&lv.listview_width),
-            assume_property_logical_length(get_property_ptr(&lv.listview_height, instance_ref)),
-        );
+        let get_prop =
+            |nr: &NamedReference| -> LogicalLength { get_prop_value(nr).try_into().unwrap() };
+        let get_prop_ref = |nr: &NamedReference| {
+            assume_property_logical_length(get_property_ptr(nr, instance_ref))
+        };
+        // Take the row requested by `scroll-to-index()`
+        let requested_row = lv.requested_row.as_ref().and_then(|nr| {
+            let row: i32 = get_prop_value(nr).try_into().unwrap();
+            if row >= 0 {
+                eval::store_property(instance_ref, &nr.element(), nr.name(), Value::Number(-1.))
+                    .unwrap();
+            }
+            usize::try_from(row).ok()
+        });
+        if let Some((cell_width, cell_height)) = &lv.cell_size {
+            repeater.ensure_updated_grid(
+                init,
+                get_prop_ref(&lv.viewport_width),
+                get_prop_ref(&lv.viewport_height),
+                get_prop_ref(&lv.viewport_y),
+                get_prop(&lv.listview_width),
+                get_prop_ref(&lv.listview_height),
+                get_prop(cell_width),
+                get_prop(cell_height),
+                requested_row,
+            );
+        } else if lv.orientation == i_slint_compiler::layout::Orientation::Horizontal {
+            repeater.ensure_updated_listview_horizontal(
+                init,
+                get_prop_ref(&lv.viewport_width),
+                get_prop_ref(&lv.viewport_height),
+                get_prop_ref(&lv.viewport_x),
+                get_prop_ref(&lv.listview_width),
+                get_prop(&lv.listview_height),
+                requested_row,
+            );
+        } else {
+            repeater.ensure_updated_listview(
+                init,
+                get_prop_ref(&lv.viewport_width),
+                get_prop_ref(&lv.viewport_height),
+                get_prop_ref(&lv.viewport_y),
+                get_prop(&lv.listview_width),
+                get_prop_ref(&lv.listview_height),
+                requested_row,
+            );
+        }
     } else {
         repeater.ensure_updated(init);
     }
@@ -987,6 +1066,7 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<BorderRectangle>(),
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
+            rtti_for::<Shortcut>(),
             rtti_for::<SwipeGestureHandler>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
@@ -1395,7 +1475,7 @@ macro_rules! match_enum_type {
 pub fn animation_for_property(
     component: InstanceRef,
     animation: &Option<i_slint_compiler::object_tree::PropertyAnimation>,
-) -> AnimatedBindingKind {
+) -> AnimatedBindingKind<Value> {
     match animation {
         Some(i_slint_compiler::object_tree::PropertyAnimation::Static(anim_elem)) => {
             AnimatedBindingKind::Animation(eval::new_struct_with_bindings(
@@ -1403,6 +1483,16 @@ pub fn animation_for_property(
                 &mut eval::EvalLocalContext::from_component_instance(component),
             ))
         }
+        Some(i_slint_compiler::object_tree::PropertyAnimation::Keyframes {
+            animation,
+            keyframes,
+        }) => {
+            let mut context = eval::EvalLocalContext::from_component_instance(component);
+            let details: PropertyAnimation =
+                eval::new_struct_with_bindings(&animation.borrow().bindings, &mut context);
+            let keyframes = eval_keyframes(keyframes, &details, &mut context);
+            AnimatedBindingKind::Keyframes(details, keyframes)
+        }
         Some(i_slint_compiler::object_tree::PropertyAnimation::Transition {
             animations,
             state_ref,
@@ -1412,7 +1502,11 @@ pub fn animation_for_property(
             let animations = animations.clone();
             let state_ref = state_ref.clone();
             AnimatedBindingKind::Transition(Box::new(
-                move || -> (PropertyAnimation, i_slint_core::animations::Instant) {
+                move || -> (
+                    PropertyAnimation,
+                    i_slint_core::animations::Instant,
+                    Vec<Keyframe<Value>>,
+                ) {
                     generativity::make_guard!(guard);
                     let component = unsafe {
                         InstanceRef::from_pin_ref(
@@ -1435,13 +1529,12 @@ pub fn animation_for_property(
                             | (TransitionDirection::Out, true, false)
                             | (TransitionDirection::InOut, false, true)
                             | (TransitionDirection::InOut, true, false) => {
-                                return (
-                                    eval::new_struct_with_bindings(
-                                        &a.animation.borrow().bindings,
-                                        &mut context,
-                                    ),
-                                    state_info.change_time,
+                                let details: PropertyAnimation = eval::new_struct_with_bindings(
+                                    &a.animation.borrow().bindings,
+                                    &mut context,
                                 );
+                                let keyframes = eval_keyframes(&a.keyframes, &details, &mut context);
+                                return (details, state_info.change_time, keyframes);
                             }
                             _ => {}
                         }
@@ -1454,6 +1547,24 @@ pub fn animation_for_property(
     }
 }
 
+/// Evaluate the keyframes of an animation. The keyframes without easing use the one of the animation.
+fn eval_keyframes(
+    keyframes: &[i_slint_compiler::object_tree::Keyframe],
+    details: &PropertyAnimation,
+    context: &mut eval::EvalLocalContext,
+) -> Vec<Keyframe<Value>> {
+    keyframes
+        .iter()
+        .map(|kf| Keyframe {
+            offset: kf.offset,
+            easing: kf.easing.as_ref().map_or(details.easing, |e| {
+                eval::eval_expression(e, context).try_into().unwrap_or_default()
+            }),
+            value: eval::eval_expression(&kf.value, context),
+        })
+        .collect()
+}
+
 fn make_callback_eval_closure(
     expr: Expression,
     self_weak: &ErasedItemTreeBoxWeak,
@@ -1837,6 +1948,30 @@ pub fn run_setup_code(&self) {
         }
         update_timers(instance_ref);
     }
+
+    /// Calls one of the functions generated for the `exit { ... }` block of the repeated element.
+    /// Returns None if there is no such block.
+    fn call_exit_transition_function(
+        &self,
+        function: impl Fn(&object_tree::ExitTransition) -> &NamedReference,
+    ) -> Option<Value> {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let parent_element = s.description.original.parent_element.upgrade()?;
+        let function = parent_element
+            .borrow()
+            .repeated
+            .as_ref()?
+            .exit_transition
+            .as_ref()
+            .map(|exit| function(exit).clone())?;
+        eval::call_function(
+            &eval::ComponentInstance::InstanceRef(s.borrow_instance()),
+            &function.element(),
+            function.name(),
+            vec![],
+        )
+    }
 }
 impl<'id> From<ItemTreeBox<'id>> for ErasedItemTreeBox {
     fn from(inner: ItemTreeBox<'id>) -> Self {
@@ -2457,6 +2592,61 @@ pub fn close_popup(
     }
 }
 
+pub fn show_secondary_window(
+    element: ElementRc,
+    instance: InstanceRef,
+    secondary_window: &object_tree::PopupWindow,
+    parent_comp: ErasedItemTreeBoxWeak,
+    parent_window_adapter: WindowAdapterRc,
+) {
+    let extra_data = instance.description.extra_data_offset.apply(instance.as_ref());
+    let existing = extra_data.secondary_windows.borrow().get(&element.borrow().id).cloned();
+    let created = existing.is_none();
+    let inst = existing.unwrap_or_else(|| {
+        generativity::make_guard!(guard);
+        let compiled = generate_item_tree(
+            &secondary_window.component,
+            None,
+            parent_comp.upgrade().unwrap().0.description().popup_menu_description.clone(),
+            false,
+            guard,
+        );
+        let inst = instantiate(
+            compiled,
+            Some(parent_comp),
+            None,
+            Some(&WindowOptions::UseExistingWindow(parent_window_adapter.clone())),
+            Default::default(),
+        );
+        extra_data.secondary_windows.borrow_mut().insert(element.borrow().id.clone(), inst.clone());
+        inst
+    });
+    close_secondary_window(element.clone(), instance, parent_window_adapter.clone());
+    let options = secondary_window.secondary_window.unwrap_or_default();
+    if let Some(id) = WindowInner::from_pub(parent_window_adapter.window()).show_secondary_window(
+        &vtable::VRc::into_dyn(inst.clone()),
+        options.transient,
+        options.modal,
+    ) {
+        instance.description.popup_ids.borrow_mut().insert(element.borrow().id.clone(), id);
+    }
+    if created {
+        inst.run_setup_code();
+    }
+}
+
+pub fn close_secondary_window(
+    element: ElementRc,
+    instance: InstanceRef,
+    parent_window_adapter: WindowAdapterRc,
+) {
+    if let Some(current_id) =
+        instance.description.popup_ids.borrow_mut().remove(&element.borrow().id)
+    {
+        WindowInner::from_pub(parent_window_adapter.window()).close_secondary_window(current_id);
+    }
+}
+
 pub fn make_menu_item_tree(
     menu_item_tree: &Rc<object_tree::Component>,
     enclosing_component: &InstanceRef,
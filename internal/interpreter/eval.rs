@@ -8,6 +8,7 @@
 use corelib::items::{ColorScheme, ItemRef, MenuEntry, PropertyAnimation};
 use corelib::menus::{Menu, MenuFromItemTree, MenuVTable};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
+use corelib::properties::Keyframe;
 use corelib::rtti::AnimatedBindingKind;
 use corelib::window::WindowInner;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
@@ -31,13 +32,13 @@ fn set(
         &self,
         item: Pin<ItemRef>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<(PropertyAnimation, Vec<Keyframe<Value>>)>,
     ) -> Result<(), ()>;
     fn set_binding(
         &self,
         item: Pin<ItemRef>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     );
     fn offset(&self) -> usize;
 
@@ -56,7 +57,7 @@ fn set(
         &self,
         item: Pin<ItemRef>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<(PropertyAnimation, Vec<Keyframe<Value>>)>,
     ) -> Result<(), ()> {
         (*self).set(ItemRef::downcast_pin(item).unwrap(), value, animation)
     }
@@ -64,7 +65,7 @@ fn set_binding(
         &self,
         item: Pin<ItemRef>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) {
         (*self).set_binding(ItemRef::downcast_pin(item).unwrap(), binding, animation).unwrap();
     }
@@ -290,7 +291,8 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 i_slint_compiler::expression_tree::ImageReference::EmbeddedData { .. } => {
                     todo!()
                 }
-                i_slint_compiler::expression_tree::ImageReference::EmbeddedTexture { .. } => {
+                i_slint_compiler::expression_tree::ImageReference::EmbeddedTexture { .. }
+                | i_slint_compiler::expression_tree::ImageReference::EmbeddedAnimatedTextures { .. } => {
                     todo!()
                 }
             }.unwrap_or_else(|_| {
@@ -344,6 +346,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::Spring(stiffness, damping, mass) => {
+                corelib::animations::EasingCurve::Spring([*stiffness, *damping, *mass])
+            }
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -673,6 +678,46 @@ fn call_builtin_function(
                 panic!("internal error: argument to ClosePopupWindow must be an element")
             }
         }
+        BuiltinFunction::ShowSecondaryWindow | BuiltinFunction::CloseSecondaryWindow => {
+            let component = local_context.component_instance;
+            if let [Expression::ElementReference(secondary_window)] = arguments {
+                let secondary_window = secondary_window.upgrade().unwrap();
+                let window_comp = secondary_window.borrow().enclosing_component.upgrade().unwrap();
+                let parent_component = window_comp
+                    .parent_element
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .enclosing_component
+                    .upgrade()
+                    .unwrap();
+                let popup_list = parent_component.popup_windows.borrow();
+                let popup =
+                    popup_list.iter().find(|p| Rc::ptr_eq(&p.component, &window_comp)).unwrap();
+
+                generativity::make_guard!(guard);
+                let enclosing_component =
+                    enclosing_component_for_element(&popup.parent_element, component, guard);
+                if matches!(f, BuiltinFunction::ShowSecondaryWindow) {
+                    crate::dynamic_item_tree::show_secondary_window(
+                        secondary_window,
+                        enclosing_component,
+                        popup,
+                        enclosing_component.self_weak().get().unwrap().clone(),
+                        component.window_adapter(),
+                    );
+                } else {
+                    crate::dynamic_item_tree::close_secondary_window(
+                        secondary_window,
+                        enclosing_component,
+                        enclosing_component.window_adapter(),
+                    );
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to {f:?} must be an element")
+            }
+        }
         BuiltinFunction::ShowPopupMenu => {
             let [Expression::ElementReference(element), entries, position] = arguments else {
                 panic!("internal error: incorrect argument count to ShowPopupMenu")
@@ -845,6 +890,59 @@ fn call_builtin_function(
                 panic!("internal error: first argument to set-selection-offsets must be an element")
             }
         }
+        BuiltinFunction::ListViewScrollToIndex => {
+            panic!("internal error: ListViewScrollToIndex should have been lowered")
+        }
+        BuiltinFunction::FlickableScrollTo | BuiltinFunction::FlickableEnsureVisible => {
+            let component = local_context.component_instance;
+            let Expression::ElementReference(element) = &arguments[0] else {
+                panic!("internal error: first argument to {f:?} must be an element")
+            };
+            generativity::make_guard!(guard);
+
+            let elem = element.upgrade().unwrap();
+            let enclosing_component = enclosing_component_for_element(&elem, component, guard);
+            let description = enclosing_component.description;
+            let item_info = &description.items[elem.borrow().id.as_str()];
+            let item_ref = unsafe { item_info.item_from_item_tree(enclosing_component.as_ptr()) };
+
+            let item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+            let item_rc = corelib::items::ItemRc::new(
+                vtable::VRc::into_dyn(item_comp),
+                item_info.item_index(),
+            );
+
+            let Some(flickable) = ItemRef::downcast_pin::<corelib::items::Flickable>(item_ref)
+            else {
+                panic!(
+                    "internal error: member function called on element that doesn't have it: {}",
+                    elem.borrow().original_name()
+                )
+            };
+            let args = arguments[1..]
+                .iter()
+                .map(|a| {
+                    let v: f64 = eval_expression(a, local_context)
+                        .try_into()
+                        .expect("internal error: Flickable function arguments must be lengths");
+                    v as corelib::Coord
+                })
+                .collect::<Vec<_>>();
+            if let [x, y] = args[..] {
+                flickable.scroll_to(corelib::lengths::LogicalPoint::new(x, y), &item_rc);
+            } else if let [x, y, w, h] = args[..] {
+                flickable.ensure_visible(
+                    corelib::lengths::LogicalRect::new(
+                        corelib::lengths::LogicalPoint::new(x, y),
+                        corelib::lengths::LogicalSize::new(w, h),
+                    ),
+                    &item_rc,
+                );
+            } else {
+                panic!("internal error: incorrect argument count to {f:?}")
+            }
+            Value::Void
+        }
         BuiltinFunction::ItemFontMetrics => {
             if arguments.len() != 1 {
                 panic!(
@@ -1144,6 +1242,9 @@ fn call_builtin_function(
                         return Value::Void;
                     }
                 }
+                WindowInner::from_pub(component.window_adapter().window()).setup_menubar_shortcuts(
+                    vtable::VBox::new(MenuFromItemTree::new(menu_item_tree.item_tree().clone())),
+                );
 
                 let (entries, sub_menu, activated) = menu_item_tree_properties(menu_item_tree);
 
@@ -1165,21 +1266,26 @@ fn call_builtin_function(
 
                 return Value::Void;
             }
-            let [entries, Expression::PropertyReference(sub_menu), Expression::PropertyReference(activated)] =
+            let [entries, Expression::PropertyReference(sub_menu), Expression::PropertyReference(activated), no_native @ ..] =
                 arguments
             else {
                 panic!("internal error: incorrect arguments to SetupNativeMenuBar: {arguments:?}")
             };
+            let no_native = matches!(no_native, [Expression::BoolLiteral(true)]);
+            let menubar = vtable::VBox::new(MenuWrapper {
+                entries: entries.clone(),
+                sub_menu: sub_menu.clone(),
+                activated: activated.clone(),
+                item_tree: component.self_weak().get().unwrap().clone(),
+            });
             if let Some(w) = component.window_adapter().internal(i_slint_core::InternalToken) {
-                if w.supports_native_menu_bar() {
-                    w.setup_menubar(vtable::VBox::new(MenuWrapper {
-                        entries: entries.clone(),
-                        sub_menu: sub_menu.clone(),
-                        activated: activated.clone(),
-                        item_tree: component.self_weak().get().unwrap().clone(),
-                    }));
+                if !no_native && w.supports_native_menu_bar() {
+                    w.setup_menubar(menubar);
+                    return Value::Void;
                 }
             }
+            WindowInner::from_pub(component.window_adapter().window())
+                .setup_menubar_shortcuts(menubar);
             Value::Void
         }
         BuiltinFunction::MonthDayCount => {
@@ -1371,8 +1477,18 @@ fn call_item_member_function(nr: &NamedReference, local_context: &mut EvalLocalC
             "cut" => textinput.cut(&window_adapter, &item_rc),
             "copy" => textinput.copy(&window_adapter, &item_rc),
             "paste" => textinput.paste(&window_adapter, &item_rc),
+            "undo" => textinput.undo(&window_adapter, &item_rc),
+            "redo" => textinput.redo(&window_adapter, &item_rc),
+            "clear-undo-history" => textinput.clear_undo_history(&window_adapter, &item_rc),
             _ => panic!("internal: Unknown member function {name} called on TextInput"),
         }
+    } else if let Some(text) = ItemRef::downcast_pin::<corelib::items::ComplexText>(item_ref) {
+        match name {
+            "select-all" => text.select_all(&window_adapter, &item_rc),
+            "clear-selection" => text.clear_selection(&window_adapter, &item_rc),
+            "copy" => text.copy(&window_adapter, &item_rc),
+            _ => panic!("internal: Unknown member function {name} called on Text"),
+        }
     } else if let Some(s) = ItemRef::downcast_pin::<corelib::items::SwipeGestureHandler>(item_ref) {
         match name {
             "cancel" => s.cancel(&window_adapter, &item_rc),
@@ -1,12 +1,14 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use crate::dynamic_item_tree::InstanceRef;
+use crate::dynamic_item_tree::{ErasedItemTreeBox, InstanceRef};
 use crate::eval::{self, EvalLocalContext};
 use crate::Value;
 use i_slint_compiler::expression_tree::Expression;
 use i_slint_compiler::langtype::Type;
-use i_slint_compiler::layout::{Layout, LayoutConstraints, LayoutGeometry, Orientation};
+use i_slint_compiler::layout::{
+    FlexLayout, Layout, LayoutConstraints, LayoutGeometry, LayoutItem, Orientation,
+};
 use i_slint_compiler::namedreference::NamedReference;
 use i_slint_compiler::object_tree::ElementRc;
 use i_slint_core::items::{DialogButtonRole, ItemRc};
@@ -14,6 +16,7 @@
 use i_slint_core::model::RepeatedItemTree;
 use i_slint_core::slice::Slice;
 use i_slint_core::window::WindowAdapter;
+use std::pin::Pin;
 use std::rc::Rc;
 use std::str::FromStr;
 
@@ -64,6 +67,25 @@ pub(crate) fn compute_layout_info(
             }
             .into()
         }
+        Layout::FlexLayout(flex_layout) => {
+            let cells = flex_layout_cells(flex_layout, component, &expr_eval, None);
+            let data = flex_layout_data(flex_layout, &cells, component, &expr_eval);
+            if orientation == flex_layout.orientation {
+                core_layout::flex_layout_info(data.cells, data.spacing, &data.padding, data.wrap)
+            } else {
+                let cross_cells =
+                    flex_layout_cross_cells(flex_layout, orientation, component, &expr_eval);
+                let (cross_padding, cross_spacing) =
+                    padding_and_spacing(&flex_layout.geometry, orientation, &expr_eval);
+                core_layout::flex_layout_info_ortho(
+                    &data,
+                    Slice::from(cross_cells.as_slice()),
+                    cross_spacing,
+                    &cross_padding,
+                )
+            }
+            .into()
+        }
     }
 }
 
@@ -129,6 +151,31 @@ pub(crate) fn solve_layout(
             )
             .into()
         }
+        Layout::FlexLayout(flex_layout) => {
+            let mut repeated_indices = Vec::new();
+            let cells =
+                flex_layout_cells(flex_layout, component, &expr_eval, Some(&mut repeated_indices));
+            let data = flex_layout_data(flex_layout, &cells, component, &expr_eval);
+            let repeated_indices = Slice::from(repeated_indices.as_slice());
+            if orientation == flex_layout.orientation {
+                core_layout::solve_flex_layout(&data, repeated_indices)
+            } else {
+                let cross_cells =
+                    flex_layout_cross_cells(flex_layout, orientation, component, &expr_eval);
+                let (cross_padding, cross_spacing) =
+                    padding_and_spacing(&flex_layout.geometry, orientation, &expr_eval);
+                let size_ref = flex_layout.geometry.rect.size_reference(orientation);
+                core_layout::solve_flex_layout_ortho(
+                    &data,
+                    Slice::from(cross_cells.as_slice()),
+                    size_ref.map(expr_eval).unwrap_or(0.),
+                    cross_spacing,
+                    &cross_padding,
+                    repeated_indices,
+                )
+            }
+            .into()
+        }
     }
 }
 
@@ -179,16 +226,128 @@ fn box_layout_data(
     orientation: Orientation,
     component: InstanceRef,
     expr_eval: &impl Fn(&NamedReference) -> f32,
-    mut repeater_indices: Option<&mut Vec<u32>>,
+    repeater_indices: Option<&mut Vec<u32>>,
 ) -> (Vec<core_layout::BoxLayoutCellData>, i_slint_core::items::LayoutAlignment) {
     let window_adapter = component.window_adapter();
-    let mut cells = Vec::with_capacity(box_layout.elems.len());
-    for cell in &box_layout.elems {
-        if cell.element.borrow().repeated.is_some() {
+    let cells = collect_layout_cells(
+        &box_layout.elems,
+        |item| item,
+        component,
+        repeater_indices,
+        |x| x.box_layout_data(to_runtime(orientation)),
+        |item| core_layout::BoxLayoutCellData {
+            constraint: constrained_layout_info(
+                item,
+                component,
+                &window_adapter,
+                orientation,
+                expr_eval,
+            ),
+        },
+    );
+    (cells, layout_alignment(&box_layout.geometry, component))
+}
+
+/// The cells of a FlexLayout along its main axis
+fn flex_layout_cells(
+    flex_layout: &FlexLayout,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    repeater_indices: Option<&mut Vec<u32>>,
+) -> Vec<core_layout::FlexLayoutCellData> {
+    let window_adapter = component.window_adapter();
+    let orientation = flex_layout.orientation;
+    collect_layout_cells(
+        &flex_layout.elems,
+        |elem| &elem.item,
+        component,
+        repeater_indices,
+        |x| x.flex_layout_data(to_runtime(orientation)),
+        |elem| core_layout::FlexLayoutCellData {
+            constraint: constrained_layout_info(
+                &elem.item,
+                component,
+                &window_adapter,
+                orientation,
+                expr_eval,
+            ),
+            grow: elem.factors.as_ref().map_or(0., |f| expr_eval(&f.grow)),
+            shrink: elem.factors.as_ref().map_or(1., |f| expr_eval(&f.shrink)),
+        },
+    )
+}
+
+/// The cells of a FlexLayout along its cross axis
+fn flex_layout_cross_cells(
+    flex_layout: &FlexLayout,
+    orientation: Orientation,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+) -> Vec<core_layout::BoxLayoutCellData> {
+    let window_adapter = component.window_adapter();
+    collect_layout_cells(
+        &flex_layout.elems,
+        |elem| &elem.item,
+        component,
+        None,
+        |x| x.box_layout_data(to_runtime(orientation)),
+        |elem| core_layout::BoxLayoutCellData {
+            constraint: constrained_layout_info(
+                &elem.item,
+                component,
+                &window_adapter,
+                orientation,
+                expr_eval,
+            ),
+        },
+    )
+}
+
+/// The FlexLayoutData of the main axis of a FlexLayout, with the given cells
+fn flex_layout_data<'a>(
+    flex_layout: &FlexLayout,
+    cells: &'a [core_layout::FlexLayoutCellData],
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+) -> core_layout::FlexLayoutData<'a> {
+    let orientation = flex_layout.orientation;
+    let (padding, spacing) = padding_and_spacing(&flex_layout.geometry, orientation, expr_eval);
+    let size_ref = flex_layout.geometry.rect.size_reference(orientation);
+    let wrap = &flex_layout.wrap;
+    core_layout::FlexLayoutData {
+        size: size_ref.map(expr_eval).unwrap_or(0.),
+        spacing,
+        padding,
+        alignment: layout_alignment(&flex_layout.geometry, component),
+        wrap: eval::load_property(component, &wrap.element(), wrap.name())
+            .unwrap()
+            .try_into()
+            .unwrap_or(true),
+        cells: Slice::from(cells),
+    }
+}
+
+/// Collect the cells of the elements of a box or flex layout, instantiating the repeaters.
+///
+/// The cells of the repeated instances are computed by `repeated_cell`, the other ones by `cell`.
+/// When `repeater_indices` is set, it is filled with the index of the first cell and the number
+/// of instances of each repeater.
+fn collect_layout_cells<E, T>(
+    elems: &[E],
+    layout_item: impl Fn(&E) -> &LayoutItem,
+    component: InstanceRef,
+    mut repeater_indices: Option<&mut Vec<u32>>,
+    repeated_cell: impl Fn(Pin<&ErasedItemTreeBox>) -> T,
+    cell: impl Fn(&E) -> T,
+) -> Vec<T> {
+    let mut cells = Vec::with_capacity(elems.len());
+    for elem in elems {
+        let element = &layout_item(elem).element;
+        if element.borrow().repeated.is_some() {
             generativity::make_guard!(guard);
             let rep = crate::dynamic_item_tree::get_repeater_by_name(
                 component,
-                cell.element.borrow().id.as_str(),
+                element.borrow().id.as_str(),
                 guard,
             );
             rep.0.as_ref().ensure_updated(|| {
@@ -206,25 +365,32 @@ fn box_layout_data(
                 ri.push(cells.len() as _);
                 ri.push(component_vec.len() as _);
             }
-            cells.extend(
-                component_vec
-                    .iter()
-                    .map(|x| x.as_pin_ref().box_layout_data(to_runtime(orientation))),
-            );
+            cells.extend(component_vec.iter().map(|x| repeated_cell(x.as_pin_ref())));
         } else {
-            let mut layout_info =
-                get_layout_info(&cell.element, component, &window_adapter, orientation);
-            fill_layout_info_constraints(
-                &mut layout_info,
-                &cell.constraints,
-                orientation,
-                &expr_eval,
-            );
-            cells.push(core_layout::BoxLayoutCellData { constraint: layout_info });
+            cells.push(cell(elem));
         }
     }
-    let alignment = box_layout
-        .geometry
+    cells
+}
+
+/// The layout info of an item, with the constraints set on it
+fn constrained_layout_info(
+    item: &LayoutItem,
+    component: InstanceRef,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    orientation: Orientation,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+) -> core_layout::LayoutInfo {
+    let mut layout_info = get_layout_info(&item.element, component, window_adapter, orientation);
+    fill_layout_info_constraints(&mut layout_info, &item.constraints, orientation, &expr_eval);
+    layout_info
+}
+
+fn layout_alignment(
+    layout_geometry: &LayoutGeometry,
+    component: InstanceRef,
+) -> i_slint_core::items::LayoutAlignment {
+    layout_geometry
         .alignment
         .as_ref()
         .map(|nr| {
@@ -233,8 +399,7 @@ fn box_layout_data(
                 .try_into()
                 .unwrap_or_default()
         })
-        .unwrap_or_default();
-    (cells, alignment)
+        .unwrap_or_default()
 }
 
 pub(crate) fn fill_layout_info_constraints(
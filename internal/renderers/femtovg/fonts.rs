@@ -11,6 +11,11 @@
 use i_slint_core::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
 use i_slint_core::lengths::PointLengths;
 use i_slint_core::lengths::{LogicalLength, LogicalSize, ScaleFactor, SizeLengths};
+use i_slint_core::styled_text::StyledText;
+use i_slint_core::textlayout::{
+    DecorationMetrics, FontMetrics, Glyph, StyledTextParagraphLayout, StyledTextSpanLayout,
+    TextLayout, TextShaper,
+};
 use i_slint_core::{SharedString, SharedVector};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
@@ -32,9 +37,34 @@ pub struct Font {
     fonts: SharedVector<femtovg::FontId>,
     pixel_size: PhysicalLength,
     text_context: TextContext,
+    design_font_metrics: sharedfontdb::DesignFontMetrics,
+    line_height: Option<PhysicalLength>,
+    paragraph_spacing: Option<PhysicalLength>,
 }
 
 impl Font {
+    fn scale_design_length(&self, design_length: f32) -> PhysicalLength {
+        PhysicalLength::new(
+            design_length * self.pixel_size.get() / self.design_font_metrics.units_per_em,
+        )
+    }
+
+    /// Returns the ascent of the primary font, which is where the overline is drawn.
+    pub fn ascent(&self) -> PhysicalLength {
+        self.scale_design_length(self.design_font_metrics.ascent)
+    }
+
+    /// Returns the placement of the underline and strikethrough of the primary font.
+    pub fn decoration_metrics(&self) -> DecorationMetrics<PhysicalLength> {
+        let metrics = &self.design_font_metrics;
+        DecorationMetrics {
+            underline_position: self.scale_design_length(metrics.underline_position),
+            underline_thickness: self.scale_design_length(metrics.underline_thickness),
+            strikethrough_position: self.scale_design_length(metrics.strikethrough_position),
+            strikethrough_thickness: self.scale_design_length(metrics.strikethrough_thickness),
+        }
+    }
+
     pub fn init_paint(
         &self,
         letter_spacing: PhysicalLength,
@@ -56,6 +86,8 @@ pub fn text_size(
         let paint = self.init_paint(letter_spacing, femtovg::Paint::default());
         let font_metrics = self.text_context.measure_font(&paint).unwrap();
         let mut lines = 0;
+        // The number of lines that end with a line break and are followed by another line
+        let mut paragraph_breaks = 0;
         let mut width = 0.;
         let mut start = 0;
         if let Some(max_width) = max_width {
@@ -71,6 +103,9 @@ pub fn text_size(
                 let index = start + index;
                 let measure =
                     self.text_context.measure_text(0., 0., &text[start..index], &paint).unwrap();
+                if text[start..index].ends_with('\n') && index < text.len() {
+                    paragraph_breaks += 1;
+                }
                 start = index;
                 lines += 1;
                 width = measure.width().max(width);
@@ -81,8 +116,14 @@ pub fn text_size(
                 lines += 1;
                 width = measure.width().max(width);
             }
+            paragraph_breaks = (lines - 1).max(0);
         }
-        euclid::size2(width, lines as f32 * font_metrics.height())
+        let line_height = self.line_height(PhysicalLength::new(font_metrics.height()));
+        euclid::size2(
+            width,
+            lines as f32 * line_height.get()
+                + paragraph_breaks as f32 * self.paragraph_spacing().get(),
+        )
     }
 
     pub fn height(&self) -> PhysicalLength {
@@ -92,6 +133,16 @@ pub fn height(&self) -> PhysicalLength {
         paint.set_font_size(self.pixel_size.get());
         PhysicalLength::new(self.text_context.measure_font(&paint).unwrap().height())
     }
+
+    /// Returns the distance between the tops of two consecutive lines, given the height of the font.
+    pub fn line_height(&self, font_height: PhysicalLength) -> PhysicalLength {
+        self.line_height.unwrap_or(font_height)
+    }
+
+    /// Returns the additional space after a line that ends with a line break.
+    pub fn paragraph_spacing(&self) -> PhysicalLength {
+        self.paragraph_spacing.unwrap_or_default()
+    }
 }
 
 pub(crate) fn text_size(
@@ -107,6 +158,175 @@ pub(crate) fn text_size(
         / scale_factor
 }
 
+/// A font along with the paint to draw text with it. This implements the traits needed to lay out
+/// text shaped by femtovg with the core text layout, which is used for styled text where each span
+/// may use a different font.
+pub(crate) struct PaintedFont {
+    pub paint: femtovg::Paint,
+    font_metrics: femtovg::FontMetrics,
+    text_context: TextContext,
+    line_height: Option<PhysicalLength>,
+    paragraph_spacing: Option<PhysicalLength>,
+}
+
+impl PaintedFont {
+    pub fn new(font: &Font, letter_spacing: PhysicalLength, paint: femtovg::Paint) -> Self {
+        let mut paint = font.init_paint(letter_spacing, paint);
+        paint.set_text_baseline(femtovg::Baseline::Alphabetic);
+        let font_metrics = font.text_context.measure_font(&paint).unwrap();
+        Self {
+            paint,
+            font_metrics,
+            text_context: font.text_context.clone(),
+            line_height: font.line_height,
+            paragraph_spacing: font.paragraph_spacing,
+        }
+    }
+}
+
+impl TextShaper for PaintedFont {
+    type LengthPrimitive = f32;
+    type Length = PhysicalLength;
+
+    fn shape_text<GlyphStorage: std::iter::Extend<Glyph<PhysicalLength>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        let metrics = self.text_context.measure_text(0., 0., text, &self.paint).unwrap();
+        glyphs.extend(metrics.glyphs.iter().map(|glyph| Glyph {
+            advance: PhysicalLength::new(glyph.advance_x),
+            offset_x: PhysicalLength::default(),
+            offset_y: PhysicalLength::default(),
+            // femtovg draws strings, not glyphs, so the id is never used.
+            glyph_id: Some(core::num::NonZeroU16::MIN),
+            text_byte_offset: glyph.byte_index,
+        }));
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
+        let mut glyphs = Vec::new();
+        self.shape_text(ch.encode_utf8(&mut [0; 4]), &mut glyphs);
+        glyphs.into_iter().next()
+    }
+
+    fn max_lines(&self, max_height: PhysicalLength) -> usize {
+        (max_height.get() / self.font_metrics.height()).floor() as _
+    }
+}
+
+impl FontMetrics<PhysicalLength> for PaintedFont {
+    fn height(&self) -> PhysicalLength {
+        PhysicalLength::new(self.font_metrics.height())
+    }
+
+    fn ascent(&self) -> PhysicalLength {
+        PhysicalLength::new(self.font_metrics.ascender())
+    }
+
+    fn descent(&self) -> PhysicalLength {
+        PhysicalLength::new(self.font_metrics.descender())
+    }
+
+    // The x-height and cap-height are not needed to lay out text.
+    fn x_height(&self) -> PhysicalLength {
+        PhysicalLength::default()
+    }
+
+    fn cap_height(&self) -> PhysicalLength {
+        PhysicalLength::default()
+    }
+}
+
+/// Returns the font and the letter spacing, in physical pixels, for each span of the styled text.
+pub(crate) fn styled_text_fonts(
+    text: &StyledText,
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+) -> Vec<(Font, PhysicalLength)> {
+    text.spans
+        .iter()
+        .map(|span| {
+            let request = span.style.font_request(font_request);
+            let letter_spacing = request.letter_spacing.unwrap_or_default() * scale_factor;
+            let font = FONT_CACHE.with(|cache| {
+                cache.borrow_mut().font(request, scale_factor, &text.text[span.range.clone()])
+            });
+            (font, letter_spacing)
+        })
+        .collect()
+}
+
+/// Returns the layout of the styled text for the given painted fonts, one per span.
+pub(crate) fn styled_text_layout<'a>(
+    text: &'a StyledText,
+    span_layouts: &'a [StyledTextSpanLayout<'a, PaintedFont>],
+    max_size: PhysicalSize,
+    alignment: (TextHorizontalAlignment, TextVerticalAlignment),
+    wrap: TextWrap,
+    overflow: TextOverflow,
+) -> StyledTextParagraphLayout<'a, PaintedFont> {
+    StyledTextParagraphLayout {
+        string: &text.text,
+        spans: span_layouts,
+        max_width: max_size.width_length(),
+        max_height: max_size.height_length(),
+        horizontal_alignment: alignment.0,
+        vertical_alignment: alignment.1,
+        wrap,
+        overflow,
+    }
+}
+
+pub(crate) fn span_layouts<'a>(
+    text: &StyledText,
+    fonts: impl IntoIterator<Item = &'a PaintedFont>,
+) -> Vec<StyledTextSpanLayout<'a, PaintedFont>> {
+    text.spans
+        .iter()
+        .zip(fonts)
+        .map(|(span, font)| StyledTextSpanLayout {
+            byte_range: span.range.clone(),
+            // The letter spacing is applied by femtovg when shaping
+            layout: TextLayout {
+                font,
+                letter_spacing: None,
+                line_height: font.line_height,
+                paragraph_spacing: font.paragraph_spacing,
+            },
+        })
+        .collect()
+}
+
+pub(crate) fn styled_text_size(
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+    text: &StyledText,
+    max_width: Option<LogicalLength>,
+    text_wrap: TextWrap,
+) -> LogicalSize {
+    let fonts = styled_text_fonts(text, font_request, scale_factor)
+        .iter()
+        .map(|(font, letter_spacing)| PaintedFont::new(font, *letter_spacing, Default::default()))
+        .collect::<Vec<_>>();
+    let span_layouts = span_layouts(text, &fonts);
+    let max_size = PhysicalSize::from_lengths(
+        max_width.map_or(PhysicalLength::new(f32::MAX), |max_width| max_width * scale_factor),
+        PhysicalLength::new(f32::MAX),
+    );
+    let wrap = if max_width.is_some() { text_wrap } else { TextWrap::NoWrap };
+    let layout = styled_text_layout(
+        text,
+        &span_layouts,
+        max_size,
+        Default::default(),
+        wrap,
+        TextOverflow::Clip,
+    );
+    let (width, height) = layout.text_size();
+    PhysicalSize::from_lengths(width, height) / scale_factor
+}
+
 pub(crate) fn font_metrics(
     font_request: i_slint_core::graphics::FontRequest,
 ) -> i_slint_core::items::FontMetrics {
@@ -365,7 +585,14 @@ pub fn font(
             }))
             .collect::<SharedVector<_>>();
 
-        Font { fonts, text_context: self.text_context.clone(), pixel_size }
+        Font {
+            fonts,
+            text_context: self.text_context.clone(),
+            pixel_size,
+            design_font_metrics: primary_font.design_font_metrics,
+            line_height: font_request.line_height.map(|height| height * scale_factor),
+            paragraph_spacing: font_request.paragraph_spacing.map(|spacing| spacing * scale_factor),
+        }
     }
 
     #[cfg(target_vendor = "apple")]
@@ -632,12 +859,15 @@ pub(crate) fn layout_text_lines(
     let text_context = FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
     let font_metrics = text_context.measure_font(paint).unwrap();
     let font_height = PhysicalLength::new(font_metrics.height());
+    let line_height = font.line_height(font_height);
+    // The glyphs are centered vertically within the line
+    let half_leading = (line_height - font_height) / 2.;
 
     let mut cursor_point: Option<PhysicalPoint> = None;
 
     let text_height = || {
         if single_line {
-            font_height
+            line_height
         } else {
             // Note: this is kind of doing twice the layout because text_size also does it
             let text_height = font
@@ -652,8 +882,8 @@ pub(crate) fn layout_text_lines(
                 // If the full text doesn't fit into max_height and eliding is
                 // enabled, calculate the height of the max number of lines that
                 // fit to ensure correct vertical alignment when elided.
-                let max_lines = (max_height.get() / font_height.get()).floor();
-                font_height * max_lines
+                let max_lines = (max_height.get() / line_height.get()).floor();
+                line_height * max_lines
             } else {
                 text_height
             }
@@ -671,7 +901,7 @@ pub(crate) fn layout_text_lines(
                     max_width - max_width.min(PhysicalLength::new(line_metrics.width()))
                 }
             };
-            let line_pos = PhysicalPoint::from_lengths(x, y);
+            let line_pos = PhysicalPoint::from_lengths(x, y + half_leading);
             layout_line(text_span, line_pos, start, line_metrics);
 
             if let Some(cursor_byte_offset) = cursor_byte_offset {
@@ -705,8 +935,8 @@ pub(crate) fn layout_text_lines(
     };
     let mut y = baseline_y;
     let mut start = 0;
-    'lines: while start < string.len() && y + font_height <= max_height {
-        if wrap && (!elide || y + font_height * 2. <= max_height) {
+    'lines: while start < string.len() && y + line_height <= max_height {
+        if wrap && (!elide || y + line_height * 2. <= max_height) {
             let max_line_index = string[start..].find('\n').map_or(string.len(), |i| i + 1 + start);
             let index = text_context
                 .break_text(max_width.get(), &string[start..max_line_index], paint)
@@ -719,7 +949,10 @@ pub(crate) fn layout_text_lines(
             let line = string[start..index].trim_end_matches('\n');
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
             process_line(line, y, start, &text_metrics);
-            y += font_height;
+            y += line_height;
+            if line.len() < index - start {
+                y += font.paragraph_spacing();
+            }
             start = index;
         } else {
             let index = if single_line {
@@ -730,7 +963,7 @@ pub(crate) fn layout_text_lines(
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
             let elide_last_line =
-                elide && index < string.len() && y + font_height * 2. > max_height;
+                elide && index < string.len() && y + line_height * 2. > max_height;
             if text_metrics.width() > max_width.get() || elide_last_line {
                 let w = max_width
                     - if elide {
@@ -751,7 +984,7 @@ pub(crate) fn layout_text_lines(
                         } else {
                             process_line(txt, y, start, &text_metrics);
                         }
-                        y += font_height;
+                        y += line_height + font.paragraph_spacing();
                         start = index + 1;
                         continue 'lines;
                     }
@@ -759,13 +992,13 @@ pub(crate) fn layout_text_lines(
                 if elide_last_line {
                     let elided = format!("{}…", line.strip_suffix('\n').unwrap_or(line));
                     process_line(&elided, y, start, &text_metrics);
-                    y += font_height;
+                    y += line_height + font.paragraph_spacing();
                     start = index + 1;
                     continue 'lines;
                 }
             }
             process_line(line, y, start, &text_metrics);
-            y += font_height;
+            y += line_height + font.paragraph_spacing();
             start = index + 1;
         }
     }
@@ -777,7 +1010,58 @@ pub(crate) fn layout_text_lines(
                 TextHorizontalAlignment::Center => max_size.width_length() / 2.,
                 TextHorizontalAlignment::Right => max_size.width_length(),
             };
-            PhysicalPoint::from_lengths(x, y)
+            PhysicalPoint::from_lengths(x, y + half_leading)
         })
     })
 }
+
+/// Returns the byte offset in `string`, laid out like [`layout_text_lines`] does, that is the closest
+/// to the given position.
+pub(crate) fn byte_offset_for_position(
+    string: &str,
+    font: &Font,
+    max_size: PhysicalSize,
+    alignment: (TextHorizontalAlignment, TextVerticalAlignment),
+    wrap: TextWrap,
+    overflow: TextOverflow,
+    single_line: bool,
+    paint: &femtovg::Paint,
+    pos: PhysicalPoint,
+) -> usize {
+    if max_size.width <= 0. || max_size.height <= 0. || pos.y < 0. {
+        return 0;
+    }
+
+    let text_context = FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
+    let font_height = PhysicalLength::new(text_context.measure_font(paint).unwrap().height());
+    let line_height = font.line_height(font_height).get();
+    let half_leading = (line_height - font_height.get()) / 2.;
+
+    let mut result = string.len();
+    layout_text_lines(
+        string,
+        font,
+        max_size,
+        alignment,
+        wrap,
+        overflow,
+        single_line,
+        None,
+        paint,
+        |line_text, line_pos, start, metrics| {
+            let line_top = line_pos.y - half_leading;
+            if (line_top..(line_top + line_height)).contains(&pos.y) {
+                let mut current_x = 0.;
+                for glyph in &metrics.glyphs {
+                    if line_pos.x + current_x + glyph.advance_x / 2. >= pos.x {
+                        result = start + glyph.byte_index;
+                        return;
+                    }
+                    current_x += glyph.advance_x;
+                }
+                result = start + line_text.trim_end().len();
+            }
+        },
+    );
+    result
+}
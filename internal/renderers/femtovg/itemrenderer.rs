@@ -13,7 +13,7 @@
 use i_slint_core::graphics::{IntRect, Point, Size};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage,
-    RenderRectangle, RenderText,
+    RenderRectangle, RenderText, TextDecoration,
 };
 use i_slint_core::items::{
     self, Clip, FillRule, ImageRendering, ImageTiling, ItemRc, Layer, Opacity, RenderingResult,
@@ -23,6 +23,8 @@
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     RectLengths, ScaleFactor, SizeLengths,
 };
+use i_slint_core::styled_text::StyledText;
+use i_slint_core::textlayout::{DecorationMetrics, FontMetrics};
 use i_slint_core::{Brush, Color, ImageInner, SharedString};
 
 use crate::images::TextureImporter;
@@ -134,6 +136,168 @@ fn rect_to_path(r: PhysicalRect) -> femtovg::Path {
     rect_with_radius_to_path(r, PhysicalBorderRadius::default())
 }
 
+/// Fills a line of text, highlighting the part of it that is within `selection`. `start` is the byte offset
+/// of the line within the text, and `selection` is a range of byte offsets within the text.
+fn fill_text_line_with_selection<R: femtovg::Renderer>(
+    canvas: &mut Canvas<R>,
+    to_draw: &str,
+    pos: PhysicalPoint,
+    start: usize,
+    metrics: &femtovg::TextMetrics,
+    selection: core::ops::Range<usize>,
+    font_height: PhysicalLength,
+    paint: &femtovg::Paint,
+    (selection_foreground, selection_background): (Color, Color),
+) {
+    let (min_select, max_select) = (selection.start, selection.end);
+    let range = start..(start + to_draw.len());
+    if min_select != max_select
+        && (range.contains(&min_select)
+            || range.contains(&max_select)
+            || (min_select..max_select).contains(&start))
+    {
+        let mut selection_start_x = PhysicalLength::default();
+        let mut selection_end_x = PhysicalLength::default();
+        let mut after_selection_x = PhysicalLength::default();
+        // Determine the first and last (inclusive) glyph of the selection. The anchor
+        // will always be at the start of a grapheme boundary, so there's at ShapedGlyph
+        // that has a matching byte index. For the selection end we have to look for the
+        // visual end of glyph before the cursor, because due to for example ligatures
+        // (or generally glyph substitution) there may not be a dedicated glyph.
+        // FIXME: in the case of ligature, there is currently no way to know the exact
+        // position of the split. When we know it, we might need to draw in two
+        // steps with clip to draw each part of the ligature in a different color
+        for glyph in &metrics.glyphs {
+            if glyph.byte_index == min_select.saturating_sub(start) {
+                selection_start_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
+            }
+            if glyph.byte_index == max_select - start || glyph.byte_index >= to_draw.len() {
+                after_selection_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
+                break;
+            }
+            selection_end_x = PhysicalLength::new(glyph.x + glyph.advance_x);
+        }
+
+        let selection_rect = PhysicalRect::new(
+            pos + PhysicalPoint::from_lengths(selection_start_x, PhysicalLength::default())
+                .to_vector(),
+            PhysicalSize::from_lengths(selection_end_x - selection_start_x, font_height),
+        );
+        canvas.fill_path(
+            &rect_to_path(selection_rect),
+            &femtovg::Paint::color(to_femtovg_color(&selection_background)),
+        );
+        let mut selected_paint = paint.clone();
+        selected_paint.set_color(to_femtovg_color(&selection_foreground));
+        canvas
+            .fill_text(pos.x, pos.y, to_draw[..min_select.saturating_sub(start)].trim_end(), paint)
+            .unwrap();
+        canvas
+            .fill_text(
+                pos.x + selection_start_x.get(),
+                pos.y,
+                to_draw[min_select.saturating_sub(start)..(max_select - start).min(to_draw.len())]
+                    .trim_end(),
+                &selected_paint,
+            )
+            .unwrap();
+        canvas
+            .fill_text(
+                pos.x + after_selection_x.get(),
+                pos.y,
+                to_draw[(max_select - start).min(to_draw.len())..].trim_end(),
+                paint,
+            )
+            .unwrap();
+    } else {
+        // no selection on this line
+        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), paint).unwrap();
+    };
+}
+
+/// Draws the underline, overline and strikethrough of a line of text that was drawn at `pos`.
+/// `paint` is the paint of the text, used if the decoration doesn't specify a color.
+fn draw_text_line_decoration<R: femtovg::Renderer>(
+    canvas: &mut Canvas<R>,
+    to_draw: &str,
+    pos: PhysicalPoint,
+    metrics: &femtovg::TextMetrics,
+    font: &fonts::Font,
+    decoration: &TextDecoration,
+    paint: &femtovg::Paint,
+) {
+    let visible_len = to_draw.trim_end().len();
+    let Some((start_x, end_x)) = metrics
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.byte_index < visible_len)
+        .map(|glyph| (glyph.x - glyph.bearing_x, glyph.x - glyph.bearing_x + glyph.advance_x))
+        .reduce(|(s1, e1), (s2, e2)| (s1.min(s2), e1.max(e2)))
+    else {
+        return;
+    };
+
+    draw_text_decoration_lines(
+        canvas,
+        (pos.x + start_x)..(pos.x + end_x),
+        pos.y + font.ascent().get(),
+        font.ascent(),
+        &font.decoration_metrics(),
+        decoration,
+        paint,
+    );
+}
+
+/// Draws the underline, overline and strikethrough spanning `x_range` horizontally, for a font
+/// with the given ascent and metrics whose baseline is at `baseline_y`.
+fn draw_text_decoration_lines<R: femtovg::Renderer>(
+    canvas: &mut Canvas<R>,
+    x_range: core::ops::Range<f32>,
+    baseline_y: f32,
+    ascent: PhysicalLength,
+    metrics: &DecorationMetrics<PhysicalLength>,
+    decoration: &TextDecoration,
+    paint: &femtovg::Paint,
+) {
+    let mut paint = if decoration.color.alpha() == 0 {
+        paint.clone()
+    } else {
+        femtovg::Paint::color(to_femtovg_color(&decoration.color))
+    };
+    let lines = [
+        (decoration.underline, metrics.underline_position, metrics.underline_thickness),
+        (decoration.overline, ascent, metrics.underline_thickness),
+        (decoration.strikethrough, metrics.strikethrough_position, metrics.strikethrough_thickness),
+    ];
+    for (_, position, thickness) in lines.into_iter().filter(|line| line.0) {
+        let thickness = thickness.get().max(1.);
+        let y = baseline_y - position.get();
+        let mut path = femtovg::Path::new();
+        match decoration.style {
+            items::TextDecorationStyle::Solid => {
+                path.rect(x_range.start, y, x_range.end - x_range.start, thickness);
+                canvas.fill_path(&path, &paint);
+            }
+            items::TextDecorationStyle::Wavy => {
+                // A zigzag around the center of the line.
+                let amplitude = thickness;
+                let half_period = 2. * thickness;
+                let center_y = y + thickness / 2.;
+                let mut x = x_range.start;
+                path.move_to(x, center_y);
+                let mut up = true;
+                while x < x_range.end {
+                    x = (x + half_period).min(x_range.end);
+                    path.line_to(x, if up { center_y - amplitude } else { center_y + amplitude });
+                    up = !up;
+                }
+                paint.set_line_width(thickness);
+                canvas.stroke_path(&path, &paint);
+            }
+        }
+    }
+}
+
 fn adjust_rect_and_border_for_inner_drawing(
     rect: &mut PhysicalRect,
     border_width: &mut PhysicalLength,
@@ -340,6 +504,11 @@ fn draw_text(
             return;
         }
 
+        if let Some(styled_text) = text.styled_text() {
+            self.draw_styled_text(text, self_rc, &styled_text, size);
+            return;
+        }
+
         let string = text.text();
         let string = string.as_str();
         let font = fonts::FONT_CACHE.with(|cache| {
@@ -375,6 +544,10 @@ fn draw_text(
             None => None,
         };
 
+        let selection = text.selection();
+        let decoration = text.decoration();
+        let font_height = font.height();
+
         let mut canvas = self.canvas.borrow_mut();
         fonts::layout_text_lines(
             string,
@@ -386,20 +559,47 @@ fn draw_text(
             false,
             None,
             &paint,
-            |to_draw, pos, _, _| {
+            |to_draw, pos, start, metrics| {
+                let fill_text = |canvas: &mut Canvas<R>| match &selection {
+                    Some(selection) => fill_text_line_with_selection(
+                        canvas,
+                        to_draw,
+                        pos,
+                        start,
+                        metrics,
+                        selection.range.clone(),
+                        font_height,
+                        &paint,
+                        (selection.foreground, selection.background),
+                    ),
+                    None => {
+                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                    }
+                };
                 match (stroke_style, &stroke_paint) {
                     (TextStrokeStyle::Outside, Some(stroke_paint)) => {
                         canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        fill_text(&mut canvas);
                     }
                     (TextStrokeStyle::Center, Some(stroke_paint)) => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        fill_text(&mut canvas);
                         canvas.stroke_text(pos.x, pos.y, to_draw.trim_end(), stroke_paint).unwrap();
                     }
                     _ => {
-                        canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
+                        fill_text(&mut canvas);
                     }
                 };
+                if !decoration.is_empty() {
+                    draw_text_line_decoration(
+                        &mut canvas,
+                        to_draw,
+                        pos,
+                        metrics,
+                        &font,
+                        &decoration,
+                        &paint,
+                    );
+                }
             },
         );
     }
@@ -451,6 +651,7 @@ fn draw_text_input(
                 (false, 0)
             };
 
+        let decoration = text_input.decoration();
         let mut canvas = self.canvas.borrow_mut();
         let font_height = font.height();
         let text: SharedString = visual_representation.text.into();
@@ -466,86 +667,31 @@ fn draw_text_input(
             cursor_visible.then_some(cursor_pos),
             &paint,
             |to_draw: &str, pos: PhysicalPoint, start, metrics: &femtovg::TextMetrics| {
-                let range = start..(start + to_draw.len());
-                if min_select != max_select
-                    && (range.contains(&min_select)
-                        || range.contains(&max_select)
-                        || (min_select..max_select).contains(&start))
-                {
-                    let mut selection_start_x = PhysicalLength::default();
-                    let mut selection_end_x = PhysicalLength::default();
-                    let mut after_selection_x = PhysicalLength::default();
-                    // Determine the first and last (inclusive) glyph of the selection. The anchor
-                    // will always be at the start of a grapheme boundary, so there's at ShapedGlyph
-                    // that has a matching byte index. For the selection end we have to look for the
-                    // visual end of glyph before the cursor, because due to for example ligatures
-                    // (or generally glyph substitution) there may not be a dedicated glyph.
-                    // FIXME: in the case of ligature, there is currently no way to know the exact
-                    // position of the split. When we know it, we might need to draw in two
-                    // steps with clip to draw each part of the ligature in a different color
-                    for glyph in &metrics.glyphs {
-                        if glyph.byte_index == min_select.saturating_sub(start) {
-                            selection_start_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
-                        }
-                        if glyph.byte_index == max_select - start
-                            || glyph.byte_index >= to_draw.len()
-                        {
-                            after_selection_x = PhysicalLength::new(glyph.x - glyph.bearing_x);
-                            break;
-                        }
-                        selection_end_x = PhysicalLength::new(glyph.x + glyph.advance_x);
-                    }
-
-                    let selection_rect = PhysicalRect::new(
-                        pos + PhysicalPoint::from_lengths(
-                            selection_start_x,
-                            PhysicalLength::default(),
-                        )
-                        .to_vector(),
-                        PhysicalSize::from_lengths(
-                            selection_end_x - selection_start_x,
-                            font_height,
-                        ),
-                    );
-                    canvas.fill_path(
-                        &rect_to_path(selection_rect),
-                        &femtovg::Paint::color(to_femtovg_color(
-                            &text_input.selection_background_color(),
-                        )),
+                fill_text_line_with_selection(
+                    &mut canvas,
+                    to_draw,
+                    pos,
+                    start,
+                    metrics,
+                    min_select..max_select,
+                    font_height,
+                    &paint,
+                    (
+                        text_input.selection_foreground_color(),
+                        text_input.selection_background_color(),
+                    ),
+                );
+                if !decoration.is_empty() {
+                    draw_text_line_decoration(
+                        &mut canvas,
+                        to_draw,
+                        pos,
+                        metrics,
+                        &font,
+                        &decoration,
+                        &paint,
                     );
-                    let mut selected_paint = paint.clone();
-                    selected_paint
-                        .set_color(to_femtovg_color(&text_input.selection_foreground_color()));
-                    canvas
-                        .fill_text(
-                            pos.x,
-                            pos.y,
-                            to_draw[..min_select.saturating_sub(start)].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + selection_start_x.get(),
-                            pos.y,
-                            to_draw[min_select.saturating_sub(start)
-                                ..(max_select - start).min(to_draw.len())]
-                                .trim_end(),
-                            &selected_paint,
-                        )
-                        .unwrap();
-                    canvas
-                        .fill_text(
-                            pos.x + after_selection_x.get(),
-                            pos.y,
-                            to_draw[(max_select - start).min(to_draw.len())..].trim_end(),
-                            &paint,
-                        )
-                        .unwrap();
-                } else {
-                    // no selection on this line
-                    canvas.fill_text(pos.x, pos.y, to_draw.trim_end(), &paint).unwrap();
-                };
+                }
             },
         );
 
@@ -1542,6 +1688,110 @@ fn draw_image_impl(
         }
     }
 
+    fn draw_styled_text(
+        &mut self,
+        text: Pin<&dyn RenderText>,
+        self_rc: &ItemRc,
+        styled_text: &StyledText,
+        size: LogicalSize,
+    ) {
+        let text_path = rect_to_path((size * self.scale_factor).into());
+        let font_request = text.font_request(self_rc);
+        // For each span, the font with the paint of the span's color, whether that paint is
+        // visible, and the placement of the decoration lines
+        let fonts = fonts::styled_text_fonts(styled_text, &font_request, self.scale_factor)
+            .iter()
+            .zip(&styled_text.spans)
+            .map(|((font, letter_spacing), span)| {
+                let brush = span.style.color.map_or_else(|| text.color(), Brush::from);
+                let paint = self.brush_to_paint(brush, &text_path);
+                let visible = paint.is_some();
+                let painted_font =
+                    fonts::PaintedFont::new(font, *letter_spacing, paint.unwrap_or_default());
+                (painted_font, visible, font.decoration_metrics())
+            })
+            .collect::<Vec<_>>();
+        let span_layouts = fonts::span_layouts(styled_text, fonts.iter().map(|(font, ..)| font));
+        let layout = fonts::styled_text_layout(
+            styled_text,
+            &span_layouts,
+            size * self.scale_factor,
+            text.alignment(),
+            text.wrap(),
+            text.overflow(),
+        );
+
+        let decoration = text.decoration();
+        let mut canvas = self.canvas.borrow_mut();
+        layout
+            .layout_lines::<()>(|glyphs, line, text_line| {
+                let baseline_y = line.y + line.ascent;
+                let mut glyphs = glyphs.collect::<Vec<_>>();
+                // The ellipsis isn't part of the text, so it's drawn on its own
+                let ellipsis = line.ellipsis_byte_offset.and_then(|byte_offset| {
+                    glyphs
+                        .last()
+                        .is_some_and(|(_, glyph)| glyph.text_byte_offset == byte_offset)
+                        .then(|| glyphs.pop())
+                        .flatten()
+                });
+                let line_end = line.ellipsis_byte_offset.unwrap_or(text_line.byte_range.end);
+                // Draw the glyphs of consecutive spans with a single call each
+                let mut start = 0;
+                while start < glyphs.len() {
+                    let span_index = glyphs[start].0;
+                    let end = glyphs[start..]
+                        .iter()
+                        .position(|(index, _)| *index != span_index)
+                        .map_or(glyphs.len(), |len| start + len);
+                    let byte_start = glyphs[start].1.text_byte_offset;
+                    let byte_end =
+                        glyphs.get(end).map_or(line_end, |(_, glyph)| glyph.text_byte_offset);
+                    let (font, visible, decoration_metrics) = &fonts[span_index];
+                    if *visible && byte_start < byte_end {
+                        let x = line.x + glyphs[start].1.x;
+                        canvas
+                            .fill_text(
+                                x.get(),
+                                baseline_y.get(),
+                                &styled_text.text[byte_start..byte_end],
+                                &font.paint,
+                            )
+                            .unwrap();
+                        // The underline of links and `<u>` is drawn with the style of the element's decoration
+                        let decoration = TextDecoration {
+                            underline: decoration.underline
+                                || styled_text.spans[span_index].style.underline,
+                            ..decoration
+                        };
+                        if !decoration.is_empty() {
+                            let last_glyph = &glyphs[end - 1].1;
+                            draw_text_decoration_lines(
+                                &mut canvas,
+                                x.get()..(line.x + last_glyph.x + last_glyph.advance).get(),
+                                baseline_y.get(),
+                                font.ascent(),
+                                decoration_metrics,
+                                &decoration,
+                                &font.paint,
+                            );
+                        }
+                    }
+                    start = end;
+                }
+                if let Some((span_index, glyph)) = ellipsis {
+                    let (font, visible, _) = &fonts[span_index];
+                    if *visible {
+                        canvas
+                            .fill_text((line.x + glyph.x).get(), baseline_y.get(), "…", &font.paint)
+                            .unwrap();
+                    }
+                }
+                core::ops::ControlFlow::Continue(())
+            })
+            .ok();
+    }
+
     fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::Paint> {
         if brush.is_transparent() {
             return None;
@@ -14,13 +14,14 @@
 use i_slint_core::graphics::{euclid, rendering_metrics_collector::RenderingMetricsCollector};
 use i_slint_core::graphics::{BorderRadius, Rgba8Pixel};
 use i_slint_core::graphics::{FontRequest, SharedPixelBuffer};
-use i_slint_core::item_rendering::ItemRenderer;
-use i_slint_core::items::TextWrap;
+use i_slint_core::item_rendering::{ItemRenderer, RenderText};
+use i_slint_core::items::{ItemRc, TextWrap};
 use i_slint_core::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PhysicalPx, ScaleFactor,
 };
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::RendererSealed;
+use i_slint_core::styled_text::StyledText;
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::Brush;
 use images::TextureImporter;
@@ -307,6 +308,17 @@ fn text_size(
         crate::fonts::text_size(&font_request, scale_factor, text, max_width)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        text: &StyledText,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        crate::fonts::styled_text_size(&font_request, scale_factor, text, max_width, text_wrap)
+    }
+
     fn font_metrics(
         &self,
         font_request: i_slint_core::graphics::FontRequest,
@@ -322,54 +334,83 @@ fn text_input_byte_offset_for_position(
         font_request: FontRequest,
         scale_factor: ScaleFactor,
     ) -> usize {
-        let pos = pos * scale_factor;
-        let text = text_input.text();
-
-        let mut result = text.len();
-
-        let width = text_input.width() * scale_factor;
-        let height = text_input.height() * scale_factor;
-        if width.get() <= 0. || height.get() <= 0. || pos.y < 0. {
-            return 0;
-        }
-
         let font = crate::fonts::FONT_CACHE
             .with(|cache| cache.borrow_mut().font(font_request, scale_factor, &text_input.text()));
 
         let visual_representation = text_input.visual_representation(None);
 
         let paint = font.init_paint(text_input.letter_spacing() * scale_factor, Default::default());
-        let text_context =
-            crate::fonts::FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
-        let font_height = text_context.measure_font(&paint).unwrap().height();
-        crate::fonts::layout_text_lines(
+        let result = crate::fonts::byte_offset_for_position(
             &visual_representation.text,
             &font,
-            PhysicalSize::from_lengths(width, height),
+            PhysicalSize::from_lengths(
+                text_input.width() * scale_factor,
+                text_input.height() * scale_factor,
+            ),
             (text_input.horizontal_alignment(), text_input.vertical_alignment()),
             text_input.wrap(),
             i_slint_core::items::TextOverflow::Clip,
             text_input.single_line(),
-            None,
             &paint,
-            |line_text, line_pos, start, metrics| {
-                if (line_pos.y..(line_pos.y + font_height)).contains(&pos.y) {
-                    let mut current_x = 0.;
-                    for glyph in &metrics.glyphs {
-                        if line_pos.x + current_x + glyph.advance_x / 2. >= pos.x {
-                            result = start + glyph.byte_index;
-                            return;
-                        }
-                        current_x += glyph.advance_x;
-                    }
-                    result = start + line_text.trim_end().len();
-                }
-            },
+            pos * scale_factor,
         );
 
         visual_representation.map_byte_offset_from_byte_offset_in_visual_text(result)
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn RenderText>,
+        self_rc: &ItemRc,
+        pos: LogicalPoint,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let string = text.text();
+        let font = crate::fonts::FONT_CACHE.with(|cache| {
+            cache.borrow_mut().font(text.font_request(self_rc), scale_factor, &string)
+        });
+        let paint = font.init_paint(text.letter_spacing() * scale_factor, Default::default());
+        crate::fonts::byte_offset_for_position(
+            &string,
+            &font,
+            text.target_size() * scale_factor,
+            text.alignment(),
+            text.wrap(),
+            text.overflow(),
+            false,
+            &paint,
+            pos * scale_factor,
+        )
+    }
+
+    fn styled_text_byte_offset_for_position(
+        &self,
+        text: Pin<&dyn RenderText>,
+        self_rc: &ItemRc,
+        styled_text: &StyledText,
+        pos: LogicalPoint,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let font_request = text.font_request(self_rc);
+        let fonts = fonts::styled_text_fonts(styled_text, &font_request, scale_factor)
+            .iter()
+            .map(|(font, letter_spacing)| {
+                fonts::PaintedFont::new(font, *letter_spacing, Default::default())
+            })
+            .collect::<Vec<_>>();
+        let span_layouts = fonts::span_layouts(styled_text, &fonts);
+        let layout = fonts::styled_text_layout(
+            styled_text,
+            &span_layouts,
+            text.target_size() * scale_factor,
+            text.alignment(),
+            text.wrap(),
+            text.overflow(),
+        );
+        let pos = pos * scale_factor;
+        layout.byte_offset_for_position((PhysicalLength::new(pos.x), PhysicalLength::new(pos.y)))
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -12,6 +12,7 @@
 use i_slint_core::graphics::ApproxEq;
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, ItemRendererFeatures, RenderImage, RenderText,
+    TextDecoration,
 };
 use i_slint_core::items::{
     ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult, TextStrokeStyle,
@@ -20,6 +21,7 @@
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
     LogicalVector, PhysicalPx, RectLengths, ScaleFactor, SizeLengths,
 };
+use i_slint_core::styled_text::StyledText;
 use i_slint_core::window::WindowInner;
 use i_slint_core::{Brush, Color};
 use skia_safe::{Matrix, TileMode};
@@ -76,6 +78,55 @@ fn default_paint(&self) -> Option<skia_safe::Paint> {
         }
     }
 
+    fn draw_styled_text(
+        &mut self,
+        text: Pin<&dyn RenderText>,
+        self_rc: &i_slint_core::items::ItemRc,
+        styled_text: &StyledText,
+        max_width: PhysicalLength,
+        max_height: PhysicalLength,
+    ) {
+        // Spans may have their own color, so a transparent text color doesn't mean nothing is visible
+        let paint_or_transparent = |brush: Brush| {
+            self.brush_to_paint(brush, max_width, max_height).unwrap_or_else(|| {
+                let mut paint = skia_safe::Paint::default();
+                paint.set_color(skia_safe::Color::TRANSPARENT);
+                paint
+            })
+        };
+
+        let mut text_style = skia_safe::textlayout::TextStyle::new();
+        text_style.set_foreground_paint(&paint_or_transparent(text.color()));
+        let decoration = text.decoration();
+
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+        let (layout, layout_top_left) = super::textlayout::create_styled_layout(
+            text.font_request(self_rc),
+            self.scale_factor,
+            styled_text,
+            Some(text_style),
+            |span_style, span_text_style| {
+                if let Some(color) = span_style.color {
+                    span_text_style.set_foreground_paint(&paint_or_transparent(color.into()));
+                }
+                // The underline of links and `<u>` is drawn with the style of the element's decoration
+                let decoration = TextDecoration {
+                    underline: decoration.underline || span_style.underline,
+                    ..decoration
+                };
+                super::textlayout::apply_decoration(span_text_style, &decoration);
+            },
+            Some(max_width),
+            max_height,
+            horizontal_alignment,
+            vertical_alignment,
+            text.wrap(),
+            text.overflow(),
+        );
+
+        layout.paint(self.canvas, to_skia_point(layout_top_left));
+    }
+
     fn brush_to_paint(
         &self,
         brush: Brush,
@@ -514,6 +565,11 @@ fn draw_text(
             return;
         }
 
+        if let Some(styled_text) = text.styled_text() {
+            self.draw_styled_text(text, self_rc, &styled_text, max_width, max_height);
+            return;
+        }
+
         let string = text.text();
         let string = string.as_str();
         let font_request = text.font_request(self_rc);
@@ -525,6 +581,7 @@ fn draw_text(
 
         let mut text_style = skia_safe::textlayout::TextStyle::new();
         text_style.set_foreground_paint(&paint);
+        super::textlayout::apply_decoration(&mut text_style, &text.decoration());
 
         let (stroke_brush, stroke_width, stroke_style) = text.stroke();
         let (horizontal_alignment, vertical_alignment) = text.alignment();
@@ -570,6 +627,13 @@ fn draw_text(
             None => None,
         };
 
+        let selection = text.selection().map(|selection| super::textlayout::Selection {
+            range: selection.range,
+            foreground: Some(selection.foreground),
+            background: Some(selection.background),
+            underline: false,
+        });
+
         let (layout, layout_top_left) = super::textlayout::create_layout(
             font_request,
             self.scale_factor,
@@ -581,7 +645,7 @@ fn draw_text(
             vertical_alignment,
             text.wrap(),
             text.overflow(),
-            None,
+            selection.as_ref(),
         );
 
         match (stroke_style, stroke_layout) {
@@ -623,6 +687,7 @@ fn draw_text_input(
 
         let mut text_style = skia_safe::textlayout::TextStyle::new();
         text_style.set_foreground_paint(&paint);
+        super::textlayout::apply_decoration(&mut text_style, &text_input.decoration());
 
         let selection = if !visual_representation.preedit_range.is_empty() {
             Some(super::textlayout::Selection {
@@ -770,6 +770,32 @@ fn text_size(
             / scale_factor
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: i_slint_core::graphics::FontRequest,
+        text: &i_slint_core::styled_text::StyledText,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap, // Skia's paragraphs only wrap at word boundaries, so char-wrap is word-wrap
+    ) -> LogicalSize {
+        let (layout, _) = textlayout::create_styled_layout(
+            font_request,
+            scale_factor,
+            text,
+            None,
+            |_, _| {},
+            max_width.map(|w| w * scale_factor),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            if max_width.is_some() { text_wrap } else { TextWrap::NoWrap },
+            Default::default(),
+        );
+
+        PhysicalSize::new(layout.max_intrinsic_width().ceil(), layout.height().ceil())
+            / scale_factor
+    }
+
     fn font_metrics(
         &self,
         font_request: i_slint_core::graphics::FontRequest,
@@ -826,6 +852,82 @@ fn text_input_byte_offset_for_position(
         visual_representation.map_byte_offset_from_byte_offset_in_visual_text(byte_offset)
     }
 
+    fn text_byte_offset_for_position(
+        &self,
+        text: std::pin::Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        self_rc: &i_slint_core::items::ItemRc,
+        pos: LogicalPoint,
+        scale_factor: ScaleFactor,
+    ) -> usize {
+        let size = text.target_size() * scale_factor;
+        let pos = pos * scale_factor;
+
+        if size.width <= 0. || size.height <= 0. {
+            return 0;
+        }
+
+        let string = text.text();
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+        let (layout, layout_top_left) = textlayout::create_layout(
+            text.font_request(self_rc),
+            scale_factor,
+            &string,
+            None,
+            Some(PhysicalLength::new(size.width)),
+            PhysicalLength::new(size.height),
+            horizontal_alignment,
+            vertical_alignment,
+            text.wrap(),
+            text.overflow(),
+            None,
+        );
+
+        let utf16_index =
+            layout.get_glyph_position_at_coordinate((pos.x, pos.y - layout_top_left.y)).position;
+        let mut utf16_count = 0;
+        string
+            .char_indices()
+            .find(|(_, x)| {
+                let r = utf16_count >= utf16_index;
+                utf16_count += x.len_utf16() as i32;
+                r
+            })
+            .map_or(string.len(), |(byte_offset, _)| byte_offset)
+    }
+
+    fn styled_text_byte_offset_for_position(
+        &self,
+        text: std::pin::Pin<&dyn i_slint_core::item_rendering::RenderText>,
+        self_rc: &i_slint_core::items::ItemRc,
+        styled_text: &i_slint_core::styled_text::StyledText,
+        pos: LogicalPoint,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let size = text.target_size() * scale_factor;
+        let pos = pos * scale_factor;
+        let (horizontal_alignment, vertical_alignment) = text.alignment();
+
+        let (layout, layout_top_left) = textlayout::create_styled_layout(
+            text.font_request(self_rc),
+            scale_factor,
+            styled_text,
+            None,
+            |_, _| {},
+            Some(PhysicalLength::new(size.width)),
+            PhysicalLength::new(size.height),
+            horizontal_alignment,
+            vertical_alignment,
+            text.wrap(),
+            text.overflow(),
+        );
+
+        textlayout::byte_offset_for_position(
+            &styled_text.text,
+            &layout,
+            PhysicalPoint::new(pos.x - layout_top_left.x, pos.y - layout_top_left.y),
+        )
+    }
+
     fn text_input_cursor_rect_for_byte_offset(
         &self,
         text_input: std::pin::Pin<&i_slint_core::items::TextInput>,
@@ -6,8 +6,10 @@
 
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::FontRequest;
+use i_slint_core::item_rendering::TextDecoration;
 use i_slint_core::items::{TextHorizontalAlignment, TextVerticalAlignment};
 use i_slint_core::lengths::{LogicalLength, ScaleFactor};
+use i_slint_core::styled_text::{SpanStyle, StyledText};
 use i_slint_core::{items, Color};
 
 use super::itemrenderer::to_skia_color;
@@ -58,6 +60,37 @@ pub struct Selection {
     pub underline: bool,
 }
 
+/// Sets the underline, overline and strikethrough of a `Text` or `TextInput` on the text style.
+/// The foreground paint must already be set, as it provides the default decoration color.
+pub fn apply_decoration(
+    text_style: &mut skia_safe::textlayout::TextStyle,
+    decoration: &TextDecoration,
+) {
+    if decoration.is_empty() {
+        return;
+    }
+    let mut skia_decoration = skia_safe::textlayout::Decoration::default();
+    for (enabled, ty) in [
+        (decoration.underline, skia_safe::textlayout::TextDecoration::UNDERLINE),
+        (decoration.overline, skia_safe::textlayout::TextDecoration::OVERLINE),
+        (decoration.strikethrough, skia_safe::textlayout::TextDecoration::LINE_THROUGH),
+    ] {
+        if enabled {
+            skia_decoration.ty |= ty;
+        }
+    }
+    skia_decoration.style = match decoration.style {
+        items::TextDecorationStyle::Solid => skia_safe::textlayout::TextDecorationStyle::Solid,
+        items::TextDecorationStyle::Wavy => skia_safe::textlayout::TextDecorationStyle::Wavy,
+    };
+    skia_decoration.color = if decoration.color.alpha() == 0 {
+        text_style.foreground().color()
+    } else {
+        to_skia_color(&decoration.color)
+    };
+    text_style.set_decoration(&skia_decoration);
+}
+
 fn font_style_for_request(font_request: &FontRequest) -> skia_safe::FontStyle {
     skia_safe::FontStyle::new(
         font_request.weight.map_or(skia_safe::font_style::Weight::NORMAL, |w| w.into()),
@@ -84,48 +117,30 @@ pub fn create_layout(
     selection: Option<&Selection>,
 ) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
     let mut text_style = text_style.unwrap_or_default();
-
-    if let Some(family_name) = font_request.family.as_ref() {
-        text_style.set_font_families(&[family_name.as_str()]);
-    }
-
-    let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
-
-    if let Some(letter_spacing) = font_request.letter_spacing {
-        text_style.set_letter_spacing((letter_spacing * scale_factor).get());
-    }
-    text_style.set_font_size(pixel_size.get());
-    text_style.set_font_style(font_style_for_request(&font_request));
+    apply_font_request(&mut text_style, &font_request, scale_factor);
+    let paragraph_spacing = font_request.paragraph_spacing.map(|spacing| spacing * scale_factor);
 
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
 
-    if overflow == items::TextOverflow::Elide {
-        style.set_ellipsis("…");
-        if wrap != items::TextWrap::NoWrap {
-            let metrics = text_style.font_metrics();
-            let line_height = metrics.descent - metrics.ascent + metrics.leading;
-            style.set_max_lines((max_height.get() / line_height).floor() as usize);
-        }
-    }
+    apply_overflow(
+        &mut style,
+        &text_style,
+        &font_request,
+        scale_factor,
+        max_height,
+        wrap,
+        overflow,
+    );
 
-    style.set_text_align(match h_align {
-        items::TextHorizontalAlignment::Left => skia_safe::textlayout::TextAlign::Left,
-        items::TextHorizontalAlignment::Center => skia_safe::textlayout::TextAlign::Center,
-        items::TextHorizontalAlignment::Right => skia_safe::textlayout::TextAlign::Right,
-    });
+    style.set_text_align(text_align(h_align));
 
     style.set_text_style(&text_style);
 
-    let mut builder = FONT_CACHE.with(|font_cache| {
-        skia_safe::textlayout::ParagraphBuilder::new(
-            &style,
-            font_cache.font_collection.borrow().clone(),
-        )
-    });
+    let mut builder = paragraph_builder(&style);
 
     if let Some(selection) = selection {
         let before_selection = &text[..selection.range.start];
-        builder.add_text(before_selection);
+        add_text(&mut builder, before_selection, &text_style, paragraph_spacing);
 
         let mut selection_style = text_style.clone();
 
@@ -150,15 +165,199 @@ pub fn create_layout(
 
         builder.push_style(&selection_style);
         let selected_text = &text[selection.range.clone()];
-        builder.add_text(selected_text);
+        add_text(&mut builder, selected_text, &selection_style, paragraph_spacing);
         builder.pop();
 
         let after_selection = &text[selection.range.end..];
-        builder.add_text(after_selection);
+        add_text(&mut builder, after_selection, &text_style, paragraph_spacing);
     } else {
+        add_text(&mut builder, text, &text_style, paragraph_spacing);
+    }
+
+    layout_paragraph(builder, max_width, max_height, v_align)
+}
+
+/// Creates a paragraph for styled text, where each span is added with a style derived from `text_style`
+/// and the span's style. `apply_span_paint` is invoked for each span to set the paint of the span's style,
+/// when rendering.
+pub fn create_styled_layout(
+    font_request: FontRequest,
+    scale_factor: ScaleFactor,
+    text: &StyledText,
+    text_style: Option<skia_safe::textlayout::TextStyle>,
+    mut apply_span_paint: impl FnMut(&SpanStyle, &mut skia_safe::textlayout::TextStyle),
+    max_width: Option<PhysicalLength>,
+    max_height: PhysicalLength,
+    h_align: items::TextHorizontalAlignment,
+    v_align: TextVerticalAlignment,
+    wrap: items::TextWrap,
+    overflow: items::TextOverflow,
+) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
+    let mut text_style = text_style.unwrap_or_default();
+    apply_font_request(&mut text_style, &font_request, scale_factor);
+    let paragraph_spacing = font_request.paragraph_spacing.map(|spacing| spacing * scale_factor);
+
+    let mut style = skia_safe::textlayout::ParagraphStyle::new();
+    apply_overflow(
+        &mut style,
+        &text_style,
+        &font_request,
+        scale_factor,
+        max_height,
+        wrap,
+        overflow,
+    );
+    style.set_text_align(text_align(h_align));
+    style.set_text_style(&text_style);
+
+    let mut builder = paragraph_builder(&style);
+
+    for span in &text.spans {
+        let mut span_text_style = text_style.clone();
+        apply_font_request(
+            &mut span_text_style,
+            &span.style.font_request(&font_request),
+            scale_factor,
+        );
+        if span.style.underline {
+            let mut decoration = skia_safe::textlayout::Decoration::default();
+            decoration.ty = skia_safe::textlayout::TextDecoration::UNDERLINE;
+            span_text_style.set_decoration(&decoration);
+        }
+        apply_span_paint(&span.style, &mut span_text_style);
+        builder.push_style(&span_text_style);
+        add_text(&mut builder, &text.text[span.range.clone()], &span_text_style, paragraph_spacing);
+        builder.pop();
+    }
+
+    // Skia elides the lines that are too long instead of wrapping them
+    if wrap != items::TextWrap::NoWrap || overflow == items::TextOverflow::Elide {
+        return layout_paragraph(builder, max_width, max_height, v_align);
+    }
+
+    // Without wrapping, the paragraph is laid out as wide as its longest line, and then aligned
+    // within the available width.
+    let (mut paragraph, mut top_left) = layout_paragraph(builder, None, max_height, v_align);
+    let Some(max_width) = max_width else {
+        return (paragraph, top_left);
+    };
+    let width = PhysicalLength::new(paragraph.max_intrinsic_width().ceil());
+    paragraph.layout(width.get());
+    top_left.x = match h_align {
+        items::TextHorizontalAlignment::Left => 0.,
+        items::TextHorizontalAlignment::Center => ((max_width - width) / 2.).get(),
+        items::TextHorizontalAlignment::Right => (max_width - width).get(),
+    };
+    (paragraph, top_left)
+}
+
+/// Sets up the paragraph style to elide the text with an ellipsis, if `overflow` is [`items::TextOverflow::Elide`].
+/// When the text wraps, the last line that fits in `max_height` is elided.
+fn apply_overflow(
+    style: &mut skia_safe::textlayout::ParagraphStyle,
+    text_style: &skia_safe::textlayout::TextStyle,
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+    max_height: PhysicalLength,
+    wrap: items::TextWrap,
+    overflow: items::TextOverflow,
+) {
+    if overflow != items::TextOverflow::Elide {
+        return;
+    }
+    style.set_ellipsis("…");
+    if wrap != items::TextWrap::NoWrap {
+        let line_height = match font_request.line_height {
+            Some(line_height) => (line_height * scale_factor).get(),
+            None => {
+                let metrics = text_style.font_metrics();
+                metrics.descent - metrics.ascent + metrics.leading
+            }
+        };
+        style.set_max_lines((max_height.get() / line_height).floor() as usize);
+    }
+}
+
+fn apply_font_request(
+    text_style: &mut skia_safe::textlayout::TextStyle,
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+) {
+    if let Some(family_name) = font_request.family.as_ref() {
+        text_style.set_font_families(&[family_name.as_str()]);
+    }
+
+    let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
+
+    if let Some(letter_spacing) = font_request.letter_spacing {
+        text_style.set_letter_spacing((letter_spacing * scale_factor).get());
+    }
+    text_style.set_font_size(pixel_size.get());
+    text_style.set_font_style(font_style_for_request(font_request));
+
+    if let Some(line_height) = font_request.line_height {
+        // The height is a multiple of the font size, and the extra space is split evenly above and below the glyphs
+        text_style.set_height_override(true);
+        text_style.set_height((line_height * scale_factor).get() / pixel_size.get());
+        text_style.set_half_leading(true);
+    }
+}
+
+/// Adds the text to the paragraph being built, where `text_style` is the style currently pushed.
+/// Skia has no paragraph spacing, so the line breaks are shifted down by the spacing instead, which makes
+/// the last line of each paragraph taller.
+fn add_text(
+    builder: &mut skia_safe::textlayout::ParagraphBuilder,
+    text: &str,
+    text_style: &skia_safe::textlayout::TextStyle,
+    paragraph_spacing: Option<PhysicalLength>,
+) {
+    let Some(paragraph_spacing) = paragraph_spacing else {
         builder.add_text(text);
+        return;
+    };
+    let mut line_break_style = text_style.clone();
+    line_break_style.set_baseline_shift(paragraph_spacing.get());
+    for chunk in text.split_inclusive('\n') {
+        match chunk.strip_suffix('\n') {
+            Some(line) => {
+                builder.add_text(line);
+                builder.push_style(&line_break_style);
+                builder.add_text("\n");
+                builder.pop();
+            }
+            None => {
+                builder.add_text(chunk);
+            }
+        }
+    }
+}
+
+fn text_align(h_align: items::TextHorizontalAlignment) -> skia_safe::textlayout::TextAlign {
+    match h_align {
+        items::TextHorizontalAlignment::Left => skia_safe::textlayout::TextAlign::Left,
+        items::TextHorizontalAlignment::Center => skia_safe::textlayout::TextAlign::Center,
+        items::TextHorizontalAlignment::Right => skia_safe::textlayout::TextAlign::Right,
     }
+}
+
+fn paragraph_builder(
+    style: &skia_safe::textlayout::ParagraphStyle,
+) -> skia_safe::textlayout::ParagraphBuilder {
+    FONT_CACHE.with(|font_cache| {
+        skia_safe::textlayout::ParagraphBuilder::new(
+            style,
+            font_cache.font_collection.borrow().clone(),
+        )
+    })
+}
 
+fn layout_paragraph(
+    mut builder: skia_safe::textlayout::ParagraphBuilder,
+    max_width: Option<PhysicalLength>,
+    max_height: PhysicalLength,
+    v_align: TextVerticalAlignment,
+) -> (skia_safe::textlayout::Paragraph, PhysicalPoint) {
     let mut paragraph = builder.build();
     paragraph.layout(max_width.map_or(f32::MAX, |physical_width| physical_width.get()));
 
@@ -173,6 +372,38 @@ pub fn create_layout(
     (paragraph, PhysicalPoint::from_lengths(Default::default(), layout_top_y))
 }
 
+/// Returns the byte offset in `text` of the character whose glyph is at the given position in the
+/// paragraph, or None if there is no glyph at that position.
+pub fn byte_offset_for_position(
+    text: &str,
+    layout: &skia_safe::textlayout::Paragraph,
+    pos: PhysicalPoint,
+) -> Option<usize> {
+    let utf16_index =
+        layout.get_glyph_position_at_coordinate((pos.x, pos.y)).position.max(0) as usize;
+    let mut utf16_start = 0;
+    text.char_indices().find_map(|(byte_offset, ch)| {
+        let utf16_range = utf16_start..utf16_start + ch.len_utf16();
+        utf16_start = utf16_range.end;
+        // Skia reports the nearest cursor position, so check the characters on both sides of it.
+        if utf16_range.end < utf16_index || utf16_range.start > utf16_index {
+            return None;
+        }
+        layout
+            .get_rects_for_range(
+                utf16_range,
+                skia_safe::textlayout::RectHeightStyle::Max,
+                skia_safe::textlayout::RectWidthStyle::Tight,
+            )
+            .iter()
+            .any(|text_box| {
+                let rect = &text_box.rect;
+                (rect.left..rect.right).contains(&pos.x) && (rect.top..rect.bottom).contains(&pos.y)
+            })
+            .then_some(byte_offset)
+    })
+}
+
 pub fn font_metrics(
     font_request: i_slint_core::graphics::FontRequest,
     scale_factor: ScaleFactor,
@@ -46,12 +46,7 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
 
     let mut generated_cpp: Vec<u8> = Vec::new();
 
-    generator::generate(
-        output_format,
-        &mut generated_cpp,
-        &root_component,
-        &loader.compiler_config,
-    )?;
+    generator::generate(output_format, &mut generated_cpp, &root_component, &loader)?;
 
     if diag.has_errors() {
         let vec = diag.to_string_vec();
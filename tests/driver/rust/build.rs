@@ -166,11 +166,6 @@ fn generate_source(
         diag.print();
     }
 
-    generator::generate(
-        generator::OutputFormat::Rust,
-        output,
-        &root_component,
-        &loader.compiler_config,
-    )?;
+    generator::generate(generator::OutputFormat::Rust, output, &root_component, &loader)?;
     Ok(())
 }
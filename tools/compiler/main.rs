@@ -7,6 +7,8 @@
 use itertools::Itertools;
 use std::io::{BufWriter, Write};
 
+mod report;
+
 #[cfg(all(
     feature = "jemalloc",
     not(any(target_os = "windows", all(target_arch = "aarch64", target_os = "linux")))
@@ -43,7 +45,8 @@ enum Embedding {
 #[command(author, version, about, long_about = None)]
 struct Cli {
     /// Set the output format for generated code.
-    /// Possible values: 'cpp' for C++ code or 'rust' for Rust code.
+    /// Possible values: 'cpp' for C++ code, 'rust' for Rust code, or 'slint' for a single
+    /// self-contained .slint file with all imports inlined.
     #[arg(short = 'f', long = "format", default_value = "cpp")]
     format: generator::OutputFormat,
 
@@ -108,6 +111,18 @@ struct Cli {
     /// If specified multiple times, the definitions are split across multiple `.cpp` files.
     #[arg(long = "cpp-file", name = "output .cpp file", number_of_values = 1)]
     cpp_files: Vec<std::path::PathBuf>,
+
+    /// Instead of generating code, output a report of the project structure: the import graph
+    /// between .slint files and, for each component, the components it instantiates, the globals
+    /// it reads, and its element, property and binding counts. The files that are loaded but
+    /// not used are listed as well.
+    #[arg(
+        long,
+        name = "report format",
+        value_enum,
+        conflicts_with_all = ["format", "dependency file"]
+    )]
+    report: Option<report::ReportFormat>,
 }
 
 fn main() -> std::io::Result<()> {
@@ -185,19 +200,44 @@ fn main() -> std::io::Result<()> {
         compiler_config.translation_path_bundle = Some(path);
     }
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
+
+    if let Some(report_format) = args.report {
+        // The report is about the documents as written, so it uses the type loader from
+        // before the components are lowered and inlined.
+        let path = syntax_node.source_file.path().to_path_buf();
+        let (_, diag, _, raw_loader) =
+            spin_on::spin_on(i_slint_compiler::load_root_file_with_raw_type_loader(
+                &path,
+                &path,
+                syntax_node.text().to_string(),
+                BuildDiagnostics::default(),
+                compiler_config,
+            ));
+        let diag = diag.check_and_exit_on_error();
+        let raw_loader = raw_loader.expect("diags contained no compilation errors");
+        let output = report::generate(report_format, &path, &raw_loader);
+        if args.output == std::path::Path::new("-") {
+            write!(std::io::stdout(), "{output}")?;
+        } else {
+            std::fs::write(&args.output, output)?;
+        }
+        diag.print_warnings_and_exit_on_error();
+        return Ok(());
+    }
+
     let (doc, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
     let diag = diag.check_and_exit_on_error();
 
     if args.output == std::path::Path::new("-") {
-        generator::generate(format, &mut std::io::stdout(), &doc, &loader.compiler_config)?;
+        generator::generate(format, &mut std::io::stdout(), &doc, &loader)?;
     } else {
         generator::generate(
             format,
             &mut BufWriter::new(std::fs::File::create(&args.output)?),
             &doc,
-            &loader.compiler_config,
+            &loader,
         )?;
     }
 
@@ -0,0 +1,365 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Implementation of `slint-compiler --report`: the import graph between the `.slint` files,
+//! the files that are loaded but not used, and for every component, the components it
+//! instantiates, the globals it reads and some statistics about its size.
+//!
+//! The report is computed from the documents as they were written, before the passes that
+//! lower and inline the components.
+
+use clap::ValueEnum;
+use i_slint_compiler::langtype::ElementType;
+use i_slint_compiler::object_tree::{self, Component, Document};
+use i_slint_compiler::typeloader::{ImportKind, TypeLoader};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum ReportFormat {
+    /// A JSON object with a `files` and an `unused_files` array
+    Json,
+    /// A graphviz digraph of the files and components
+    Dot,
+}
+
+struct FileReport {
+    path: PathBuf,
+    imports: Vec<PathBuf>,
+    components: Vec<ComponentReport>,
+    used: bool,
+}
+
+struct ComponentReport {
+    component: Rc<Component>,
+    elements: usize,
+    properties: usize,
+    bindings: usize,
+    instantiates: Vec<Rc<Component>>,
+    globals: Vec<Rc<Component>>,
+}
+
+/// Generate the report for the document at `root_path`.
+///
+/// `loader` must be a type loader on which only the import passes were run, such as the
+/// raw type loader returned by [`i_slint_compiler::load_root_file_with_raw_type_loader`].
+pub fn generate(format: ReportFormat, root_path: &Path, loader: &TypeLoader) -> String {
+    let mut documents = loader.all_documents().collect::<Vec<_>>();
+    // The root document first, then the other ones sorted by path
+    documents.sort_by_key(|d| (document_path(d) != root_path, document_path(d)));
+    let mut files = documents.into_iter().map(file_report).collect::<Vec<_>>();
+    mark_used_files(&mut files, root_path);
+
+    match format {
+        ReportFormat::Json => to_json(&files),
+        ReportFormat::Dot => to_dot(&files),
+    }
+}
+
+fn document_path(doc: &Document) -> PathBuf {
+    doc.node.as_ref().map(|n| n.source_file.path().to_path_buf()).unwrap_or_default()
+}
+
+fn component_path(component: &Component) -> PathBuf {
+    component.node.as_ref().map(|n| n.source_file.path().to_path_buf()).unwrap_or_default()
+}
+
+fn is_builtin(path: &Path) -> bool {
+    i_slint_compiler::fileaccess::load_file(path).is_some_and(|f| f.is_builtin())
+}
+
+fn file_report(doc: &Document) -> FileReport {
+    let mut imports = doc
+        .imports
+        .iter()
+        .filter(|i| !matches!(i.import_kind, ImportKind::FileImport))
+        .map(|i| PathBuf::from(&i.file))
+        .collect::<Vec<_>>();
+    imports.sort();
+    imports.dedup();
+    FileReport {
+        path: document_path(doc),
+        imports,
+        components: doc.inner_components.iter().map(component_report).collect(),
+        used: false,
+    }
+}
+
+fn component_report(component: &Rc<Component>) -> ComponentReport {
+    let mut report = ComponentReport {
+        component: component.clone(),
+        elements: 0,
+        properties: 0,
+        bindings: 0,
+        instantiates: Vec::new(),
+        globals: Vec::new(),
+    };
+    object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        report.elements += 1;
+        let e = elem.borrow();
+        report.properties += e.property_declarations.len();
+        report.bindings += e.bindings.len();
+        if let ElementType::Component(base) = &e.base_type {
+            if !report.instantiates.iter().any(|c| Rc::ptr_eq(c, base)) {
+                report.instantiates.push(base.clone());
+            }
+        }
+        drop(e);
+        object_tree::visit_element_expressions(elem, |expr, _, _| {
+            object_tree::visit_named_references_in_expression(expr, &mut |nr| {
+                let Some(c) = nr.element().borrow().enclosing_component.upgrade() else { return };
+                if c.is_global()
+                    && !Rc::ptr_eq(&c, component)
+                    && !report.globals.iter().any(|g| Rc::ptr_eq(g, &c))
+                {
+                    report.globals.push(c);
+                }
+            })
+        });
+    });
+    report.instantiates.sort_by(|a, b| a.id.cmp(&b.id));
+    report.globals.sort_by(|a, b| a.id.cmp(&b.id));
+    report
+}
+
+/// A file is used if it is the root file, or if it declares a component that is reachable
+/// from the components of the root file. Files without components (that only declare
+/// structs or enums, or only re-export) are considered used unless all they import is unused.
+fn mark_used_files(files: &mut [FileReport], root_path: &Path) {
+    let components: HashMap<*const Component, &ComponentReport> = files
+        .iter()
+        .flat_map(|f| f.components.iter().map(|c| (Rc::as_ptr(&c.component), c)))
+        .collect();
+
+    let mut reachable = HashSet::new();
+    let mut queue = files
+        .iter()
+        .filter(|f| f.path == root_path)
+        .flat_map(|f| f.components.iter().map(|c| Rc::as_ptr(&c.component)))
+        .collect::<Vec<_>>();
+    while let Some(c) = queue.pop() {
+        if !reachable.insert(c) {
+            continue;
+        }
+        if let Some(report) = components.get(&c) {
+            queue.extend(report.instantiates.iter().chain(&report.globals).map(Rc::as_ptr));
+        }
+    }
+
+    let mut used = files
+        .iter()
+        .map(|f| {
+            f.path == root_path
+                || (f.components.is_empty() && f.imports.is_empty())
+                || f.components.iter().any(|c| reachable.contains(&Rc::as_ptr(&c.component)))
+        })
+        .collect::<Vec<_>>();
+
+    let index: HashMap<&Path, usize> =
+        files.iter().enumerate().map(|(i, f)| (f.path.as_path(), i)).collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (i, file) in files.iter().enumerate() {
+            if used[i] || !file.components.is_empty() {
+                continue;
+            }
+            if file.imports.iter().any(|p| index.get(p.as_path()).is_some_and(|&j| used[j])) {
+                used[i] = true;
+                changed = true;
+            }
+        }
+    }
+
+    for (file, used) in files.iter_mut().zip(used) {
+        file.used = used;
+    }
+}
+
+fn component_refs(components: &[Rc<Component>]) -> serde_json::Value {
+    components
+        .iter()
+        .map(|c| json!({ "name": c.id.as_str(), "file": component_path(c).to_string_lossy() }))
+        .collect()
+}
+
+fn to_json(files: &[FileReport]) -> String {
+    let files_json = files
+        .iter()
+        .map(|file| {
+            let components = file
+                .components
+                .iter()
+                .map(|c| {
+                    json!({
+                        "name": c.component.id.as_str(),
+                        "global": c.component.is_global(),
+                        "elements": c.elements,
+                        "properties": c.properties,
+                        "bindings": c.bindings,
+                        "instantiates": component_refs(&c.instantiates),
+                        "globals": component_refs(&c.globals),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let imports = file.imports.iter().map(|p| p.to_string_lossy()).collect::<Vec<_>>();
+            json!({ "path": file.path.to_string_lossy(), "imports": imports, "components": components })
+        })
+        .collect::<Vec<_>>();
+    let unused_files = files
+        .iter()
+        .filter(|f| !f.used && !is_builtin(&f.path))
+        .map(|f| f.path.to_string_lossy())
+        .collect::<Vec<_>>();
+    let mut out =
+        serde_json::to_string_pretty(&json!({ "files": files_json, "unused_files": unused_files }))
+            .unwrap();
+    out.push('\n');
+    out
+}
+
+fn dot_string(s: &str) -> String {
+    serde_json::Value::from(s).to_string()
+}
+
+fn to_dot(files: &[FileReport]) -> String {
+    let mut out = String::from("digraph slint {\n");
+    let file_ids: HashMap<&Path, usize> =
+        files.iter().enumerate().map(|(i, f)| (f.path.as_path(), i)).collect();
+    let component_ids: HashMap<*const Component, String> = files
+        .iter()
+        .enumerate()
+        .flat_map(|(i, f)| {
+            f.components
+                .iter()
+                .enumerate()
+                .map(move |(j, c)| (Rc::as_ptr(&c.component), format!("c{i}_{j}")))
+        })
+        .collect();
+
+    for (i, file) in files.iter().enumerate() {
+        let style = if file.used { "" } else { ", style=dashed, color=gray" };
+        let label = dot_string(&file.path.to_string_lossy());
+        writeln!(out, "  f{i} [shape=note, label={label}{style}];").unwrap();
+        for import in &file.imports {
+            if let Some(j) = file_ids.get(import.as_path()) {
+                writeln!(out, "  f{i} -> f{j};").unwrap();
+            }
+        }
+        for c in &file.components {
+            let id = &component_ids[&Rc::as_ptr(&c.component)];
+            let label = format!(
+                "{}\nelements: {}, properties: {}, bindings: {}",
+                c.component.id, c.elements, c.properties, c.bindings
+            );
+            let shape = if c.component.is_global() { "ellipse" } else { "box" };
+            writeln!(out, "  {id} [shape={shape}, label={}];", dot_string(&label)).unwrap();
+            writeln!(out, "  f{i} -> {id} [style=dotted, arrowhead=none];").unwrap();
+        }
+    }
+    for file in files {
+        for c in &file.components {
+            let id = &component_ids[&Rc::as_ptr(&c.component)];
+            let targets = c
+                .instantiates
+                .iter()
+                .map(|t| (t, ""))
+                .chain(c.globals.iter().map(|g| (g, " [style=dashed]")));
+            for (target, style) in targets {
+                if let Some(target_id) = component_ids.get(&Rc::as_ptr(target)) {
+                    writeln!(out, "  {id} -> {target_id}{style};").unwrap();
+                }
+            }
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[test]
+fn test_report_multi_file_project() {
+    let files: HashMap<&str, &str> = HashMap::from([
+        ("theme.slint", "export global Theme { out property <color> accent: blue; }"),
+        (
+            "card.slint",
+            r#"
+            import { Theme } from "theme.slint";
+            export component Card inherits Rectangle {
+                in property <string> title;
+                background: Theme.accent;
+                Text { text: root.title; }
+            }
+            "#,
+        ),
+        ("unused.slint", "export component Unused inherits Rectangle { }"),
+        ("types.slint", "export struct Item { name: string }"),
+    ]);
+    let main = r#"
+        import { Card } from "card.slint";
+        import { Unused } from "unused.slint";
+        import { Item } from "types.slint";
+        export component App inherits Window {
+            in property <[Item]> items;
+            for item in items: Card { title: item.name; }
+        }
+    "#;
+
+    let mut compiler_config = i_slint_compiler::CompilerConfiguration::new(
+        i_slint_compiler::generator::OutputFormat::Llr,
+    );
+    compiler_config.style = Some("fluent".into());
+    compiler_config.open_import_fallback = Some(Rc::new(move |path| {
+        let file_name = Path::new(&path).file_name().and_then(|f| f.to_str()).unwrap_or_default();
+        let content = files.get(file_name).map(|content| Ok(content.to_string()));
+        Box::pin(async move { content })
+    }));
+
+    let root_path = Path::new("/report-test/main.slint");
+    let (_, diag, _, raw_loader) =
+        spin_on::spin_on(i_slint_compiler::load_root_file_with_raw_type_loader(
+            root_path,
+            root_path,
+            main.into(),
+            i_slint_compiler::diagnostics::BuildDiagnostics::default(),
+            compiler_config,
+        ));
+    assert!(!diag.has_errors(), "{:?}", diag.to_string_vec());
+    let report = generate(ReportFormat::Json, root_path, &raw_loader.unwrap());
+    let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+    let files = report["files"].as_array().unwrap();
+    assert_eq!(files[0]["path"], "/report-test/main.slint");
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|f| f["path"].as_str().unwrap().ends_with(name))
+            .unwrap_or_else(|| panic!("{name} not in the report"))
+    };
+
+    let app = &file("main.slint")["components"][0];
+    assert_eq!(app["name"], "App");
+    // The repeated Card is not lowered into a sub-component
+    assert_eq!(app["elements"], 2);
+    assert_eq!(app["properties"], 1);
+    assert_eq!(app["instantiates"][0]["name"], "Card");
+    assert!(app["instantiates"][0]["file"].as_str().unwrap().ends_with("card.slint"));
+
+    let card = &file("card.slint")["components"][0];
+    assert_eq!(card["elements"], 2);
+    assert_eq!(card["properties"], 1);
+    assert_eq!(card["bindings"], 2);
+    assert_eq!(card["globals"][0]["name"], "Theme");
+    assert!(file("card.slint")["imports"][0].as_str().unwrap().ends_with("theme.slint"));
+
+    let unused = report["unused_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f.as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(unused.len(), 1, "{unused:?}");
+    assert!(unused[0].ends_with("unused.slint"));
+}
@@ -12,7 +12,8 @@
 
 #[cfg(feature = "preview-engine")]
 fn builtin_component_info(name: &str) -> ComponentInformation {
-    let is_layout = matches!(name, "GridLayout" | "HorizontalLayout" | "VerticalLayout");
+    let is_layout =
+        matches!(name, "FlexLayout" | "GridLayout" | "HorizontalLayout" | "VerticalLayout");
     let is_interactive =
         matches!(name, "Flickable" | "FocusScope" | "SwipeGestureHandler" | "TouchArea");
 
@@ -29,7 +30,7 @@ fn builtin_component_info(name: &str) -> ComponentInformation {
             "Keyboard Input"
         } else if name.ends_with("Layout") {
             "Basic Layouts"
-        } else if ["Dialog", "PopupWindow", "Window"].contains(&name) {
+        } else if ["Dialog", "PopupWindow", "SecondaryWindow", "Window"].contains(&name) {
             "Window"
         } else {
             "Basic Elements"
@@ -45,6 +45,14 @@ fn layout_kind(&self) -> crate::preview::ui::LayoutKind {
                 orientation: layout::Orientation::Vertical,
                 ..
             })) => ui::LayoutKind::Vertical,
+            Some(layout::Layout::FlexLayout(layout::FlexLayout {
+                orientation: layout::Orientation::Horizontal,
+                ..
+            })) => ui::LayoutKind::Horizontal,
+            Some(layout::Layout::FlexLayout(layout::FlexLayout {
+                orientation: layout::Orientation::Vertical,
+                ..
+            })) => ui::LayoutKind::Vertical,
             _ => ui::LayoutKind::None,
         })
     }
@@ -524,6 +524,8 @@ pub(super) fn get_properties(
                     "max-height" => p.priority = 450,
                     "horizontal-stretch" => p.priority = 500,
                     "vertical-stretch" => p.priority = 550,
+                    "flex-grow" => p.priority = 600,
+                    "flex-shrink" => p.priority = 650,
                     _ => { /* do nothing */ }
                 }
                 p